@@ -0,0 +1,214 @@
+// Composite extension, version 0.4
+// Generated by tools/xcbgen from tools/xcb-proto/composite.xml. Don't edit this file, change the generator or
+// composite_extra.rs (which has everything written by hand) instead.
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+#[allow(unused_imports)] // Some extra modules only add methods
+pub use super::composite_extra::*;
+
+pub const EXTENSION_NAME: &str = "Composite";
+pub const MAJOR_VERSION: u32 = 0;
+pub const MINOR_VERSION: u32 = 4;
+
+pub const OP_QUERY_VERSION: u8 = 0;
+pub const OP_REDIRECT_WINDOW: u8 = 1;
+pub const OP_REDIRECT_SUBWINDOWS: u8 = 2;
+pub const OP_UNREDIRECT_WINDOW: u8 = 3;
+pub const OP_UNREDIRECT_SUBWINDOWS: u8 = 4;
+pub const OP_CREATE_REGION_FROM_BORDER_CLIP: u8 = 5;
+pub const OP_NAME_WINDOW_PIXMAP: u8 = 6;
+pub const OP_GET_OVERLAY_WINDOW: u8 = 7;
+pub const OP_RELEASE_OVERLAY_WINDOW: u8 = 8;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Redirect {
+    Automatic,
+    Manual
+}
+impl Redirect {
+    pub fn get(id: u32) -> Option<Redirect> {
+        match id {
+            0 => Some(Redirect::Automatic),
+            1 => Some(Redirect::Manual),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &Redirect::Automatic => 0,
+            &Redirect::Manual => 1
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CompositeReply {
+    QueryVersion {
+        major_version: u32,
+        minor_version: u32
+    },
+    GetOverlayWindow {
+        overlay_win: u32
+    }
+}
+
+/** Decodes Composite replies and events */
+#[derive(Debug, Default)]
+pub struct Composite;
+
+impl Extension for Composite {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_QUERY_VERSION => reader.read_composite_query_version_reply(detail),
+            OP_GET_OVERLAY_WINDOW => reader.read_composite_get_overlay_window_reply(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the Composite QueryVersion request. Returns None without sending anything if the server doesn't have Composite, like every request here. */
+    pub fn composite_query_version(&mut self, client_major_version: u32, client_minor_version: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Composite>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_VERSION);
+        self.write_u16(3);
+        self.write_u32(client_major_version);
+        self.write_u32(client_minor_version);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_VERSION)))
+    }
+
+    /** Sends the Composite RedirectWindow request */
+    pub fn composite_redirect_window(&mut self, window: u32, update: &Redirect) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Composite>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_REDIRECT_WINDOW);
+        self.write_u16(3);
+        self.write_u32(window);
+        self.write_u8(update.val() as u8);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the Composite RedirectSubwindows request */
+    pub fn composite_redirect_subwindows(&mut self, window: u32, update: &Redirect) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Composite>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_REDIRECT_SUBWINDOWS);
+        self.write_u16(3);
+        self.write_u32(window);
+        self.write_u8(update.val() as u8);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the Composite UnredirectWindow request */
+    pub fn composite_unredirect_window(&mut self, window: u32, update: &Redirect) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Composite>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_UNREDIRECT_WINDOW);
+        self.write_u16(3);
+        self.write_u32(window);
+        self.write_u8(update.val() as u8);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the Composite UnredirectSubwindows request */
+    pub fn composite_unredirect_subwindows(&mut self, window: u32, update: &Redirect) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Composite>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_UNREDIRECT_SUBWINDOWS);
+        self.write_u16(3);
+        self.write_u32(window);
+        self.write_u8(update.val() as u8);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the Composite CreateRegionFromBorderClip request */
+    pub fn composite_create_region_from_border_clip(&mut self, region: u32, window: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Composite>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_REGION_FROM_BORDER_CLIP);
+        self.write_u16(3);
+        self.write_u32(region);
+        self.write_u32(window);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the Composite NameWindowPixmap request */
+    pub fn composite_name_window_pixmap(&mut self, window: u32, pixmap: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Composite>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_NAME_WINDOW_PIXMAP);
+        self.write_u16(3);
+        self.write_u32(window);
+        self.write_u32(pixmap);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the Composite GetOverlayWindow request */
+    pub fn composite_get_overlay_window(&mut self, window: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Composite>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_OVERLAY_WINDOW);
+        self.write_u16(2);
+        self.write_u32(window);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_OVERLAY_WINDOW)))
+    }
+
+    /** Sends the Composite ReleaseOverlayWindow request */
+    pub fn composite_release_overlay_window(&mut self, window: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Composite>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_RELEASE_OVERLAY_WINDOW);
+        self.write_u16(2);
+        self.write_u32(window);
+
+        self.write_request();
+        Some(())
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to Composite QueryVersion */
+    fn read_composite_query_version_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let major_version = self.read_u32();
+        let minor_version = self.read_u32();
+        self.read_pad(16);
+        Some(ServerReply::Composite(CompositeReply::QueryVersion { major_version, minor_version }))
+    }
+
+    /** Reads a reply to Composite GetOverlayWindow */
+    fn read_composite_get_overlay_window_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let overlay_win = self.read_u32();
+        self.read_pad(20);
+        Some(ServerReply::Composite(CompositeReply::GetOverlayWindow { overlay_win }))
+    }
+}
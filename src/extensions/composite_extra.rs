@@ -0,0 +1,24 @@
+// Window::name_pixmap, for the off-screen pixmap of a redirected window.
+
+use models::{Pixmap, Window};
+use XClient;
+
+impl Window {
+    /**
+     * Names the off-screen storage of this window, which must be redirected (see composite_redirect_window).
+     * The pixmap includes the border, so it can be used with copy_area and get_image like any other Pixmap.
+     * It keeps the current contents until it is freed: resizing or remapping the window allocates new storage, which needs a new name.
+     * None if the server doesn't have Composite.
+     */
+    pub fn name_pixmap(&self, client: &mut XClient) -> Option<Pixmap> {
+        let pid = client.new_resource_id();
+        client.composite_name_window_pixmap(self.wid, pid)?;
+        Some(Pixmap {
+            depth: self.depth,
+            pid,
+            drawable: self.wid,
+            width: self.width + 2 * self.border_width,
+            height: self.height + 2 * self.border_width
+        })
+    }
+}
@@ -0,0 +1,181 @@
+// DAMAGE extension, version 1.1
+// Generated by tools/xcbgen from tools/xcb-proto/damage.xml. Don't edit this file, change the generator or
+// damage_extra.rs (which has everything written by hand) instead.
+//
+// Written by hand in damage_extra.rs:
+//   - event Notify
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{ServerEvent, ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+#[allow(unused_imports)] // Some extra modules only add methods
+pub use super::damage_extra::*;
+
+pub const EXTENSION_NAME: &str = "DAMAGE";
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 1;
+
+pub const OP_QUERY_VERSION: u8 = 0;
+pub const OP_CREATE: u8 = 1;
+pub const OP_DESTROY: u8 = 2;
+pub const OP_SUBTRACT: u8 = 3;
+pub const OP_ADD: u8 = 4;
+
+pub const EVENT_NOTIFY: u8 = 0;
+
+pub const ERROR_BAD_DAMAGE: u8 = 0;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ReportLevel {
+    RawRectangles,
+    DeltaRectangles,
+    BoundingBox,
+    NonEmpty
+}
+impl ReportLevel {
+    pub fn get(id: u32) -> Option<ReportLevel> {
+        match id {
+            0 => Some(ReportLevel::RawRectangles),
+            1 => Some(ReportLevel::DeltaRectangles),
+            2 => Some(ReportLevel::BoundingBox),
+            3 => Some(ReportLevel::NonEmpty),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &ReportLevel::RawRectangles => 0,
+            &ReportLevel::DeltaRectangles => 1,
+            &ReportLevel::BoundingBox => 2,
+            &ReportLevel::NonEmpty => 3
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum DamageReply {
+    QueryVersion {
+        major_version: u32,
+        minor_version: u32
+    }
+}
+
+#[derive(Debug)]
+pub enum DamageEvent {
+    Notify(NotifyEvent)
+}
+
+/** Decodes DAMAGE replies and events */
+#[derive(Debug, Default)]
+pub struct Damage;
+
+impl Extension for Damage {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn event_count(&self) -> u8 {
+        1
+    }
+
+    fn error_names(&self) -> &'static [&'static str] {
+        &["BadDamage"]
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_QUERY_VERSION => reader.read_damage_query_version_reply(detail),
+            _ => None
+        }
+    }
+
+    fn read_event(&self, reader: &mut XReadHelper, code: u8, detail: u8) -> Option<ServerEvent> {
+        match code {
+            EVENT_NOTIFY => reader.read_damage_notify_event(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the DAMAGE QueryVersion request. Returns None without sending anything if the server doesn't have DAMAGE, like every request here. */
+    pub fn damage_query_version(&mut self, client_major_version: u32, client_minor_version: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Damage>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_VERSION);
+        self.write_u16(3);
+        self.write_u32(client_major_version);
+        self.write_u32(client_minor_version);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_VERSION)))
+    }
+
+    /** Sends the DAMAGE Create request */
+    pub fn damage_create(&mut self, damage: u32, drawable: u32, level: &ReportLevel) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Damage>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE);
+        self.write_u16(4);
+        self.write_u32(damage);
+        self.write_u32(drawable);
+        self.write_u8(level.val() as u8);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the DAMAGE Destroy request */
+    pub fn damage_destroy(&mut self, damage: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Damage>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_DESTROY);
+        self.write_u16(2);
+        self.write_u32(damage);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the DAMAGE Subtract request */
+    pub fn damage_subtract(&mut self, damage: u32, repair: u32, parts: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Damage>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SUBTRACT);
+        self.write_u16(4);
+        self.write_u32(damage);
+        self.write_u32(repair);
+        self.write_u32(parts);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the DAMAGE Add request */
+    pub fn damage_add(&mut self, drawable: u32, region: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Damage>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_ADD);
+        self.write_u16(3);
+        self.write_u32(drawable);
+        self.write_u32(region);
+
+        self.write_request();
+        Some(())
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to DAMAGE QueryVersion */
+    fn read_damage_query_version_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let major_version = self.read_u32();
+        let minor_version = self.read_u32();
+        self.read_pad(16);
+        Some(ServerReply::Damage(DamageReply::QueryVersion { major_version, minor_version }))
+    }
+}
@@ -0,0 +1,40 @@
+// The DAMAGE Notify event, with the "more follow" bit split out of its level, and DamageSubtract of everything.
+
+use models::{Rectangle, ServerEvent};
+use xreaderwriter::{XBufferedReader, XReadHelper};
+use XClient;
+
+use super::damage::*;
+
+/** A DamageNotify event: part of a drawable changed */
+#[derive(Debug, Clone)]
+pub struct NotifyEvent {
+    pub level: ReportLevel,
+    pub more: bool, // More DamageNotify events for the same damage follow this one
+    pub drawable: u32,
+    pub damage: u32,
+    pub timestamp: u32,
+    pub area: Rectangle, // What changed, relative to the drawable
+    pub geometry: Rectangle // Where the drawable is, relative to its parent (root, for a window)
+}
+
+impl XClient {
+    /** Marks everything a damage object has collected as repaired, so the next change reports again */
+    pub fn damage_subtract_all(&mut self, damage: u32) -> Option<()> {
+        self.damage_subtract(damage, 0, 0)
+    }
+}
+
+impl XReadHelper {
+    /** Reads a DAMAGE Notify event. The top bit of `level` says if more follow. */
+    pub(crate) fn read_damage_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let level = ReportLevel::get((detail & 0x7F) as u32)?;
+        let more = detail & 0x80 != 0;
+        let drawable = self.read_u32();
+        let damage = self.read_u32();
+        let timestamp = self.read_u32();
+        let area = Rectangle { x: self.read_i16(), y: self.read_i16(), width: self.read_u16(), height: self.read_u16() };
+        let geometry = Rectangle { x: self.read_i16(), y: self.read_i16(), width: self.read_u16(), height: self.read_u16() };
+        Some(ServerEvent::Damage(DamageEvent::Notify(NotifyEvent { level, more, drawable, damage, timestamp, area, geometry })))
+    }
+}
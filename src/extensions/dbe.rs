@@ -0,0 +1,307 @@
+// DOUBLE-BUFFER extension, version 1.0
+// Generated by tools/xcbgen from tools/xcb-proto/dbe.xml. Don't edit this file, change the generator or
+// dbe_extra.rs (which has everything written by hand) instead.
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+#[allow(unused_imports)] // Some extra modules only add methods
+pub use super::dbe_extra::*;
+
+pub const EXTENSION_NAME: &str = "DOUBLE-BUFFER";
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 0;
+
+pub const OP_QUERY_VERSION: u8 = 0;
+pub const OP_ALLOCATE_BACK_BUFFER: u8 = 1;
+pub const OP_DEALLOCATE_BACK_BUFFER: u8 = 2;
+pub const OP_SWAP_BUFFERS: u8 = 3;
+pub const OP_BEGIN_IDIOM: u8 = 4;
+pub const OP_END_IDIOM: u8 = 5;
+pub const OP_GET_VISUAL_INFO: u8 = 6;
+pub const OP_GET_BACK_BUFFER_ATTRIBUTES: u8 = 7;
+
+pub const ERROR_BAD_BUFFER: u8 = 0;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SwapAction {
+    Undefined,
+    Background,
+    Untouched,
+    Copied
+}
+impl SwapAction {
+    pub fn get(id: u32) -> Option<SwapAction> {
+        match id {
+            0 => Some(SwapAction::Undefined),
+            1 => Some(SwapAction::Background),
+            2 => Some(SwapAction::Untouched),
+            3 => Some(SwapAction::Copied),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &SwapAction::Undefined => 0,
+            &SwapAction::Background => 1,
+            &SwapAction::Untouched => 2,
+            &SwapAction::Copied => 3
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SwapInfo {
+    pub window: u32,
+    pub swap_action: u8
+}
+impl SwapInfo {
+    pub fn read(reader: &mut XReadHelper) -> SwapInfo {
+        let window = reader.read_u32();
+        let swap_action = reader.read_u8();
+        reader.read_pad(3);
+        SwapInfo { window, swap_action }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.window);
+        client.write_u8(self.swap_action);
+        client.write_pad(3);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct BufferAttributes {
+    pub window: u32
+}
+impl BufferAttributes {
+    pub fn read(reader: &mut XReadHelper) -> BufferAttributes {
+        let window = reader.read_u32();
+        BufferAttributes { window }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.window);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct VisualInfo {
+    pub visual_id: u32,
+    pub depth: u8,
+    pub perf_level: u8
+}
+impl VisualInfo {
+    pub fn read(reader: &mut XReadHelper) -> VisualInfo {
+        let visual_id = reader.read_u32();
+        let depth = reader.read_u8();
+        let perf_level = reader.read_u8();
+        reader.read_pad(2);
+        VisualInfo { visual_id, depth, perf_level }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.visual_id);
+        client.write_u8(self.depth);
+        client.write_u8(self.perf_level);
+        client.write_pad(2);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VisualInfos {
+    pub infos: Vec<VisualInfo>
+}
+impl VisualInfos {
+    pub fn read(reader: &mut XReadHelper) -> VisualInfos {
+        let n_infos = reader.read_u32();
+        let mut infos = Vec::with_capacity(n_infos as usize);
+        for _ in 0..n_infos as usize {
+            infos.push(VisualInfo::read(reader));
+        }
+        VisualInfos { infos }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.infos.len() as u32);
+        for value in &self.infos {
+            value.write(client);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum DbeReply {
+    QueryVersion {
+        major_version: u8,
+        minor_version: u8
+    },
+    GetVisualInfo {
+        supported_visuals: Vec<VisualInfos>
+    },
+    GetBackBufferAttributes {
+        attributes: BufferAttributes
+    }
+}
+
+/** Decodes DOUBLE-BUFFER replies and events */
+#[derive(Debug, Default)]
+pub struct Dbe;
+
+impl Extension for Dbe {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn error_names(&self) -> &'static [&'static str] {
+        &["BadBuffer"]
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_QUERY_VERSION => reader.read_dbe_query_version_reply(detail),
+            OP_GET_VISUAL_INFO => reader.read_dbe_get_visual_info_reply(detail),
+            OP_GET_BACK_BUFFER_ATTRIBUTES => reader.read_dbe_get_back_buffer_attributes_reply(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Queries the version of this extension. Returns None without sending anything if the server doesn't have DOUBLE-BUFFER, like every request here. */
+    pub fn dbe_query_version(&mut self, major_version: u8, minor_version: u8) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Dbe>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_VERSION);
+        self.write_u16(2);
+        self.write_u8(major_version);
+        self.write_u8(minor_version);
+        self.write_pad(2);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_VERSION)))
+    }
+
+    /** Allocates a back buffer */
+    pub fn dbe_allocate_back_buffer(&mut self, window: u32, buffer: u32, swap_action: u8) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Dbe>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_ALLOCATE_BACK_BUFFER);
+        self.write_u16(4);
+        self.write_u32(window);
+        self.write_u32(buffer);
+        self.write_u8(swap_action);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Deallocates a back buffer */
+    pub fn dbe_deallocate_back_buffer(&mut self, buffer: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Dbe>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_DEALLOCATE_BACK_BUFFER);
+        self.write_u16(2);
+        self.write_u32(buffer);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Swaps front and back buffers */
+    pub fn dbe_swap_buffers(&mut self, actions: &Vec<SwapInfo>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Dbe>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SWAP_BUFFERS);
+        let pad = self.write_dynamic_len(2, actions.len() * 8);
+        self.write_u32(actions.len() as u32);
+        for value in actions {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Begins a logical swap block */
+    pub fn dbe_begin_idiom(&mut self) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Dbe>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_BEGIN_IDIOM);
+        self.write_u16(1);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Ends a logical swap block */
+    pub fn dbe_end_idiom(&mut self) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Dbe>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_END_IDIOM);
+        self.write_u16(1);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Requests visuals that support double buffering */
+    pub fn dbe_get_visual_info(&mut self, drawables: &Vec<u32>) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Dbe>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_VISUAL_INFO);
+        let pad = self.write_dynamic_len(2, drawables.len() * 4);
+        self.write_u32(drawables.len() as u32);
+        for value in drawables {
+            self.write_u32(*value);
+        }
+        self.write_pad_op(pad);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_VISUAL_INFO)))
+    }
+
+    /** Gets back buffer attributes */
+    pub fn dbe_get_back_buffer_attributes(&mut self, buffer: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Dbe>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_BACK_BUFFER_ATTRIBUTES);
+        self.write_u16(2);
+        self.write_u32(buffer);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_BACK_BUFFER_ATTRIBUTES)))
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to DOUBLE-BUFFER QueryVersion */
+    fn read_dbe_query_version_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let major_version = self.read_u8();
+        let minor_version = self.read_u8();
+        self.read_pad(22);
+        Some(ServerReply::Dbe(DbeReply::QueryVersion { major_version, minor_version }))
+    }
+
+    /** Reads a reply to DOUBLE-BUFFER GetVisualInfo */
+    fn read_dbe_get_visual_info_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let n_supported_visuals = self.read_u32();
+        self.read_pad(20);
+        let mut supported_visuals = Vec::with_capacity(n_supported_visuals as usize);
+        for _ in 0..n_supported_visuals as usize {
+            supported_visuals.push(VisualInfos::read(self));
+        }
+        Some(ServerReply::Dbe(DbeReply::GetVisualInfo { supported_visuals }))
+    }
+
+    /** Reads a reply to DOUBLE-BUFFER GetBackBufferAttributes */
+    fn read_dbe_get_back_buffer_attributes_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let attributes = BufferAttributes::read(self);
+        self.read_pad(20);
+        Some(ServerReply::Dbe(DbeReply::GetBackBufferAttributes { attributes }))
+    }
+}
@@ -0,0 +1,56 @@
+// BackBuffer, a window's back buffer as a Drawable.
+
+use models::{Drawable, ServerReply, ServerResponse};
+use XClient;
+
+use super::dbe::*;
+
+/**
+ * The back buffer of a window. Draw into it like any other Drawable, then swap to show it all at once.
+ * It has the window's size, and goes away with the window.
+ */
+#[derive(Debug)]
+pub struct BackBuffer {
+    pub buffer: u32, // Back buffer's ID
+    pub window: u32
+}
+
+impl BackBuffer {
+    /** Gives a window a back buffer. `swap_action` is a hint for what swaps will use. None if the server doesn't have DOUBLE-BUFFER. */
+    pub fn allocate(client: &mut XClient, window: u32, swap_action: &SwapAction) -> Option<BackBuffer> {
+        let buffer = client.new_resource_id();
+        client.dbe_allocate_back_buffer(window, buffer, swap_action.val() as u8)?;
+        Some(BackBuffer { buffer, window })
+    }
+
+    /** Shows what was drawn into the back buffer */
+    pub fn swap(&self, client: &mut XClient, swap_action: &SwapAction) {
+        client.dbe_swap_buffers(&vec![SwapInfo { window: self.window, swap_action: swap_action.val() as u8 }]);
+    }
+
+    /** Frees the back buffer's ID (the buffer itself goes when the window stops using it) */
+    pub fn deallocate(&self, client: &mut XClient) {
+        client.dbe_deallocate_back_buffer(self.buffer);
+    }
+}
+
+impl Drawable for BackBuffer {
+    #[inline(always)]
+    fn get_drawable(&self) -> u32 {
+        self.buffer
+    }
+}
+
+impl XClient {
+    /** Gets the double buffered visuals of every screen. Blocks. */
+    pub fn dbe_get_visual_info_sync(&mut self) -> Vec<VisualInfos> {
+        let seq = match self.dbe_get_visual_info(&vec![]) {
+            Some(seq) => seq,
+            None => return vec![]
+        };
+        match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::Dbe(DbeReply::GetVisualInfo { supported_visuals }), _) => supported_visuals,
+            _ => vec![]
+        }
+    }
+}
@@ -0,0 +1,222 @@
+// DPMS extension, version 0.0
+// Generated by tools/xcbgen from tools/xcb-proto/dpms.xml. Don't edit this file, change the generator or
+// add a dpms_extra.rs for anything that has to be written by hand.
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+pub const EXTENSION_NAME: &str = "DPMS";
+pub const MAJOR_VERSION: u32 = 0;
+pub const MINOR_VERSION: u32 = 0;
+
+pub const OP_GET_VERSION: u8 = 0;
+pub const OP_CAPABLE: u8 = 1;
+pub const OP_GET_TIMEOUTS: u8 = 2;
+pub const OP_SET_TIMEOUTS: u8 = 3;
+pub const OP_ENABLE: u8 = 4;
+pub const OP_DISABLE: u8 = 5;
+pub const OP_FORCE_LEVEL: u8 = 6;
+pub const OP_INFO: u8 = 7;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DPMSMode {
+    On,
+    Standby,
+    Suspend,
+    Off
+}
+impl DPMSMode {
+    pub fn get(id: u32) -> Option<DPMSMode> {
+        match id {
+            0 => Some(DPMSMode::On),
+            1 => Some(DPMSMode::Standby),
+            2 => Some(DPMSMode::Suspend),
+            3 => Some(DPMSMode::Off),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &DPMSMode::On => 0,
+            &DPMSMode::Standby => 1,
+            &DPMSMode::Suspend => 2,
+            &DPMSMode::Off => 3
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum DPMSReply {
+    GetVersion {
+        server_major_version: u16,
+        server_minor_version: u16
+    },
+    Capable {
+        capable: bool
+    },
+    GetTimeouts {
+        standby_timeout: u16,
+        suspend_timeout: u16,
+        off_timeout: u16
+    },
+    Info {
+        power_level: DPMSMode,
+        state: bool
+    }
+}
+
+/** Decodes DPMS replies and events */
+#[derive(Debug, Default)]
+pub struct DPMS;
+
+impl Extension for DPMS {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_GET_VERSION => reader.read_dpms_get_version_reply(detail),
+            OP_CAPABLE => reader.read_dpms_capable_reply(detail),
+            OP_GET_TIMEOUTS => reader.read_dpms_get_timeouts_reply(detail),
+            OP_INFO => reader.read_dpms_info_reply(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the DPMS GetVersion request. Returns None without sending anything if the server doesn't have DPMS, like every request here. */
+    pub fn dpms_get_version(&mut self, client_major_version: u16, client_minor_version: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<DPMS>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_VERSION);
+        self.write_u16(2);
+        self.write_u16(client_major_version);
+        self.write_u16(client_minor_version);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_VERSION)))
+    }
+
+    /** Sends the DPMS Capable request */
+    pub fn dpms_capable(&mut self) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<DPMS>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CAPABLE);
+        self.write_u16(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_CAPABLE)))
+    }
+
+    /** Sends the DPMS GetTimeouts request */
+    pub fn dpms_get_timeouts(&mut self) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<DPMS>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_TIMEOUTS);
+        self.write_u16(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_TIMEOUTS)))
+    }
+
+    /** Sends the DPMS SetTimeouts request */
+    pub fn dpms_set_timeouts(&mut self, standby_timeout: u16, suspend_timeout: u16, off_timeout: u16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<DPMS>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_TIMEOUTS);
+        self.write_u16(3);
+        self.write_u16(standby_timeout);
+        self.write_u16(suspend_timeout);
+        self.write_u16(off_timeout);
+        self.write_pad(2);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the DPMS Enable request */
+    pub fn dpms_enable(&mut self) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<DPMS>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_ENABLE);
+        self.write_u16(1);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the DPMS Disable request */
+    pub fn dpms_disable(&mut self) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<DPMS>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_DISABLE);
+        self.write_u16(1);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the DPMS ForceLevel request */
+    pub fn dpms_force_level(&mut self, power_level: &DPMSMode) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<DPMS>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_FORCE_LEVEL);
+        self.write_u16(2);
+        self.write_u16(power_level.val() as u16);
+        self.write_pad(2);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the DPMS Info request */
+    pub fn dpms_info(&mut self) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<DPMS>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_INFO);
+        self.write_u16(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_INFO)))
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to DPMS GetVersion */
+    fn read_dpms_get_version_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let server_major_version = self.read_u16();
+        let server_minor_version = self.read_u16();
+        Some(ServerReply::DPMS(DPMSReply::GetVersion { server_major_version, server_minor_version }))
+    }
+
+    /** Reads a reply to DPMS Capable */
+    fn read_dpms_capable_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let capable = self.read_bool();
+        self.read_pad(23);
+        Some(ServerReply::DPMS(DPMSReply::Capable { capable }))
+    }
+
+    /** Reads a reply to DPMS GetTimeouts */
+    fn read_dpms_get_timeouts_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let standby_timeout = self.read_u16();
+        let suspend_timeout = self.read_u16();
+        let off_timeout = self.read_u16();
+        self.read_pad(18);
+        Some(ServerReply::DPMS(DPMSReply::GetTimeouts { standby_timeout, suspend_timeout, off_timeout }))
+    }
+
+    /** Reads a reply to DPMS Info */
+    fn read_dpms_info_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let power_level = self.read_u16();
+        let power_level = match DPMSMode::get(power_level as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let state = self.read_bool();
+        self.read_pad(21);
+        Some(ServerReply::DPMS(DPMSReply::Info { power_level, state }))
+    }
+}
@@ -0,0 +1,276 @@
+// Extension support.
+// Every extension gets a major opcode, a range of event codes, and a range of error codes from the X Server at runtime.
+// The registry remembers what QueryExtension said for each name, and the reader thread uses it to hand
+// events, errors, and replies in those ranges over to whichever `Extension` was registered for them.
+
+use std::collections::HashMap;
+
+use models::*;
+use xreaderwriter::{XBufferedReader, XReadHelper};
+
+pub use self::monitors::MonitorRect;
+
+// Generated from tools/xcb-proto by tools/xcbgen (see tools/xcbgen/modules.txt). <name>_extra.rs has the parts of an
+// extension written by hand (whatever xcbgen can't generate, and helpers on top of the generated requests), and
+// everything public in it is re-exported from <name>. Each extension's decoder is registered the first time one of its
+// requests is sent, and its requests return None without sending anything if the server doesn't have it.
+pub mod xc_misc;
+pub mod xkb;
+mod xkb_extra;
+pub mod xinput;
+mod xinput_extra;
+pub mod composite;
+mod composite_extra;
+pub mod randr;
+mod randr_extra;
+pub mod xinerama;
+pub mod sync;
+mod sync_extra;
+pub mod shape;
+mod shape_extra;
+pub mod render;
+mod render_extra;
+pub mod xfixes;
+mod xfixes_extra;
+pub mod damage;
+mod damage_extra;
+pub mod shm;
+mod shm_extra;
+pub mod xtest;
+mod xtest_extra;
+pub mod record;
+mod record_extra;
+pub mod dpms;
+pub mod screensaver;
+mod screensaver_extra;
+pub mod present;
+mod present_extra;
+pub mod res;
+mod res_extra;
+pub mod xv;
+mod xv_extra;
+pub mod dbe;
+mod dbe_extra;
+
+// Written by hand from its spec, since xcb-proto has no XML for it, but laid out like the generated modules
+pub mod security;
+
+mod monitors;
+
+/** The decoder of an extension in this module, for extensions that are queried before (or without) registering one */
+fn builtin(name: &str) -> Option<Box<dyn Extension>> {
+    Some(match name {
+        xc_misc::EXTENSION_NAME => Box::new(xc_misc::XCMisc),
+        xkb::EXTENSION_NAME => Box::new(xkb::Xkb),
+        xinput::EXTENSION_NAME => Box::new(xinput::XInput),
+        composite::EXTENSION_NAME => Box::new(composite::Composite),
+        randr::EXTENSION_NAME => Box::new(randr::RandR),
+        xinerama::EXTENSION_NAME => Box::new(xinerama::Xinerama),
+        sync::EXTENSION_NAME => Box::new(sync::Sync),
+        shape::EXTENSION_NAME => Box::new(shape::Shape),
+        render::EXTENSION_NAME => Box::new(render::Render),
+        xfixes::EXTENSION_NAME => Box::new(xfixes::XFixes),
+        damage::EXTENSION_NAME => Box::new(damage::Damage),
+        shm::EXTENSION_NAME => Box::new(shm::Shm),
+        xtest::EXTENSION_NAME => Box::new(xtest::XTest),
+        record::EXTENSION_NAME => Box::new(record::Record),
+        dpms::EXTENSION_NAME => Box::new(dpms::DPMS),
+        screensaver::EXTENSION_NAME => Box::new(screensaver::ScreenSaver),
+        present::EXTENSION_NAME => Box::new(present::Present),
+        res::EXTENSION_NAME => Box::new(res::XRes),
+        xv::EXTENSION_NAME => Box::new(xv::Xv),
+        dbe::EXTENSION_NAME => Box::new(dbe::Dbe),
+        security::EXTENSION_NAME => Box::new(security::Security),
+        _ => return None
+    })
+}
+
+/** The name of an error of an extension in this module (ie "BadOutput"), by its code relative to the extension's first_error */
+pub(crate) fn error_name(extension: &str, code: u8) -> Option<&'static str> {
+    builtin(extension)?.error_names().get(code as usize).cloned().filter(|name| !name.is_empty())
+}
+
+/** What QueryExtension told us about an extension */
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ExtensionInfo {
+    pub major_opcode: u8,
+    pub first_event: u8, // 0 = extension has no events
+    pub first_error: u8, // 0 = extension has no errors
+    pub events: u8, // How many event codes it uses from first_event, from its decoder (0 = none, or we don't know)
+    pub errors: u8 // How many error codes it uses from first_error, from its decoder (0 = none, or we don't know)
+}
+
+/**
+ * Decodes the replies, events, and errors of an extension.
+ * Register one with XClient::register_extension. Every method is optional; anything that isn't decoded is
+ * passed on as ServerReply::Unknown, ServerEvent::Unknown, or ServerEvent::Generic instead.
+ *
+ * Requests are written with the XBufferedWriter methods on XClient, using the `major_opcode` from
+ * XClient::query_extension_sync, and ServerReplyType::Extension(major_opcode, minor_opcode) if they have a reply.
+ */
+pub trait Extension: Send {
+    /** The name given to QueryExtension (ie "DAMAGE") */
+    fn name(&self) -> &'static str;
+
+    /** How many event codes it uses, starting at first_event. Events past these aren't given to read_event. */
+    fn event_count(&self) -> u8 {
+        0
+    }
+
+    /** The names of its errors (ie "BadDamage"), by code. Errors past these aren't given to read_error. */
+    fn error_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /** Reads a reply (the reader is positioned after the reply length) */
+    fn read_reply(&self, _reader: &mut XReadHelper, _minor_opcode: u8, _detail: u8) -> Option<ServerReply> {
+        None
+    }
+
+    /** Reads an event (the reader is positioned after the sequence number). `code` is relative to first_event. */
+    fn read_event(&self, _reader: &mut XReadHelper, _code: u8, _detail: u8) -> Option<ServerEvent> {
+        None
+    }
+
+    /** Reads a GenericEvent sent by this extension (the reader is positioned after `evtype`) */
+    fn read_generic_event(&self, _reader: &mut XReadHelper, _evtype: u16) -> Option<ServerEvent> {
+        None
+    }
+
+    /** Reads an error (the reader is positioned after the sequence number). `code` is relative to first_error. */
+    fn read_error(&self, _reader: &mut XReadHelper, _code: u8) -> Option<ServerError> {
+        None
+    }
+}
+
+/** Shared between XClient and the reader thread */
+#[derive(Default)]
+pub struct ExtensionRegistry {
+    queried: HashMap<&'static str, Option<ExtensionInfo>>, // None = the server doesn't have it
+    decoders: HashMap<String, Box<dyn Extension>>
+}
+
+impl ExtensionRegistry {
+    pub fn new() -> ExtensionRegistry {
+        ExtensionRegistry {
+            queried: HashMap::new(),
+            decoders: HashMap::new()
+        }
+    }
+
+    /** Returns Some(info) if we have already asked the server about this extension */
+    pub fn get(&self, name: &str) -> Option<Option<ExtensionInfo>> {
+        self.queried.get(name).cloned()
+    }
+
+    /**
+     * Remembers what the server said about an extension.
+     * How many event and error codes it uses come from its decoder, or the one in this module if none is registered.
+     */
+    pub fn insert(&mut self, name: &'static str, info: Option<ExtensionInfo>) {
+        let info = info.map(|mut info| {
+            if let Some(decoder) = self.decoders.get(name).or(builtin(name).as_ref()) {
+                info.events = decoder.event_count();
+                info.errors = decoder.error_names().len() as u8;
+            }
+            info
+        });
+        self.queried.insert(name, info);
+    }
+
+    /** Adds a decoder. Replaces any existing decoder for the same extension. */
+    pub fn register(&mut self, extension: Box<dyn Extension>) {
+        if let Some(Some(info)) = self.queried.get_mut(extension.name()) {
+            info.events = extension.event_count();
+            info.errors = extension.error_names().len() as u8;
+        }
+        self.decoders.insert(String::from(extension.name()), extension);
+    }
+
+    /** Returns true if a decoder has been registered for the given extension */
+    pub fn is_registered(&self, name: &str) -> bool {
+        self.decoders.contains_key(name)
+    }
+
+    /** Finds the extension with the given major opcode */
+    pub fn find_by_opcode(&self, major_opcode: u8) -> Option<(&'static str, ExtensionInfo)> {
+        for (&name, info) in self.queried.iter() {
+            if let Some(info) = *info {
+                if info.major_opcode == major_opcode {
+                    return Some((name, info));
+                }
+            }
+        }
+        None
+    }
+
+    /**
+     * Finds the extension that owns an event or error code, given the first code and how many codes each one uses.
+     * Codes of extensions we haven't queried, or whose number of codes we don't know, aren't found.
+     */
+    fn find_by_code<F: Fn(&ExtensionInfo) -> (u8, u8)>(&self, code: u8, range: F) -> Option<(&'static str, ExtensionInfo)> {
+        for (&name, info) in self.queried.iter() {
+            if let Some(info) = *info {
+                let (first, count) = range(&info);
+                if first != 0 && code >= first && code - first < count {
+                    return Some((name, info));
+                }
+            }
+        }
+        None
+    }
+
+    /** Finds the extension that owns an event code */
+    pub fn find_by_event(&self, code: u8) -> Option<(&'static str, ExtensionInfo)> {
+        self.find_by_code(code, |info| (info.first_event, info.events))
+    }
+
+    /** Finds the extension that owns an error code */
+    pub fn find_by_error(&self, code: u8) -> Option<(&'static str, ExtensionInfo)> {
+        self.find_by_code(code, |info| (info.first_error, info.errors))
+    }
+
+    /** Reads a reply to an extension request, if there is a decoder for it */
+    pub fn read_reply(&self, reader: &mut XReadHelper, major_opcode: u8, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        let (name, _) = self.find_by_opcode(major_opcode)?;
+        let decoder = self.decoders.get(name)?;
+        reader.seek(8);
+        decoder.read_reply(reader, minor_opcode, detail)
+    }
+
+    /** Reads an extension event, if there is a decoder for it */
+    pub fn read_event(&self, reader: &mut XReadHelper, code: u8, detail: u8) -> Option<ServerEvent> {
+        let (name, info) = self.find_by_event(code)?;
+        let decoder = self.decoders.get(name)?;
+        reader.seek(4);
+        decoder.read_event(reader, code - info.first_event, detail)
+    }
+
+    /** Reads a GenericEvent, if there is a decoder for the extension that sent it */
+    pub fn read_generic_event(&self, reader: &mut XReadHelper, extension: u8, evtype: u16) -> Option<ServerEvent> {
+        let (name, _) = self.find_by_opcode(extension)?;
+        let decoder = self.decoders.get(name)?;
+        reader.seek(10);
+        decoder.read_generic_event(reader, evtype)
+    }
+
+    /**
+     * Reads an error from an extension we've queried, with its decoder if one is registered.
+     * Errors the decoder doesn't read itself (or with no decoder) are given as ServerError::Extension.
+     */
+    pub fn read_error(&self, reader: &mut XReadHelper, code: u8) -> Option<ServerError> {
+        let (name, info) = self.find_by_error(code)?;
+        let code = code - info.first_error;
+        if let Some(decoder) = self.decoders.get(name) {
+            reader.seek(4);
+            if let Some(error) = decoder.read_error(reader, code) {
+                return Some(error);
+            }
+        }
+        reader.seek(4);
+        let bad_value = reader.read_u32();
+        let minor_opcode = reader.read_u16();
+        let major_opcode = reader.read_u8();
+        Some(ServerError::Extension { extension: name, code, bad_value, minor_opcode, major_opcode })
+    }
+}
@@ -0,0 +1,85 @@
+// One way to get the monitor layout that works on every server: RandR 1.5 monitors, then Xinerama screens, then the core screens.
+
+use models::{ServerReply, ServerResponse};
+use XClient;
+
+use super::randr::RandRReply;
+use super::xinerama::XineramaReply;
+
+/** Where a monitor is, in root window coordinates */
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MonitorRect {
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub primary: bool // Without RandR, the first monitor is the primary one
+}
+
+impl XClient {
+    /**
+     * Gets the monitors of the first screen. Blocks.
+     * Uses RandR GetMonitors if the server has RandR 1.5, then Xinerama if it's active, and otherwise treats the screen as one monitor.
+     */
+    pub fn monitors(&mut self) -> Vec<MonitorRect> {
+        let (root, width, height) = match self.info.screens.first() {
+            Some(screen) => (screen.root, screen.width_in_pixels, screen.height_in_pixels),
+            None => return vec![]
+        };
+
+        if let Some(monitors) = self.randr_monitors(root) {
+            return monitors;
+        }
+        if let Some(monitors) = self.xinerama_monitors() {
+            return monitors;
+        }
+
+        vec![MonitorRect { x: 0, y: 0, width, height, primary: true }]
+    }
+
+    /** The RandR monitors of `root`, if the server has RandR 1.5 and any monitors */
+    fn randr_monitors(&mut self, root: u32) -> Option<Vec<MonitorRect>> {
+        let version = match self.randr_version {
+            Some(version) => version,
+            None => {
+                let seq = self.randr_query_version(1, 5)?;
+                let version = match self.wait_for_response(seq) {
+                    ServerResponse::Reply(ServerReply::RandR(RandRReply::QueryVersion { major_version, minor_version }), _) => (major_version, minor_version),
+                    _ => (0, 0)
+                };
+                self.randr_version = Some(version);
+                version
+            }
+        };
+        if version < (1, 5) {
+            return None;
+        }
+
+        let seq = self.randr_get_monitors(root, true)?;
+        match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::RandR(RandRReply::GetMonitors { monitors, .. }), _) if !monitors.is_empty() => {
+                Some(monitors.iter().map(|m| MonitorRect { x: m.x, y: m.y, width: m.width, height: m.height, primary: m.primary }).collect())
+            },
+            _ => None
+        }
+    }
+
+    /** The Xinerama screens, if Xinerama is active */
+    fn xinerama_monitors(&mut self) -> Option<Vec<MonitorRect>> {
+        let seq = self.xinerama_is_active()?;
+        match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::Xinerama(XineramaReply::IsActive { state }), _) if state != 0 => (),
+            _ => return None
+        };
+
+        let seq = self.xinerama_query_screens()?;
+        match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::Xinerama(XineramaReply::QueryScreens { screen_info }), _) if !screen_info.is_empty() => {
+                Some(screen_info.iter().enumerate()
+                    .map(|(i, s)| MonitorRect { x: s.x_org, y: s.y_org, width: s.width, height: s.height, primary: i == 0 })
+                    .collect())
+            },
+            _ => None
+        }
+    }
+}
@@ -0,0 +1,483 @@
+// Present extension, version 1.2
+// Generated by tools/xcbgen from tools/xcb-proto/present.xml. Don't edit this file, change the generator or
+// present_extra.rs (which has everything written by hand) instead.
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{Rectangle, ServerEvent, ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+#[allow(unused_imports)] // Some extra modules only add methods
+pub use super::present_extra::*;
+
+pub const EXTENSION_NAME: &str = "Present";
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 2;
+
+pub const OP_QUERY_VERSION: u8 = 0;
+pub const OP_PIXMAP: u8 = 1;
+pub const OP_NOTIFY_MSC: u8 = 2;
+pub const OP_SELECT_INPUT: u8 = 3;
+pub const OP_QUERY_CAPABILITIES: u8 = 4;
+
+pub const EVENT_GENERIC: u8 = 0;
+pub const GENERIC_EVENT_CONFIGURE_NOTIFY: u16 = 0;
+pub const GENERIC_EVENT_COMPLETE_NOTIFY: u16 = 1;
+pub const GENERIC_EVENT_IDLE_NOTIFY: u16 = 2;
+pub const GENERIC_EVENT_REDIRECT_NOTIFY: u16 = 3;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Event {
+    ConfigureNotify,
+    CompleteNotify,
+    IdleNotify,
+    RedirectNotify
+}
+impl Event {
+    pub fn get(id: u32) -> Option<Event> {
+        match id {
+            0 => Some(Event::ConfigureNotify),
+            1 => Some(Event::CompleteNotify),
+            2 => Some(Event::IdleNotify),
+            3 => Some(Event::RedirectNotify),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &Event::ConfigureNotify => 0,
+            &Event::CompleteNotify => 1,
+            &Event::IdleNotify => 2,
+            &Event::RedirectNotify => 3
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum EventMask {
+    NoEvent,
+    ConfigureNotify,
+    CompleteNotify,
+    IdleNotify,
+    RedirectNotify
+}
+impl EventMask {
+    pub fn val(&self) -> u32 {
+        match self {
+            &EventMask::NoEvent => 0x00000000,
+            &EventMask::ConfigureNotify => 0x00000001,
+            &EventMask::CompleteNotify => 0x00000002,
+            &EventMask::IdleNotify => 0x00000004,
+            &EventMask::RedirectNotify => 0x00000008
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Options {
+    None,
+    Async,
+    Copy,
+    Ust,
+    Suboptimal
+}
+impl Options {
+    pub fn val(&self) -> u32 {
+        match self {
+            &Options::None => 0x00000000,
+            &Options::Async => 0x00000001,
+            &Options::Copy => 0x00000002,
+            &Options::Ust => 0x00000004,
+            &Options::Suboptimal => 0x00000008
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Capability {
+    None,
+    Async,
+    Fence,
+    Ust
+}
+impl Capability {
+    pub fn val(&self) -> u32 {
+        match self {
+            &Capability::None => 0x00000000,
+            &Capability::Async => 0x00000001,
+            &Capability::Fence => 0x00000002,
+            &Capability::Ust => 0x00000004
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CompleteKind {
+    Pixmap,
+    NotifyMSC
+}
+impl CompleteKind {
+    pub fn get(id: u32) -> Option<CompleteKind> {
+        match id {
+            0 => Some(CompleteKind::Pixmap),
+            1 => Some(CompleteKind::NotifyMSC),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &CompleteKind::Pixmap => 0,
+            &CompleteKind::NotifyMSC => 1
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CompleteMode {
+    Copy,
+    Flip,
+    Skip,
+    SuboptimalCopy
+}
+impl CompleteMode {
+    pub fn get(id: u32) -> Option<CompleteMode> {
+        match id {
+            0 => Some(CompleteMode::Copy),
+            1 => Some(CompleteMode::Flip),
+            2 => Some(CompleteMode::Skip),
+            3 => Some(CompleteMode::SuboptimalCopy),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &CompleteMode::Copy => 0,
+            &CompleteMode::Flip => 1,
+            &CompleteMode::Skip => 2,
+            &CompleteMode::SuboptimalCopy => 3
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Notify {
+    pub window: u32,
+    pub serial: u32
+}
+impl Notify {
+    pub fn read(reader: &mut XReadHelper) -> Notify {
+        let window = reader.read_u32();
+        let serial = reader.read_u32();
+        Notify { window, serial }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.window);
+        client.write_u32(self.serial);
+    }
+}
+
+#[derive(Debug)]
+pub enum PresentReply {
+    QueryVersion {
+        major_version: u32,
+        minor_version: u32
+    },
+    QueryCapabilities {
+        capabilities: u32
+    }
+}
+
+#[derive(Debug)]
+pub enum PresentEvent {
+    Generic {
+        extension: u8,
+        length: u32,
+        evtype: u16,
+        event: u32
+    },
+    ConfigureNotify {
+        event: u32,
+        window: u32,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        off_x: i16,
+        off_y: i16,
+        pixmap_width: u16,
+        pixmap_height: u16,
+        pixmap_flags: u32
+    },
+    CompleteNotify {
+        kind: CompleteKind,
+        mode: CompleteMode,
+        event: u32,
+        window: u32,
+        serial: u32,
+        ust: u64,
+        msc: u64
+    },
+    IdleNotify {
+        event: u32,
+        window: u32,
+        serial: u32,
+        pixmap: u32,
+        idle_fence: u32
+    },
+    RedirectNotify {
+        update_window: bool,
+        event: u32,
+        event_window: u32,
+        window: u32,
+        pixmap: u32,
+        serial: u32,
+        valid_region: u32,
+        update_region: u32,
+        valid_rect: Rectangle,
+        update_rect: Rectangle,
+        x_off: i16,
+        y_off: i16,
+        target_crtc: u32,
+        wait_fence: u32,
+        idle_fence: u32,
+        options: u32,
+        target_msc: u64,
+        divisor: u64,
+        remainder: u64,
+        notifies: Vec<Notify>
+    }
+}
+
+/** Decodes Present replies and events */
+#[derive(Debug, Default)]
+pub struct Present;
+
+impl Extension for Present {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn event_count(&self) -> u8 {
+        1
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_QUERY_VERSION => reader.read_present_query_version_reply(detail),
+            OP_QUERY_CAPABILITIES => reader.read_present_query_capabilities_reply(detail),
+            _ => None
+        }
+    }
+
+    fn read_event(&self, reader: &mut XReadHelper, code: u8, detail: u8) -> Option<ServerEvent> {
+        match code {
+            EVENT_GENERIC => reader.read_present_generic_event(detail),
+            _ => None
+        }
+    }
+
+    fn read_generic_event(&self, reader: &mut XReadHelper, evtype: u16) -> Option<ServerEvent> {
+        match evtype {
+            GENERIC_EVENT_CONFIGURE_NOTIFY => reader.read_present_configure_notify_event(),
+            GENERIC_EVENT_COMPLETE_NOTIFY => reader.read_present_complete_notify_event(),
+            GENERIC_EVENT_IDLE_NOTIFY => reader.read_present_idle_notify_event(),
+            GENERIC_EVENT_REDIRECT_NOTIFY => reader.read_present_redirect_notify_event(),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the Present QueryVersion request. Returns None without sending anything if the server doesn't have Present, like every request here. */
+    pub fn present_query_version(&mut self, major_version: u32, minor_version: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Present>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_VERSION);
+        self.write_u16(3);
+        self.write_u32(major_version);
+        self.write_u32(minor_version);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_VERSION)))
+    }
+
+    /** Sends the Present Pixmap request */
+    pub fn present_pixmap(&mut self, window: u32, pixmap: u32, serial: u32, valid: u32, update: u32, x_off: i16, y_off: i16, target_crtc: u32, wait_fence: u32, idle_fence: u32, options: u32, target_msc: u64, divisor: u64, remainder: u64, notifies: &Vec<Notify>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Present>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_PIXMAP);
+        let pad = self.write_dynamic_len(18, notifies.len() * 8);
+        self.write_u32(window);
+        self.write_u32(pixmap);
+        self.write_u32(serial);
+        self.write_u32(valid);
+        self.write_u32(update);
+        self.write_i16(x_off);
+        self.write_i16(y_off);
+        self.write_u32(target_crtc);
+        self.write_u32(wait_fence);
+        self.write_u32(idle_fence);
+        self.write_u32(options);
+        self.write_pad(4);
+        self.write_u64(target_msc);
+        self.write_u64(divisor);
+        self.write_u64(remainder);
+        for value in notifies {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the Present NotifyMSC request */
+    pub fn present_notify_msc(&mut self, window: u32, serial: u32, target_msc: u64, divisor: u64, remainder: u64) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Present>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_NOTIFY_MSC);
+        self.write_u16(10);
+        self.write_u32(window);
+        self.write_u32(serial);
+        self.write_pad(4);
+        self.write_u64(target_msc);
+        self.write_u64(divisor);
+        self.write_u64(remainder);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the Present SelectInput request */
+    pub fn present_select_input(&mut self, eid: u32, window: u32, event_mask: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Present>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SELECT_INPUT);
+        self.write_u16(4);
+        self.write_u32(eid);
+        self.write_u32(window);
+        self.write_u32(event_mask);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the Present QueryCapabilities request */
+    pub fn present_query_capabilities(&mut self, target: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Present>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_CAPABILITIES);
+        self.write_u16(2);
+        self.write_u32(target);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_CAPABILITIES)))
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to Present QueryVersion */
+    fn read_present_query_version_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let major_version = self.read_u32();
+        let minor_version = self.read_u32();
+        Some(ServerReply::Present(PresentReply::QueryVersion { major_version, minor_version }))
+    }
+
+    /** Reads a reply to Present QueryCapabilities */
+    fn read_present_query_capabilities_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let capabilities = self.read_u32();
+        Some(ServerReply::Present(PresentReply::QueryCapabilities { capabilities }))
+    }
+
+    /** Reads a Present Generic event */
+    fn read_present_generic_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let extension = detail;
+        let length = self.read_u32();
+        let evtype = self.read_u16();
+        self.read_pad(2);
+        let event = self.read_u32();
+        Some(ServerEvent::Present(PresentEvent::Generic { extension, length, evtype, event }))
+    }
+
+    /** Reads a Present ConfigureNotify event */
+    fn read_present_configure_notify_event(&mut self) -> Option<ServerEvent> {
+        self.read_pad(2);
+        let event = self.read_u32();
+        let window = self.read_u32();
+        let x = self.read_i16();
+        let y = self.read_i16();
+        let width = self.read_u16();
+        let height = self.read_u16();
+        let off_x = self.read_i16();
+        let off_y = self.read_i16();
+        let pixmap_width = self.read_u16();
+        let pixmap_height = self.read_u16();
+        let pixmap_flags = self.read_u32();
+        Some(ServerEvent::Present(PresentEvent::ConfigureNotify { event, window, x, y, width, height, off_x, off_y, pixmap_width, pixmap_height, pixmap_flags }))
+    }
+
+    /** Reads a Present CompleteNotify event */
+    fn read_present_complete_notify_event(&mut self) -> Option<ServerEvent> {
+        let kind = self.read_u8();
+        let kind = match CompleteKind::get(kind as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let mode = self.read_u8();
+        let mode = match CompleteMode::get(mode as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let event = self.read_u32();
+        let window = self.read_u32();
+        let serial = self.read_u32();
+        let ust = self.read_u64();
+        let msc = self.read_u64();
+        Some(ServerEvent::Present(PresentEvent::CompleteNotify { kind, mode, event, window, serial, ust, msc }))
+    }
+
+    /** Reads a Present IdleNotify event */
+    fn read_present_idle_notify_event(&mut self) -> Option<ServerEvent> {
+        self.read_pad(2);
+        let event = self.read_u32();
+        let window = self.read_u32();
+        let serial = self.read_u32();
+        let pixmap = self.read_u32();
+        let idle_fence = self.read_u32();
+        Some(ServerEvent::Present(PresentEvent::IdleNotify { event, window, serial, pixmap, idle_fence }))
+    }
+
+    /** Reads a Present RedirectNotify event */
+    fn read_present_redirect_notify_event(&mut self) -> Option<ServerEvent> {
+        let update_window = self.read_bool();
+        self.read_pad(1);
+        let event = self.read_u32();
+        let event_window = self.read_u32();
+        let window = self.read_u32();
+        let pixmap = self.read_u32();
+        let serial = self.read_u32();
+        let valid_region = self.read_u32();
+        let update_region = self.read_u32();
+        let valid_rect = Rectangle { x: self.read_i16(), y: self.read_i16(), width: self.read_u16(), height: self.read_u16() };
+        let update_rect = Rectangle { x: self.read_i16(), y: self.read_i16(), width: self.read_u16(), height: self.read_u16() };
+        let x_off = self.read_i16();
+        let y_off = self.read_i16();
+        let target_crtc = self.read_u32();
+        let wait_fence = self.read_u32();
+        let idle_fence = self.read_u32();
+        let options = self.read_u32();
+        self.read_pad(4);
+        let target_msc = self.read_u64();
+        let divisor = self.read_u64();
+        let remainder = self.read_u64();
+        let mut notifies = Vec::with_capacity(self.remaining() / 8);
+        for _ in 0..self.remaining() / 8 {
+            notifies.push(Notify::read(self));
+        }
+        Some(ServerEvent::Present(PresentEvent::RedirectNotify { update_window, event, event_window, window, pixmap, serial, valid_region, update_region, valid_rect, update_rect, x_off, y_off, target_crtc, wait_fence, idle_fence, options, target_msc, divisor, remainder, notifies }))
+    }
+}
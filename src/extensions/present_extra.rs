@@ -0,0 +1,91 @@
+// Presenting pixmaps at vblank, and Swapchain, which cycles through pixmaps as the server lets go of them.
+
+use models::{Pixmap, ServerEvent};
+use XClient;
+
+use super::present::*;
+
+impl XClient {
+    /**
+     * Shows a pixmap on a window at the next vblank (MSC). The server may flip to the pixmap instead of copying it, so
+     * don't draw to it again until its IdleNotify (Swapchain does this). A CompleteNotify with `serial` says when it's
+     * shown. Both need to be selected with present_select_input.
+     */
+    pub fn present_pixmap_next_msc(&mut self, window: u32, pixmap: u32, serial: u32) -> Option<()> {
+        self.present_pixmap(window, pixmap, serial, 0, 0, 0, 0, 0, 0, 0, Options::None.val(), 0, 1, 0, &vec![])
+    }
+}
+
+/**
+ * Some pixmaps the size of a window, presented in turn at each vblank, so drawing never shows half done.
+ * Draw into the pixmap from acquire, present it, and pass every Present event to handle_event so the swapchain knows
+ * which pixmaps the server is done with.
+ */
+#[derive(Debug)]
+pub struct Swapchain {
+    pub window: u32,
+    pub eid: u32, // The ID of the Present event selection
+    pub pixmaps: Vec<Pixmap>,
+    idle: Vec<bool>, // Whether each pixmap can be drawn to (it isn't waiting to be shown, or being shown)
+    serial: u32, // The serial of the last present
+    last_msc: u64 // The MSC of the last CompleteNotify
+}
+
+impl Swapchain {
+    /** Makes `count` pixmaps (2 for double buffering) for a window, and selects the Present events for it. None if the server doesn't have Present. */
+    pub fn create(client: &mut XClient, window: u32, depth: u8, width: u16, height: u16, count: usize) -> Option<Swapchain> {
+        client.query_extension_sync(EXTENSION_NAME)?;
+        let pixmaps = (0..count).map(|_| Pixmap::create(client, window, depth, width, height)).collect();
+        let eid = client.new_resource_id();
+        let mask = EventMask::ConfigureNotify.val() | EventMask::CompleteNotify.val() | EventMask::IdleNotify.val();
+        client.present_select_input(eid, window, mask)?;
+        Some(Swapchain { window, eid, pixmaps, idle: vec![true; count], serial: 0, last_msc: 0 })
+    }
+
+    /** Gets the index of a pixmap that can be drawn to, or None if the server still has all of them */
+    pub fn acquire(&self) -> Option<usize> {
+        self.idle.iter().position(|&idle| idle)
+    }
+
+    /** Shows the pixmap at `index` at the next vblank. Returns the serial the CompleteNotify will have. */
+    pub fn present(&mut self, client: &mut XClient, index: usize) -> u32 {
+        self.serial = self.serial.wrapping_add(1);
+        self.idle[index] = false;
+        client.present_pixmap_next_msc(self.window, self.pixmaps[index].pid, self.serial);
+        self.serial
+    }
+
+    /** The MSC (vblank counter) the last present was shown at */
+    pub fn last_msc(&self) -> u64 {
+        self.last_msc
+    }
+
+    /**
+     * Keeps track of the pixmaps from Present events. Returns true if the event was for this swapchain.
+     * After a ConfigureNotify, the window has a new size, so the swapchain should be destroyed and made again.
+     */
+    pub fn handle_event(&mut self, event: &ServerEvent) -> bool {
+        match *event {
+            ServerEvent::Present(PresentEvent::IdleNotify { event, pixmap, .. }) if event == self.eid => {
+                if let Some(i) = self.pixmaps.iter().position(|p| p.pid == pixmap) {
+                    self.idle[i] = true;
+                }
+                true
+            },
+            ServerEvent::Present(PresentEvent::CompleteNotify { event, msc, .. }) if event == self.eid => {
+                self.last_msc = msc;
+                true
+            },
+            ServerEvent::Present(PresentEvent::ConfigureNotify { event, .. }) => event == self.eid,
+            _ => false
+        }
+    }
+
+    /** Stops the events and frees the pixmaps */
+    pub fn destroy(self, client: &mut XClient) {
+        client.present_select_input(self.eid, self.window, EventMask::NoEvent.val());
+        for pixmap in &self.pixmaps {
+            client.free_pixmap(pixmap.pid);
+        }
+    }
+}
@@ -0,0 +1,1963 @@
+// RANDR extension, version 1.6
+// Generated by tools/xcbgen from tools/xcb-proto/randr.xml. Don't edit this file, change the generator or
+// randr_extra.rs (which has everything written by hand) instead.
+//
+// Written by hand in randr_extra.rs:
+//   - request SetMonitor
+//   - event Notify
+//
+// Not generated:
+//   - <union> NotifyData (not understood)
+//   - reply to CreateLease (comes back as ServerReply::Unknown): <fd>
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{ServerEvent, ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+#[allow(unused_imports)] // Some extra modules only add methods
+pub use super::randr_extra::*;
+
+pub const EXTENSION_NAME: &str = "RANDR";
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 6;
+
+pub const OP_QUERY_VERSION: u8 = 0;
+pub const OP_SET_SCREEN_CONFIG: u8 = 2;
+pub const OP_SELECT_INPUT: u8 = 4;
+pub const OP_GET_SCREEN_INFO: u8 = 5;
+pub const OP_GET_SCREEN_SIZE_RANGE: u8 = 6;
+pub const OP_SET_SCREEN_SIZE: u8 = 7;
+pub const OP_GET_SCREEN_RESOURCES: u8 = 8;
+pub const OP_GET_OUTPUT_INFO: u8 = 9;
+pub const OP_LIST_OUTPUT_PROPERTIES: u8 = 10;
+pub const OP_QUERY_OUTPUT_PROPERTY: u8 = 11;
+pub const OP_CONFIGURE_OUTPUT_PROPERTY: u8 = 12;
+pub const OP_CHANGE_OUTPUT_PROPERTY: u8 = 13;
+pub const OP_DELETE_OUTPUT_PROPERTY: u8 = 14;
+pub const OP_GET_OUTPUT_PROPERTY: u8 = 15;
+pub const OP_CREATE_MODE: u8 = 16;
+pub const OP_DESTROY_MODE: u8 = 17;
+pub const OP_ADD_OUTPUT_MODE: u8 = 18;
+pub const OP_DELETE_OUTPUT_MODE: u8 = 19;
+pub const OP_GET_CRTC_INFO: u8 = 20;
+pub const OP_SET_CRTC_CONFIG: u8 = 21;
+pub const OP_GET_CRTC_GAMMA_SIZE: u8 = 22;
+pub const OP_GET_CRTC_GAMMA: u8 = 23;
+pub const OP_SET_CRTC_GAMMA: u8 = 24;
+pub const OP_GET_SCREEN_RESOURCES_CURRENT: u8 = 25;
+pub const OP_SET_CRTC_TRANSFORM: u8 = 26;
+pub const OP_GET_CRTC_TRANSFORM: u8 = 27;
+pub const OP_GET_PANNING: u8 = 28;
+pub const OP_SET_PANNING: u8 = 29;
+pub const OP_SET_OUTPUT_PRIMARY: u8 = 30;
+pub const OP_GET_OUTPUT_PRIMARY: u8 = 31;
+pub const OP_GET_PROVIDERS: u8 = 32;
+pub const OP_GET_PROVIDER_INFO: u8 = 33;
+pub const OP_SET_PROVIDER_OFFLOAD_SINK: u8 = 34;
+pub const OP_SET_PROVIDER_OUTPUT_SOURCE: u8 = 35;
+pub const OP_LIST_PROVIDER_PROPERTIES: u8 = 36;
+pub const OP_QUERY_PROVIDER_PROPERTY: u8 = 37;
+pub const OP_CONFIGURE_PROVIDER_PROPERTY: u8 = 38;
+pub const OP_CHANGE_PROVIDER_PROPERTY: u8 = 39;
+pub const OP_DELETE_PROVIDER_PROPERTY: u8 = 40;
+pub const OP_GET_PROVIDER_PROPERTY: u8 = 41;
+pub const OP_GET_MONITORS: u8 = 42;
+pub const OP_SET_MONITOR: u8 = 43;
+pub const OP_DELETE_MONITOR: u8 = 44;
+pub const OP_CREATE_LEASE: u8 = 45;
+pub const OP_FREE_LEASE: u8 = 46;
+
+pub const EVENT_SCREEN_CHANGE_NOTIFY: u8 = 0;
+pub const EVENT_NOTIFY: u8 = 1;
+
+pub const ERROR_BAD_OUTPUT: u8 = 0;
+pub const ERROR_BAD_CRTC: u8 = 1;
+pub const ERROR_BAD_MODE: u8 = 2;
+pub const ERROR_BAD_PROVIDER: u8 = 3;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Rotation {
+    Rotate0,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    ReflectX,
+    ReflectY
+}
+impl Rotation {
+    pub fn val(&self) -> u32 {
+        match self {
+            &Rotation::Rotate0 => 0x00000001,
+            &Rotation::Rotate90 => 0x00000002,
+            &Rotation::Rotate180 => 0x00000004,
+            &Rotation::Rotate270 => 0x00000008,
+            &Rotation::ReflectX => 0x00000010,
+            &Rotation::ReflectY => 0x00000020
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SetConfig {
+    Success,
+    InvalidConfigTime,
+    InvalidTime,
+    Failed
+}
+impl SetConfig {
+    pub fn get(id: u32) -> Option<SetConfig> {
+        match id {
+            0 => Some(SetConfig::Success),
+            1 => Some(SetConfig::InvalidConfigTime),
+            2 => Some(SetConfig::InvalidTime),
+            3 => Some(SetConfig::Failed),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &SetConfig::Success => 0,
+            &SetConfig::InvalidConfigTime => 1,
+            &SetConfig::InvalidTime => 2,
+            &SetConfig::Failed => 3
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum NotifyMask {
+    ScreenChange,
+    CrtcChange,
+    OutputChange,
+    OutputProperty,
+    ProviderChange,
+    ProviderProperty,
+    ResourceChange,
+    Lease
+}
+impl NotifyMask {
+    pub fn val(&self) -> u32 {
+        match self {
+            &NotifyMask::ScreenChange => 0x00000001,
+            &NotifyMask::CrtcChange => 0x00000002,
+            &NotifyMask::OutputChange => 0x00000004,
+            &NotifyMask::OutputProperty => 0x00000008,
+            &NotifyMask::ProviderChange => 0x00000010,
+            &NotifyMask::ProviderProperty => 0x00000020,
+            &NotifyMask::ResourceChange => 0x00000040,
+            &NotifyMask::Lease => 0x00000080
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ModeFlag {
+    HsyncPositive,
+    HsyncNegative,
+    VsyncPositive,
+    VsyncNegative,
+    Interlace,
+    DoubleScan,
+    Csync,
+    CsyncPositive,
+    CsyncNegative,
+    HskewPresent,
+    Bcast,
+    PixelMultiplex,
+    DoubleClock,
+    HalveClock
+}
+impl ModeFlag {
+    pub fn val(&self) -> u32 {
+        match self {
+            &ModeFlag::HsyncPositive => 0x00000001,
+            &ModeFlag::HsyncNegative => 0x00000002,
+            &ModeFlag::VsyncPositive => 0x00000004,
+            &ModeFlag::VsyncNegative => 0x00000008,
+            &ModeFlag::Interlace => 0x00000010,
+            &ModeFlag::DoubleScan => 0x00000020,
+            &ModeFlag::Csync => 0x00000040,
+            &ModeFlag::CsyncPositive => 0x00000080,
+            &ModeFlag::CsyncNegative => 0x00000100,
+            &ModeFlag::HskewPresent => 0x00000200,
+            &ModeFlag::Bcast => 0x00000400,
+            &ModeFlag::PixelMultiplex => 0x00000800,
+            &ModeFlag::DoubleClock => 0x00001000,
+            &ModeFlag::HalveClock => 0x00002000
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Connection {
+    Connected,
+    Disconnected,
+    Unknown
+}
+impl Connection {
+    pub fn get(id: u32) -> Option<Connection> {
+        match id {
+            0 => Some(Connection::Connected),
+            1 => Some(Connection::Disconnected),
+            2 => Some(Connection::Unknown),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &Connection::Connected => 0,
+            &Connection::Disconnected => 1,
+            &Connection::Unknown => 2
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Transform {
+    Unit,
+    ScaleUp,
+    ScaleDown,
+    Projective
+}
+impl Transform {
+    pub fn val(&self) -> u32 {
+        match self {
+            &Transform::Unit => 0x00000001,
+            &Transform::ScaleUp => 0x00000002,
+            &Transform::ScaleDown => 0x00000004,
+            &Transform::Projective => 0x00000008
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ProviderCapability {
+    SourceOutput,
+    SinkOutput,
+    SourceOffload,
+    SinkOffload
+}
+impl ProviderCapability {
+    pub fn val(&self) -> u32 {
+        match self {
+            &ProviderCapability::SourceOutput => 0x00000001,
+            &ProviderCapability::SinkOutput => 0x00000002,
+            &ProviderCapability::SourceOffload => 0x00000004,
+            &ProviderCapability::SinkOffload => 0x00000008
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Notify {
+    CrtcChange,
+    OutputChange,
+    OutputProperty,
+    ProviderChange,
+    ProviderProperty,
+    ResourceChange,
+    Lease
+}
+impl Notify {
+    pub fn get(id: u32) -> Option<Notify> {
+        match id {
+            0 => Some(Notify::CrtcChange),
+            1 => Some(Notify::OutputChange),
+            2 => Some(Notify::OutputProperty),
+            3 => Some(Notify::ProviderChange),
+            4 => Some(Notify::ProviderProperty),
+            5 => Some(Notify::ResourceChange),
+            6 => Some(Notify::Lease),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &Notify::CrtcChange => 0,
+            &Notify::OutputChange => 1,
+            &Notify::OutputProperty => 2,
+            &Notify::ProviderChange => 3,
+            &Notify::ProviderProperty => 4,
+            &Notify::ResourceChange => 5,
+            &Notify::Lease => 6
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ScreenSize {
+    pub width: u16,
+    pub height: u16,
+    pub mwidth: u16,
+    pub mheight: u16
+}
+impl ScreenSize {
+    pub fn read(reader: &mut XReadHelper) -> ScreenSize {
+        let width = reader.read_u16();
+        let height = reader.read_u16();
+        let mwidth = reader.read_u16();
+        let mheight = reader.read_u16();
+        ScreenSize { width, height, mwidth, mheight }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.width);
+        client.write_u16(self.height);
+        client.write_u16(self.mwidth);
+        client.write_u16(self.mheight);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RefreshRates {
+    pub rates: Vec<u16>
+}
+impl RefreshRates {
+    pub fn read(reader: &mut XReadHelper) -> RefreshRates {
+        let n_rates = reader.read_u16();
+        let mut rates = Vec::with_capacity(n_rates as usize);
+        for _ in 0..n_rates as usize {
+            rates.push(reader.read_u16());
+        }
+        RefreshRates { rates }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.rates.len() as u16);
+        for value in &self.rates {
+            client.write_u16(*value);
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ModeInfo {
+    pub id: u32,
+    pub width: u16,
+    pub height: u16,
+    pub dot_clock: u32,
+    pub hsync_start: u16,
+    pub hsync_end: u16,
+    pub htotal: u16,
+    pub hskew: u16,
+    pub vsync_start: u16,
+    pub vsync_end: u16,
+    pub vtotal: u16,
+    pub name_len: u16,
+    pub mode_flags: u32
+}
+impl ModeInfo {
+    pub fn read(reader: &mut XReadHelper) -> ModeInfo {
+        let id = reader.read_u32();
+        let width = reader.read_u16();
+        let height = reader.read_u16();
+        let dot_clock = reader.read_u32();
+        let hsync_start = reader.read_u16();
+        let hsync_end = reader.read_u16();
+        let htotal = reader.read_u16();
+        let hskew = reader.read_u16();
+        let vsync_start = reader.read_u16();
+        let vsync_end = reader.read_u16();
+        let vtotal = reader.read_u16();
+        let name_len = reader.read_u16();
+        let mode_flags = reader.read_u32();
+        ModeInfo { id, width, height, dot_clock, hsync_start, hsync_end, htotal, hskew, vsync_start, vsync_end, vtotal, name_len, mode_flags }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.id);
+        client.write_u16(self.width);
+        client.write_u16(self.height);
+        client.write_u32(self.dot_clock);
+        client.write_u16(self.hsync_start);
+        client.write_u16(self.hsync_end);
+        client.write_u16(self.htotal);
+        client.write_u16(self.hskew);
+        client.write_u16(self.vsync_start);
+        client.write_u16(self.vsync_end);
+        client.write_u16(self.vtotal);
+        client.write_u16(self.name_len);
+        client.write_u32(self.mode_flags);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct CrtcChange {
+    pub timestamp: u32,
+    pub window: u32,
+    pub crtc: u32,
+    pub mode: u32,
+    pub rotation: u16,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16
+}
+impl CrtcChange {
+    pub fn read(reader: &mut XReadHelper) -> CrtcChange {
+        let timestamp = reader.read_u32();
+        let window = reader.read_u32();
+        let crtc = reader.read_u32();
+        let mode = reader.read_u32();
+        let rotation = reader.read_u16();
+        reader.read_pad(2);
+        let x = reader.read_i16();
+        let y = reader.read_i16();
+        let width = reader.read_u16();
+        let height = reader.read_u16();
+        CrtcChange { timestamp, window, crtc, mode, rotation, x, y, width, height }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.timestamp);
+        client.write_u32(self.window);
+        client.write_u32(self.crtc);
+        client.write_u32(self.mode);
+        client.write_u16(self.rotation);
+        client.write_pad(2);
+        client.write_i16(self.x);
+        client.write_i16(self.y);
+        client.write_u16(self.width);
+        client.write_u16(self.height);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct OutputChange {
+    pub timestamp: u32,
+    pub config_timestamp: u32,
+    pub window: u32,
+    pub output: u32,
+    pub crtc: u32,
+    pub mode: u32,
+    pub rotation: u16,
+    pub connection: u8,
+    pub subpixel_order: u8
+}
+impl OutputChange {
+    pub fn read(reader: &mut XReadHelper) -> OutputChange {
+        let timestamp = reader.read_u32();
+        let config_timestamp = reader.read_u32();
+        let window = reader.read_u32();
+        let output = reader.read_u32();
+        let crtc = reader.read_u32();
+        let mode = reader.read_u32();
+        let rotation = reader.read_u16();
+        let connection = reader.read_u8();
+        let subpixel_order = reader.read_u8();
+        OutputChange { timestamp, config_timestamp, window, output, crtc, mode, rotation, connection, subpixel_order }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.timestamp);
+        client.write_u32(self.config_timestamp);
+        client.write_u32(self.window);
+        client.write_u32(self.output);
+        client.write_u32(self.crtc);
+        client.write_u32(self.mode);
+        client.write_u16(self.rotation);
+        client.write_u8(self.connection);
+        client.write_u8(self.subpixel_order);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct OutputProperty {
+    pub window: u32,
+    pub output: u32,
+    pub atom: u32,
+    pub timestamp: u32,
+    pub status: u8
+}
+impl OutputProperty {
+    pub fn read(reader: &mut XReadHelper) -> OutputProperty {
+        let window = reader.read_u32();
+        let output = reader.read_u32();
+        let atom = reader.read_u32();
+        let timestamp = reader.read_u32();
+        let status = reader.read_u8();
+        reader.read_pad(11);
+        OutputProperty { window, output, atom, timestamp, status }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.window);
+        client.write_u32(self.output);
+        client.write_u32(self.atom);
+        client.write_u32(self.timestamp);
+        client.write_u8(self.status);
+        client.write_pad(11);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ProviderChange {
+    pub timestamp: u32,
+    pub window: u32,
+    pub provider: u32
+}
+impl ProviderChange {
+    pub fn read(reader: &mut XReadHelper) -> ProviderChange {
+        let timestamp = reader.read_u32();
+        let window = reader.read_u32();
+        let provider = reader.read_u32();
+        reader.read_pad(16);
+        ProviderChange { timestamp, window, provider }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.timestamp);
+        client.write_u32(self.window);
+        client.write_u32(self.provider);
+        client.write_pad(16);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ProviderProperty {
+    pub window: u32,
+    pub provider: u32,
+    pub atom: u32,
+    pub timestamp: u32,
+    pub state: u8
+}
+impl ProviderProperty {
+    pub fn read(reader: &mut XReadHelper) -> ProviderProperty {
+        let window = reader.read_u32();
+        let provider = reader.read_u32();
+        let atom = reader.read_u32();
+        let timestamp = reader.read_u32();
+        let state = reader.read_u8();
+        reader.read_pad(11);
+        ProviderProperty { window, provider, atom, timestamp, state }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.window);
+        client.write_u32(self.provider);
+        client.write_u32(self.atom);
+        client.write_u32(self.timestamp);
+        client.write_u8(self.state);
+        client.write_pad(11);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ResourceChange {
+    pub timestamp: u32,
+    pub window: u32
+}
+impl ResourceChange {
+    pub fn read(reader: &mut XReadHelper) -> ResourceChange {
+        let timestamp = reader.read_u32();
+        let window = reader.read_u32();
+        reader.read_pad(20);
+        ResourceChange { timestamp, window }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.timestamp);
+        client.write_u32(self.window);
+        client.write_pad(20);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MonitorInfo {
+    pub name: u32,
+    pub primary: bool,
+    pub automatic: bool,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub width_in_millimeters: u32,
+    pub height_in_millimeters: u32,
+    pub outputs: Vec<u32>
+}
+impl MonitorInfo {
+    pub fn read(reader: &mut XReadHelper) -> MonitorInfo {
+        let name = reader.read_u32();
+        let primary = reader.read_bool();
+        let automatic = reader.read_bool();
+        let n_output = reader.read_u16();
+        let x = reader.read_i16();
+        let y = reader.read_i16();
+        let width = reader.read_u16();
+        let height = reader.read_u16();
+        let width_in_millimeters = reader.read_u32();
+        let height_in_millimeters = reader.read_u32();
+        let mut outputs = Vec::with_capacity(n_output as usize);
+        for _ in 0..n_output as usize {
+            outputs.push(reader.read_u32());
+        }
+        MonitorInfo { name, primary, automatic, x, y, width, height, width_in_millimeters, height_in_millimeters, outputs }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.name);
+        client.write_bool(self.primary);
+        client.write_bool(self.automatic);
+        client.write_u16(self.outputs.len() as u16);
+        client.write_i16(self.x);
+        client.write_i16(self.y);
+        client.write_u16(self.width);
+        client.write_u16(self.height);
+        client.write_u32(self.width_in_millimeters);
+        client.write_u32(self.height_in_millimeters);
+        for value in &self.outputs {
+            client.write_u32(*value);
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct LeaseNotify {
+    pub timestamp: u32,
+    pub window: u32,
+    pub lease: u32,
+    pub created: u8
+}
+impl LeaseNotify {
+    pub fn read(reader: &mut XReadHelper) -> LeaseNotify {
+        let timestamp = reader.read_u32();
+        let window = reader.read_u32();
+        let lease = reader.read_u32();
+        let created = reader.read_u8();
+        reader.read_pad(15);
+        LeaseNotify { timestamp, window, lease, created }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.timestamp);
+        client.write_u32(self.window);
+        client.write_u32(self.lease);
+        client.write_u8(self.created);
+        client.write_pad(15);
+    }
+}
+
+#[derive(Debug)]
+pub enum RandRReply {
+    QueryVersion {
+        major_version: u32,
+        minor_version: u32
+    },
+    SetScreenConfig {
+        status: SetConfig,
+        new_timestamp: u32,
+        config_timestamp: u32,
+        root: u32,
+        subpixel_order: u16
+    },
+    GetScreenInfo {
+        rotations: u8,
+        root: u32,
+        timestamp: u32,
+        config_timestamp: u32,
+        size_id: u16,
+        rotation: u16,
+        rate: u16,
+        n_info: u16,
+        sizes: Vec<ScreenSize>,
+        rates: Vec<RefreshRates>
+    },
+    GetScreenSizeRange {
+        min_width: u16,
+        min_height: u16,
+        max_width: u16,
+        max_height: u16
+    },
+    GetScreenResources {
+        timestamp: u32,
+        config_timestamp: u32,
+        crtcs: Vec<u32>,
+        outputs: Vec<u32>,
+        modes: Vec<ModeInfo>,
+        names: Vec<u8>
+    },
+    GetOutputInfo {
+        status: SetConfig,
+        timestamp: u32,
+        crtc: u32,
+        mm_width: u32,
+        mm_height: u32,
+        connection: Connection,
+        subpixel_order: u8,
+        num_preferred: u16,
+        crtcs: Vec<u32>,
+        modes: Vec<u32>,
+        clones: Vec<u32>,
+        name: Vec<u8>
+    },
+    ListOutputProperties {
+        atoms: Vec<u32>
+    },
+    QueryOutputProperty {
+        pending: bool,
+        range: bool,
+        immutable: bool,
+        valid_values: Vec<i32>
+    },
+    GetOutputProperty {
+        format: u8,
+        kind: u32,
+        bytes_after: u32,
+        num_items: u32,
+        data: Vec<u8>
+    },
+    CreateMode {
+        mode: u32
+    },
+    GetCrtcInfo {
+        status: SetConfig,
+        timestamp: u32,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        mode: u32,
+        rotation: u16,
+        rotations: u16,
+        outputs: Vec<u32>,
+        possible: Vec<u32>
+    },
+    SetCrtcConfig {
+        status: SetConfig,
+        timestamp: u32
+    },
+    GetCrtcGammaSize {
+        size: u16
+    },
+    GetCrtcGamma {
+        red: Vec<u16>,
+        green: Vec<u16>,
+        blue: Vec<u16>
+    },
+    GetScreenResourcesCurrent {
+        timestamp: u32,
+        config_timestamp: u32,
+        crtcs: Vec<u32>,
+        outputs: Vec<u32>,
+        modes: Vec<ModeInfo>,
+        names: Vec<u8>
+    },
+    GetCrtcTransform {
+        pending_transform: super::render::Transform,
+        has_transforms: bool,
+        current_transform: super::render::Transform,
+        pending_filter_name: String,
+        pending_params: Vec<f64>,
+        current_filter_name: String,
+        current_params: Vec<f64>
+    },
+    GetPanning {
+        status: SetConfig,
+        timestamp: u32,
+        left: u16,
+        top: u16,
+        width: u16,
+        height: u16,
+        track_left: u16,
+        track_top: u16,
+        track_width: u16,
+        track_height: u16,
+        border_left: i16,
+        border_top: i16,
+        border_right: i16,
+        border_bottom: i16
+    },
+    SetPanning {
+        status: SetConfig,
+        timestamp: u32
+    },
+    GetOutputPrimary {
+        output: u32
+    },
+    GetProviders {
+        timestamp: u32,
+        providers: Vec<u32>
+    },
+    GetProviderInfo {
+        status: u8,
+        timestamp: u32,
+        capabilities: u32,
+        crtcs: Vec<u32>,
+        outputs: Vec<u32>,
+        associated_providers: Vec<u32>,
+        associated_capability: Vec<u32>,
+        name: String
+    },
+    ListProviderProperties {
+        atoms: Vec<u32>
+    },
+    QueryProviderProperty {
+        pending: bool,
+        range: bool,
+        immutable: bool,
+        valid_values: Vec<i32>
+    },
+    GetProviderProperty {
+        format: u8,
+        kind: u32,
+        bytes_after: u32,
+        num_items: u32,
+        data: Vec<u8>
+    },
+    GetMonitors {
+        timestamp: u32,
+        n_outputs: u32,
+        monitors: Vec<MonitorInfo>
+    }
+}
+
+#[derive(Debug)]
+pub enum RandREvent {
+    ScreenChangeNotify {
+        rotation: u8,
+        timestamp: u32,
+        config_timestamp: u32,
+        root: u32,
+        request_window: u32,
+        size_id: u16,
+        subpixel_order: u16,
+        width: u16,
+        height: u16,
+        mwidth: u16,
+        mheight: u16
+    },
+    Notify(NotifyEvent)
+}
+
+/** Decodes RANDR replies and events */
+#[derive(Debug, Default)]
+pub struct RandR;
+
+impl Extension for RandR {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn event_count(&self) -> u8 {
+        2
+    }
+
+    fn error_names(&self) -> &'static [&'static str] {
+        &["BadOutput", "BadCrtc", "BadMode", "BadProvider"]
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_QUERY_VERSION => reader.read_randr_query_version_reply(detail),
+            OP_SET_SCREEN_CONFIG => reader.read_randr_set_screen_config_reply(detail),
+            OP_GET_SCREEN_INFO => reader.read_randr_get_screen_info_reply(detail),
+            OP_GET_SCREEN_SIZE_RANGE => reader.read_randr_get_screen_size_range_reply(detail),
+            OP_GET_SCREEN_RESOURCES => reader.read_randr_get_screen_resources_reply(detail),
+            OP_GET_OUTPUT_INFO => reader.read_randr_get_output_info_reply(detail),
+            OP_LIST_OUTPUT_PROPERTIES => reader.read_randr_list_output_properties_reply(detail),
+            OP_QUERY_OUTPUT_PROPERTY => reader.read_randr_query_output_property_reply(detail),
+            OP_GET_OUTPUT_PROPERTY => reader.read_randr_get_output_property_reply(detail),
+            OP_CREATE_MODE => reader.read_randr_create_mode_reply(detail),
+            OP_GET_CRTC_INFO => reader.read_randr_get_crtc_info_reply(detail),
+            OP_SET_CRTC_CONFIG => reader.read_randr_set_crtc_config_reply(detail),
+            OP_GET_CRTC_GAMMA_SIZE => reader.read_randr_get_crtc_gamma_size_reply(detail),
+            OP_GET_CRTC_GAMMA => reader.read_randr_get_crtc_gamma_reply(detail),
+            OP_GET_SCREEN_RESOURCES_CURRENT => reader.read_randr_get_screen_resources_current_reply(detail),
+            OP_GET_CRTC_TRANSFORM => reader.read_randr_get_crtc_transform_reply(detail),
+            OP_GET_PANNING => reader.read_randr_get_panning_reply(detail),
+            OP_SET_PANNING => reader.read_randr_set_panning_reply(detail),
+            OP_GET_OUTPUT_PRIMARY => reader.read_randr_get_output_primary_reply(detail),
+            OP_GET_PROVIDERS => reader.read_randr_get_providers_reply(detail),
+            OP_GET_PROVIDER_INFO => reader.read_randr_get_provider_info_reply(detail),
+            OP_LIST_PROVIDER_PROPERTIES => reader.read_randr_list_provider_properties_reply(detail),
+            OP_QUERY_PROVIDER_PROPERTY => reader.read_randr_query_provider_property_reply(detail),
+            OP_GET_PROVIDER_PROPERTY => reader.read_randr_get_provider_property_reply(detail),
+            OP_GET_MONITORS => reader.read_randr_get_monitors_reply(detail),
+            _ => None
+        }
+    }
+
+    fn read_event(&self, reader: &mut XReadHelper, code: u8, detail: u8) -> Option<ServerEvent> {
+        match code {
+            EVENT_SCREEN_CHANGE_NOTIFY => reader.read_randr_screen_change_notify_event(detail),
+            EVENT_NOTIFY => reader.read_randr_notify_event(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the RANDR QueryVersion request. Returns None without sending anything if the server doesn't have RANDR, like every request here. */
+    pub fn randr_query_version(&mut self, major_version: u32, minor_version: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_VERSION);
+        self.write_u16(3);
+        self.write_u32(major_version);
+        self.write_u32(minor_version);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_VERSION)))
+    }
+
+    /** Sends the RANDR SetScreenConfig request */
+    pub fn randr_set_screen_config(&mut self, window: u32, timestamp: u32, config_timestamp: u32, size_id: u16, rotation: u16, rate: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_SCREEN_CONFIG);
+        self.write_u16(6);
+        self.write_u32(window);
+        self.write_u32(timestamp);
+        self.write_u32(config_timestamp);
+        self.write_u16(size_id);
+        self.write_u16(rotation);
+        self.write_u16(rate);
+        self.write_pad(2);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_SET_SCREEN_CONFIG)))
+    }
+
+    /** Sends the RANDR SelectInput request */
+    pub fn randr_select_input(&mut self, window: u32, enable: u16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SELECT_INPUT);
+        self.write_u16(3);
+        self.write_u32(window);
+        self.write_u16(enable);
+        self.write_pad(2);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RANDR GetScreenInfo request */
+    pub fn randr_get_screen_info(&mut self, window: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_SCREEN_INFO);
+        self.write_u16(2);
+        self.write_u32(window);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_SCREEN_INFO)))
+    }
+
+    /** Sends the RANDR GetScreenSizeRange request */
+    pub fn randr_get_screen_size_range(&mut self, window: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_SCREEN_SIZE_RANGE);
+        self.write_u16(2);
+        self.write_u32(window);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_SCREEN_SIZE_RANGE)))
+    }
+
+    /** Sends the RANDR SetScreenSize request */
+    pub fn randr_set_screen_size(&mut self, window: u32, width: u16, height: u16, mm_width: u32, mm_height: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_SCREEN_SIZE);
+        self.write_u16(5);
+        self.write_u32(window);
+        self.write_u16(width);
+        self.write_u16(height);
+        self.write_u32(mm_width);
+        self.write_u32(mm_height);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RANDR GetScreenResources request */
+    pub fn randr_get_screen_resources(&mut self, window: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_SCREEN_RESOURCES);
+        self.write_u16(2);
+        self.write_u32(window);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_SCREEN_RESOURCES)))
+    }
+
+    /** Sends the RANDR GetOutputInfo request */
+    pub fn randr_get_output_info(&mut self, output: u32, config_timestamp: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_OUTPUT_INFO);
+        self.write_u16(3);
+        self.write_u32(output);
+        self.write_u32(config_timestamp);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_OUTPUT_INFO)))
+    }
+
+    /** Sends the RANDR ListOutputProperties request */
+    pub fn randr_list_output_properties(&mut self, output: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_LIST_OUTPUT_PROPERTIES);
+        self.write_u16(2);
+        self.write_u32(output);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_LIST_OUTPUT_PROPERTIES)))
+    }
+
+    /** Sends the RANDR QueryOutputProperty request */
+    pub fn randr_query_output_property(&mut self, output: u32, property: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_OUTPUT_PROPERTY);
+        self.write_u16(3);
+        self.write_u32(output);
+        self.write_u32(property);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_OUTPUT_PROPERTY)))
+    }
+
+    /** Sends the RANDR ConfigureOutputProperty request */
+    pub fn randr_configure_output_property(&mut self, output: u32, property: u32, pending: bool, range: bool, values: &Vec<i32>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CONFIGURE_OUTPUT_PROPERTY);
+        let pad = self.write_dynamic_len(4, values.len() * 4);
+        self.write_u32(output);
+        self.write_u32(property);
+        self.write_bool(pending);
+        self.write_bool(range);
+        self.write_pad(2);
+        for value in values {
+            self.write_i32(*value);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RANDR ChangeOutputProperty request */
+    pub fn randr_change_output_property(&mut self, output: u32, property: u32, kind: u32, format: u8, mode: u8, num_units: u32, data: &[u8]) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CHANGE_OUTPUT_PROPERTY);
+        let pad = self.write_dynamic_len(6, data.len());
+        self.write_u32(output);
+        self.write_u32(property);
+        self.write_u32(kind);
+        self.write_u8(format);
+        self.write_u8(mode);
+        self.write_pad(2);
+        self.write_u32(num_units);
+        self.write_raw(data);
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RANDR DeleteOutputProperty request */
+    pub fn randr_delete_output_property(&mut self, output: u32, property: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_DELETE_OUTPUT_PROPERTY);
+        self.write_u16(3);
+        self.write_u32(output);
+        self.write_u32(property);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RANDR GetOutputProperty request */
+    pub fn randr_get_output_property(&mut self, output: u32, property: u32, kind: u32, long_offset: u32, long_length: u32, delete: bool, pending: bool) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_OUTPUT_PROPERTY);
+        self.write_u16(7);
+        self.write_u32(output);
+        self.write_u32(property);
+        self.write_u32(kind);
+        self.write_u32(long_offset);
+        self.write_u32(long_length);
+        self.write_bool(delete);
+        self.write_bool(pending);
+        self.write_pad(2);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_OUTPUT_PROPERTY)))
+    }
+
+    /** Sends the RANDR CreateMode request */
+    pub fn randr_create_mode(&mut self, window: u32, mode_info: &ModeInfo, name: &str) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_MODE);
+        let pad = self.write_dynamic_len(10, name.len());
+        self.write_u32(window);
+        mode_info.write(self);
+        self.write_str(name);
+        self.write_pad_op(pad);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_CREATE_MODE)))
+    }
+
+    /** Sends the RANDR DestroyMode request */
+    pub fn randr_destroy_mode(&mut self, mode: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_DESTROY_MODE);
+        self.write_u16(2);
+        self.write_u32(mode);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RANDR AddOutputMode request */
+    pub fn randr_add_output_mode(&mut self, output: u32, mode: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_ADD_OUTPUT_MODE);
+        self.write_u16(3);
+        self.write_u32(output);
+        self.write_u32(mode);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RANDR DeleteOutputMode request */
+    pub fn randr_delete_output_mode(&mut self, output: u32, mode: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_DELETE_OUTPUT_MODE);
+        self.write_u16(3);
+        self.write_u32(output);
+        self.write_u32(mode);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RANDR GetCrtcInfo request */
+    pub fn randr_get_crtc_info(&mut self, crtc: u32, config_timestamp: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_CRTC_INFO);
+        self.write_u16(3);
+        self.write_u32(crtc);
+        self.write_u32(config_timestamp);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_CRTC_INFO)))
+    }
+
+    /** Sends the RANDR SetCrtcConfig request */
+    pub fn randr_set_crtc_config(&mut self, crtc: u32, timestamp: u32, config_timestamp: u32, x: i16, y: i16, mode: u32, rotation: u16, outputs: &Vec<u32>) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_CRTC_CONFIG);
+        let pad = self.write_dynamic_len(7, outputs.len() * 4);
+        self.write_u32(crtc);
+        self.write_u32(timestamp);
+        self.write_u32(config_timestamp);
+        self.write_i16(x);
+        self.write_i16(y);
+        self.write_u32(mode);
+        self.write_u16(rotation);
+        self.write_pad(2);
+        for value in outputs {
+            self.write_u32(*value);
+        }
+        self.write_pad_op(pad);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_SET_CRTC_CONFIG)))
+    }
+
+    /** Sends the RANDR GetCrtcGammaSize request */
+    pub fn randr_get_crtc_gamma_size(&mut self, crtc: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_CRTC_GAMMA_SIZE);
+        self.write_u16(2);
+        self.write_u32(crtc);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_CRTC_GAMMA_SIZE)))
+    }
+
+    /** Sends the RANDR GetCrtcGamma request */
+    pub fn randr_get_crtc_gamma(&mut self, crtc: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_CRTC_GAMMA);
+        self.write_u16(2);
+        self.write_u32(crtc);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_CRTC_GAMMA)))
+    }
+
+    /** Sends the RANDR SetCrtcGamma request. Panics if `red`, `green`, and `blue` aren't the same length. */
+    pub fn randr_set_crtc_gamma(&mut self, crtc: u32, red: &Vec<u16>, green: &Vec<u16>, blue: &Vec<u16>) -> Option<()> {
+        assert_eq!(red.len(), green.len(), "red and green need the same length");
+        assert_eq!(red.len(), blue.len(), "red and blue need the same length");
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_CRTC_GAMMA);
+        let pad = self.write_dynamic_len(3, red.len() * 2 + green.len() * 2 + blue.len() * 2);
+        self.write_u32(crtc);
+        self.write_u16(red.len() as u16);
+        self.write_pad(2);
+        for value in red {
+            self.write_u16(*value);
+        }
+        for value in green {
+            self.write_u16(*value);
+        }
+        for value in blue {
+            self.write_u16(*value);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RANDR GetScreenResourcesCurrent request */
+    pub fn randr_get_screen_resources_current(&mut self, window: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_SCREEN_RESOURCES_CURRENT);
+        self.write_u16(2);
+        self.write_u32(window);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_SCREEN_RESOURCES_CURRENT)))
+    }
+
+    /** Sends the RANDR SetCrtcTransform request */
+    pub fn randr_set_crtc_transform(&mut self, crtc: u32, transform: &super::render::Transform, filter_name: &str, filter_params: &Vec<f64>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_CRTC_TRANSFORM);
+        let pad = self.write_dynamic_len(12, filter_name.len() + (4 - (48 + filter_name.len()) % 4) % 4 + filter_params.len() * 4);
+        self.write_u32(crtc);
+        transform.write(self);
+        self.write_u16(filter_name.len() as u16);
+        self.write_pad(2);
+        self.write_str(filter_name);
+        self.write_pad_op((4 - (48 + filter_name.len()) % 4) % 4);
+        for value in filter_params {
+            self.write_fp1616(*value);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RANDR GetCrtcTransform request */
+    pub fn randr_get_crtc_transform(&mut self, crtc: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_CRTC_TRANSFORM);
+        self.write_u16(2);
+        self.write_u32(crtc);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_CRTC_TRANSFORM)))
+    }
+
+    /** Sends the RANDR GetPanning request */
+    pub fn randr_get_panning(&mut self, crtc: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_PANNING);
+        self.write_u16(2);
+        self.write_u32(crtc);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_PANNING)))
+    }
+
+    /** Sends the RANDR SetPanning request */
+    pub fn randr_set_panning(&mut self, crtc: u32, timestamp: u32, left: u16, top: u16, width: u16, height: u16, track_left: u16, track_top: u16, track_width: u16, track_height: u16, border_left: i16, border_top: i16, border_right: i16, border_bottom: i16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_PANNING);
+        self.write_u16(9);
+        self.write_u32(crtc);
+        self.write_u32(timestamp);
+        self.write_u16(left);
+        self.write_u16(top);
+        self.write_u16(width);
+        self.write_u16(height);
+        self.write_u16(track_left);
+        self.write_u16(track_top);
+        self.write_u16(track_width);
+        self.write_u16(track_height);
+        self.write_i16(border_left);
+        self.write_i16(border_top);
+        self.write_i16(border_right);
+        self.write_i16(border_bottom);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_SET_PANNING)))
+    }
+
+    /** Sends the RANDR SetOutputPrimary request */
+    pub fn randr_set_output_primary(&mut self, window: u32, output: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_OUTPUT_PRIMARY);
+        self.write_u16(3);
+        self.write_u32(window);
+        self.write_u32(output);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RANDR GetOutputPrimary request */
+    pub fn randr_get_output_primary(&mut self, window: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_OUTPUT_PRIMARY);
+        self.write_u16(2);
+        self.write_u32(window);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_OUTPUT_PRIMARY)))
+    }
+
+    /** Sends the RANDR GetProviders request */
+    pub fn randr_get_providers(&mut self, window: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_PROVIDERS);
+        self.write_u16(2);
+        self.write_u32(window);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_PROVIDERS)))
+    }
+
+    /** Sends the RANDR GetProviderInfo request */
+    pub fn randr_get_provider_info(&mut self, provider: u32, config_timestamp: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_PROVIDER_INFO);
+        self.write_u16(3);
+        self.write_u32(provider);
+        self.write_u32(config_timestamp);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_PROVIDER_INFO)))
+    }
+
+    /** Sends the RANDR SetProviderOffloadSink request */
+    pub fn randr_set_provider_offload_sink(&mut self, provider: u32, sink_provider: u32, config_timestamp: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_PROVIDER_OFFLOAD_SINK);
+        self.write_u16(4);
+        self.write_u32(provider);
+        self.write_u32(sink_provider);
+        self.write_u32(config_timestamp);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RANDR SetProviderOutputSource request */
+    pub fn randr_set_provider_output_source(&mut self, provider: u32, source_provider: u32, config_timestamp: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_PROVIDER_OUTPUT_SOURCE);
+        self.write_u16(4);
+        self.write_u32(provider);
+        self.write_u32(source_provider);
+        self.write_u32(config_timestamp);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RANDR ListProviderProperties request */
+    pub fn randr_list_provider_properties(&mut self, provider: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_LIST_PROVIDER_PROPERTIES);
+        self.write_u16(2);
+        self.write_u32(provider);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_LIST_PROVIDER_PROPERTIES)))
+    }
+
+    /** Sends the RANDR QueryProviderProperty request */
+    pub fn randr_query_provider_property(&mut self, provider: u32, property: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_PROVIDER_PROPERTY);
+        self.write_u16(3);
+        self.write_u32(provider);
+        self.write_u32(property);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_PROVIDER_PROPERTY)))
+    }
+
+    /** Sends the RANDR ConfigureProviderProperty request */
+    pub fn randr_configure_provider_property(&mut self, provider: u32, property: u32, pending: bool, range: bool, values: &Vec<i32>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CONFIGURE_PROVIDER_PROPERTY);
+        let pad = self.write_dynamic_len(4, values.len() * 4);
+        self.write_u32(provider);
+        self.write_u32(property);
+        self.write_bool(pending);
+        self.write_bool(range);
+        self.write_pad(2);
+        for value in values {
+            self.write_i32(*value);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RANDR ChangeProviderProperty request */
+    pub fn randr_change_provider_property(&mut self, provider: u32, property: u32, kind: u32, format: u8, mode: u8, num_items: u32, data: &[u8]) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CHANGE_PROVIDER_PROPERTY);
+        let pad = self.write_dynamic_len(6, data.len());
+        self.write_u32(provider);
+        self.write_u32(property);
+        self.write_u32(kind);
+        self.write_u8(format);
+        self.write_u8(mode);
+        self.write_pad(2);
+        self.write_u32(num_items);
+        self.write_raw(data);
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RANDR DeleteProviderProperty request */
+    pub fn randr_delete_provider_property(&mut self, provider: u32, property: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_DELETE_PROVIDER_PROPERTY);
+        self.write_u16(3);
+        self.write_u32(provider);
+        self.write_u32(property);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RANDR GetProviderProperty request */
+    pub fn randr_get_provider_property(&mut self, provider: u32, property: u32, kind: u32, long_offset: u32, long_length: u32, delete: bool, pending: bool) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_PROVIDER_PROPERTY);
+        self.write_u16(7);
+        self.write_u32(provider);
+        self.write_u32(property);
+        self.write_u32(kind);
+        self.write_u32(long_offset);
+        self.write_u32(long_length);
+        self.write_bool(delete);
+        self.write_bool(pending);
+        self.write_pad(2);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_PROVIDER_PROPERTY)))
+    }
+
+    /** Sends the RANDR GetMonitors request */
+    pub fn randr_get_monitors(&mut self, window: u32, get_active: bool) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_MONITORS);
+        self.write_u16(3);
+        self.write_u32(window);
+        self.write_bool(get_active);
+        self.write_pad(3);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_MONITORS)))
+    }
+
+    /** Sends the RANDR DeleteMonitor request */
+    pub fn randr_delete_monitor(&mut self, window: u32, name: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_DELETE_MONITOR);
+        self.write_u16(3);
+        self.write_u32(window);
+        self.write_u32(name);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RANDR CreateLease request */
+    pub fn randr_create_lease(&mut self, window: u32, lid: u32, crtcs: &Vec<u32>, outputs: &Vec<u32>) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_LEASE);
+        let pad = self.write_dynamic_len(4, crtcs.len() * 4 + outputs.len() * 4);
+        self.write_u32(window);
+        self.write_u32(lid);
+        self.write_u16(crtcs.len() as u16);
+        self.write_u16(outputs.len() as u16);
+        for value in crtcs {
+            self.write_u32(*value);
+        }
+        for value in outputs {
+            self.write_u32(*value);
+        }
+        self.write_pad_op(pad);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_CREATE_LEASE)))
+    }
+
+    /** Sends the RANDR FreeLease request */
+    pub fn randr_free_lease(&mut self, lid: u32, terminate: u8) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_FREE_LEASE);
+        self.write_u16(3);
+        self.write_u32(lid);
+        self.write_u8(terminate);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to RANDR QueryVersion */
+    fn read_randr_query_version_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let major_version = self.read_u32();
+        let minor_version = self.read_u32();
+        self.read_pad(16);
+        Some(ServerReply::RandR(RandRReply::QueryVersion { major_version, minor_version }))
+    }
+
+    /** Reads a reply to RANDR SetScreenConfig */
+    fn read_randr_set_screen_config_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let status = detail;
+        let status = match SetConfig::get(status as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let new_timestamp = self.read_u32();
+        let config_timestamp = self.read_u32();
+        let root = self.read_u32();
+        let subpixel_order = self.read_u16();
+        self.read_pad(10);
+        Some(ServerReply::RandR(RandRReply::SetScreenConfig { status, new_timestamp, config_timestamp, root, subpixel_order }))
+    }
+
+    /** Reads a reply to RANDR GetScreenInfo */
+    fn read_randr_get_screen_info_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let rotations = detail;
+        let root = self.read_u32();
+        let timestamp = self.read_u32();
+        let config_timestamp = self.read_u32();
+        let n_sizes = self.read_u16();
+        let size_id = self.read_u16();
+        let rotation = self.read_u16();
+        let rate = self.read_u16();
+        let n_info = self.read_u16();
+        self.read_pad(2);
+        let mut sizes = Vec::with_capacity(n_sizes as usize);
+        for _ in 0..n_sizes as usize {
+            sizes.push(ScreenSize::read(self));
+        }
+        let mut rates = Vec::with_capacity((n_info as usize) - (n_sizes as usize));
+        for _ in 0..(n_info as usize) - (n_sizes as usize) {
+            rates.push(RefreshRates::read(self));
+        }
+        Some(ServerReply::RandR(RandRReply::GetScreenInfo { rotations, root, timestamp, config_timestamp, size_id, rotation, rate, n_info, sizes, rates }))
+    }
+
+    /** Reads a reply to RANDR GetScreenSizeRange */
+    fn read_randr_get_screen_size_range_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let min_width = self.read_u16();
+        let min_height = self.read_u16();
+        let max_width = self.read_u16();
+        let max_height = self.read_u16();
+        self.read_pad(16);
+        Some(ServerReply::RandR(RandRReply::GetScreenSizeRange { min_width, min_height, max_width, max_height }))
+    }
+
+    /** Reads a reply to RANDR GetScreenResources */
+    fn read_randr_get_screen_resources_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let timestamp = self.read_u32();
+        let config_timestamp = self.read_u32();
+        let num_crtcs = self.read_u16();
+        let num_outputs = self.read_u16();
+        let num_modes = self.read_u16();
+        let names_len = self.read_u16();
+        self.read_pad(8);
+        let mut crtcs = Vec::with_capacity(num_crtcs as usize);
+        for _ in 0..num_crtcs as usize {
+            crtcs.push(self.read_u32());
+        }
+        let mut outputs = Vec::with_capacity(num_outputs as usize);
+        for _ in 0..num_outputs as usize {
+            outputs.push(self.read_u32());
+        }
+        let mut modes = Vec::with_capacity(num_modes as usize);
+        for _ in 0..num_modes as usize {
+            modes.push(ModeInfo::read(self));
+        }
+        let names = self.read_raw(names_len as usize);
+        Some(ServerReply::RandR(RandRReply::GetScreenResources { timestamp, config_timestamp, crtcs, outputs, modes, names }))
+    }
+
+    /** Reads a reply to RANDR GetOutputInfo */
+    fn read_randr_get_output_info_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let status = detail;
+        let status = match SetConfig::get(status as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let timestamp = self.read_u32();
+        let crtc = self.read_u32();
+        let mm_width = self.read_u32();
+        let mm_height = self.read_u32();
+        let connection = self.read_u8();
+        let connection = match Connection::get(connection as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let subpixel_order = self.read_u8();
+        let num_crtcs = self.read_u16();
+        let num_modes = self.read_u16();
+        let num_preferred = self.read_u16();
+        let num_clones = self.read_u16();
+        let name_len = self.read_u16();
+        let mut crtcs = Vec::with_capacity(num_crtcs as usize);
+        for _ in 0..num_crtcs as usize {
+            crtcs.push(self.read_u32());
+        }
+        let mut modes = Vec::with_capacity(num_modes as usize);
+        for _ in 0..num_modes as usize {
+            modes.push(self.read_u32());
+        }
+        let mut clones = Vec::with_capacity(num_clones as usize);
+        for _ in 0..num_clones as usize {
+            clones.push(self.read_u32());
+        }
+        let name = self.read_raw(name_len as usize);
+        Some(ServerReply::RandR(RandRReply::GetOutputInfo { status, timestamp, crtc, mm_width, mm_height, connection, subpixel_order, num_preferred, crtcs, modes, clones, name }))
+    }
+
+    /** Reads a reply to RANDR ListOutputProperties */
+    fn read_randr_list_output_properties_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let num_atoms = self.read_u16();
+        self.read_pad(22);
+        let mut atoms = Vec::with_capacity(num_atoms as usize);
+        for _ in 0..num_atoms as usize {
+            atoms.push(self.read_u32());
+        }
+        Some(ServerReply::RandR(RandRReply::ListOutputProperties { atoms }))
+    }
+
+    /** Reads a reply to RANDR QueryOutputProperty */
+    fn read_randr_query_output_property_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let length = self.extra_length();
+        let pending = self.read_bool();
+        let range = self.read_bool();
+        let immutable = self.read_bool();
+        self.read_pad(21);
+        let mut valid_values = Vec::with_capacity(length as usize);
+        for _ in 0..length as usize {
+            valid_values.push(self.read_i32());
+        }
+        Some(ServerReply::RandR(RandRReply::QueryOutputProperty { pending, range, immutable, valid_values }))
+    }
+
+    /** Reads a reply to RANDR GetOutputProperty */
+    fn read_randr_get_output_property_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let format = detail;
+        let kind = self.read_u32();
+        let bytes_after = self.read_u32();
+        let num_items = self.read_u32();
+        self.read_pad(12);
+        let data = self.read_raw((num_items as usize) * ((format as usize) / 8));
+        Some(ServerReply::RandR(RandRReply::GetOutputProperty { format, kind, bytes_after, num_items, data }))
+    }
+
+    /** Reads a reply to RANDR CreateMode */
+    fn read_randr_create_mode_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let mode = self.read_u32();
+        self.read_pad(20);
+        Some(ServerReply::RandR(RandRReply::CreateMode { mode }))
+    }
+
+    /** Reads a reply to RANDR GetCrtcInfo */
+    fn read_randr_get_crtc_info_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let status = detail;
+        let status = match SetConfig::get(status as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let timestamp = self.read_u32();
+        let x = self.read_i16();
+        let y = self.read_i16();
+        let width = self.read_u16();
+        let height = self.read_u16();
+        let mode = self.read_u32();
+        let rotation = self.read_u16();
+        let rotations = self.read_u16();
+        let num_outputs = self.read_u16();
+        let num_possible_outputs = self.read_u16();
+        let mut outputs = Vec::with_capacity(num_outputs as usize);
+        for _ in 0..num_outputs as usize {
+            outputs.push(self.read_u32());
+        }
+        let mut possible = Vec::with_capacity(num_possible_outputs as usize);
+        for _ in 0..num_possible_outputs as usize {
+            possible.push(self.read_u32());
+        }
+        Some(ServerReply::RandR(RandRReply::GetCrtcInfo { status, timestamp, x, y, width, height, mode, rotation, rotations, outputs, possible }))
+    }
+
+    /** Reads a reply to RANDR SetCrtcConfig */
+    fn read_randr_set_crtc_config_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let status = detail;
+        let status = match SetConfig::get(status as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let timestamp = self.read_u32();
+        self.read_pad(20);
+        Some(ServerReply::RandR(RandRReply::SetCrtcConfig { status, timestamp }))
+    }
+
+    /** Reads a reply to RANDR GetCrtcGammaSize */
+    fn read_randr_get_crtc_gamma_size_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let size = self.read_u16();
+        self.read_pad(22);
+        Some(ServerReply::RandR(RandRReply::GetCrtcGammaSize { size }))
+    }
+
+    /** Reads a reply to RANDR GetCrtcGamma */
+    fn read_randr_get_crtc_gamma_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let size = self.read_u16();
+        self.read_pad(22);
+        let mut red = Vec::with_capacity(size as usize);
+        for _ in 0..size as usize {
+            red.push(self.read_u16());
+        }
+        let mut green = Vec::with_capacity(size as usize);
+        for _ in 0..size as usize {
+            green.push(self.read_u16());
+        }
+        let mut blue = Vec::with_capacity(size as usize);
+        for _ in 0..size as usize {
+            blue.push(self.read_u16());
+        }
+        Some(ServerReply::RandR(RandRReply::GetCrtcGamma { red, green, blue }))
+    }
+
+    /** Reads a reply to RANDR GetScreenResourcesCurrent */
+    fn read_randr_get_screen_resources_current_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let timestamp = self.read_u32();
+        let config_timestamp = self.read_u32();
+        let num_crtcs = self.read_u16();
+        let num_outputs = self.read_u16();
+        let num_modes = self.read_u16();
+        let names_len = self.read_u16();
+        self.read_pad(8);
+        let mut crtcs = Vec::with_capacity(num_crtcs as usize);
+        for _ in 0..num_crtcs as usize {
+            crtcs.push(self.read_u32());
+        }
+        let mut outputs = Vec::with_capacity(num_outputs as usize);
+        for _ in 0..num_outputs as usize {
+            outputs.push(self.read_u32());
+        }
+        let mut modes = Vec::with_capacity(num_modes as usize);
+        for _ in 0..num_modes as usize {
+            modes.push(ModeInfo::read(self));
+        }
+        let names = self.read_raw(names_len as usize);
+        Some(ServerReply::RandR(RandRReply::GetScreenResourcesCurrent { timestamp, config_timestamp, crtcs, outputs, modes, names }))
+    }
+
+    /** Reads a reply to RANDR GetCrtcTransform */
+    fn read_randr_get_crtc_transform_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let pending_transform = super::render::Transform::read(self);
+        let has_transforms = self.read_bool();
+        self.read_pad(3);
+        let current_transform = super::render::Transform::read(self);
+        self.read_pad(4);
+        let pending_len = self.read_u16();
+        let pending_nparams = self.read_u16();
+        let current_len = self.read_u16();
+        let current_nparams = self.read_u16();
+        let pending_filter_name = self.read_str(pending_len as usize);
+        self.read_pad((4 - (96 + pending_filter_name.len()) % 4) % 4);
+        let mut pending_params = Vec::with_capacity(pending_nparams as usize);
+        for _ in 0..pending_nparams as usize {
+            pending_params.push(self.read_fp1616());
+        }
+        let current_filter_name = self.read_str(current_len as usize);
+        self.read_pad((4 - (pending_params.len() * 4 + current_filter_name.len()) % 4) % 4);
+        let mut current_params = Vec::with_capacity(current_nparams as usize);
+        for _ in 0..current_nparams as usize {
+            current_params.push(self.read_fp1616());
+        }
+        Some(ServerReply::RandR(RandRReply::GetCrtcTransform { pending_transform, has_transforms, current_transform, pending_filter_name, pending_params, current_filter_name, current_params }))
+    }
+
+    /** Reads a reply to RANDR GetPanning */
+    fn read_randr_get_panning_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let status = detail;
+        let status = match SetConfig::get(status as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let timestamp = self.read_u32();
+        let left = self.read_u16();
+        let top = self.read_u16();
+        let width = self.read_u16();
+        let height = self.read_u16();
+        let track_left = self.read_u16();
+        let track_top = self.read_u16();
+        let track_width = self.read_u16();
+        let track_height = self.read_u16();
+        let border_left = self.read_i16();
+        let border_top = self.read_i16();
+        let border_right = self.read_i16();
+        let border_bottom = self.read_i16();
+        Some(ServerReply::RandR(RandRReply::GetPanning { status, timestamp, left, top, width, height, track_left, track_top, track_width, track_height, border_left, border_top, border_right, border_bottom }))
+    }
+
+    /** Reads a reply to RANDR SetPanning */
+    fn read_randr_set_panning_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let status = detail;
+        let status = match SetConfig::get(status as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let timestamp = self.read_u32();
+        Some(ServerReply::RandR(RandRReply::SetPanning { status, timestamp }))
+    }
+
+    /** Reads a reply to RANDR GetOutputPrimary */
+    fn read_randr_get_output_primary_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let output = self.read_u32();
+        Some(ServerReply::RandR(RandRReply::GetOutputPrimary { output }))
+    }
+
+    /** Reads a reply to RANDR GetProviders */
+    fn read_randr_get_providers_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let timestamp = self.read_u32();
+        let num_providers = self.read_u16();
+        self.read_pad(18);
+        let mut providers = Vec::with_capacity(num_providers as usize);
+        for _ in 0..num_providers as usize {
+            providers.push(self.read_u32());
+        }
+        Some(ServerReply::RandR(RandRReply::GetProviders { timestamp, providers }))
+    }
+
+    /** Reads a reply to RANDR GetProviderInfo */
+    fn read_randr_get_provider_info_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let status = detail;
+        let timestamp = self.read_u32();
+        let capabilities = self.read_u32();
+        let num_crtcs = self.read_u16();
+        let num_outputs = self.read_u16();
+        let num_associated_providers = self.read_u16();
+        let name_len = self.read_u16();
+        self.read_pad(8);
+        let mut crtcs = Vec::with_capacity(num_crtcs as usize);
+        for _ in 0..num_crtcs as usize {
+            crtcs.push(self.read_u32());
+        }
+        let mut outputs = Vec::with_capacity(num_outputs as usize);
+        for _ in 0..num_outputs as usize {
+            outputs.push(self.read_u32());
+        }
+        let mut associated_providers = Vec::with_capacity(num_associated_providers as usize);
+        for _ in 0..num_associated_providers as usize {
+            associated_providers.push(self.read_u32());
+        }
+        let mut associated_capability = Vec::with_capacity(num_associated_providers as usize);
+        for _ in 0..num_associated_providers as usize {
+            associated_capability.push(self.read_u32());
+        }
+        let name = self.read_str(name_len as usize);
+        Some(ServerReply::RandR(RandRReply::GetProviderInfo { status, timestamp, capabilities, crtcs, outputs, associated_providers, associated_capability, name }))
+    }
+
+    /** Reads a reply to RANDR ListProviderProperties */
+    fn read_randr_list_provider_properties_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let num_atoms = self.read_u16();
+        self.read_pad(22);
+        let mut atoms = Vec::with_capacity(num_atoms as usize);
+        for _ in 0..num_atoms as usize {
+            atoms.push(self.read_u32());
+        }
+        Some(ServerReply::RandR(RandRReply::ListProviderProperties { atoms }))
+    }
+
+    /** Reads a reply to RANDR QueryProviderProperty */
+    fn read_randr_query_provider_property_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let length = self.extra_length();
+        let pending = self.read_bool();
+        let range = self.read_bool();
+        let immutable = self.read_bool();
+        self.read_pad(21);
+        let mut valid_values = Vec::with_capacity(length as usize);
+        for _ in 0..length as usize {
+            valid_values.push(self.read_i32());
+        }
+        Some(ServerReply::RandR(RandRReply::QueryProviderProperty { pending, range, immutable, valid_values }))
+    }
+
+    /** Reads a reply to RANDR GetProviderProperty */
+    fn read_randr_get_provider_property_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let format = detail;
+        let kind = self.read_u32();
+        let bytes_after = self.read_u32();
+        let num_items = self.read_u32();
+        self.read_pad(12);
+        let data = self.read_raw((num_items as usize) * ((format as usize) / 8));
+        Some(ServerReply::RandR(RandRReply::GetProviderProperty { format, kind, bytes_after, num_items, data }))
+    }
+
+    /** Reads a reply to RANDR GetMonitors */
+    fn read_randr_get_monitors_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let timestamp = self.read_u32();
+        let n_monitors = self.read_u32();
+        let n_outputs = self.read_u32();
+        self.read_pad(12);
+        let mut monitors = Vec::with_capacity(n_monitors as usize);
+        for _ in 0..n_monitors as usize {
+            monitors.push(MonitorInfo::read(self));
+        }
+        Some(ServerReply::RandR(RandRReply::GetMonitors { timestamp, n_outputs, monitors }))
+    }
+
+    /** Reads a RANDR ScreenChangeNotify event */
+    fn read_randr_screen_change_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let rotation = detail;
+        let timestamp = self.read_u32();
+        let config_timestamp = self.read_u32();
+        let root = self.read_u32();
+        let request_window = self.read_u32();
+        let size_id = self.read_u16();
+        let subpixel_order = self.read_u16();
+        let width = self.read_u16();
+        let height = self.read_u16();
+        let mwidth = self.read_u16();
+        let mheight = self.read_u16();
+        Some(ServerEvent::RandR(RandREvent::ScreenChangeNotify { rotation, timestamp, config_timestamp, root, request_window, size_id, subpixel_order, width, height, mwidth, mheight }))
+    }
+}
@@ -0,0 +1,61 @@
+// The RANDR Notify event and SetMonitor, which xcbgen can't generate, and mode names.
+
+use models::ServerEvent;
+use xreaderwriter::{XBufferedWriter, XReadHelper};
+use XClient;
+
+use super::randr::*;
+
+/** A RandR Notify event, which is one of several kinds of change depending on `sub_code` (see SelectInput's NotifyMask) */
+#[derive(Debug, Clone)]
+pub enum NotifyEvent {
+    CrtcChange(CrtcChange),
+    OutputChange(OutputChange),
+    OutputProperty(OutputProperty),
+    ProviderChange(ProviderChange),
+    ProviderProperty(ProviderProperty),
+    ResourceChange(ResourceChange),
+    Lease(LeaseNotify)
+}
+
+/** Splits the `names` of a GetScreenResources(Current) reply into one name per mode */
+pub fn mode_names(modes: &[ModeInfo], names: &[u8]) -> Vec<String> {
+    let mut start = 0;
+    modes.iter().map(|mode| {
+        let end = (start + mode.name_len as usize).min(names.len());
+        let name = String::from_utf8_lossy(&names[start..end]).into_owned();
+        start = end;
+        name
+    }).collect()
+}
+
+impl XClient {
+    /** Sends the RANDR SetMonitor request, which adds a monitor or replaces the one with the same name */
+    pub fn randr_set_monitor(&mut self, window: u32, monitor_info: &MonitorInfo) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<RandR>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_MONITOR);
+        self.write_u16(8 + monitor_info.outputs.len() as u16);
+        self.write_u32(window);
+        monitor_info.write(self);
+
+        self.write_request();
+        Some(())
+    }
+}
+
+impl XReadHelper {
+    /** Reads a RANDR Notify event */
+    pub(crate) fn read_randr_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let event = match Notify::get(detail as u32)? {
+            Notify::CrtcChange => NotifyEvent::CrtcChange(CrtcChange::read(self)),
+            Notify::OutputChange => NotifyEvent::OutputChange(OutputChange::read(self)),
+            Notify::OutputProperty => NotifyEvent::OutputProperty(OutputProperty::read(self)),
+            Notify::ProviderChange => NotifyEvent::ProviderChange(ProviderChange::read(self)),
+            Notify::ProviderProperty => NotifyEvent::ProviderProperty(ProviderProperty::read(self)),
+            Notify::ResourceChange => NotifyEvent::ResourceChange(ResourceChange::read(self)),
+            Notify::Lease => NotifyEvent::Lease(LeaseNotify::read(self))
+        };
+        Some(ServerEvent::RandR(RandREvent::Notify(event)))
+    }
+}
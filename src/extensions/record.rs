@@ -0,0 +1,373 @@
+// RECORD extension, version 1.13
+// Generated by tools/xcbgen from tools/xcb-proto/record.xml. Don't edit this file, change the generator or
+// record_extra.rs (which has everything written by hand) instead.
+//
+// Written by hand in record_extra.rs:
+//   - reply to EnableContext
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+#[allow(unused_imports)] // Some extra modules only add methods
+pub use super::record_extra::*;
+
+pub const EXTENSION_NAME: &str = "RECORD";
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 13;
+
+pub const OP_QUERY_VERSION: u8 = 0;
+pub const OP_CREATE_CONTEXT: u8 = 1;
+pub const OP_REGISTER_CLIENTS: u8 = 2;
+pub const OP_UNREGISTER_CLIENTS: u8 = 3;
+pub const OP_GET_CONTEXT: u8 = 4;
+pub const OP_ENABLE_CONTEXT: u8 = 5;
+pub const OP_DISABLE_CONTEXT: u8 = 6;
+pub const OP_FREE_CONTEXT: u8 = 7;
+
+pub const ERROR_BAD_CONTEXT: u8 = 0;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum HType {
+    FromServerTime,
+    FromClientTime,
+    FromClientSequence
+}
+impl HType {
+    pub fn val(&self) -> u32 {
+        match self {
+            &HType::FromServerTime => 0x00000001,
+            &HType::FromClientTime => 0x00000002,
+            &HType::FromClientSequence => 0x00000004
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Cs {
+    CurrentClients,
+    FutureClients,
+    AllClients
+}
+impl Cs {
+    pub fn get(id: u32) -> Option<Cs> {
+        match id {
+            1 => Some(Cs::CurrentClients),
+            2 => Some(Cs::FutureClients),
+            3 => Some(Cs::AllClients),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &Cs::CurrentClients => 1,
+            &Cs::FutureClients => 2,
+            &Cs::AllClients => 3
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Range8 {
+    pub first: u8,
+    pub last: u8
+}
+impl Range8 {
+    pub fn read(reader: &mut XReadHelper) -> Range8 {
+        let first = reader.read_u8();
+        let last = reader.read_u8();
+        Range8 { first, last }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.first);
+        client.write_u8(self.last);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Range16 {
+    pub first: u16,
+    pub last: u16
+}
+impl Range16 {
+    pub fn read(reader: &mut XReadHelper) -> Range16 {
+        let first = reader.read_u16();
+        let last = reader.read_u16();
+        Range16 { first, last }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.first);
+        client.write_u16(self.last);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ExtRange {
+    pub major: Range8,
+    pub minor: Range16
+}
+impl ExtRange {
+    pub fn read(reader: &mut XReadHelper) -> ExtRange {
+        let major = Range8::read(reader);
+        let minor = Range16::read(reader);
+        ExtRange { major, minor }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        self.major.write(client);
+        self.minor.write(client);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Range {
+    pub core_requests: Range8,
+    pub core_replies: Range8,
+    pub ext_requests: ExtRange,
+    pub ext_replies: ExtRange,
+    pub delivered_events: Range8,
+    pub device_events: Range8,
+    pub errors: Range8,
+    pub client_started: bool,
+    pub client_died: bool
+}
+impl Range {
+    pub fn read(reader: &mut XReadHelper) -> Range {
+        let core_requests = Range8::read(reader);
+        let core_replies = Range8::read(reader);
+        let ext_requests = ExtRange::read(reader);
+        let ext_replies = ExtRange::read(reader);
+        let delivered_events = Range8::read(reader);
+        let device_events = Range8::read(reader);
+        let errors = Range8::read(reader);
+        let client_started = reader.read_bool();
+        let client_died = reader.read_bool();
+        Range { core_requests, core_replies, ext_requests, ext_replies, delivered_events, device_events, errors, client_started, client_died }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        self.core_requests.write(client);
+        self.core_replies.write(client);
+        self.ext_requests.write(client);
+        self.ext_replies.write(client);
+        self.delivered_events.write(client);
+        self.device_events.write(client);
+        self.errors.write(client);
+        client.write_bool(self.client_started);
+        client.write_bool(self.client_died);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ClientInfo {
+    pub client_resource: u32,
+    pub ranges: Vec<Range>
+}
+impl ClientInfo {
+    pub fn read(reader: &mut XReadHelper) -> ClientInfo {
+        let client_resource = reader.read_u32();
+        let num_ranges = reader.read_u32();
+        let mut ranges = Vec::with_capacity(num_ranges as usize);
+        for _ in 0..num_ranges as usize {
+            ranges.push(Range::read(reader));
+        }
+        ClientInfo { client_resource, ranges }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.client_resource);
+        client.write_u32(self.ranges.len() as u32);
+        for value in &self.ranges {
+            value.write(client);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum RecordReply {
+    QueryVersion {
+        major_version: u16,
+        minor_version: u16
+    },
+    GetContext {
+        enabled: bool,
+        element_header: u8,
+        intercepted_clients: Vec<ClientInfo>
+    },
+    EnableContext(EnableContextReply)
+}
+
+/** Decodes RECORD replies and events */
+#[derive(Debug, Default)]
+pub struct Record;
+
+impl Extension for Record {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn error_names(&self) -> &'static [&'static str] {
+        &["BadContext"]
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_QUERY_VERSION => reader.read_record_query_version_reply(detail),
+            OP_GET_CONTEXT => reader.read_record_get_context_reply(detail),
+            OP_ENABLE_CONTEXT => reader.read_record_enable_context_reply(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the RECORD QueryVersion request. Returns None without sending anything if the server doesn't have RECORD, like every request here. */
+    pub fn record_query_version(&mut self, major_version: u16, minor_version: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Record>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_VERSION);
+        self.write_u16(2);
+        self.write_u16(major_version);
+        self.write_u16(minor_version);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_VERSION)))
+    }
+
+    /** Sends the RECORD CreateContext request */
+    pub fn record_create_context(&mut self, context: u32, element_header: u8, client_specs: &Vec<u32>, ranges: &Vec<Range>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Record>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_CONTEXT);
+        let pad = self.write_dynamic_len(5, client_specs.len() * 4 + ranges.len() * 24);
+        self.write_u32(context);
+        self.write_u8(element_header);
+        self.write_pad(3);
+        self.write_u32(client_specs.len() as u32);
+        self.write_u32(ranges.len() as u32);
+        for value in client_specs {
+            self.write_u32(*value);
+        }
+        for value in ranges {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RECORD RegisterClients request */
+    pub fn record_register_clients(&mut self, context: u32, element_header: u8, client_specs: &Vec<u32>, ranges: &Vec<Range>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Record>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_REGISTER_CLIENTS);
+        let pad = self.write_dynamic_len(5, client_specs.len() * 4 + ranges.len() * 24);
+        self.write_u32(context);
+        self.write_u8(element_header);
+        self.write_pad(3);
+        self.write_u32(client_specs.len() as u32);
+        self.write_u32(ranges.len() as u32);
+        for value in client_specs {
+            self.write_u32(*value);
+        }
+        for value in ranges {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RECORD UnregisterClients request */
+    pub fn record_unregister_clients(&mut self, context: u32, client_specs: &Vec<u32>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Record>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_UNREGISTER_CLIENTS);
+        let pad = self.write_dynamic_len(3, client_specs.len() * 4);
+        self.write_u32(context);
+        self.write_u32(client_specs.len() as u32);
+        for value in client_specs {
+            self.write_u32(*value);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RECORD GetContext request */
+    pub fn record_get_context(&mut self, context: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Record>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_CONTEXT);
+        self.write_u16(2);
+        self.write_u32(context);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_CONTEXT)))
+    }
+
+    /** Sends the RECORD EnableContext request */
+    pub fn record_enable_context(&mut self, context: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Record>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_ENABLE_CONTEXT);
+        self.write_u16(2);
+        self.write_u32(context);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_ENABLE_CONTEXT)))
+    }
+
+    /** Sends the RECORD DisableContext request */
+    pub fn record_disable_context(&mut self, context: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Record>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_DISABLE_CONTEXT);
+        self.write_u16(2);
+        self.write_u32(context);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RECORD FreeContext request */
+    pub fn record_free_context(&mut self, context: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Record>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_FREE_CONTEXT);
+        self.write_u16(2);
+        self.write_u32(context);
+
+        self.write_request();
+        Some(())
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to RECORD QueryVersion */
+    fn read_record_query_version_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let major_version = self.read_u16();
+        let minor_version = self.read_u16();
+        Some(ServerReply::Record(RecordReply::QueryVersion { major_version, minor_version }))
+    }
+
+    /** Reads a reply to RECORD GetContext */
+    fn read_record_get_context_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let enabled = detail != 0;
+        let element_header = self.read_u8();
+        self.read_pad(3);
+        let num_intercepted_clients = self.read_u32();
+        self.read_pad(16);
+        let mut intercepted_clients = Vec::with_capacity(num_intercepted_clients as usize);
+        for _ in 0..num_intercepted_clients as usize {
+            intercepted_clients.push(ClientInfo::read(self));
+        }
+        Some(ServerReply::Record(RecordReply::GetContext { enabled, element_header, intercepted_clients }))
+    }
+}
@@ -0,0 +1,239 @@
+// RecordContext and RecordStream, which open the second connection EnableContext needs and decode what it records.
+
+use models::{ServerEvent, ServerReply, ServerResponse};
+use protocol;
+use xreaderwriter::{XBufferedReader, XReadHelper};
+use XClient;
+
+use super::record::*;
+
+/** What the data in an EnableContext reply is */
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Category {
+    FromServer, // Events, errors, and replies the server sent to a recorded client
+    FromClient, // Requests a recorded client sent
+    ClientStarted, // A recorded client connected (data is its connection setup)
+    ClientDied, // A recorded client disconnected
+    StartOfData, // The first reply, once the context is enabled
+    EndOfData // The last reply, after DisableContext (or FreeContext)
+}
+
+impl Category {
+    pub fn get(id: u32) -> Option<Category> {
+        match id {
+            0 => Some(Category::FromServer),
+            1 => Some(Category::FromClient),
+            2 => Some(Category::ClientStarted),
+            3 => Some(Category::ClientDied),
+            4 => Some(Category::StartOfData),
+            5 => Some(Category::EndOfData),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match *self {
+            Category::FromServer => 0,
+            Category::FromClient => 1,
+            Category::ClientStarted => 2,
+            Category::ClientDied => 3,
+            Category::StartOfData => 4,
+            Category::EndOfData => 5
+        }
+    }
+}
+
+/** One of the replies to EnableContext. There is one for every bit of protocol recorded, until EndOfData. */
+#[derive(Debug)]
+pub struct EnableContextReply {
+    pub category: Category,
+    pub element_header: u8, // Which HType values the context was created with (what's around each element in `data`)
+    pub client_swapped: bool, // The recorded client uses the other byte order, so `data` does too
+    pub xid_base: u32, // The resource ID base of the recorded client (0 if it's not about one client)
+    pub server_time: u32,
+    pub rec_sequence_num: u32, // Counts up from 0 for every reply to this EnableContext
+    pub events: Vec<ServerEvent>, // The events in `data`, when it's FromServer
+    pub data: Vec<u8> // The recorded protocol, as it was sent
+}
+
+impl Range {
+    /** A range with nothing in it, to fill in with what should be recorded */
+    pub fn none() -> Range {
+        let none8 = Range8 { first: 0, last: 0 };
+        let none_ext = ExtRange { major: none8, minor: Range16 { first: 0, last: 0 } };
+        Range {
+            core_requests: none8,
+            core_replies: none8,
+            ext_requests: none_ext,
+            ext_replies: none_ext,
+            delivered_events: none8,
+            device_events: none8,
+            errors: none8,
+            client_started: false,
+            client_died: false
+        }
+    }
+
+    /** Records input (events from first to last, ie KeyPress to MotionNotify) as the server gets it, before it's delivered to any window */
+    pub fn device_events(first: u8, last: u8) -> Range {
+        Range { device_events: Range8 { first, last }, ..Range::none() }
+    }
+
+    /** Records events (from first to last) as they are sent to the recorded clients */
+    pub fn delivered_events(first: u8, last: u8) -> Range {
+        Range { delivered_events: Range8 { first, last }, ..Range::none() }
+    }
+}
+
+/** A RECORD context: what to record from which clients */
+#[derive(Debug)]
+pub struct RecordContext {
+    pub context: u32
+}
+
+impl RecordContext {
+    /**
+     * Creates a context. `element_header` is made of HType values, and `client_specs` are client resource IDs or Cs values.
+     * None if the server doesn't have RECORD.
+     */
+    pub fn create(client: &mut XClient, element_header: u8, client_specs: &[u32], ranges: &[Range]) -> Option<RecordContext> {
+        let context = client.new_resource_id();
+        client.record_create_context(context, element_header, &client_specs.to_vec(), &ranges.to_vec())?;
+        Some(RecordContext { context })
+    }
+
+    /** Records more clients, or changes what's recorded from them */
+    pub fn register_clients(&self, client: &mut XClient, element_header: u8, client_specs: &[u32], ranges: &[Range]) {
+        client.record_register_clients(self.context, element_header, &client_specs.to_vec(), &ranges.to_vec());
+    }
+
+    /** Stops recording some clients */
+    pub fn unregister_clients(&self, client: &mut XClient, client_specs: &[u32]) {
+        client.record_unregister_clients(self.context, &client_specs.to_vec());
+    }
+
+    /**
+     * Starts recording. The data comes on a second connection to the same server (opened here), because the replies
+     * keep coming until the context is disabled. Blocks until the connection is set up.
+     */
+    pub fn enable(&self, client: &XClient) -> RecordStream {
+        let mut data = client.connect_again();
+        match data.record_enable_context(self.context) {
+            Some(seq) => RecordStream { data: Some(data), seq },
+            None => {
+                data.shutdown();
+                RecordStream { data: None, seq: 0 }
+            }
+        }
+    }
+
+    /** Stops recording. The stream gets EndOfData and then ends. */
+    pub fn disable(&self, client: &mut XClient) {
+        client.record_disable_context(self.context);
+    }
+
+    /** Destroys the context, disabling it first if needed */
+    pub fn free(&self, client: &mut XClient) {
+        client.record_free_context(self.context);
+    }
+}
+
+/**
+ * The replies to an enabled RecordContext, from its own connection.
+ * Iterating blocks until the next reply. It ends after EndOfData (or an error). The connection is closed then, or when the stream is dropped.
+ */
+pub struct RecordStream {
+    data: Option<XClient>,
+    seq: u16
+}
+
+impl Iterator for RecordStream {
+    type Item = EnableContextReply;
+
+    fn next(&mut self) -> Option<EnableContextReply> {
+        let reply = match self.data.as_mut()?.wait_for_response(self.seq) {
+            ServerResponse::Reply(ServerReply::Record(RecordReply::EnableContext(reply)), _) => reply,
+            _ => {
+                self.close();
+                return None;
+            }
+        };
+
+        if reply.category == Category::EndOfData {
+            self.close();
+        }
+        Some(reply)
+    }
+}
+
+impl RecordStream {
+    /** Closes the data connection, which also stops its reader thread */
+    fn close(&mut self) {
+        if let Some(data) = self.data.take() {
+            data.shutdown();
+        }
+    }
+}
+
+impl Drop for RecordStream {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+impl XReadHelper {
+    /** Reads a RECORD EnableContext reply, decoding the core events in FromServer data */
+    pub(crate) fn read_record_enable_context_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let category = Category::get(detail as u32)?;
+        let element_header = self.read_u8();
+        let client_swapped = self.read_bool();
+        self.read_pad(2);
+        let xid_base = self.read_u32();
+        let server_time = self.read_u32();
+        let rec_sequence_num = self.read_u32();
+        self.read_pad(8);
+
+        let start = self.position();
+        let data = self.read_raw_remaining();
+        let end = self.position();
+
+        let mut events = vec![];
+        if category == Category::FromServer && !client_swapped {
+            let time_len = if element_header as u32 & HType::FromServerTime.val() != 0 { 4 } else { 0 };
+            let mut pos = start;
+            while pos + time_len + 32 <= end {
+                let element = pos + time_len;
+                self.seek(element);
+                let code = self.read_u8() & 0x7F; // Without the SendEvent bit
+                let detail = self.read_u8();
+                let sequence_number = self.read_u16();
+                let len = match code {
+                    protocol::REPLY_REPLY | protocol::REPLY_GENERIC_EVENT => 32 + self.read_u32() as usize * 4,
+                    _ => 32
+                };
+
+                match code {
+                    protocol::REPLY_ERROR | protocol::REPLY_REPLY | protocol::REPLY_GENERIC_EVENT => (),
+                    protocol::REPLY_KEY_PRESS..=protocol::REPLY_MAPPING_NOTIFY => {
+                        self.seek(element + 4);
+                        if let Some(event) = self.read_core_event(code, detail, sequence_number) {
+                            events.push(event);
+                        }
+                    },
+                    _ => {
+                        self.seek(element);
+                        let mut raw = [0u8; 32];
+                        self.read_raw_buf(&mut raw);
+                        events.push(ServerEvent::Unknown { code, raw });
+                    }
+                }
+                pos = element + len;
+            }
+            self.seek(end);
+        }
+
+        Some(ServerReply::Record(RecordReply::EnableContext(EnableContextReply {
+            category, element_header, client_swapped, xid_base, server_time, rec_sequence_num, events, data
+        })))
+    }
+}
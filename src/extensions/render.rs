@@ -0,0 +1,1442 @@
+// RENDER extension, version 0.11
+// Generated by tools/xcbgen from tools/xcb-proto/render.xml. Don't edit this file, change the generator or
+// render_extra.rs (which has everything written by hand) instead.
+//
+// Written by hand in render_extra.rs:
+//   - reply to QueryPictFormats
+//
+// Not generated:
+//   - struct PICTSCREEN: list depths of variable sized items
+//   - reply to QueryFilters (comes back as ServerReply::Unknown): xproto struct STR
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{Rectangle, ServerReply, ServerReplyType, Value};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+#[allow(unused_imports)] // Some extra modules only add methods
+pub use super::render_extra::*;
+
+pub const EXTENSION_NAME: &str = "RENDER";
+pub const MAJOR_VERSION: u32 = 0;
+pub const MINOR_VERSION: u32 = 11;
+
+pub const OP_QUERY_VERSION: u8 = 0;
+pub const OP_QUERY_PICT_FORMATS: u8 = 1;
+pub const OP_QUERY_PICT_INDEX_VALUES: u8 = 2;
+pub const OP_CREATE_PICTURE: u8 = 4;
+pub const OP_CHANGE_PICTURE: u8 = 5;
+pub const OP_SET_PICTURE_CLIP_RECTANGLES: u8 = 6;
+pub const OP_FREE_PICTURE: u8 = 7;
+pub const OP_COMPOSITE: u8 = 8;
+pub const OP_TRAPEZOIDS: u8 = 10;
+pub const OP_TRIANGLES: u8 = 11;
+pub const OP_TRI_STRIP: u8 = 12;
+pub const OP_TRI_FAN: u8 = 13;
+pub const OP_CREATE_GLYPH_SET: u8 = 17;
+pub const OP_REFERENCE_GLYPH_SET: u8 = 18;
+pub const OP_FREE_GLYPH_SET: u8 = 19;
+pub const OP_ADD_GLYPHS: u8 = 20;
+pub const OP_FREE_GLYPHS: u8 = 22;
+pub const OP_COMPOSITE_GLYPHS8: u8 = 23;
+pub const OP_COMPOSITE_GLYPHS16: u8 = 24;
+pub const OP_COMPOSITE_GLYPHS32: u8 = 25;
+pub const OP_FILL_RECTANGLES: u8 = 26;
+pub const OP_CREATE_CURSOR: u8 = 27;
+pub const OP_SET_PICTURE_TRANSFORM: u8 = 28;
+pub const OP_QUERY_FILTERS: u8 = 29;
+pub const OP_SET_PICTURE_FILTER: u8 = 30;
+pub const OP_CREATE_ANIM_CURSOR: u8 = 31;
+pub const OP_ADD_TRAPS: u8 = 32;
+pub const OP_CREATE_SOLID_FILL: u8 = 33;
+pub const OP_CREATE_LINEAR_GRADIENT: u8 = 34;
+pub const OP_CREATE_RADIAL_GRADIENT: u8 = 35;
+pub const OP_CREATE_CONICAL_GRADIENT: u8 = 36;
+
+pub const ERROR_PICT_FORMAT: u8 = 0;
+pub const ERROR_PICTURE: u8 = 1;
+pub const ERROR_PICT_OP: u8 = 2;
+pub const ERROR_GLYPH_SET: u8 = 3;
+pub const ERROR_GLYPH: u8 = 4;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PictType {
+    Indexed,
+    Direct
+}
+impl PictType {
+    pub fn get(id: u32) -> Option<PictType> {
+        match id {
+            0 => Some(PictType::Indexed),
+            1 => Some(PictType::Direct),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &PictType::Indexed => 0,
+            &PictType::Direct => 1
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Picture {
+    None
+}
+impl Picture {
+    pub fn get(id: u32) -> Option<Picture> {
+        match id {
+            0 => Some(Picture::None),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &Picture::None => 0
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PictOp {
+    Clear,
+    Src,
+    Dst,
+    Over,
+    OverReverse,
+    In,
+    InReverse,
+    Out,
+    OutReverse,
+    Atop,
+    AtopReverse,
+    Xor,
+    Add,
+    Saturate,
+    DisjointClear,
+    DisjointSrc,
+    DisjointDst,
+    DisjointOver,
+    DisjointOverReverse,
+    DisjointIn,
+    DisjointInReverse,
+    DisjointOut,
+    DisjointOutReverse,
+    DisjointAtop,
+    DisjointAtopReverse,
+    DisjointXor,
+    ConjointClear,
+    ConjointSrc,
+    ConjointDst,
+    ConjointOver,
+    ConjointOverReverse,
+    ConjointIn,
+    ConjointInReverse,
+    ConjointOut,
+    ConjointOutReverse,
+    ConjointAtop,
+    ConjointAtopReverse,
+    ConjointXor,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+    HSLHue,
+    HSLSaturation,
+    HSLColor,
+    HSLLuminosity
+}
+impl PictOp {
+    pub fn get(id: u32) -> Option<PictOp> {
+        match id {
+            0 => Some(PictOp::Clear),
+            1 => Some(PictOp::Src),
+            2 => Some(PictOp::Dst),
+            3 => Some(PictOp::Over),
+            4 => Some(PictOp::OverReverse),
+            5 => Some(PictOp::In),
+            6 => Some(PictOp::InReverse),
+            7 => Some(PictOp::Out),
+            8 => Some(PictOp::OutReverse),
+            9 => Some(PictOp::Atop),
+            10 => Some(PictOp::AtopReverse),
+            11 => Some(PictOp::Xor),
+            12 => Some(PictOp::Add),
+            13 => Some(PictOp::Saturate),
+            16 => Some(PictOp::DisjointClear),
+            17 => Some(PictOp::DisjointSrc),
+            18 => Some(PictOp::DisjointDst),
+            19 => Some(PictOp::DisjointOver),
+            20 => Some(PictOp::DisjointOverReverse),
+            21 => Some(PictOp::DisjointIn),
+            22 => Some(PictOp::DisjointInReverse),
+            23 => Some(PictOp::DisjointOut),
+            24 => Some(PictOp::DisjointOutReverse),
+            25 => Some(PictOp::DisjointAtop),
+            26 => Some(PictOp::DisjointAtopReverse),
+            27 => Some(PictOp::DisjointXor),
+            32 => Some(PictOp::ConjointClear),
+            33 => Some(PictOp::ConjointSrc),
+            34 => Some(PictOp::ConjointDst),
+            35 => Some(PictOp::ConjointOver),
+            36 => Some(PictOp::ConjointOverReverse),
+            37 => Some(PictOp::ConjointIn),
+            38 => Some(PictOp::ConjointInReverse),
+            39 => Some(PictOp::ConjointOut),
+            40 => Some(PictOp::ConjointOutReverse),
+            41 => Some(PictOp::ConjointAtop),
+            42 => Some(PictOp::ConjointAtopReverse),
+            43 => Some(PictOp::ConjointXor),
+            48 => Some(PictOp::Multiply),
+            49 => Some(PictOp::Screen),
+            50 => Some(PictOp::Overlay),
+            51 => Some(PictOp::Darken),
+            52 => Some(PictOp::Lighten),
+            53 => Some(PictOp::ColorDodge),
+            54 => Some(PictOp::ColorBurn),
+            55 => Some(PictOp::HardLight),
+            56 => Some(PictOp::SoftLight),
+            57 => Some(PictOp::Difference),
+            58 => Some(PictOp::Exclusion),
+            59 => Some(PictOp::HSLHue),
+            60 => Some(PictOp::HSLSaturation),
+            61 => Some(PictOp::HSLColor),
+            62 => Some(PictOp::HSLLuminosity),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &PictOp::Clear => 0,
+            &PictOp::Src => 1,
+            &PictOp::Dst => 2,
+            &PictOp::Over => 3,
+            &PictOp::OverReverse => 4,
+            &PictOp::In => 5,
+            &PictOp::InReverse => 6,
+            &PictOp::Out => 7,
+            &PictOp::OutReverse => 8,
+            &PictOp::Atop => 9,
+            &PictOp::AtopReverse => 10,
+            &PictOp::Xor => 11,
+            &PictOp::Add => 12,
+            &PictOp::Saturate => 13,
+            &PictOp::DisjointClear => 16,
+            &PictOp::DisjointSrc => 17,
+            &PictOp::DisjointDst => 18,
+            &PictOp::DisjointOver => 19,
+            &PictOp::DisjointOverReverse => 20,
+            &PictOp::DisjointIn => 21,
+            &PictOp::DisjointInReverse => 22,
+            &PictOp::DisjointOut => 23,
+            &PictOp::DisjointOutReverse => 24,
+            &PictOp::DisjointAtop => 25,
+            &PictOp::DisjointAtopReverse => 26,
+            &PictOp::DisjointXor => 27,
+            &PictOp::ConjointClear => 32,
+            &PictOp::ConjointSrc => 33,
+            &PictOp::ConjointDst => 34,
+            &PictOp::ConjointOver => 35,
+            &PictOp::ConjointOverReverse => 36,
+            &PictOp::ConjointIn => 37,
+            &PictOp::ConjointInReverse => 38,
+            &PictOp::ConjointOut => 39,
+            &PictOp::ConjointOutReverse => 40,
+            &PictOp::ConjointAtop => 41,
+            &PictOp::ConjointAtopReverse => 42,
+            &PictOp::ConjointXor => 43,
+            &PictOp::Multiply => 48,
+            &PictOp::Screen => 49,
+            &PictOp::Overlay => 50,
+            &PictOp::Darken => 51,
+            &PictOp::Lighten => 52,
+            &PictOp::ColorDodge => 53,
+            &PictOp::ColorBurn => 54,
+            &PictOp::HardLight => 55,
+            &PictOp::SoftLight => 56,
+            &PictOp::Difference => 57,
+            &PictOp::Exclusion => 58,
+            &PictOp::HSLHue => 59,
+            &PictOp::HSLSaturation => 60,
+            &PictOp::HSLColor => 61,
+            &PictOp::HSLLuminosity => 62
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PolyEdge {
+    Sharp,
+    Smooth
+}
+impl PolyEdge {
+    pub fn get(id: u32) -> Option<PolyEdge> {
+        match id {
+            0 => Some(PolyEdge::Sharp),
+            1 => Some(PolyEdge::Smooth),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &PolyEdge::Sharp => 0,
+            &PolyEdge::Smooth => 1
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PolyMode {
+    Precise,
+    Imprecise
+}
+impl PolyMode {
+    pub fn get(id: u32) -> Option<PolyMode> {
+        match id {
+            0 => Some(PolyMode::Precise),
+            1 => Some(PolyMode::Imprecise),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &PolyMode::Precise => 0,
+            &PolyMode::Imprecise => 1
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Cp {
+    Repeat,
+    AlphaMap,
+    AlphaXOrigin,
+    AlphaYOrigin,
+    ClipXOrigin,
+    ClipYOrigin,
+    ClipMask,
+    GraphicsExposure,
+    SubwindowMode,
+    PolyEdge,
+    PolyMode,
+    Dither,
+    ComponentAlpha
+}
+impl Cp {
+    pub fn val(&self) -> u32 {
+        match self {
+            &Cp::Repeat => 0x00000001,
+            &Cp::AlphaMap => 0x00000002,
+            &Cp::AlphaXOrigin => 0x00000004,
+            &Cp::AlphaYOrigin => 0x00000008,
+            &Cp::ClipXOrigin => 0x00000010,
+            &Cp::ClipYOrigin => 0x00000020,
+            &Cp::ClipMask => 0x00000040,
+            &Cp::GraphicsExposure => 0x00000080,
+            &Cp::SubwindowMode => 0x00000100,
+            &Cp::PolyEdge => 0x00000200,
+            &Cp::PolyMode => 0x00000400,
+            &Cp::Dither => 0x00000800,
+            &Cp::ComponentAlpha => 0x00001000
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SubPixel {
+    Unknown,
+    HorizontalRGB,
+    HorizontalBGR,
+    VerticalRGB,
+    VerticalBGR,
+    None
+}
+impl SubPixel {
+    pub fn get(id: u32) -> Option<SubPixel> {
+        match id {
+            0 => Some(SubPixel::Unknown),
+            1 => Some(SubPixel::HorizontalRGB),
+            2 => Some(SubPixel::HorizontalBGR),
+            3 => Some(SubPixel::VerticalRGB),
+            4 => Some(SubPixel::VerticalBGR),
+            5 => Some(SubPixel::None),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &SubPixel::Unknown => 0,
+            &SubPixel::HorizontalRGB => 1,
+            &SubPixel::HorizontalBGR => 2,
+            &SubPixel::VerticalRGB => 3,
+            &SubPixel::VerticalBGR => 4,
+            &SubPixel::None => 5
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Repeat {
+    None,
+    Normal,
+    Pad,
+    Reflect
+}
+impl Repeat {
+    pub fn get(id: u32) -> Option<Repeat> {
+        match id {
+            0 => Some(Repeat::None),
+            1 => Some(Repeat::Normal),
+            2 => Some(Repeat::Pad),
+            3 => Some(Repeat::Reflect),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &Repeat::None => 0,
+            &Repeat::Normal => 1,
+            &Repeat::Pad => 2,
+            &Repeat::Reflect => 3
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct DirectFormat {
+    pub red_shift: u16,
+    pub red_mask: u16,
+    pub green_shift: u16,
+    pub green_mask: u16,
+    pub blue_shift: u16,
+    pub blue_mask: u16,
+    pub alpha_shift: u16,
+    pub alpha_mask: u16
+}
+impl DirectFormat {
+    pub fn read(reader: &mut XReadHelper) -> DirectFormat {
+        let red_shift = reader.read_u16();
+        let red_mask = reader.read_u16();
+        let green_shift = reader.read_u16();
+        let green_mask = reader.read_u16();
+        let blue_shift = reader.read_u16();
+        let blue_mask = reader.read_u16();
+        let alpha_shift = reader.read_u16();
+        let alpha_mask = reader.read_u16();
+        DirectFormat { red_shift, red_mask, green_shift, green_mask, blue_shift, blue_mask, alpha_shift, alpha_mask }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.red_shift);
+        client.write_u16(self.red_mask);
+        client.write_u16(self.green_shift);
+        client.write_u16(self.green_mask);
+        client.write_u16(self.blue_shift);
+        client.write_u16(self.blue_mask);
+        client.write_u16(self.alpha_shift);
+        client.write_u16(self.alpha_mask);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct PictFormInfo {
+    pub id: u32,
+    pub kind: u8,
+    pub depth: u8,
+    pub direct: DirectFormat,
+    pub colormap: u32
+}
+impl PictFormInfo {
+    pub fn read(reader: &mut XReadHelper) -> PictFormInfo {
+        let id = reader.read_u32();
+        let kind = reader.read_u8();
+        let depth = reader.read_u8();
+        reader.read_pad(2);
+        let direct = DirectFormat::read(reader);
+        let colormap = reader.read_u32();
+        PictFormInfo { id, kind, depth, direct, colormap }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.id);
+        client.write_u8(self.kind);
+        client.write_u8(self.depth);
+        client.write_pad(2);
+        self.direct.write(client);
+        client.write_u32(self.colormap);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct PictVisual {
+    pub visual: u32,
+    pub format: u32
+}
+impl PictVisual {
+    pub fn read(reader: &mut XReadHelper) -> PictVisual {
+        let visual = reader.read_u32();
+        let format = reader.read_u32();
+        PictVisual { visual, format }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.visual);
+        client.write_u32(self.format);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PictDepth {
+    pub depth: u8,
+    pub visuals: Vec<PictVisual>
+}
+impl PictDepth {
+    pub fn read(reader: &mut XReadHelper) -> PictDepth {
+        let depth = reader.read_u8();
+        reader.read_pad(1);
+        let num_visuals = reader.read_u16();
+        reader.read_pad(4);
+        let mut visuals = Vec::with_capacity(num_visuals as usize);
+        for _ in 0..num_visuals as usize {
+            visuals.push(PictVisual::read(reader));
+        }
+        PictDepth { depth, visuals }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.depth);
+        client.write_pad(1);
+        client.write_u16(self.visuals.len() as u16);
+        client.write_pad(4);
+        for value in &self.visuals {
+            value.write(client);
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct IndexValue {
+    pub pixel: u32,
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+    pub alpha: u16
+}
+impl IndexValue {
+    pub fn read(reader: &mut XReadHelper) -> IndexValue {
+        let pixel = reader.read_u32();
+        let red = reader.read_u16();
+        let green = reader.read_u16();
+        let blue = reader.read_u16();
+        let alpha = reader.read_u16();
+        IndexValue { pixel, red, green, blue, alpha }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.pixel);
+        client.write_u16(self.red);
+        client.write_u16(self.green);
+        client.write_u16(self.blue);
+        client.write_u16(self.alpha);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Color {
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16,
+    pub alpha: u16
+}
+impl Color {
+    pub fn read(reader: &mut XReadHelper) -> Color {
+        let red = reader.read_u16();
+        let green = reader.read_u16();
+        let blue = reader.read_u16();
+        let alpha = reader.read_u16();
+        Color { red, green, blue, alpha }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.red);
+        client.write_u16(self.green);
+        client.write_u16(self.blue);
+        client.write_u16(self.alpha);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct PointFix {
+    pub x: f64,
+    pub y: f64
+}
+impl PointFix {
+    pub fn read(reader: &mut XReadHelper) -> PointFix {
+        let x = reader.read_fp1616();
+        let y = reader.read_fp1616();
+        PointFix { x, y }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_fp1616(self.x);
+        client.write_fp1616(self.y);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct LineFix {
+    pub p1: PointFix,
+    pub p2: PointFix
+}
+impl LineFix {
+    pub fn read(reader: &mut XReadHelper) -> LineFix {
+        let p1 = PointFix::read(reader);
+        let p2 = PointFix::read(reader);
+        LineFix { p1, p2 }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        self.p1.write(client);
+        self.p2.write(client);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Triangle {
+    pub p1: PointFix,
+    pub p2: PointFix,
+    pub p3: PointFix
+}
+impl Triangle {
+    pub fn read(reader: &mut XReadHelper) -> Triangle {
+        let p1 = PointFix::read(reader);
+        let p2 = PointFix::read(reader);
+        let p3 = PointFix::read(reader);
+        Triangle { p1, p2, p3 }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        self.p1.write(client);
+        self.p2.write(client);
+        self.p3.write(client);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Trapezoid {
+    pub top: f64,
+    pub bottom: f64,
+    pub left: LineFix,
+    pub right: LineFix
+}
+impl Trapezoid {
+    pub fn read(reader: &mut XReadHelper) -> Trapezoid {
+        let top = reader.read_fp1616();
+        let bottom = reader.read_fp1616();
+        let left = LineFix::read(reader);
+        let right = LineFix::read(reader);
+        Trapezoid { top, bottom, left, right }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_fp1616(self.top);
+        client.write_fp1616(self.bottom);
+        self.left.write(client);
+        self.right.write(client);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GlyphInfo {
+    pub width: u16,
+    pub height: u16,
+    pub x: i16,
+    pub y: i16,
+    pub x_off: i16,
+    pub y_off: i16
+}
+impl GlyphInfo {
+    pub fn read(reader: &mut XReadHelper) -> GlyphInfo {
+        let width = reader.read_u16();
+        let height = reader.read_u16();
+        let x = reader.read_i16();
+        let y = reader.read_i16();
+        let x_off = reader.read_i16();
+        let y_off = reader.read_i16();
+        GlyphInfo { width, height, x, y, x_off, y_off }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.width);
+        client.write_u16(self.height);
+        client.write_i16(self.x);
+        client.write_i16(self.y);
+        client.write_i16(self.x_off);
+        client.write_i16(self.y_off);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Transform {
+    pub matrix11: f64,
+    pub matrix12: f64,
+    pub matrix13: f64,
+    pub matrix21: f64,
+    pub matrix22: f64,
+    pub matrix23: f64,
+    pub matrix31: f64,
+    pub matrix32: f64,
+    pub matrix33: f64
+}
+impl Transform {
+    pub fn read(reader: &mut XReadHelper) -> Transform {
+        let matrix11 = reader.read_fp1616();
+        let matrix12 = reader.read_fp1616();
+        let matrix13 = reader.read_fp1616();
+        let matrix21 = reader.read_fp1616();
+        let matrix22 = reader.read_fp1616();
+        let matrix23 = reader.read_fp1616();
+        let matrix31 = reader.read_fp1616();
+        let matrix32 = reader.read_fp1616();
+        let matrix33 = reader.read_fp1616();
+        Transform { matrix11, matrix12, matrix13, matrix21, matrix22, matrix23, matrix31, matrix32, matrix33 }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_fp1616(self.matrix11);
+        client.write_fp1616(self.matrix12);
+        client.write_fp1616(self.matrix13);
+        client.write_fp1616(self.matrix21);
+        client.write_fp1616(self.matrix22);
+        client.write_fp1616(self.matrix23);
+        client.write_fp1616(self.matrix31);
+        client.write_fp1616(self.matrix32);
+        client.write_fp1616(self.matrix33);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct AnimCursorElt {
+    pub cursor: u32,
+    pub delay: u32
+}
+impl AnimCursorElt {
+    pub fn read(reader: &mut XReadHelper) -> AnimCursorElt {
+        let cursor = reader.read_u32();
+        let delay = reader.read_u32();
+        AnimCursorElt { cursor, delay }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.cursor);
+        client.write_u32(self.delay);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SpanFix {
+    pub l: f64,
+    pub r: f64,
+    pub y: f64
+}
+impl SpanFix {
+    pub fn read(reader: &mut XReadHelper) -> SpanFix {
+        let l = reader.read_fp1616();
+        let r = reader.read_fp1616();
+        let y = reader.read_fp1616();
+        SpanFix { l, r, y }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_fp1616(self.l);
+        client.write_fp1616(self.r);
+        client.write_fp1616(self.y);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Trap {
+    pub top: SpanFix,
+    pub bot: SpanFix
+}
+impl Trap {
+    pub fn read(reader: &mut XReadHelper) -> Trap {
+        let top = SpanFix::read(reader);
+        let bot = SpanFix::read(reader);
+        Trap { top, bot }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        self.top.write(client);
+        self.bot.write(client);
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CpValue {
+    Repeat(u32),
+    AlphaMap(u32),
+    AlphaXOrigin(i32),
+    AlphaYOrigin(i32),
+    ClipXOrigin(i32),
+    ClipYOrigin(i32),
+    ClipMask(u32),
+    GraphicsExposure(u32),
+    SubwindowMode(u32),
+    PolyEdge(u32),
+    PolyMode(u32),
+    Dither(u32),
+    ComponentAlpha(u32)
+}
+impl Value for CpValue {
+    fn get_mask(&self) -> u32 {
+        match self {
+            &CpValue::Repeat(_) => 0x00000001,
+            &CpValue::AlphaMap(_) => 0x00000002,
+            &CpValue::AlphaXOrigin(_) => 0x00000004,
+            &CpValue::AlphaYOrigin(_) => 0x00000008,
+            &CpValue::ClipXOrigin(_) => 0x00000010,
+            &CpValue::ClipYOrigin(_) => 0x00000020,
+            &CpValue::ClipMask(_) => 0x00000040,
+            &CpValue::GraphicsExposure(_) => 0x00000080,
+            &CpValue::SubwindowMode(_) => 0x00000100,
+            &CpValue::PolyEdge(_) => 0x00000200,
+            &CpValue::PolyMode(_) => 0x00000400,
+            &CpValue::Dither(_) => 0x00000800,
+            &CpValue::ComponentAlpha(_) => 0x00001000
+        }
+    }
+
+    fn write(&self, client: &mut XClient) {
+        match self {
+            &CpValue::Repeat(val) => client.write_val_u32(val),
+            &CpValue::AlphaMap(val) => client.write_val_u32(val),
+            &CpValue::AlphaXOrigin(val) => client.write_val_i32(val),
+            &CpValue::AlphaYOrigin(val) => client.write_val_i32(val),
+            &CpValue::ClipXOrigin(val) => client.write_val_i32(val),
+            &CpValue::ClipYOrigin(val) => client.write_val_i32(val),
+            &CpValue::ClipMask(val) => client.write_val_u32(val),
+            &CpValue::GraphicsExposure(val) => client.write_val_u32(val),
+            &CpValue::SubwindowMode(val) => client.write_val_u32(val),
+            &CpValue::PolyEdge(val) => client.write_val_u32(val),
+            &CpValue::PolyMode(val) => client.write_val_u32(val),
+            &CpValue::Dither(val) => client.write_val_u32(val),
+            &CpValue::ComponentAlpha(val) => client.write_val_u32(val)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum RenderReply {
+    QueryVersion {
+        major_version: u32,
+        minor_version: u32
+    },
+    QueryPictIndexValues {
+        values: Vec<IndexValue>
+    },
+    QueryPictFormats(QueryPictFormatsReply)
+}
+
+/** Decodes RENDER replies and events */
+#[derive(Debug, Default)]
+pub struct Render;
+
+impl Extension for Render {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn error_names(&self) -> &'static [&'static str] {
+        &["BadPictFormat", "BadPicture", "BadPictOp", "BadGlyphSet", "BadGlyph"]
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_QUERY_VERSION => reader.read_render_query_version_reply(detail),
+            OP_QUERY_PICT_INDEX_VALUES => reader.read_render_query_pict_index_values_reply(detail),
+            OP_QUERY_PICT_FORMATS => reader.read_render_query_pict_formats_reply(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the RENDER QueryVersion request. Returns None without sending anything if the server doesn't have RENDER, like every request here. */
+    pub fn render_query_version(&mut self, client_major_version: u32, client_minor_version: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_VERSION);
+        self.write_u16(3);
+        self.write_u32(client_major_version);
+        self.write_u32(client_minor_version);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_VERSION)))
+    }
+
+    /** Sends the RENDER QueryPictFormats request */
+    pub fn render_query_pict_formats(&mut self) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_PICT_FORMATS);
+        self.write_u16(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_PICT_FORMATS)))
+    }
+
+    /** Sends the RENDER QueryPictIndexValues request */
+    pub fn render_query_pict_index_values(&mut self, format: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_PICT_INDEX_VALUES);
+        self.write_u16(2);
+        self.write_u32(format);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_PICT_INDEX_VALUES)))
+    }
+
+    /** Sends the RENDER CreatePicture request */
+    pub fn render_create_picture(&mut self, pid: u32, drawable: u32, format: u32, value_list: &Vec<CpValue>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_PICTURE);
+        let pad = self.write_dynamic_len(5, value_list.len() * 4);
+        self.write_u32(pid);
+        self.write_u32(drawable);
+        self.write_u32(format);
+        self.write_values(value_list, 32);
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER ChangePicture request */
+    pub fn render_change_picture(&mut self, picture: u32, value_list: &Vec<CpValue>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CHANGE_PICTURE);
+        let pad = self.write_dynamic_len(3, value_list.len() * 4);
+        self.write_u32(picture);
+        self.write_values(value_list, 32);
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER SetPictureClipRectangles request */
+    pub fn render_set_picture_clip_rectangles(&mut self, picture: u32, clip_x_origin: i16, clip_y_origin: i16, rectangles: &Vec<Rectangle>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_PICTURE_CLIP_RECTANGLES);
+        let pad = self.write_dynamic_len(3, rectangles.len() * 8);
+        self.write_u32(picture);
+        self.write_i16(clip_x_origin);
+        self.write_i16(clip_y_origin);
+        for value in rectangles {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER FreePicture request */
+    pub fn render_free_picture(&mut self, picture: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_FREE_PICTURE);
+        self.write_u16(2);
+        self.write_u32(picture);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER Composite request */
+    pub fn render_composite(&mut self, op: &PictOp, src: u32, mask: u32, dst: u32, src_x: i16, src_y: i16, mask_x: i16, mask_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_COMPOSITE);
+        self.write_u16(9);
+        self.write_u8(op.val() as u8);
+        self.write_pad(3);
+        self.write_u32(src);
+        self.write_u32(mask);
+        self.write_u32(dst);
+        self.write_i16(src_x);
+        self.write_i16(src_y);
+        self.write_i16(mask_x);
+        self.write_i16(mask_y);
+        self.write_i16(dst_x);
+        self.write_i16(dst_y);
+        self.write_u16(width);
+        self.write_u16(height);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER Trapezoids request */
+    pub fn render_trapezoids(&mut self, op: &PictOp, src: u32, dst: u32, mask_format: u32, src_x: i16, src_y: i16, traps: &Vec<Trapezoid>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_TRAPEZOIDS);
+        let pad = self.write_dynamic_len(6, traps.len() * 40);
+        self.write_u8(op.val() as u8);
+        self.write_pad(3);
+        self.write_u32(src);
+        self.write_u32(dst);
+        self.write_u32(mask_format);
+        self.write_i16(src_x);
+        self.write_i16(src_y);
+        for value in traps {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER Triangles request */
+    pub fn render_triangles(&mut self, op: &PictOp, src: u32, dst: u32, mask_format: u32, src_x: i16, src_y: i16, triangles: &Vec<Triangle>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_TRIANGLES);
+        let pad = self.write_dynamic_len(6, triangles.len() * 24);
+        self.write_u8(op.val() as u8);
+        self.write_pad(3);
+        self.write_u32(src);
+        self.write_u32(dst);
+        self.write_u32(mask_format);
+        self.write_i16(src_x);
+        self.write_i16(src_y);
+        for value in triangles {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER TriStrip request */
+    pub fn render_tri_strip(&mut self, op: &PictOp, src: u32, dst: u32, mask_format: u32, src_x: i16, src_y: i16, points: &Vec<PointFix>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_TRI_STRIP);
+        let pad = self.write_dynamic_len(6, points.len() * 8);
+        self.write_u8(op.val() as u8);
+        self.write_pad(3);
+        self.write_u32(src);
+        self.write_u32(dst);
+        self.write_u32(mask_format);
+        self.write_i16(src_x);
+        self.write_i16(src_y);
+        for value in points {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER TriFan request */
+    pub fn render_tri_fan(&mut self, op: &PictOp, src: u32, dst: u32, mask_format: u32, src_x: i16, src_y: i16, points: &Vec<PointFix>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_TRI_FAN);
+        let pad = self.write_dynamic_len(6, points.len() * 8);
+        self.write_u8(op.val() as u8);
+        self.write_pad(3);
+        self.write_u32(src);
+        self.write_u32(dst);
+        self.write_u32(mask_format);
+        self.write_i16(src_x);
+        self.write_i16(src_y);
+        for value in points {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER CreateGlyphSet request */
+    pub fn render_create_glyph_set(&mut self, gsid: u32, format: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_GLYPH_SET);
+        self.write_u16(3);
+        self.write_u32(gsid);
+        self.write_u32(format);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER ReferenceGlyphSet request */
+    pub fn render_reference_glyph_set(&mut self, gsid: u32, existing: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_REFERENCE_GLYPH_SET);
+        self.write_u16(3);
+        self.write_u32(gsid);
+        self.write_u32(existing);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER FreeGlyphSet request */
+    pub fn render_free_glyph_set(&mut self, glyphset: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_FREE_GLYPH_SET);
+        self.write_u16(2);
+        self.write_u32(glyphset);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER AddGlyphs request. Panics if `glyphids` and `glyphs` aren't the same length. */
+    pub fn render_add_glyphs(&mut self, glyphset: u32, glyphids: &Vec<u32>, glyphs: &Vec<GlyphInfo>, data: &[u8]) -> Option<()> {
+        assert_eq!(glyphids.len(), glyphs.len(), "glyphids and glyphs need the same length");
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_ADD_GLYPHS);
+        let pad = self.write_dynamic_len(3, glyphids.len() * 4 + glyphs.len() * 12 + data.len());
+        self.write_u32(glyphset);
+        self.write_u32(glyphids.len() as u32);
+        for value in glyphids {
+            self.write_u32(*value);
+        }
+        for value in glyphs {
+            value.write(self);
+        }
+        self.write_raw(data);
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER FreeGlyphs request */
+    pub fn render_free_glyphs(&mut self, glyphset: u32, glyphs: &Vec<u32>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_FREE_GLYPHS);
+        let pad = self.write_dynamic_len(2, glyphs.len() * 4);
+        self.write_u32(glyphset);
+        for value in glyphs {
+            self.write_u32(*value);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER CompositeGlyphs8 request */
+    pub fn render_composite_glyphs8(&mut self, op: &PictOp, src: u32, dst: u32, mask_format: u32, glyphset: u32, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_COMPOSITE_GLYPHS8);
+        let pad = self.write_dynamic_len(7, glyphcmds.len());
+        self.write_u8(op.val() as u8);
+        self.write_pad(3);
+        self.write_u32(src);
+        self.write_u32(dst);
+        self.write_u32(mask_format);
+        self.write_u32(glyphset);
+        self.write_i16(src_x);
+        self.write_i16(src_y);
+        self.write_raw(glyphcmds);
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER CompositeGlyphs16 request */
+    pub fn render_composite_glyphs16(&mut self, op: &PictOp, src: u32, dst: u32, mask_format: u32, glyphset: u32, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_COMPOSITE_GLYPHS16);
+        let pad = self.write_dynamic_len(7, glyphcmds.len());
+        self.write_u8(op.val() as u8);
+        self.write_pad(3);
+        self.write_u32(src);
+        self.write_u32(dst);
+        self.write_u32(mask_format);
+        self.write_u32(glyphset);
+        self.write_i16(src_x);
+        self.write_i16(src_y);
+        self.write_raw(glyphcmds);
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER CompositeGlyphs32 request */
+    pub fn render_composite_glyphs32(&mut self, op: &PictOp, src: u32, dst: u32, mask_format: u32, glyphset: u32, src_x: i16, src_y: i16, glyphcmds: &[u8]) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_COMPOSITE_GLYPHS32);
+        let pad = self.write_dynamic_len(7, glyphcmds.len());
+        self.write_u8(op.val() as u8);
+        self.write_pad(3);
+        self.write_u32(src);
+        self.write_u32(dst);
+        self.write_u32(mask_format);
+        self.write_u32(glyphset);
+        self.write_i16(src_x);
+        self.write_i16(src_y);
+        self.write_raw(glyphcmds);
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER FillRectangles request */
+    pub fn render_fill_rectangles(&mut self, op: &PictOp, dst: u32, color: &Color, rects: &Vec<Rectangle>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_FILL_RECTANGLES);
+        let pad = self.write_dynamic_len(5, rects.len() * 8);
+        self.write_u8(op.val() as u8);
+        self.write_pad(3);
+        self.write_u32(dst);
+        color.write(self);
+        for value in rects {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER CreateCursor request */
+    pub fn render_create_cursor(&mut self, cid: u32, source: u32, x: u16, y: u16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_CURSOR);
+        self.write_u16(4);
+        self.write_u32(cid);
+        self.write_u32(source);
+        self.write_u16(x);
+        self.write_u16(y);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER SetPictureTransform request */
+    pub fn render_set_picture_transform(&mut self, picture: u32, transform: &Transform) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_PICTURE_TRANSFORM);
+        self.write_u16(11);
+        self.write_u32(picture);
+        transform.write(self);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER QueryFilters request */
+    pub fn render_query_filters(&mut self, drawable: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_FILTERS);
+        self.write_u16(2);
+        self.write_u32(drawable);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_FILTERS)))
+    }
+
+    /** Sends the RENDER SetPictureFilter request */
+    pub fn render_set_picture_filter(&mut self, picture: u32, filter: &str, values: &Vec<f64>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_PICTURE_FILTER);
+        let pad = self.write_dynamic_len(3, filter.len() + (4 - (12 + filter.len()) % 4) % 4 + values.len() * 4);
+        self.write_u32(picture);
+        self.write_u16(filter.len() as u16);
+        self.write_pad(2);
+        self.write_str(filter);
+        self.write_pad_op((4 - (12 + filter.len()) % 4) % 4);
+        for value in values {
+            self.write_fp1616(*value);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER CreateAnimCursor request */
+    pub fn render_create_anim_cursor(&mut self, cid: u32, cursors: &Vec<AnimCursorElt>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_ANIM_CURSOR);
+        let pad = self.write_dynamic_len(2, cursors.len() * 8);
+        self.write_u32(cid);
+        for value in cursors {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER AddTraps request */
+    pub fn render_add_traps(&mut self, picture: u32, x_off: i16, y_off: i16, traps: &Vec<Trap>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_ADD_TRAPS);
+        let pad = self.write_dynamic_len(3, traps.len() * 24);
+        self.write_u32(picture);
+        self.write_i16(x_off);
+        self.write_i16(y_off);
+        for value in traps {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER CreateSolidFill request */
+    pub fn render_create_solid_fill(&mut self, picture: u32, color: &Color) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_SOLID_FILL);
+        self.write_u16(4);
+        self.write_u32(picture);
+        color.write(self);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER CreateLinearGradient request. Panics if `stops` and `colors` aren't the same length. */
+    pub fn render_create_linear_gradient(&mut self, picture: u32, p1: &PointFix, p2: &PointFix, stops: &Vec<f64>, colors: &Vec<Color>) -> Option<()> {
+        assert_eq!(stops.len(), colors.len(), "stops and colors need the same length");
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_LINEAR_GRADIENT);
+        let pad = self.write_dynamic_len(7, stops.len() * 4 + colors.len() * 8);
+        self.write_u32(picture);
+        p1.write(self);
+        p2.write(self);
+        self.write_u32(stops.len() as u32);
+        for value in stops {
+            self.write_fp1616(*value);
+        }
+        for value in colors {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER CreateRadialGradient request. Panics if `stops` and `colors` aren't the same length. */
+    pub fn render_create_radial_gradient(&mut self, picture: u32, inner: &PointFix, outer: &PointFix, inner_radius: f64, outer_radius: f64, stops: &Vec<f64>, colors: &Vec<Color>) -> Option<()> {
+        assert_eq!(stops.len(), colors.len(), "stops and colors need the same length");
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_RADIAL_GRADIENT);
+        let pad = self.write_dynamic_len(9, stops.len() * 4 + colors.len() * 8);
+        self.write_u32(picture);
+        inner.write(self);
+        outer.write(self);
+        self.write_fp1616(inner_radius);
+        self.write_fp1616(outer_radius);
+        self.write_u32(stops.len() as u32);
+        for value in stops {
+            self.write_fp1616(*value);
+        }
+        for value in colors {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the RENDER CreateConicalGradient request. Panics if `stops` and `colors` aren't the same length. */
+    pub fn render_create_conical_gradient(&mut self, picture: u32, center: &PointFix, angle: f64, stops: &Vec<f64>, colors: &Vec<Color>) -> Option<()> {
+        assert_eq!(stops.len(), colors.len(), "stops and colors need the same length");
+        let extension_opcode = self.extension_opcode::<Render>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_CONICAL_GRADIENT);
+        let pad = self.write_dynamic_len(6, stops.len() * 4 + colors.len() * 8);
+        self.write_u32(picture);
+        center.write(self);
+        self.write_fp1616(angle);
+        self.write_u32(stops.len() as u32);
+        for value in stops {
+            self.write_fp1616(*value);
+        }
+        for value in colors {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to RENDER QueryVersion */
+    fn read_render_query_version_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let major_version = self.read_u32();
+        let minor_version = self.read_u32();
+        self.read_pad(16);
+        Some(ServerReply::Render(RenderReply::QueryVersion { major_version, minor_version }))
+    }
+
+    /** Reads a reply to RENDER QueryPictIndexValues */
+    fn read_render_query_pict_index_values_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let num_values = self.read_u32();
+        self.read_pad(20);
+        let mut values = Vec::with_capacity(num_values as usize);
+        for _ in 0..num_values as usize {
+            values.push(IndexValue::read(self));
+        }
+        Some(ServerReply::Render(RenderReply::QueryPictIndexValues { values }))
+    }
+}
@@ -0,0 +1,234 @@
+// QueryPictFormats, standard formats, DrawablePicture, and glyph runs for RENDER.
+
+#![allow(clippy::ptr_arg, clippy::too_many_arguments)]
+
+use models::{Drawable, Point, Rectangle, ServerReply, ServerResponse};
+use xreaderwriter::{XBufferedReader, XReadHelper};
+use XClient;
+
+use super::render::*;
+
+/** The formats that every server with RENDER has (like PictStandard in libXrender) */
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum StandardFormat {
+    Argb32, // 32 bits with alpha in the top 8, for translucent windows and images
+    Rgb24, // 24 bits without alpha, the usual window format
+    A8 // 8 bits of alpha only, for masks and glyphs
+}
+
+#[derive(Debug, Clone)]
+pub struct PictScreen {
+    pub fallback: u32, // Format to use for visuals that aren't in any depth
+    pub depths: Vec<PictDepth>
+}
+
+#[derive(Debug, Clone)]
+pub struct QueryPictFormatsReply {
+    pub formats: Vec<PictFormInfo>,
+    pub screens: Vec<PictScreen>,
+    pub subpixels: Vec<u32> // A SubPixel for each screen
+}
+
+impl QueryPictFormatsReply {
+    /** Finds one of the standard formats. Every server should have all of them. */
+    pub fn find_standard(&self, standard: StandardFormat) -> Option<&PictFormInfo> {
+        let (depth, red, green, blue, alpha) = match standard {
+            StandardFormat::Argb32 => (32, (16, 0xFF), (8, 0xFF), (0, 0xFF), (24, 0xFF)),
+            StandardFormat::Rgb24 => (24, (16, 0xFF), (8, 0xFF), (0, 0xFF), (0, 0)),
+            StandardFormat::A8 => (8, (0, 0), (0, 0), (0, 0), (0, 0xFF))
+        };
+
+        self.formats.iter().find(|format| {
+            let direct = &format.direct;
+            format.kind as u32 == PictType::Direct.val() && format.depth == depth
+                && (direct.red_mask == 0 || direct.red_shift == red.0) && direct.red_mask == red.1
+                && (direct.green_mask == 0 || direct.green_shift == green.0) && direct.green_mask == green.1
+                && (direct.blue_mask == 0 || direct.blue_shift == blue.0) && direct.blue_mask == blue.1
+                && (direct.alpha_mask == 0 || direct.alpha_shift == alpha.0) && direct.alpha_mask == alpha.1
+        })
+    }
+
+    /** The ID of the ARGB32 format */
+    pub fn argb32(&self) -> Option<u32> {
+        self.find_standard(StandardFormat::Argb32).map(|format| format.id)
+    }
+
+    /** The ID of the RGB24 format */
+    pub fn rgb24(&self) -> Option<u32> {
+        self.find_standard(StandardFormat::Rgb24).map(|format| format.id)
+    }
+
+    /** The ID of the A8 format */
+    pub fn a8(&self) -> Option<u32> {
+        self.find_standard(StandardFormat::A8).map(|format| format.id)
+    }
+
+    /** Finds the format for a visual (ie a window's visual_id) */
+    pub fn find_visual_format(&self, visual: u32) -> Option<u32> {
+        self.screens.iter()
+            .flat_map(|screen| screen.depths.iter())
+            .flat_map(|depth| depth.visuals.iter())
+            .find(|pictvisual| pictvisual.visual == visual)
+            .map(|pictvisual| pictvisual.format)
+    }
+}
+
+/** A picture of a window or pixmap, which is what RENDER draws from and to */
+#[derive(Debug)]
+pub struct DrawablePicture {
+    pub pid: u32, // Picture's ID
+    pub drawable: u32, // Window or Pixmap ID
+    pub format: u32
+}
+
+impl DrawablePicture {
+    /** Creates a picture for a drawable. The format has to have the same depth as the drawable. None if the server doesn't have RENDER. */
+    pub fn create<D: Drawable>(client: &mut XClient, drawable: &D, format: u32, values: &Vec<CpValue>) -> Option<DrawablePicture> {
+        let pid = client.new_resource_id();
+        let picture = DrawablePicture { pid, drawable: drawable.get_drawable(), format };
+        client.render_create_picture(pid, picture.drawable, format, values)?;
+        Some(picture)
+    }
+
+    /** Frees the picture (the drawable stays) */
+    pub fn free(&self, client: &mut XClient) {
+        client.render_free_picture(self.pid);
+    }
+
+    /** Fills some rectangles with a color */
+    pub fn fill_rectangles(&self, client: &mut XClient, op: &PictOp, color: &Color, rectangles: &Vec<Rectangle>) {
+        client.render_fill_rectangles(op, self.pid, color, rectangles);
+    }
+
+    /**
+     * Blends the `width` x `height` area of `src` at `src_origin` onto this picture at `dst_origin`, through the same
+     * area of `mask` at its own origin if there is one
+     */
+    pub fn composite(&self, client: &mut XClient, op: &PictOp, src: &DrawablePicture, src_origin: Point, mask: Option<(&DrawablePicture, Point)>, dst_origin: Point, width: u16, height: u16) {
+        let (mask, mask_origin) = mask.map_or((Picture::None.val(), Point { x: 0, y: 0 }), |(mask, origin)| (mask.pid, origin));
+        client.render_composite(op, src.pid, mask, self.pid, src_origin.x, src_origin.y, mask_origin.x, mask_origin.y, dst_origin.x, dst_origin.y, width, height);
+    }
+
+    /** Sets the transform used when this picture is the source (ie for scaling) */
+    pub fn set_transform(&self, client: &mut XClient, transform: &Transform) {
+        client.render_set_picture_transform(self.pid, transform);
+    }
+
+    /** Sets the filter used when this picture is transformed (ie "bilinear"; see QueryFilters) */
+    pub fn set_filter(&self, client: &mut XClient, filter: &str, values: &Vec<f64>) {
+        client.render_set_picture_filter(self.pid, filter, values);
+    }
+}
+
+impl Transform {
+    /** The transform that doesn't change anything */
+    pub fn identity() -> Transform {
+        Transform::scale(1.0, 1.0)
+    }
+
+    /** Scales a picture. The matrix maps destination to source, so 0.5 makes it twice as big. */
+    pub fn scale(x: f64, y: f64) -> Transform {
+        Transform {
+            matrix11: x, matrix12: 0.0, matrix13: 0.0,
+            matrix21: 0.0, matrix22: y, matrix23: 0.0,
+            matrix31: 0.0, matrix32: 0.0, matrix33: 1.0
+        }
+    }
+}
+
+impl Color {
+    /** Makes a color from 8 bit components. RENDER colors are premultiplied by alpha, which is done here. */
+    pub fn from_rgba8(red: u8, green: u8, blue: u8, alpha: u8) -> Color {
+        let premultiply = |value: u8| (value as u32 * alpha as u32 * 0x101 / 0xFF) as u16;
+        Color { red: premultiply(red), green: premultiply(green), blue: premultiply(blue), alpha: alpha as u16 * 0x101 }
+    }
+}
+
+/** A run of glyphs for CompositeGlyphs. It starts (dx, dy) from where the last run ended. */
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlyphElt {
+    pub dx: i16,
+    pub dy: i16,
+    pub glyphs: Vec<u32>
+}
+
+/**
+ * Encodes runs of glyphs into the glyphcmds of CompositeGlyphs8/16/32, with each glyph taking `size` bytes (1, 2 or 4).
+ * Runs longer than the protocol allows are split.
+ */
+pub fn glyph_cmds(elts: &[GlyphElt], size: usize) -> Vec<u8> {
+    let mut cmds = vec![];
+    for elt in elts {
+        let (mut dx, mut dy) = (elt.dx, elt.dy);
+        for chunk in elt.glyphs.chunks(254) {
+            cmds.push(chunk.len() as u8);
+            cmds.extend_from_slice(&[0, 0, 0]);
+            cmds.extend_from_slice(&dx.to_le_bytes());
+            cmds.extend_from_slice(&dy.to_le_bytes());
+            for glyph in chunk {
+                cmds.extend_from_slice(&glyph.to_le_bytes()[..size]);
+            }
+            cmds.resize(cmds.len() + (4 - chunk.len() * size % 4) % 4, 0);
+            dx = 0;
+            dy = 0;
+        }
+    }
+    cmds
+}
+
+impl XClient {
+    /** Gets the picture formats. Blocks. */
+    pub fn render_query_pict_formats_sync(&mut self) -> Option<QueryPictFormatsReply> {
+        let seq = self.render_query_pict_formats()?;
+        match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::Render(RenderReply::QueryPictFormats(reply)), _) => Some(reply),
+            _ => None
+        }
+    }
+
+    /** Draws runs of glyphs from a glyph set, using CompositeGlyphs8, 16 or 32 depending on the biggest glyph ID */
+    pub fn render_composite_glyphs(&mut self, op: &PictOp, src: u32, dst: u32, mask_format: u32, glyphset: u32, src_x: i16, src_y: i16, elts: &[GlyphElt]) -> Option<()> {
+        let max = elts.iter().flat_map(|elt| elt.glyphs.iter()).max().cloned().unwrap_or(0);
+        if max <= 0xFF {
+            self.render_composite_glyphs8(op, src, dst, mask_format, glyphset, src_x, src_y, &glyph_cmds(elts, 1))
+        } else if max <= 0xFFFF {
+            self.render_composite_glyphs16(op, src, dst, mask_format, glyphset, src_x, src_y, &glyph_cmds(elts, 2))
+        } else {
+            self.render_composite_glyphs32(op, src, dst, mask_format, glyphset, src_x, src_y, &glyph_cmds(elts, 4))
+        }
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to RENDER QueryPictFormats */
+    pub(crate) fn read_render_query_pict_formats_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let num_formats = self.read_u32();
+        let num_screens = self.read_u32();
+        self.read_pad(8); // num_depths and num_visuals (totals over all the screens)
+        let num_subpixel = self.read_u32();
+        self.read_pad(4);
+
+        let mut formats = Vec::with_capacity(num_formats as usize);
+        for _ in 0..num_formats {
+            formats.push(PictFormInfo::read(self));
+        }
+
+        let mut screens = Vec::with_capacity(num_screens as usize);
+        for _ in 0..num_screens {
+            let num_depths = self.read_u32();
+            let fallback = self.read_u32();
+            let mut depths = Vec::with_capacity(num_depths as usize);
+            for _ in 0..num_depths {
+                depths.push(PictDepth::read(self));
+            }
+            screens.push(PictScreen { fallback, depths });
+        }
+
+        let mut subpixels = Vec::with_capacity(num_subpixel as usize);
+        for _ in 0..num_subpixel {
+            subpixels.push(self.read_u32());
+        }
+
+        Some(ServerReply::Render(RenderReply::QueryPictFormats(QueryPictFormatsReply { formats, screens, subpixels })))
+    }
+}
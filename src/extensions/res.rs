@@ -0,0 +1,369 @@
+// X-Resource extension, version 1.2
+// Generated by tools/xcbgen from tools/xcb-proto/res.xml. Don't edit this file, change the generator or
+// res_extra.rs (which has everything written by hand) instead.
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+#[allow(unused_imports)] // Some extra modules only add methods
+pub use super::res_extra::*;
+
+pub const EXTENSION_NAME: &str = "X-Resource";
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 2;
+
+pub const OP_QUERY_VERSION: u8 = 0;
+pub const OP_QUERY_CLIENTS: u8 = 1;
+pub const OP_QUERY_CLIENT_RESOURCES: u8 = 2;
+pub const OP_QUERY_CLIENT_PIXMAP_BYTES: u8 = 3;
+pub const OP_QUERY_CLIENT_IDS: u8 = 4;
+pub const OP_QUERY_RESOURCE_BYTES: u8 = 5;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ClientIdMask {
+    ClientXID,
+    LocalClientPID
+}
+impl ClientIdMask {
+    pub fn val(&self) -> u32 {
+        match self {
+            &ClientIdMask::ClientXID => 0x00000001,
+            &ClientIdMask::LocalClientPID => 0x00000002
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Client {
+    pub resource_base: u32,
+    pub resource_mask: u32
+}
+impl Client {
+    pub fn read(reader: &mut XReadHelper) -> Client {
+        let resource_base = reader.read_u32();
+        let resource_mask = reader.read_u32();
+        Client { resource_base, resource_mask }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.resource_base);
+        client.write_u32(self.resource_mask);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Type {
+    pub resource_type: u32,
+    pub count: u32
+}
+impl Type {
+    pub fn read(reader: &mut XReadHelper) -> Type {
+        let resource_type = reader.read_u32();
+        let count = reader.read_u32();
+        Type { resource_type, count }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.resource_type);
+        client.write_u32(self.count);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ClientIdSpec {
+    pub client: u32,
+    pub mask: u32
+}
+impl ClientIdSpec {
+    pub fn read(reader: &mut XReadHelper) -> ClientIdSpec {
+        let client = reader.read_u32();
+        let mask = reader.read_u32();
+        ClientIdSpec { client, mask }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.client);
+        client.write_u32(self.mask);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ClientIdValue {
+    pub spec: ClientIdSpec,
+    pub length: u32,
+    pub value: Vec<u32>
+}
+impl ClientIdValue {
+    pub fn read(reader: &mut XReadHelper) -> ClientIdValue {
+        let spec = ClientIdSpec::read(reader);
+        let length = reader.read_u32();
+        let mut value = Vec::with_capacity((length as usize) / 4);
+        for _ in 0..(length as usize) / 4 {
+            value.push(reader.read_u32());
+        }
+        ClientIdValue { spec, length, value }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        self.spec.write(client);
+        client.write_u32(self.length);
+        for value in &self.value {
+            client.write_u32(*value);
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ResourceIdSpec {
+    pub resource: u32,
+    pub kind: u32
+}
+impl ResourceIdSpec {
+    pub fn read(reader: &mut XReadHelper) -> ResourceIdSpec {
+        let resource = reader.read_u32();
+        let kind = reader.read_u32();
+        ResourceIdSpec { resource, kind }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.resource);
+        client.write_u32(self.kind);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ResourceSizeSpec {
+    pub spec: ResourceIdSpec,
+    pub bytes: u32,
+    pub ref_count: u32,
+    pub use_count: u32
+}
+impl ResourceSizeSpec {
+    pub fn read(reader: &mut XReadHelper) -> ResourceSizeSpec {
+        let spec = ResourceIdSpec::read(reader);
+        let bytes = reader.read_u32();
+        let ref_count = reader.read_u32();
+        let use_count = reader.read_u32();
+        ResourceSizeSpec { spec, bytes, ref_count, use_count }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        self.spec.write(client);
+        client.write_u32(self.bytes);
+        client.write_u32(self.ref_count);
+        client.write_u32(self.use_count);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ResourceSizeValue {
+    pub size: ResourceSizeSpec,
+    pub cross_references: Vec<ResourceSizeSpec>
+}
+impl ResourceSizeValue {
+    pub fn read(reader: &mut XReadHelper) -> ResourceSizeValue {
+        let size = ResourceSizeSpec::read(reader);
+        let num_cross_references = reader.read_u32();
+        let mut cross_references = Vec::with_capacity(num_cross_references as usize);
+        for _ in 0..num_cross_references as usize {
+            cross_references.push(ResourceSizeSpec::read(reader));
+        }
+        ResourceSizeValue { size, cross_references }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        self.size.write(client);
+        client.write_u32(self.cross_references.len() as u32);
+        for value in &self.cross_references {
+            value.write(client);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum XResReply {
+    QueryVersion {
+        server_major: u16,
+        server_minor: u16
+    },
+    QueryClients {
+        clients: Vec<Client>
+    },
+    QueryClientResources {
+        types: Vec<Type>
+    },
+    QueryClientPixmapBytes {
+        bytes: u32,
+        bytes_overflow: u32
+    },
+    QueryClientIds {
+        ids: Vec<ClientIdValue>
+    },
+    QueryResourceBytes {
+        sizes: Vec<ResourceSizeValue>
+    }
+}
+
+/** Decodes X-Resource replies and events */
+#[derive(Debug, Default)]
+pub struct XRes;
+
+impl Extension for XRes {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_QUERY_VERSION => reader.read_res_query_version_reply(detail),
+            OP_QUERY_CLIENTS => reader.read_res_query_clients_reply(detail),
+            OP_QUERY_CLIENT_RESOURCES => reader.read_res_query_client_resources_reply(detail),
+            OP_QUERY_CLIENT_PIXMAP_BYTES => reader.read_res_query_client_pixmap_bytes_reply(detail),
+            OP_QUERY_CLIENT_IDS => reader.read_res_query_client_ids_reply(detail),
+            OP_QUERY_RESOURCE_BYTES => reader.read_res_query_resource_bytes_reply(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the X-Resource QueryVersion request. Returns None without sending anything if the server doesn't have X-Resource, like every request here. */
+    pub fn res_query_version(&mut self, client_major: u8, client_minor: u8) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XRes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_VERSION);
+        self.write_u16(2);
+        self.write_u8(client_major);
+        self.write_u8(client_minor);
+        self.write_pad(2);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_VERSION)))
+    }
+
+    /** Sends the X-Resource QueryClients request */
+    pub fn res_query_clients(&mut self) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XRes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_CLIENTS);
+        self.write_u16(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_CLIENTS)))
+    }
+
+    /** Sends the X-Resource QueryClientResources request */
+    pub fn res_query_client_resources(&mut self, xid: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XRes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_CLIENT_RESOURCES);
+        self.write_u16(2);
+        self.write_u32(xid);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_CLIENT_RESOURCES)))
+    }
+
+    /** Sends the X-Resource QueryClientPixmapBytes request */
+    pub fn res_query_client_pixmap_bytes(&mut self, xid: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XRes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_CLIENT_PIXMAP_BYTES);
+        self.write_u16(2);
+        self.write_u32(xid);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_CLIENT_PIXMAP_BYTES)))
+    }
+
+    /** Sends the X-Resource QueryClientIds request */
+    pub fn res_query_client_ids(&mut self, specs: &Vec<ClientIdSpec>) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XRes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_CLIENT_IDS);
+        let pad = self.write_dynamic_len(2, specs.len() * 8);
+        self.write_u32(specs.len() as u32);
+        for value in specs {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_CLIENT_IDS)))
+    }
+
+    /** Sends the X-Resource QueryResourceBytes request */
+    pub fn res_query_resource_bytes(&mut self, client: u32, specs: &Vec<ResourceIdSpec>) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XRes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_RESOURCE_BYTES);
+        let pad = self.write_dynamic_len(3, specs.len() * 8);
+        self.write_u32(client);
+        self.write_u32(specs.len() as u32);
+        for value in specs {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_RESOURCE_BYTES)))
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to X-Resource QueryVersion */
+    fn read_res_query_version_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let server_major = self.read_u16();
+        let server_minor = self.read_u16();
+        Some(ServerReply::XRes(XResReply::QueryVersion { server_major, server_minor }))
+    }
+
+    /** Reads a reply to X-Resource QueryClients */
+    fn read_res_query_clients_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let num_clients = self.read_u32();
+        self.read_pad(20);
+        let mut clients = Vec::with_capacity(num_clients as usize);
+        for _ in 0..num_clients as usize {
+            clients.push(Client::read(self));
+        }
+        Some(ServerReply::XRes(XResReply::QueryClients { clients }))
+    }
+
+    /** Reads a reply to X-Resource QueryClientResources */
+    fn read_res_query_client_resources_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let num_types = self.read_u32();
+        self.read_pad(20);
+        let mut types = Vec::with_capacity(num_types as usize);
+        for _ in 0..num_types as usize {
+            types.push(Type::read(self));
+        }
+        Some(ServerReply::XRes(XResReply::QueryClientResources { types }))
+    }
+
+    /** Reads a reply to X-Resource QueryClientPixmapBytes */
+    fn read_res_query_client_pixmap_bytes_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let bytes = self.read_u32();
+        let bytes_overflow = self.read_u32();
+        Some(ServerReply::XRes(XResReply::QueryClientPixmapBytes { bytes, bytes_overflow }))
+    }
+
+    /** Reads a reply to X-Resource QueryClientIds */
+    fn read_res_query_client_ids_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let num_ids = self.read_u32();
+        self.read_pad(20);
+        let mut ids = Vec::with_capacity(num_ids as usize);
+        for _ in 0..num_ids as usize {
+            ids.push(ClientIdValue::read(self));
+        }
+        Some(ServerReply::XRes(XResReply::QueryClientIds { ids }))
+    }
+
+    /** Reads a reply to X-Resource QueryResourceBytes */
+    fn read_res_query_resource_bytes_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let num_sizes = self.read_u32();
+        self.read_pad(20);
+        let mut sizes = Vec::with_capacity(num_sizes as usize);
+        for _ in 0..num_sizes as usize {
+            sizes.push(ResourceSizeValue::read(self));
+        }
+        Some(ServerReply::XRes(XResReply::QueryResourceBytes { sizes }))
+    }
+}
@@ -0,0 +1,66 @@
+// X-Resource resource type names, pixmap bytes, and client PIDs.
+
+use models::{ServerReply, ServerResponse};
+use XClient;
+
+use super::res::*;
+
+impl Type {
+    /** Gets the name of the resource type (ie "PIXMAP" or "WINDOW"), which is an atom. Blocks. */
+    pub fn name_sync(&self, client: &mut XClient) -> Option<String> {
+        let seq = client.get_atom_name(self.resource_type);
+        match client.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::GetAtomName { name }, _) => Some(name),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /**
+     * Counts the resources of the client that owns `xid` (any of its resource IDs), by type name. Blocks.
+     * Types whose atom has no name are given as their number.
+     */
+    pub fn res_client_resources_sync(&mut self, xid: u32) -> Vec<(String, u32)> {
+        let seq = match self.res_query_client_resources(xid) {
+            Some(seq) => seq,
+            None => return vec![]
+        };
+        let types = match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::XRes(XResReply::QueryClientResources { types }), _) => types,
+            _ => return vec![]
+        };
+
+        // Ask for every name before waiting for any
+        let seqs: Vec<u16> = types.iter().map(|t| self.get_atom_name(t.resource_type)).collect();
+        types.iter().zip(seqs).map(|(t, seq)| {
+            let name = match self.wait_for_response(seq) {
+                ServerResponse::Reply(ServerReply::GetAtomName { name }, _) => name,
+                _ => t.resource_type.to_string()
+            };
+            (name, t.count)
+        }).collect()
+    }
+
+    /** Gets how many bytes of pixmaps the client that owns `xid` has. Blocks. */
+    pub fn res_client_pixmap_bytes_sync(&mut self, xid: u32) -> Option<u64> {
+        let seq = self.res_query_client_pixmap_bytes(xid)?;
+        match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::XRes(XResReply::QueryClientPixmapBytes { bytes, bytes_overflow }), _) => {
+                Some(((bytes_overflow as u64) << 32) | bytes as u64)
+            },
+            _ => None
+        }
+    }
+
+    /** Gets the process ID of the client that owns `xid`, if it's on the same machine as the server (X-Resource 1.2). Blocks. */
+    pub fn res_client_pid_sync(&mut self, xid: u32) -> Option<u32> {
+        let seq = self.res_query_client_ids(&vec![ClientIdSpec { client: xid, mask: ClientIdMask::LocalClientPID.val() }])?;
+        match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::XRes(XResReply::QueryClientIds { ids }), _) => {
+                ids.iter().find(|id| id.spec.mask & ClientIdMask::LocalClientPID.val() != 0).and_then(|id| id.value.first().cloned())
+            },
+            _ => None
+        }
+    }
+}
@@ -0,0 +1,262 @@
+// MIT-SCREEN-SAVER extension, version 1.1
+// Generated by tools/xcbgen from tools/xcb-proto/screensaver.xml. Don't edit this file, change the generator or
+// screensaver_extra.rs (which has everything written by hand) instead.
+//
+// Written by hand in screensaver_extra.rs:
+//   - request SetAttributes
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{ServerEvent, ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+#[allow(unused_imports)] // Some extra modules only add methods
+pub use super::screensaver_extra::*;
+
+pub const EXTENSION_NAME: &str = "MIT-SCREEN-SAVER";
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 1;
+
+pub const OP_QUERY_VERSION: u8 = 0;
+pub const OP_QUERY_INFO: u8 = 1;
+pub const OP_SELECT_INPUT: u8 = 2;
+pub const OP_SET_ATTRIBUTES: u8 = 3;
+pub const OP_UNSET_ATTRIBUTES: u8 = 4;
+pub const OP_SUSPEND: u8 = 5;
+
+pub const EVENT_NOTIFY: u8 = 0;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Kind {
+    Blanked,
+    Internal,
+    External
+}
+impl Kind {
+    pub fn get(id: u32) -> Option<Kind> {
+        match id {
+            0 => Some(Kind::Blanked),
+            1 => Some(Kind::Internal),
+            2 => Some(Kind::External),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &Kind::Blanked => 0,
+            &Kind::Internal => 1,
+            &Kind::External => 2
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Event {
+    NotifyMask,
+    CycleMask
+}
+impl Event {
+    pub fn val(&self) -> u32 {
+        match self {
+            &Event::NotifyMask => 0x00000001,
+            &Event::CycleMask => 0x00000002
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum State {
+    Off,
+    On,
+    Cycle,
+    Disabled
+}
+impl State {
+    pub fn get(id: u32) -> Option<State> {
+        match id {
+            0 => Some(State::Off),
+            1 => Some(State::On),
+            2 => Some(State::Cycle),
+            3 => Some(State::Disabled),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &State::Off => 0,
+            &State::On => 1,
+            &State::Cycle => 2,
+            &State::Disabled => 3
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ScreenSaverReply {
+    QueryVersion {
+        server_major_version: u16,
+        server_minor_version: u16
+    },
+    QueryInfo {
+        state: u8,
+        saver_window: u32,
+        ms_until_server: u32,
+        ms_since_user_input: u32,
+        event_mask: u32,
+        kind: Kind
+    }
+}
+
+#[derive(Debug)]
+pub enum ScreenSaverEvent {
+    Notify {
+        state: State,
+        time: u32,
+        root: u32,
+        window: u32,
+        kind: Kind,
+        forced: bool
+    }
+}
+
+/** Decodes MIT-SCREEN-SAVER replies and events */
+#[derive(Debug, Default)]
+pub struct ScreenSaver;
+
+impl Extension for ScreenSaver {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn event_count(&self) -> u8 {
+        1
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_QUERY_VERSION => reader.read_screensaver_query_version_reply(detail),
+            OP_QUERY_INFO => reader.read_screensaver_query_info_reply(detail),
+            _ => None
+        }
+    }
+
+    fn read_event(&self, reader: &mut XReadHelper, code: u8, detail: u8) -> Option<ServerEvent> {
+        match code {
+            EVENT_NOTIFY => reader.read_screensaver_notify_event(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the MIT-SCREEN-SAVER QueryVersion request. Returns None without sending anything if the server doesn't have MIT-SCREEN-SAVER, like every request here. */
+    pub fn screensaver_query_version(&mut self, client_major_version: u8, client_minor_version: u8) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<ScreenSaver>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_VERSION);
+        self.write_u16(2);
+        self.write_u8(client_major_version);
+        self.write_u8(client_minor_version);
+        self.write_pad(2);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_VERSION)))
+    }
+
+    /** Sends the MIT-SCREEN-SAVER QueryInfo request */
+    pub fn screensaver_query_info(&mut self, drawable: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<ScreenSaver>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_INFO);
+        self.write_u16(2);
+        self.write_u32(drawable);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_INFO)))
+    }
+
+    /** Sends the MIT-SCREEN-SAVER SelectInput request */
+    pub fn screensaver_select_input(&mut self, drawable: u32, event_mask: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<ScreenSaver>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SELECT_INPUT);
+        self.write_u16(3);
+        self.write_u32(drawable);
+        self.write_u32(event_mask);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the MIT-SCREEN-SAVER UnsetAttributes request */
+    pub fn screensaver_unset_attributes(&mut self, drawable: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<ScreenSaver>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_UNSET_ATTRIBUTES);
+        self.write_u16(2);
+        self.write_u32(drawable);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the MIT-SCREEN-SAVER Suspend request */
+    pub fn screensaver_suspend(&mut self, suspend: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<ScreenSaver>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SUSPEND);
+        self.write_u16(2);
+        self.write_u32(suspend);
+
+        self.write_request();
+        Some(())
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to MIT-SCREEN-SAVER QueryVersion */
+    fn read_screensaver_query_version_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let server_major_version = self.read_u16();
+        let server_minor_version = self.read_u16();
+        self.read_pad(20);
+        Some(ServerReply::ScreenSaver(ScreenSaverReply::QueryVersion { server_major_version, server_minor_version }))
+    }
+
+    /** Reads a reply to MIT-SCREEN-SAVER QueryInfo */
+    fn read_screensaver_query_info_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let state = detail;
+        let saver_window = self.read_u32();
+        let ms_until_server = self.read_u32();
+        let ms_since_user_input = self.read_u32();
+        let event_mask = self.read_u32();
+        let kind = self.read_u8();
+        let kind = match Kind::get(kind as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        self.read_pad(7);
+        Some(ServerReply::ScreenSaver(ScreenSaverReply::QueryInfo { state, saver_window, ms_until_server, ms_since_user_input, event_mask, kind }))
+    }
+
+    /** Reads a MIT-SCREEN-SAVER Notify event */
+    fn read_screensaver_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let state = detail;
+        let state = match State::get(state as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let time = self.read_u32();
+        let root = self.read_u32();
+        let window = self.read_u32();
+        let kind = self.read_u8();
+        let kind = match Kind::get(kind as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let forced = self.read_bool();
+        self.read_pad(14);
+        Some(ServerEvent::ScreenSaver(ScreenSaverEvent::Notify { state, time, root, window, kind, forced }))
+    }
+}
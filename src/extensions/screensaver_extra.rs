@@ -0,0 +1,50 @@
+// MIT-SCREEN-SAVER SetAttributes, which xcbgen can't generate, and the idle time.
+
+#![allow(clippy::ptr_arg, clippy::too_many_arguments)]
+
+use models::{ServerReply, ServerResponse, WindowInputType, WindowValue};
+use xreaderwriter::XBufferedWriter;
+use XClient;
+
+use super::screensaver::*;
+
+impl XClient {
+    /**
+     * Sends the MIT-SCREEN-SAVER SetAttributes request, which sets up the window the server shows as the screen saver
+     * on the screen of `drawable`. The arguments are the same as create_window's.
+     */
+    pub fn screensaver_set_attributes(&mut self, drawable: u32, x: i16, y: i16, width: u16, height: u16, border_width: u16, class: &WindowInputType, depth: u8, visual: u32, values: &Vec<WindowValue>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<ScreenSaver>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_ATTRIBUTES);
+        self.write_u16(7 + values.len() as u16);
+        self.write_u32(drawable);
+        self.write_i16(x);
+        self.write_i16(y);
+        self.write_u16(width);
+        self.write_u16(height);
+        self.write_u16(border_width);
+        self.write_u8(class.val() as u8);
+        self.write_u8(depth);
+        self.write_u32(visual);
+        self.write_values(values, 32);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Asks for ScreenSaverNotify events (NotifyMask) and/or cycle events (CycleMask) on the screen of `drawable` */
+    pub fn screensaver_select_events(&mut self, drawable: u32, events: &[Event]) -> Option<()> {
+        let event_mask = events.iter().fold(0, |mask, event| mask | event.val());
+        self.screensaver_select_input(drawable, event_mask)
+    }
+
+    /** Gets how long it's been since the user last used the keyboard or pointer, in milliseconds. Blocks. */
+    pub fn screensaver_idle_time_sync(&mut self, drawable: u32) -> Option<u32> {
+        let seq = self.screensaver_query_info(drawable)?;
+        match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::ScreenSaver(ScreenSaverReply::QueryInfo { ms_since_user_input, .. }), _) => Some(ms_since_user_input),
+            _ => None
+        }
+    }
+}
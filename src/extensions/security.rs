@@ -0,0 +1,240 @@
+// SECURITY extension, version 1.0
+
+#![allow(clippy::match_ref_pats, clippy::ptr_arg)]
+
+use models::{ServerEvent, ServerReply, ServerReplyType, ServerResponse, Value};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+pub const EXTENSION_NAME: &str = "SECURITY";
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 0;
+
+pub const OP_QUERY_VERSION: u8 = 0;
+pub const OP_GENERATE_AUTHORIZATION: u8 = 1;
+pub const OP_REVOKE_AUTHORIZATION: u8 = 2;
+
+pub const EVENT_AUTHORIZATION_REVOKED: u8 = 0;
+
+pub const ERROR_BAD_AUTHORIZATION: u8 = 0;
+pub const ERROR_BAD_AUTHORIZATION_PROTOCOL: u8 = 1;
+
+/** The only authorization protocol servers generate cookies for */
+pub const MIT_MAGIC_COOKIE: &str = "MIT-MAGIC-COOKIE-1";
+
+/** The event mask for GenerateAuthorization's EventMask value */
+pub const AUTHORIZATION_REVOKED_MASK: u32 = 1;
+
+/** What clients connecting with an authorization may do */
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TrustLevel {
+    Trusted, // Like any other client
+    Untrusted // Can only see and touch the resources of other untrusted clients (in the same group)
+}
+impl TrustLevel {
+    pub fn get(id: u32) -> Option<TrustLevel> {
+        match id {
+            0 => Some(TrustLevel::Trusted),
+            1 => Some(TrustLevel::Untrusted),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &TrustLevel::Trusted => 0,
+            &TrustLevel::Untrusted => 1
+        }
+    }
+}
+
+/** An attribute of a generated authorization, for GenerateAuthorization */
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AuthorizationValue {
+    Timeout(u32), // Seconds the authorization lasts once no client is using it (the server picks if not given)
+    TrustLevel(TrustLevel), // Untrusted if not given
+    Group(u32), // The application group (from XC-APPGROUP) of the clients, or 0 for none
+    EventMask(u32) // AUTHORIZATION_REVOKED_MASK to get an AuthorizationRevoked event
+}
+
+impl Value for AuthorizationValue {
+    fn get_mask(&self) -> u32 {
+        match self {
+            &AuthorizationValue::Timeout(_) => 0x01,
+            &AuthorizationValue::TrustLevel(_) => 0x02,
+            &AuthorizationValue::Group(_) => 0x04,
+            &AuthorizationValue::EventMask(_) => 0x08
+        }
+    }
+
+    fn write(&self, client: &mut XClient) {
+        match self {
+            &AuthorizationValue::Timeout(val) => client.write_val_u32(val),
+            &AuthorizationValue::TrustLevel(val) => client.write_val(val.val()),
+            &AuthorizationValue::Group(val) => client.write_val_u32(val),
+            &AuthorizationValue::EventMask(val) => client.write_val_u32(val)
+        }
+    }
+}
+
+/**
+ * A generated authorization. Another process connects with it by passing MIT_MAGIC_COOKIE and `data` to
+ * XClient::connect_with_authorization.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Authorization {
+    pub id: u32, // For RevokeAuthorization and AuthorizationRevoked
+    pub data: Vec<u8> // The cookie
+}
+
+#[derive(Debug)]
+pub enum SecurityReply {
+    QueryVersion {
+        server_major_version: u16,
+        server_minor_version: u16
+    },
+    GenerateAuthorization {
+        authorization_id: u32,
+        authorization_data: Vec<u8>
+    }
+}
+
+#[derive(Debug)]
+pub enum SecurityEvent {
+    AuthorizationRevoked {
+        authorization_id: u32
+    }
+}
+
+/** Decodes SECURITY replies and events */
+#[derive(Debug, Default)]
+pub struct Security;
+
+impl Extension for Security {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn event_count(&self) -> u8 {
+        1
+    }
+
+    fn error_names(&self) -> &'static [&'static str] {
+        &["BadAuthorization", "BadAuthorizationProtocol"]
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_QUERY_VERSION => reader.read_security_query_version_reply(detail),
+            OP_GENERATE_AUTHORIZATION => reader.read_security_generate_authorization_reply(detail),
+            _ => None
+        }
+    }
+
+    fn read_event(&self, reader: &mut XReadHelper, code: u8, detail: u8) -> Option<ServerEvent> {
+        match code {
+            EVENT_AUTHORIZATION_REVOKED => reader.read_security_authorization_revoked_event(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the SECURITY QueryVersion request. Returns None without sending anything if the server doesn't have SECURITY, like every request here. */
+    pub fn security_query_version(&mut self, client_major_version: u16, client_minor_version: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Security>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_VERSION);
+        self.write_u16(2);
+        self.write_u16(client_major_version);
+        self.write_u16(client_minor_version);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_VERSION)))
+    }
+
+    /**
+     * Sends the SECURITY GenerateAuthorization request, which makes a new authorization for `auth_protocol_name`
+     * (ie MIT_MAGIC_COOKIE). If `auth_protocol_data` is empty, the server makes up the cookie.
+     */
+    pub fn security_generate_authorization(&mut self, auth_protocol_name: &str, auth_protocol_data: &[u8], values: &Vec<AuthorizationValue>) -> Option<u16> {
+        let name_pad = (4 - auth_protocol_name.len() % 4) % 4;
+        let data_pad = (4 - auth_protocol_data.len() % 4) % 4;
+        let extension_opcode = self.extension_opcode::<Security>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GENERATE_AUTHORIZATION);
+        self.write_u16(3 + ((auth_protocol_name.len() + name_pad + auth_protocol_data.len() + data_pad) / 4 + values.len()) as u16);
+        self.write_u16(auth_protocol_name.len() as u16);
+        self.write_u16(auth_protocol_data.len() as u16);
+        self.write_u32(values.iter().fold(0, |mask, value| mask | value.get_mask()));
+        self.write_str(auth_protocol_name);
+        self.write_pad_op(name_pad);
+        self.write_raw(auth_protocol_data);
+        self.write_pad_op(data_pad);
+        // The values come after the strings here, so write_values (which puts the mask first) can't be used
+        let mut ordered: Vec<&AuthorizationValue> = values.iter().collect();
+        ordered.sort_by_key(|value| value.get_mask());
+        for value in ordered {
+            value.write(self);
+        }
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GENERATE_AUTHORIZATION)))
+    }
+
+    /** Sends the SECURITY RevokeAuthorization request. Clients that connected with it are disconnected. */
+    pub fn security_revoke_authorization(&mut self, authorization_id: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Security>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_REVOKE_AUTHORIZATION);
+        self.write_u16(2);
+        self.write_u32(authorization_id);
+
+        self.write_request();
+        Some(())
+    }
+
+    /**
+     * Makes a MIT-MAGIC-COOKIE-1 authorization for untrusted clients (ie sandboxed plugins), which lasts `timeout`
+     * seconds once none of them are connected. Asks for an AuthorizationRevoked event when it goes away. Blocks.
+     * Returns None if the server doesn't have SECURITY, or wouldn't make one.
+     */
+    pub fn security_generate_untrusted_sync(&mut self, timeout: u32) -> Option<Authorization> {
+        let values = vec![
+            AuthorizationValue::Timeout(timeout),
+            AuthorizationValue::TrustLevel(TrustLevel::Untrusted),
+            AuthorizationValue::EventMask(AUTHORIZATION_REVOKED_MASK)
+        ];
+        let seq = self.security_generate_authorization(MIT_MAGIC_COOKIE, &[], &values)?;
+        match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::Security(SecurityReply::GenerateAuthorization { authorization_id, authorization_data }), _) => {
+                Some(Authorization { id: authorization_id, data: authorization_data })
+            },
+            _ => None
+        }
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to SECURITY QueryVersion */
+    fn read_security_query_version_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let server_major_version = self.read_u16();
+        let server_minor_version = self.read_u16();
+        self.read_pad(20);
+        Some(ServerReply::Security(SecurityReply::QueryVersion { server_major_version, server_minor_version }))
+    }
+
+    /** Reads a reply to SECURITY GenerateAuthorization */
+    fn read_security_generate_authorization_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let authorization_id = self.read_u32();
+        let data_length = self.read_u16();
+        self.read_pad(18);
+        let authorization_data = self.read_raw(data_length as usize);
+        Some(ServerReply::Security(SecurityReply::GenerateAuthorization { authorization_id, authorization_data }))
+    }
+
+    /** Reads a SECURITY AuthorizationRevoked event */
+    fn read_security_authorization_revoked_event(&mut self, _detail: u8) -> Option<ServerEvent> {
+        let authorization_id = self.read_u32();
+        Some(ServerEvent::Security(SecurityEvent::AuthorizationRevoked { authorization_id }))
+    }
+}
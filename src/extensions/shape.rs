@@ -0,0 +1,354 @@
+// SHAPE extension, version 1.1
+// Generated by tools/xcbgen from tools/xcb-proto/shape.xml. Don't edit this file, change the generator or
+// shape_extra.rs (which has everything written by hand) instead.
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{Rectangle, ServerEvent, ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+#[allow(unused_imports)] // Some extra modules only add methods
+pub use super::shape_extra::*;
+
+pub const EXTENSION_NAME: &str = "SHAPE";
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 1;
+
+pub const OP_QUERY_VERSION: u8 = 0;
+pub const OP_RECTANGLES: u8 = 1;
+pub const OP_MASK: u8 = 2;
+pub const OP_COMBINE: u8 = 3;
+pub const OP_OFFSET: u8 = 4;
+pub const OP_QUERY_EXTENTS: u8 = 5;
+pub const OP_SELECT_INPUT: u8 = 6;
+pub const OP_INPUT_SELECTED: u8 = 7;
+pub const OP_GET_RECTANGLES: u8 = 8;
+
+pub const EVENT_NOTIFY: u8 = 0;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum So {
+    Set,
+    Union,
+    Intersect,
+    Subtract,
+    Invert
+}
+impl So {
+    pub fn get(id: u32) -> Option<So> {
+        match id {
+            0 => Some(So::Set),
+            1 => Some(So::Union),
+            2 => Some(So::Intersect),
+            3 => Some(So::Subtract),
+            4 => Some(So::Invert),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &So::Set => 0,
+            &So::Union => 1,
+            &So::Intersect => 2,
+            &So::Subtract => 3,
+            &So::Invert => 4
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Sk {
+    Bounding,
+    Clip,
+    Input
+}
+impl Sk {
+    pub fn get(id: u32) -> Option<Sk> {
+        match id {
+            0 => Some(Sk::Bounding),
+            1 => Some(Sk::Clip),
+            2 => Some(Sk::Input),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &Sk::Bounding => 0,
+            &Sk::Clip => 1,
+            &Sk::Input => 2
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ShapeReply {
+    QueryVersion {
+        major_version: u16,
+        minor_version: u16
+    },
+    QueryExtents {
+        bounding_shaped: bool,
+        clip_shaped: bool,
+        bounding_shape_extents_x: i16,
+        bounding_shape_extents_y: i16,
+        bounding_shape_extents_width: u16,
+        bounding_shape_extents_height: u16,
+        clip_shape_extents_x: i16,
+        clip_shape_extents_y: i16,
+        clip_shape_extents_width: u16,
+        clip_shape_extents_height: u16
+    },
+    InputSelected {
+        enabled: bool
+    },
+    GetRectangles {
+        ordering: u8,
+        rectangles: Vec<Rectangle>
+    }
+}
+
+#[derive(Debug)]
+pub enum ShapeEvent {
+    Notify {
+        shape_kind: Sk,
+        affected_window: u32,
+        extents_x: i16,
+        extents_y: i16,
+        extents_width: u16,
+        extents_height: u16,
+        server_time: u32,
+        shaped: bool
+    }
+}
+
+/** Decodes SHAPE replies and events */
+#[derive(Debug, Default)]
+pub struct Shape;
+
+impl Extension for Shape {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn event_count(&self) -> u8 {
+        1
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_QUERY_VERSION => reader.read_shape_query_version_reply(detail),
+            OP_QUERY_EXTENTS => reader.read_shape_query_extents_reply(detail),
+            OP_INPUT_SELECTED => reader.read_shape_input_selected_reply(detail),
+            OP_GET_RECTANGLES => reader.read_shape_get_rectangles_reply(detail),
+            _ => None
+        }
+    }
+
+    fn read_event(&self, reader: &mut XReadHelper, code: u8, detail: u8) -> Option<ServerEvent> {
+        match code {
+            EVENT_NOTIFY => reader.read_shape_notify_event(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the SHAPE QueryVersion request. Returns None without sending anything if the server doesn't have SHAPE, like every request here. */
+    pub fn shape_query_version(&mut self) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Shape>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_VERSION);
+        self.write_u16(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_VERSION)))
+    }
+
+    /** Sends the SHAPE Rectangles request */
+    pub fn shape_rectangles(&mut self, operation: &So, destination_kind: &Sk, ordering: u8, destination_window: u32, x_offset: i16, y_offset: i16, rectangles: &Vec<Rectangle>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Shape>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_RECTANGLES);
+        let pad = self.write_dynamic_len(4, rectangles.len() * 8);
+        self.write_u8(operation.val() as u8);
+        self.write_u8(destination_kind.val() as u8);
+        self.write_u8(ordering);
+        self.write_pad(1);
+        self.write_u32(destination_window);
+        self.write_i16(x_offset);
+        self.write_i16(y_offset);
+        for value in rectangles {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the SHAPE Mask request */
+    pub fn shape_mask(&mut self, operation: &So, destination_kind: &Sk, destination_window: u32, x_offset: i16, y_offset: i16, source_bitmap: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Shape>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_MASK);
+        self.write_u16(5);
+        self.write_u8(operation.val() as u8);
+        self.write_u8(destination_kind.val() as u8);
+        self.write_pad(2);
+        self.write_u32(destination_window);
+        self.write_i16(x_offset);
+        self.write_i16(y_offset);
+        self.write_u32(source_bitmap);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the SHAPE Combine request */
+    pub fn shape_combine(&mut self, operation: &So, destination_kind: &Sk, source_kind: &Sk, destination_window: u32, x_offset: i16, y_offset: i16, source_window: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Shape>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_COMBINE);
+        self.write_u16(5);
+        self.write_u8(operation.val() as u8);
+        self.write_u8(destination_kind.val() as u8);
+        self.write_u8(source_kind.val() as u8);
+        self.write_pad(1);
+        self.write_u32(destination_window);
+        self.write_i16(x_offset);
+        self.write_i16(y_offset);
+        self.write_u32(source_window);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the SHAPE Offset request */
+    pub fn shape_offset(&mut self, destination_kind: &Sk, destination_window: u32, x_offset: i16, y_offset: i16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Shape>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_OFFSET);
+        self.write_u16(4);
+        self.write_u8(destination_kind.val() as u8);
+        self.write_pad(3);
+        self.write_u32(destination_window);
+        self.write_i16(x_offset);
+        self.write_i16(y_offset);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the SHAPE QueryExtents request */
+    pub fn shape_query_extents(&mut self, destination_window: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Shape>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_EXTENTS);
+        self.write_u16(2);
+        self.write_u32(destination_window);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_EXTENTS)))
+    }
+
+    /** Sends the SHAPE SelectInput request */
+    pub fn shape_select_input(&mut self, destination_window: u32, enable: bool) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Shape>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SELECT_INPUT);
+        self.write_u16(3);
+        self.write_u32(destination_window);
+        self.write_bool(enable);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the SHAPE InputSelected request */
+    pub fn shape_input_selected(&mut self, destination_window: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Shape>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_INPUT_SELECTED);
+        self.write_u16(2);
+        self.write_u32(destination_window);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_INPUT_SELECTED)))
+    }
+
+    /** Sends the SHAPE GetRectangles request */
+    pub fn shape_get_rectangles(&mut self, window: u32, source_kind: &Sk) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Shape>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_RECTANGLES);
+        self.write_u16(3);
+        self.write_u32(window);
+        self.write_u8(source_kind.val() as u8);
+        self.write_pad(3);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_RECTANGLES)))
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to SHAPE QueryVersion */
+    fn read_shape_query_version_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let major_version = self.read_u16();
+        let minor_version = self.read_u16();
+        Some(ServerReply::Shape(ShapeReply::QueryVersion { major_version, minor_version }))
+    }
+
+    /** Reads a reply to SHAPE QueryExtents */
+    fn read_shape_query_extents_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let bounding_shaped = self.read_bool();
+        let clip_shaped = self.read_bool();
+        self.read_pad(2);
+        let bounding_shape_extents_x = self.read_i16();
+        let bounding_shape_extents_y = self.read_i16();
+        let bounding_shape_extents_width = self.read_u16();
+        let bounding_shape_extents_height = self.read_u16();
+        let clip_shape_extents_x = self.read_i16();
+        let clip_shape_extents_y = self.read_i16();
+        let clip_shape_extents_width = self.read_u16();
+        let clip_shape_extents_height = self.read_u16();
+        Some(ServerReply::Shape(ShapeReply::QueryExtents { bounding_shaped, clip_shaped, bounding_shape_extents_x, bounding_shape_extents_y, bounding_shape_extents_width, bounding_shape_extents_height, clip_shape_extents_x, clip_shape_extents_y, clip_shape_extents_width, clip_shape_extents_height }))
+    }
+
+    /** Reads a reply to SHAPE InputSelected */
+    fn read_shape_input_selected_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let enabled = detail != 0;
+        Some(ServerReply::Shape(ShapeReply::InputSelected { enabled }))
+    }
+
+    /** Reads a reply to SHAPE GetRectangles */
+    fn read_shape_get_rectangles_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let ordering = detail;
+        let rectangles_len = self.read_u32();
+        self.read_pad(20);
+        let mut rectangles = Vec::with_capacity(rectangles_len as usize);
+        for _ in 0..rectangles_len as usize {
+            rectangles.push(Rectangle { x: self.read_i16(), y: self.read_i16(), width: self.read_u16(), height: self.read_u16() });
+        }
+        Some(ServerReply::Shape(ShapeReply::GetRectangles { ordering, rectangles }))
+    }
+
+    /** Reads a SHAPE Notify event */
+    fn read_shape_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let shape_kind = detail;
+        let shape_kind = match Sk::get(shape_kind as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let affected_window = self.read_u32();
+        let extents_x = self.read_i16();
+        let extents_y = self.read_i16();
+        let extents_width = self.read_u16();
+        let extents_height = self.read_u16();
+        let server_time = self.read_u32();
+        let shaped = self.read_bool();
+        self.read_pad(11);
+        Some(ServerEvent::Shape(ShapeEvent::Notify { shape_kind, affected_window, extents_x, extents_y, extents_width, extents_height, server_time, shaped }))
+    }
+}
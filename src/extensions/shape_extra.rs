@@ -0,0 +1,28 @@
+// Window helpers for setting and resetting shapes.
+
+use models::{Rectangle, Window};
+use XClient;
+
+use super::shape::*;
+
+impl Window {
+    /** Sets the shape of a kind to the union of some rectangles (relative to the window's origin) */
+    pub fn set_shape(&self, client: &mut XClient, kind: &Sk, rectangles: &[Rectangle]) -> Option<()> {
+        client.shape_rectangles(&So::Set, kind, 0, self.wid, 0, 0, &rectangles.to_vec())
+    }
+
+    /** Sets the outline of the window, including its border. Anything outside of it is see-through. */
+    pub fn set_bounding_shape(&self, client: &mut XClient, rectangles: &[Rectangle]) -> Option<()> {
+        self.set_shape(client, &Sk::Bounding, rectangles)
+    }
+
+    /** Sets the parts of the window that get pointer input. Anything outside of it is clicked through. An empty list ignores all input. */
+    pub fn set_input_shape(&self, client: &mut XClient, rectangles: &[Rectangle]) -> Option<()> {
+        self.set_shape(client, &Sk::Input, rectangles)
+    }
+
+    /** Goes back to the default (rectangular) shape for a kind */
+    pub fn reset_shape(&self, client: &mut XClient, kind: &Sk) -> Option<()> {
+        client.shape_mask(&So::Set, kind, self.wid, 0, 0, 0)
+    }
+}
@@ -0,0 +1,247 @@
+// MIT-SHM extension, version 1.2
+// Generated by tools/xcbgen from tools/xcb-proto/shm.xml. Don't edit this file, change the generator or
+// shm_extra.rs (which has everything written by hand) instead.
+//
+// Not generated:
+//   - request AttachFd: <fd>
+//   - reply to CreateSegment (comes back as ServerReply::Unknown): <fd>
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{ServerEvent, ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+#[allow(unused_imports)] // Some extra modules only add methods
+pub use super::shm_extra::*;
+
+pub const EXTENSION_NAME: &str = "MIT-SHM";
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 2;
+
+pub const OP_QUERY_VERSION: u8 = 0;
+pub const OP_ATTACH: u8 = 1;
+pub const OP_DETACH: u8 = 2;
+pub const OP_PUT_IMAGE: u8 = 3;
+pub const OP_GET_IMAGE: u8 = 4;
+pub const OP_CREATE_PIXMAP: u8 = 5;
+pub const OP_ATTACH_FD: u8 = 6;
+pub const OP_CREATE_SEGMENT: u8 = 7;
+
+pub const EVENT_COMPLETION: u8 = 0;
+
+pub const ERROR_BAD_SEG: u8 = 0;
+
+#[derive(Debug)]
+pub enum ShmReply {
+    QueryVersion {
+        shared_pixmaps: bool,
+        major_version: u16,
+        minor_version: u16,
+        uid: u16,
+        gid: u16,
+        pixmap_format: u8
+    },
+    GetImage {
+        depth: u8,
+        visual: u32,
+        size: u32
+    }
+}
+
+#[derive(Debug)]
+pub enum ShmEvent {
+    Completion {
+        drawable: u32,
+        minor_event: u16,
+        major_event: u8,
+        shmseg: u32,
+        offset: u32
+    }
+}
+
+/** Decodes MIT-SHM replies and events */
+#[derive(Debug, Default)]
+pub struct Shm;
+
+impl Extension for Shm {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn event_count(&self) -> u8 {
+        1
+    }
+
+    fn error_names(&self) -> &'static [&'static str] {
+        &["BadSeg"]
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_QUERY_VERSION => reader.read_shm_query_version_reply(detail),
+            OP_GET_IMAGE => reader.read_shm_get_image_reply(detail),
+            _ => None
+        }
+    }
+
+    fn read_event(&self, reader: &mut XReadHelper, code: u8, detail: u8) -> Option<ServerEvent> {
+        match code {
+            EVENT_COMPLETION => reader.read_shm_completion_event(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the MIT-SHM QueryVersion request. Returns None without sending anything if the server doesn't have MIT-SHM, like every request here. */
+    pub fn shm_query_version(&mut self) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Shm>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_VERSION);
+        self.write_u16(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_VERSION)))
+    }
+
+    /** Sends the MIT-SHM Attach request */
+    pub fn shm_attach(&mut self, shmseg: u32, shmid: u32, read_only: bool) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Shm>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_ATTACH);
+        self.write_u16(4);
+        self.write_u32(shmseg);
+        self.write_u32(shmid);
+        self.write_bool(read_only);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the MIT-SHM Detach request */
+    pub fn shm_detach(&mut self, shmseg: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Shm>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_DETACH);
+        self.write_u16(2);
+        self.write_u32(shmseg);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the MIT-SHM PutImage request */
+    pub fn shm_put_image(&mut self, drawable: u32, gc: u32, total_width: u16, total_height: u16, src_x: u16, src_y: u16, src_width: u16, src_height: u16, dst_x: i16, dst_y: i16, depth: u8, format: u8, send_event: bool, shmseg: u32, offset: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Shm>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_PUT_IMAGE);
+        self.write_u16(10);
+        self.write_u32(drawable);
+        self.write_u32(gc);
+        self.write_u16(total_width);
+        self.write_u16(total_height);
+        self.write_u16(src_x);
+        self.write_u16(src_y);
+        self.write_u16(src_width);
+        self.write_u16(src_height);
+        self.write_i16(dst_x);
+        self.write_i16(dst_y);
+        self.write_u8(depth);
+        self.write_u8(format);
+        self.write_bool(send_event);
+        self.write_pad(1);
+        self.write_u32(shmseg);
+        self.write_u32(offset);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the MIT-SHM GetImage request */
+    pub fn shm_get_image(&mut self, drawable: u32, x: i16, y: i16, width: u16, height: u16, plane_mask: u32, format: u8, shmseg: u32, offset: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Shm>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_IMAGE);
+        self.write_u16(8);
+        self.write_u32(drawable);
+        self.write_i16(x);
+        self.write_i16(y);
+        self.write_u16(width);
+        self.write_u16(height);
+        self.write_u32(plane_mask);
+        self.write_u8(format);
+        self.write_pad(3);
+        self.write_u32(shmseg);
+        self.write_u32(offset);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_IMAGE)))
+    }
+
+    /** Sends the MIT-SHM CreatePixmap request */
+    pub fn shm_create_pixmap(&mut self, pid: u32, drawable: u32, width: u16, height: u16, depth: u8, shmseg: u32, offset: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Shm>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_PIXMAP);
+        self.write_u16(7);
+        self.write_u32(pid);
+        self.write_u32(drawable);
+        self.write_u16(width);
+        self.write_u16(height);
+        self.write_u8(depth);
+        self.write_pad(3);
+        self.write_u32(shmseg);
+        self.write_u32(offset);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the MIT-SHM CreateSegment request */
+    pub fn shm_create_segment(&mut self, shmseg: u32, size: u32, read_only: bool) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Shm>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_SEGMENT);
+        self.write_u16(4);
+        self.write_u32(shmseg);
+        self.write_u32(size);
+        self.write_bool(read_only);
+        self.write_pad(3);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_CREATE_SEGMENT)))
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to MIT-SHM QueryVersion */
+    fn read_shm_query_version_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let shared_pixmaps = detail != 0;
+        let major_version = self.read_u16();
+        let minor_version = self.read_u16();
+        let uid = self.read_u16();
+        let gid = self.read_u16();
+        let pixmap_format = self.read_u8();
+        self.read_pad(15);
+        Some(ServerReply::Shm(ShmReply::QueryVersion { shared_pixmaps, major_version, minor_version, uid, gid, pixmap_format }))
+    }
+
+    /** Reads a reply to MIT-SHM GetImage */
+    fn read_shm_get_image_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let depth = detail;
+        let visual = self.read_u32();
+        let size = self.read_u32();
+        Some(ServerReply::Shm(ShmReply::GetImage { depth, visual, size }))
+    }
+
+    /** Reads a MIT-SHM Completion event */
+    fn read_shm_completion_event(&mut self, _detail: u8) -> Option<ServerEvent> {
+        let drawable = self.read_u32();
+        let minor_event = self.read_u16();
+        let major_event = self.read_u8();
+        self.read_pad(1);
+        let shmseg = self.read_u32();
+        let offset = self.read_u32();
+        Some(ServerEvent::Shm(ShmEvent::Completion { drawable, minor_event, major_event, shmseg, offset }))
+    }
+}
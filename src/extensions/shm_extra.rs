@@ -0,0 +1,165 @@
+// ShmSegment, which owns memory shared with the server, and AttachFd, which passes a file descriptor.
+
+use std::fs::File;
+use std::io;
+use std::os::unix::io::{AsRawFd, FromRawFd, RawFd};
+use std::ptr;
+use std::slice;
+
+use libc;
+
+use models::{Pixmap, ServerReply, ServerResponse};
+use XClient;
+
+use super::shm::*;
+
+/** The error for a server without MIT-SHM */
+fn missing() -> io::Error {
+    io::Error::new(io::ErrorKind::Unsupported, "the X server doesn't have MIT-SHM")
+}
+
+/** Where the memory of a ShmSegment came from */
+#[derive(Debug)]
+enum Backing {
+    SysV,
+    Memfd
+}
+
+/**
+ * Memory shared with the X server, for images that don't have to go through the socket.
+ * The memory is freed when this is dropped, so call detach first to let the server know.
+ */
+#[derive(Debug)]
+pub struct ShmSegment {
+    pub shmseg: u32, // The server's ID for the segment
+    ptr: *mut u8,
+    len: usize,
+    backing: Backing
+}
+
+impl ShmSegment {
+    /** Makes a segment of `len` bytes, with memfd if the server has MIT-SHM 1.2 and otherwise with SysV shared memory. Blocks. */
+    pub fn create(client: &mut XClient, len: usize, read_only: bool) -> io::Result<ShmSegment> {
+        let seq = client.shm_query_version().ok_or_else(missing)?;
+        match client.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::Shm(ShmReply::QueryVersion { major_version, minor_version, .. }), _) if (major_version, minor_version) >= (1, 2) => {
+                ShmSegment::create_memfd(client, len, read_only)
+            },
+            _ => ShmSegment::create_sysv(client, len, read_only)
+        }
+    }
+
+    /**
+     * Makes a segment with SysV shared memory (shmget), which every MIT-SHM server can attach. `read_only` is for the server.
+     * Blocks until the server has attached it, and then marks it removed, so it goes away with the last process using it.
+     * Fails with the server's error if it couldn't attach it (ie BadAccess, when the server runs as another user).
+     */
+    pub fn create_sysv(client: &mut XClient, len: usize, read_only: bool) -> io::Result<ShmSegment> {
+        let shmid = unsafe { libc::shmget(libc::IPC_PRIVATE, len, libc::IPC_CREAT | 0o600) };
+        if shmid < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let ptr = unsafe { libc::shmat(shmid, ptr::null(), 0) };
+        if ptr as isize == -1 {
+            let err = io::Error::last_os_error();
+            unsafe { libc::shmctl(shmid, libc::IPC_RMID, ptr::null_mut()) };
+            return Err(err);
+        }
+
+        let shmseg = client.new_resource_id();
+        if client.shm_attach(shmseg, shmid as u32, read_only).is_none() {
+            unsafe {
+                libc::shmdt(ptr);
+                libc::shmctl(shmid, libc::IPC_RMID, ptr::null_mut());
+            }
+            return Err(missing());
+        }
+        let attach = client.current_sequence.wrapping_sub(1);
+        let seq = client.get_input_focus();
+        client.wait_for_response(seq);
+        let error = client.take_error(attach);
+        unsafe { libc::shmctl(shmid, libc::IPC_RMID, ptr::null_mut()) };
+        if let Some(error) = error {
+            unsafe { libc::shmdt(ptr) };
+            return Err(io::Error::other(error));
+        }
+        Ok(ShmSegment { shmseg, ptr: ptr as *mut u8, len, backing: Backing::SysV })
+    }
+
+    /** Makes a segment with a memfd, and passes it with AttachFd (MIT-SHM 1.2). `read_only` is for the server. */
+    pub fn create_memfd(client: &mut XClient, len: usize, read_only: bool) -> io::Result<ShmSegment> {
+        let fd = unsafe { libc::memfd_create(b"xrb-shm\0".as_ptr() as *const libc::c_char, libc::MFD_CLOEXEC) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let file = unsafe { File::from_raw_fd(fd) }; // Closes the fd when done; the server and the mapping keep their own references
+        file.set_len(len as u64)?;
+
+        let ptr = unsafe { libc::mmap(ptr::null_mut(), len, libc::PROT_READ | libc::PROT_WRITE, libc::MAP_SHARED, file.as_raw_fd(), 0) };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        let segment = ShmSegment { shmseg: client.new_resource_id(), ptr: ptr as *mut u8, len, backing: Backing::Memfd };
+
+        client.shm_attach_fd(segment.shmseg, file.as_raw_fd(), read_only)?;
+        Ok(segment)
+    }
+
+    /** The size of the segment in bytes */
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /** The memory. After a GetImage, this is the image. */
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    /** The memory, to write an image for PutImage. Wait until the server is done with the last PutImage before changing it. */
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        unsafe { slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    /** Makes a pixmap that uses the segment (from `offset`) as its pixels. The server has to support shared pixmaps (see QueryVersion). */
+    pub fn create_pixmap(&self, client: &mut XClient, drawable: u32, depth: u8, width: u16, height: u16, offset: u32) -> Pixmap {
+        let pid = client.new_resource_id();
+        client.shm_create_pixmap(pid, drawable, width, height, depth, self.shmseg, offset);
+        Pixmap { depth, pid, drawable, width, height }
+    }
+
+    /** Tells the server to let go of the segment, and frees the memory */
+    pub fn detach(self, client: &mut XClient) {
+        client.shm_detach(self.shmseg);
+    }
+}
+
+impl Drop for ShmSegment {
+    fn drop(&mut self) {
+        unsafe {
+            match self.backing {
+                Backing::SysV => {
+                    libc::shmdt(self.ptr as *const libc::c_void);
+                },
+                Backing::Memfd => {
+                    libc::munmap(self.ptr as *mut libc::c_void, self.len);
+                }
+            }
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the MIT-SHM AttachFd request, which passes `fd` to the server (it gets its own copy) */
+    pub fn shm_attach_fd(&mut self, shmseg: u32, fd: RawFd, read_only: bool) -> io::Result<()> {
+        let extension_opcode = self.extension_opcode::<Shm>().ok_or_else(missing)?;
+        let mut request = vec![extension_opcode, OP_ATTACH_FD, 3, 0];
+        request.extend_from_slice(&shmseg.to_le_bytes());
+        request.extend_from_slice(&[read_only as u8, 0, 0, 0]);
+        self.write_request_with_fds(&request, &[fd])
+    }
+}
@@ -0,0 +1,606 @@
+// SYNC extension, version 3.1
+// Generated by tools/xcbgen from tools/xcb-proto/sync.xml. Don't edit this file, change the generator or
+// sync_extra.rs (which has everything written by hand) instead.
+//
+// Written by hand in sync_extra.rs:
+//   - request CreateAlarm
+//   - request ChangeAlarm
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{ServerEvent, ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+#[allow(unused_imports)] // Some extra modules only add methods
+pub use super::sync_extra::*;
+
+pub const EXTENSION_NAME: &str = "SYNC";
+pub const MAJOR_VERSION: u32 = 3;
+pub const MINOR_VERSION: u32 = 1;
+
+pub const OP_INITIALIZE: u8 = 0;
+pub const OP_LIST_SYSTEM_COUNTERS: u8 = 1;
+pub const OP_CREATE_COUNTER: u8 = 2;
+pub const OP_DESTROY_COUNTER: u8 = 6;
+pub const OP_QUERY_COUNTER: u8 = 5;
+pub const OP_AWAIT: u8 = 7;
+pub const OP_CHANGE_COUNTER: u8 = 4;
+pub const OP_SET_COUNTER: u8 = 3;
+pub const OP_CREATE_ALARM: u8 = 8;
+pub const OP_CHANGE_ALARM: u8 = 9;
+pub const OP_DESTROY_ALARM: u8 = 11;
+pub const OP_QUERY_ALARM: u8 = 10;
+pub const OP_SET_PRIORITY: u8 = 12;
+pub const OP_GET_PRIORITY: u8 = 13;
+pub const OP_CREATE_FENCE: u8 = 14;
+pub const OP_TRIGGER_FENCE: u8 = 15;
+pub const OP_RESET_FENCE: u8 = 16;
+pub const OP_DESTROY_FENCE: u8 = 17;
+pub const OP_QUERY_FENCE: u8 = 18;
+pub const OP_AWAIT_FENCE: u8 = 19;
+
+pub const EVENT_COUNTER_NOTIFY: u8 = 0;
+pub const EVENT_ALARM_NOTIFY: u8 = 1;
+
+pub const ERROR_COUNTER: u8 = 0;
+pub const ERROR_ALARM: u8 = 1;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AlarmState {
+    Active,
+    Inactive,
+    Destroyed
+}
+impl AlarmState {
+    pub fn get(id: u32) -> Option<AlarmState> {
+        match id {
+            0 => Some(AlarmState::Active),
+            1 => Some(AlarmState::Inactive),
+            2 => Some(AlarmState::Destroyed),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &AlarmState::Active => 0,
+            &AlarmState::Inactive => 1,
+            &AlarmState::Destroyed => 2
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TestType {
+    PositiveTransition,
+    NegativeTransition,
+    PositiveComparison,
+    NegativeComparison
+}
+impl TestType {
+    pub fn get(id: u32) -> Option<TestType> {
+        match id {
+            0 => Some(TestType::PositiveTransition),
+            1 => Some(TestType::NegativeTransition),
+            2 => Some(TestType::PositiveComparison),
+            3 => Some(TestType::NegativeComparison),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &TestType::PositiveTransition => 0,
+            &TestType::NegativeTransition => 1,
+            &TestType::PositiveComparison => 2,
+            &TestType::NegativeComparison => 3
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ValueType {
+    Absolute,
+    Relative
+}
+impl ValueType {
+    pub fn get(id: u32) -> Option<ValueType> {
+        match id {
+            0 => Some(ValueType::Absolute),
+            1 => Some(ValueType::Relative),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &ValueType::Absolute => 0,
+            &ValueType::Relative => 1
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Ca {
+    Counter,
+    ValueType,
+    Value,
+    TestType,
+    Delta,
+    Events
+}
+impl Ca {
+    pub fn val(&self) -> u32 {
+        match self {
+            &Ca::Counter => 0x00000001,
+            &Ca::ValueType => 0x00000002,
+            &Ca::Value => 0x00000004,
+            &Ca::TestType => 0x00000008,
+            &Ca::Delta => 0x00000010,
+            &Ca::Events => 0x00000020
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SystemCounter {
+    pub counter: u32,
+    pub resolution: i64,
+    pub name: String
+}
+impl SystemCounter {
+    pub fn read(reader: &mut XReadHelper) -> SystemCounter {
+        let counter = reader.read_u32();
+        let resolution = reader.read_hi_lo_i64();
+        let name_len = reader.read_u16();
+        let name = reader.read_str(name_len as usize);
+        reader.read_pad((4 - (14 + name.len()) % 4) % 4);
+        SystemCounter { counter, resolution, name }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.counter);
+        client.write_hi_lo_i64(self.resolution);
+        client.write_u16(self.name.len() as u16);
+        client.write_str(&self.name);
+        client.write_pad_op((4 - (14 + self.name.len()) % 4) % 4);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Trigger {
+    pub counter: u32,
+    pub wait_type: u32,
+    pub wait_value: i64,
+    pub test_type: u32
+}
+impl Trigger {
+    pub fn read(reader: &mut XReadHelper) -> Trigger {
+        let counter = reader.read_u32();
+        let wait_type = reader.read_u32();
+        let wait_value = reader.read_hi_lo_i64();
+        let test_type = reader.read_u32();
+        Trigger { counter, wait_type, wait_value, test_type }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.counter);
+        client.write_u32(self.wait_type);
+        client.write_hi_lo_i64(self.wait_value);
+        client.write_u32(self.test_type);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct WaitCondition {
+    pub trigger: Trigger,
+    pub event_threshold: i64
+}
+impl WaitCondition {
+    pub fn read(reader: &mut XReadHelper) -> WaitCondition {
+        let trigger = Trigger::read(reader);
+        let event_threshold = reader.read_hi_lo_i64();
+        WaitCondition { trigger, event_threshold }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        self.trigger.write(client);
+        client.write_hi_lo_i64(self.event_threshold);
+    }
+}
+
+#[derive(Debug)]
+pub enum SyncReply {
+    Initialize {
+        major_version: u8,
+        minor_version: u8
+    },
+    ListSystemCounters {
+        counters: Vec<SystemCounter>
+    },
+    QueryCounter {
+        counter_value: i64
+    },
+    QueryAlarm {
+        trigger: Trigger,
+        delta: i64,
+        events: bool,
+        state: AlarmState
+    },
+    GetPriority {
+        priority: i32
+    },
+    QueryFence {
+        triggered: bool
+    }
+}
+
+#[derive(Debug)]
+pub enum SyncEvent {
+    CounterNotify {
+        kind: u8,
+        counter: u32,
+        wait_value: i64,
+        counter_value: i64,
+        timestamp: u32,
+        count: u16,
+        destroyed: bool
+    },
+    AlarmNotify {
+        kind: u8,
+        alarm: u32,
+        counter_value: i64,
+        alarm_value: i64,
+        timestamp: u32,
+        state: AlarmState
+    }
+}
+
+/** Decodes SYNC replies and events */
+#[derive(Debug, Default)]
+pub struct Sync;
+
+impl Extension for Sync {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn event_count(&self) -> u8 {
+        2
+    }
+
+    fn error_names(&self) -> &'static [&'static str] {
+        &["BadCounter", "BadAlarm"]
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_INITIALIZE => reader.read_sync_initialize_reply(detail),
+            OP_LIST_SYSTEM_COUNTERS => reader.read_sync_list_system_counters_reply(detail),
+            OP_QUERY_COUNTER => reader.read_sync_query_counter_reply(detail),
+            OP_QUERY_ALARM => reader.read_sync_query_alarm_reply(detail),
+            OP_GET_PRIORITY => reader.read_sync_get_priority_reply(detail),
+            OP_QUERY_FENCE => reader.read_sync_query_fence_reply(detail),
+            _ => None
+        }
+    }
+
+    fn read_event(&self, reader: &mut XReadHelper, code: u8, detail: u8) -> Option<ServerEvent> {
+        match code {
+            EVENT_COUNTER_NOTIFY => reader.read_sync_counter_notify_event(detail),
+            EVENT_ALARM_NOTIFY => reader.read_sync_alarm_notify_event(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the SYNC Initialize request. Returns None without sending anything if the server doesn't have SYNC, like every request here. */
+    pub fn sync_initialize(&mut self, desired_major_version: u8, desired_minor_version: u8) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_INITIALIZE);
+        self.write_u16(2);
+        self.write_u8(desired_major_version);
+        self.write_u8(desired_minor_version);
+        self.write_pad(2);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_INITIALIZE)))
+    }
+
+    /** Sends the SYNC ListSystemCounters request */
+    pub fn sync_list_system_counters(&mut self) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_LIST_SYSTEM_COUNTERS);
+        self.write_u16(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_LIST_SYSTEM_COUNTERS)))
+    }
+
+    /** Sends the SYNC CreateCounter request */
+    pub fn sync_create_counter(&mut self, id: u32, initial_value: i64) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_COUNTER);
+        self.write_u16(4);
+        self.write_u32(id);
+        self.write_hi_lo_i64(initial_value);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the SYNC DestroyCounter request */
+    pub fn sync_destroy_counter(&mut self, counter: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_DESTROY_COUNTER);
+        self.write_u16(2);
+        self.write_u32(counter);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the SYNC QueryCounter request */
+    pub fn sync_query_counter(&mut self, counter: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_COUNTER);
+        self.write_u16(2);
+        self.write_u32(counter);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_COUNTER)))
+    }
+
+    /** Sends the SYNC Await request */
+    pub fn sync_await(&mut self, wait_list: &Vec<WaitCondition>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_AWAIT);
+        let pad = self.write_dynamic_len(1, wait_list.len() * 28);
+        for value in wait_list {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the SYNC ChangeCounter request */
+    pub fn sync_change_counter(&mut self, counter: u32, amount: i64) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CHANGE_COUNTER);
+        self.write_u16(4);
+        self.write_u32(counter);
+        self.write_hi_lo_i64(amount);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the SYNC SetCounter request */
+    pub fn sync_set_counter(&mut self, counter: u32, value: i64) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_COUNTER);
+        self.write_u16(4);
+        self.write_u32(counter);
+        self.write_hi_lo_i64(value);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the SYNC DestroyAlarm request */
+    pub fn sync_destroy_alarm(&mut self, alarm: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_DESTROY_ALARM);
+        self.write_u16(2);
+        self.write_u32(alarm);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the SYNC QueryAlarm request */
+    pub fn sync_query_alarm(&mut self, alarm: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_ALARM);
+        self.write_u16(2);
+        self.write_u32(alarm);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_ALARM)))
+    }
+
+    /** Sends the SYNC SetPriority request */
+    pub fn sync_set_priority(&mut self, id: u32, priority: i32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_PRIORITY);
+        self.write_u16(3);
+        self.write_u32(id);
+        self.write_i32(priority);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the SYNC GetPriority request */
+    pub fn sync_get_priority(&mut self, id: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_PRIORITY);
+        self.write_u16(2);
+        self.write_u32(id);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_PRIORITY)))
+    }
+
+    /** Sends the SYNC CreateFence request */
+    pub fn sync_create_fence(&mut self, drawable: u32, fence: u32, initially_triggered: bool) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_FENCE);
+        self.write_u16(4);
+        self.write_u32(drawable);
+        self.write_u32(fence);
+        self.write_bool(initially_triggered);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the SYNC TriggerFence request */
+    pub fn sync_trigger_fence(&mut self, fence: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_TRIGGER_FENCE);
+        self.write_u16(2);
+        self.write_u32(fence);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the SYNC ResetFence request */
+    pub fn sync_reset_fence(&mut self, fence: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_RESET_FENCE);
+        self.write_u16(2);
+        self.write_u32(fence);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the SYNC DestroyFence request */
+    pub fn sync_destroy_fence(&mut self, fence: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_DESTROY_FENCE);
+        self.write_u16(2);
+        self.write_u32(fence);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the SYNC QueryFence request */
+    pub fn sync_query_fence(&mut self, fence: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_FENCE);
+        self.write_u16(2);
+        self.write_u32(fence);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_FENCE)))
+    }
+
+    /** Sends the SYNC AwaitFence request */
+    pub fn sync_await_fence(&mut self, fence_list: &Vec<u32>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_AWAIT_FENCE);
+        let pad = self.write_dynamic_len(1, fence_list.len() * 4);
+        for value in fence_list {
+            self.write_u32(*value);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to SYNC Initialize */
+    fn read_sync_initialize_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let major_version = self.read_u8();
+        let minor_version = self.read_u8();
+        self.read_pad(22);
+        Some(ServerReply::Sync(SyncReply::Initialize { major_version, minor_version }))
+    }
+
+    /** Reads a reply to SYNC ListSystemCounters */
+    fn read_sync_list_system_counters_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let counters_len = self.read_u32();
+        self.read_pad(20);
+        let mut counters = Vec::with_capacity(counters_len as usize);
+        for _ in 0..counters_len as usize {
+            counters.push(SystemCounter::read(self));
+        }
+        Some(ServerReply::Sync(SyncReply::ListSystemCounters { counters }))
+    }
+
+    /** Reads a reply to SYNC QueryCounter */
+    fn read_sync_query_counter_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let counter_value = self.read_hi_lo_i64();
+        Some(ServerReply::Sync(SyncReply::QueryCounter { counter_value }))
+    }
+
+    /** Reads a reply to SYNC QueryAlarm */
+    fn read_sync_query_alarm_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let trigger = Trigger::read(self);
+        let delta = self.read_hi_lo_i64();
+        let events = self.read_bool();
+        let state = self.read_u8();
+        let state = match AlarmState::get(state as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        self.read_pad(2);
+        Some(ServerReply::Sync(SyncReply::QueryAlarm { trigger, delta, events, state }))
+    }
+
+    /** Reads a reply to SYNC GetPriority */
+    fn read_sync_get_priority_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let priority = self.read_i32();
+        Some(ServerReply::Sync(SyncReply::GetPriority { priority }))
+    }
+
+    /** Reads a reply to SYNC QueryFence */
+    fn read_sync_query_fence_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let triggered = self.read_bool();
+        self.read_pad(23);
+        Some(ServerReply::Sync(SyncReply::QueryFence { triggered }))
+    }
+
+    /** Reads a SYNC CounterNotify event */
+    fn read_sync_counter_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let kind = detail;
+        let counter = self.read_u32();
+        let wait_value = self.read_hi_lo_i64();
+        let counter_value = self.read_hi_lo_i64();
+        let timestamp = self.read_u32();
+        let count = self.read_u16();
+        let destroyed = self.read_bool();
+        self.read_pad(1);
+        Some(ServerEvent::Sync(SyncEvent::CounterNotify { kind, counter, wait_value, counter_value, timestamp, count, destroyed }))
+    }
+
+    /** Reads a SYNC AlarmNotify event */
+    fn read_sync_alarm_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let kind = detail;
+        let alarm = self.read_u32();
+        let counter_value = self.read_hi_lo_i64();
+        let alarm_value = self.read_hi_lo_i64();
+        let timestamp = self.read_u32();
+        let state = self.read_u8();
+        let state = match AlarmState::get(state as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        self.read_pad(3);
+        Some(ServerEvent::Sync(SyncEvent::AlarmNotify { kind, alarm, counter_value, alarm_value, timestamp, state }))
+    }
+}
@@ -0,0 +1,107 @@
+// SYNC alarm values, which xcbgen can't generate, and system counters by name.
+
+#![allow(clippy::match_ref_pats, clippy::ptr_arg)]
+
+use models::{ServerReply, ServerResponse, Value};
+use xreaderwriter::XBufferedWriter;
+use XClient;
+
+use super::sync::*;
+
+/** The system counter for how long it has been since the last input, in milliseconds */
+pub const IDLETIME: &str = "IDLETIME";
+
+/** The system counter for the server's time, in milliseconds */
+pub const SERVERTIME: &str = "SERVERTIME";
+
+/** A value of an alarm, for CreateAlarm and ChangeAlarm */
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AlarmValue {
+    Counter(u32),
+    ValueType(ValueType),
+    Value(i64),
+    TestType(TestType),
+    Delta(i64),
+    Events(bool)
+}
+impl AlarmValue {
+    /** The number of bytes this takes up in the request */
+    fn size(&self) -> usize {
+        match self {
+            &AlarmValue::Value(_) | &AlarmValue::Delta(_) => 8,
+            _ => 4
+        }
+    }
+}
+
+impl Value for AlarmValue {
+    fn get_mask(&self) -> u32 {
+        match self {
+            &AlarmValue::Counter(_) => Ca::Counter.val(),
+            &AlarmValue::ValueType(_) => Ca::ValueType.val(),
+            &AlarmValue::Value(_) => Ca::Value.val(),
+            &AlarmValue::TestType(_) => Ca::TestType.val(),
+            &AlarmValue::Delta(_) => Ca::Delta.val(),
+            &AlarmValue::Events(_) => Ca::Events.val()
+        }
+    }
+
+    fn write(&self, client: &mut XClient) {
+        match self {
+            &AlarmValue::Counter(val) => client.write_val_u32(val),
+            &AlarmValue::ValueType(val) => client.write_val(val.val()),
+            &AlarmValue::Value(val) => client.write_hi_lo_i64(val),
+            &AlarmValue::TestType(val) => client.write_val(val.val()),
+            &AlarmValue::Delta(val) => client.write_hi_lo_i64(val),
+            &AlarmValue::Events(val) => client.write_val_bool(val)
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the SYNC CreateAlarm request */
+    pub fn sync_create_alarm(&mut self, id: u32, values: &Vec<AlarmValue>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_ALARM);
+        self.write_u16(3 + values.iter().map(|value| value.size() as u16 / 4).sum::<u16>());
+        self.write_u32(id);
+        self.write_values(values, 32);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the SYNC ChangeAlarm request */
+    pub fn sync_change_alarm(&mut self, id: u32, values: &Vec<AlarmValue>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Sync>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CHANGE_ALARM);
+        self.write_u16(3 + values.iter().map(|value| value.size() as u16 / 4).sum::<u16>());
+        self.write_u32(id);
+        self.write_values(values, 32);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Finds a system counter by name (ie IDLETIME). Blocks. */
+    pub fn sync_system_counter_sync(&mut self, name: &str) -> Option<u32> {
+        let seq = self.sync_list_system_counters()?;
+        match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::Sync(SyncReply::ListSystemCounters { counters }), _) => {
+                counters.iter().find(|counter| counter.name == name).map(|counter| counter.counter)
+            },
+            _ => None
+        }
+    }
+
+    /** Gets the value of a counter. Blocks. */
+    pub fn sync_query_counter_sync(&mut self, counter: u32) -> Option<i64> {
+        let seq = self.sync_query_counter(counter)?;
+        match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::Sync(SyncReply::QueryCounter { counter_value }), _) => Some(counter_value),
+            _ => None
+        }
+    }
+}
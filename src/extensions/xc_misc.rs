@@ -0,0 +1,114 @@
+// XC-MISC extension, version 1.1
+// Generated by tools/xcbgen from tools/xcb-proto/xc_misc.xml. Don't edit this file, change the generator or
+// add a xc_misc_extra.rs for anything that has to be written by hand.
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+pub const EXTENSION_NAME: &str = "XC-MISC";
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 1;
+
+pub const OP_GET_VERSION: u8 = 0;
+pub const OP_GET_XID_RANGE: u8 = 1;
+pub const OP_GET_XID_LIST: u8 = 2;
+
+#[derive(Debug)]
+pub enum XCMiscReply {
+    GetVersion {
+        server_major_version: u16,
+        server_minor_version: u16
+    },
+    GetXIDRange {
+        start_id: u32,
+        count: u32
+    },
+    GetXIDList {
+        ids: Vec<u32>
+    }
+}
+
+/** Decodes XC-MISC replies and events */
+#[derive(Debug, Default)]
+pub struct XCMisc;
+
+impl Extension for XCMisc {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_GET_VERSION => reader.read_xc_misc_get_version_reply(detail),
+            OP_GET_XID_RANGE => reader.read_xc_misc_get_xid_range_reply(detail),
+            OP_GET_XID_LIST => reader.read_xc_misc_get_xid_list_reply(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the XC-MISC GetVersion request. Returns None without sending anything if the server doesn't have XC-MISC, like every request here. */
+    pub fn xc_misc_get_version(&mut self, client_major_version: u16, client_minor_version: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XCMisc>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_VERSION);
+        self.write_u16(2);
+        self.write_u16(client_major_version);
+        self.write_u16(client_minor_version);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_VERSION)))
+    }
+
+    /** Sends the XC-MISC GetXIDRange request */
+    pub fn xc_misc_get_xid_range(&mut self) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XCMisc>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_XID_RANGE);
+        self.write_u16(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_XID_RANGE)))
+    }
+
+    /** Sends the XC-MISC GetXIDList request */
+    pub fn xc_misc_get_xid_list(&mut self, count: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XCMisc>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_XID_LIST);
+        self.write_u16(2);
+        self.write_u32(count);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_XID_LIST)))
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to XC-MISC GetVersion */
+    fn read_xc_misc_get_version_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let server_major_version = self.read_u16();
+        let server_minor_version = self.read_u16();
+        Some(ServerReply::XCMisc(XCMiscReply::GetVersion { server_major_version, server_minor_version }))
+    }
+
+    /** Reads a reply to XC-MISC GetXIDRange */
+    fn read_xc_misc_get_xid_range_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let start_id = self.read_u32();
+        let count = self.read_u32();
+        Some(ServerReply::XCMisc(XCMiscReply::GetXIDRange { start_id, count }))
+    }
+
+    /** Reads a reply to XC-MISC GetXIDList */
+    fn read_xc_misc_get_xid_list_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let ids_len = self.read_u32();
+        self.read_pad(20);
+        let mut ids = Vec::with_capacity(ids_len as usize);
+        for _ in 0..ids_len as usize {
+            ids.push(self.read_u32());
+        }
+        Some(ServerReply::XCMisc(XCMiscReply::GetXIDList { ids }))
+    }
+}
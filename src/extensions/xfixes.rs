@@ -0,0 +1,886 @@
+// XFIXES extension, version 5.0
+// Generated by tools/xcbgen from tools/xcb-proto/xfixes.xml. Don't edit this file, change the generator or
+// xfixes_extra.rs (which has everything written by hand) instead.
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{Rectangle, ServerEvent, ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+#[allow(unused_imports)] // Some extra modules only add methods
+pub use super::xfixes_extra::*;
+
+pub const EXTENSION_NAME: &str = "XFIXES";
+pub const MAJOR_VERSION: u32 = 5;
+pub const MINOR_VERSION: u32 = 0;
+
+pub const OP_QUERY_VERSION: u8 = 0;
+pub const OP_CHANGE_SAVE_SET: u8 = 1;
+pub const OP_SELECT_SELECTION_INPUT: u8 = 2;
+pub const OP_SELECT_CURSOR_INPUT: u8 = 3;
+pub const OP_GET_CURSOR_IMAGE: u8 = 4;
+pub const OP_CREATE_REGION: u8 = 5;
+pub const OP_CREATE_REGION_FROM_BITMAP: u8 = 6;
+pub const OP_CREATE_REGION_FROM_WINDOW: u8 = 7;
+pub const OP_CREATE_REGION_FROM_GC: u8 = 8;
+pub const OP_CREATE_REGION_FROM_PICTURE: u8 = 9;
+pub const OP_DESTROY_REGION: u8 = 10;
+pub const OP_SET_REGION: u8 = 11;
+pub const OP_COPY_REGION: u8 = 12;
+pub const OP_UNION_REGION: u8 = 13;
+pub const OP_INTERSECT_REGION: u8 = 14;
+pub const OP_SUBTRACT_REGION: u8 = 15;
+pub const OP_INVERT_REGION: u8 = 16;
+pub const OP_TRANSLATE_REGION: u8 = 17;
+pub const OP_REGION_EXTENTS: u8 = 18;
+pub const OP_FETCH_REGION: u8 = 19;
+pub const OP_SET_GC_CLIP_REGION: u8 = 20;
+pub const OP_SET_WINDOW_SHAPE_REGION: u8 = 21;
+pub const OP_SET_PICTURE_CLIP_REGION: u8 = 22;
+pub const OP_SET_CURSOR_NAME: u8 = 23;
+pub const OP_GET_CURSOR_NAME: u8 = 24;
+pub const OP_GET_CURSOR_IMAGE_AND_NAME: u8 = 25;
+pub const OP_CHANGE_CURSOR: u8 = 26;
+pub const OP_CHANGE_CURSOR_BY_NAME: u8 = 27;
+pub const OP_EXPAND_REGION: u8 = 28;
+pub const OP_HIDE_CURSOR: u8 = 29;
+pub const OP_SHOW_CURSOR: u8 = 30;
+pub const OP_CREATE_POINTER_BARRIER: u8 = 31;
+pub const OP_DELETE_POINTER_BARRIER: u8 = 32;
+
+pub const EVENT_SELECTION_NOTIFY: u8 = 0;
+pub const EVENT_CURSOR_NOTIFY: u8 = 1;
+
+pub const ERROR_BAD_REGION: u8 = 0;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SaveSetMode {
+    Insert,
+    Delete
+}
+impl SaveSetMode {
+    pub fn get(id: u32) -> Option<SaveSetMode> {
+        match id {
+            0 => Some(SaveSetMode::Insert),
+            1 => Some(SaveSetMode::Delete),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &SaveSetMode::Insert => 0,
+            &SaveSetMode::Delete => 1
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SaveSetTarget {
+    Nearest,
+    Root
+}
+impl SaveSetTarget {
+    pub fn get(id: u32) -> Option<SaveSetTarget> {
+        match id {
+            0 => Some(SaveSetTarget::Nearest),
+            1 => Some(SaveSetTarget::Root),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &SaveSetTarget::Nearest => 0,
+            &SaveSetTarget::Root => 1
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SaveSetMapping {
+    Map,
+    Unmap
+}
+impl SaveSetMapping {
+    pub fn get(id: u32) -> Option<SaveSetMapping> {
+        match id {
+            0 => Some(SaveSetMapping::Map),
+            1 => Some(SaveSetMapping::Unmap),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &SaveSetMapping::Map => 0,
+            &SaveSetMapping::Unmap => 1
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SelectionEvent {
+    SetSelectionOwner,
+    SelectionWindowDestroy,
+    SelectionClientClose
+}
+impl SelectionEvent {
+    pub fn get(id: u32) -> Option<SelectionEvent> {
+        match id {
+            0 => Some(SelectionEvent::SetSelectionOwner),
+            1 => Some(SelectionEvent::SelectionWindowDestroy),
+            2 => Some(SelectionEvent::SelectionClientClose),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &SelectionEvent::SetSelectionOwner => 0,
+            &SelectionEvent::SelectionWindowDestroy => 1,
+            &SelectionEvent::SelectionClientClose => 2
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SelectionEventMask {
+    SetSelectionOwner,
+    SelectionWindowDestroy,
+    SelectionClientClose
+}
+impl SelectionEventMask {
+    pub fn val(&self) -> u32 {
+        match self {
+            &SelectionEventMask::SetSelectionOwner => 0x00000001,
+            &SelectionEventMask::SelectionWindowDestroy => 0x00000002,
+            &SelectionEventMask::SelectionClientClose => 0x00000004
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CursorNotify {
+    DisplayCursor
+}
+impl CursorNotify {
+    pub fn get(id: u32) -> Option<CursorNotify> {
+        match id {
+            0 => Some(CursorNotify::DisplayCursor),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &CursorNotify::DisplayCursor => 0
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CursorNotifyMask {
+    DisplayCursor
+}
+impl CursorNotifyMask {
+    pub fn val(&self) -> u32 {
+        match self {
+            &CursorNotifyMask::DisplayCursor => 0x00000001
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Region {
+    None
+}
+impl Region {
+    pub fn get(id: u32) -> Option<Region> {
+        match id {
+            0 => Some(Region::None),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &Region::None => 0
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BarrierDirections {
+    PositiveX,
+    PositiveY,
+    NegativeX,
+    NegativeY
+}
+impl BarrierDirections {
+    pub fn val(&self) -> u32 {
+        match self {
+            &BarrierDirections::PositiveX => 0x00000001,
+            &BarrierDirections::PositiveY => 0x00000002,
+            &BarrierDirections::NegativeX => 0x00000004,
+            &BarrierDirections::NegativeY => 0x00000008
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum XFixesReply {
+    QueryVersion {
+        major_version: u32,
+        minor_version: u32
+    },
+    GetCursorImage {
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        xhot: u16,
+        yhot: u16,
+        cursor_serial: u32,
+        cursor_image: Vec<u32>
+    },
+    FetchRegion {
+        extents: Rectangle,
+        rectangles: Vec<Rectangle>
+    },
+    GetCursorName {
+        atom: u32,
+        name: String
+    },
+    GetCursorImageAndName {
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        xhot: u16,
+        yhot: u16,
+        cursor_serial: u32,
+        cursor_atom: u32,
+        cursor_image: Vec<u32>,
+        name: String
+    }
+}
+
+#[derive(Debug)]
+pub enum XFixesEvent {
+    SelectionNotify {
+        subtype: SelectionEvent,
+        window: u32,
+        owner: u32,
+        selection: u32,
+        timestamp: u32,
+        selection_timestamp: u32
+    },
+    CursorNotify {
+        subtype: CursorNotify,
+        window: u32,
+        cursor_serial: u32,
+        timestamp: u32,
+        name: u32
+    }
+}
+
+/** Decodes XFIXES replies and events */
+#[derive(Debug, Default)]
+pub struct XFixes;
+
+impl Extension for XFixes {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn event_count(&self) -> u8 {
+        2
+    }
+
+    fn error_names(&self) -> &'static [&'static str] {
+        &["BadRegion"]
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_QUERY_VERSION => reader.read_xfixes_query_version_reply(detail),
+            OP_GET_CURSOR_IMAGE => reader.read_xfixes_get_cursor_image_reply(detail),
+            OP_FETCH_REGION => reader.read_xfixes_fetch_region_reply(detail),
+            OP_GET_CURSOR_NAME => reader.read_xfixes_get_cursor_name_reply(detail),
+            OP_GET_CURSOR_IMAGE_AND_NAME => reader.read_xfixes_get_cursor_image_and_name_reply(detail),
+            _ => None
+        }
+    }
+
+    fn read_event(&self, reader: &mut XReadHelper, code: u8, detail: u8) -> Option<ServerEvent> {
+        match code {
+            EVENT_SELECTION_NOTIFY => reader.read_xfixes_selection_notify_event(detail),
+            EVENT_CURSOR_NOTIFY => reader.read_xfixes_cursor_notify_event(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the XFIXES QueryVersion request. Returns None without sending anything if the server doesn't have XFIXES, like every request here. */
+    pub fn xfixes_query_version(&mut self, client_major_version: u32, client_minor_version: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_VERSION);
+        self.write_u16(3);
+        self.write_u32(client_major_version);
+        self.write_u32(client_minor_version);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_VERSION)))
+    }
+
+    /** Sends the XFIXES ChangeSaveSet request */
+    pub fn xfixes_change_save_set(&mut self, mode: &SaveSetMode, target: &SaveSetTarget, map: &SaveSetMapping, window: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CHANGE_SAVE_SET);
+        self.write_u16(3);
+        self.write_u8(mode.val() as u8);
+        self.write_u8(target.val() as u8);
+        self.write_u8(map.val() as u8);
+        self.write_pad(1);
+        self.write_u32(window);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES SelectSelectionInput request */
+    pub fn xfixes_select_selection_input(&mut self, window: u32, selection: u32, event_mask: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SELECT_SELECTION_INPUT);
+        self.write_u16(4);
+        self.write_u32(window);
+        self.write_u32(selection);
+        self.write_u32(event_mask);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES SelectCursorInput request */
+    pub fn xfixes_select_cursor_input(&mut self, window: u32, event_mask: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SELECT_CURSOR_INPUT);
+        self.write_u16(3);
+        self.write_u32(window);
+        self.write_u32(event_mask);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES GetCursorImage request */
+    pub fn xfixes_get_cursor_image(&mut self) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_CURSOR_IMAGE);
+        self.write_u16(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_CURSOR_IMAGE)))
+    }
+
+    /** Sends the XFIXES CreateRegion request */
+    pub fn xfixes_create_region(&mut self, region: u32, rectangles: &Vec<Rectangle>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_REGION);
+        let pad = self.write_dynamic_len(2, rectangles.len() * 8);
+        self.write_u32(region);
+        for value in rectangles {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES CreateRegionFromBitmap request */
+    pub fn xfixes_create_region_from_bitmap(&mut self, region: u32, bitmap: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_REGION_FROM_BITMAP);
+        self.write_u16(3);
+        self.write_u32(region);
+        self.write_u32(bitmap);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES CreateRegionFromWindow request */
+    pub fn xfixes_create_region_from_window(&mut self, region: u32, window: u32, kind: u8) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_REGION_FROM_WINDOW);
+        self.write_u16(4);
+        self.write_u32(region);
+        self.write_u32(window);
+        self.write_u8(kind);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES CreateRegionFromGC request */
+    pub fn xfixes_create_region_from_gc(&mut self, region: u32, gc: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_REGION_FROM_GC);
+        self.write_u16(3);
+        self.write_u32(region);
+        self.write_u32(gc);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES CreateRegionFromPicture request */
+    pub fn xfixes_create_region_from_picture(&mut self, region: u32, picture: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_REGION_FROM_PICTURE);
+        self.write_u16(3);
+        self.write_u32(region);
+        self.write_u32(picture);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES DestroyRegion request */
+    pub fn xfixes_destroy_region(&mut self, region: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_DESTROY_REGION);
+        self.write_u16(2);
+        self.write_u32(region);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES SetRegion request */
+    pub fn xfixes_set_region(&mut self, region: u32, rectangles: &Vec<Rectangle>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_REGION);
+        let pad = self.write_dynamic_len(2, rectangles.len() * 8);
+        self.write_u32(region);
+        for value in rectangles {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES CopyRegion request */
+    pub fn xfixes_copy_region(&mut self, source: u32, destination: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_COPY_REGION);
+        self.write_u16(3);
+        self.write_u32(source);
+        self.write_u32(destination);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES UnionRegion request */
+    pub fn xfixes_union_region(&mut self, source1: u32, source2: u32, destination: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_UNION_REGION);
+        self.write_u16(4);
+        self.write_u32(source1);
+        self.write_u32(source2);
+        self.write_u32(destination);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES IntersectRegion request */
+    pub fn xfixes_intersect_region(&mut self, source1: u32, source2: u32, destination: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_INTERSECT_REGION);
+        self.write_u16(4);
+        self.write_u32(source1);
+        self.write_u32(source2);
+        self.write_u32(destination);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES SubtractRegion request */
+    pub fn xfixes_subtract_region(&mut self, source1: u32, source2: u32, destination: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SUBTRACT_REGION);
+        self.write_u16(4);
+        self.write_u32(source1);
+        self.write_u32(source2);
+        self.write_u32(destination);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES InvertRegion request */
+    pub fn xfixes_invert_region(&mut self, source: u32, bounds: &Rectangle, destination: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_INVERT_REGION);
+        self.write_u16(5);
+        self.write_u32(source);
+        bounds.write(self);
+        self.write_u32(destination);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES TranslateRegion request */
+    pub fn xfixes_translate_region(&mut self, region: u32, dx: i16, dy: i16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_TRANSLATE_REGION);
+        self.write_u16(3);
+        self.write_u32(region);
+        self.write_i16(dx);
+        self.write_i16(dy);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES RegionExtents request */
+    pub fn xfixes_region_extents(&mut self, source: u32, destination: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_REGION_EXTENTS);
+        self.write_u16(3);
+        self.write_u32(source);
+        self.write_u32(destination);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES FetchRegion request */
+    pub fn xfixes_fetch_region(&mut self, region: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_FETCH_REGION);
+        self.write_u16(2);
+        self.write_u32(region);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_FETCH_REGION)))
+    }
+
+    /** Sends the XFIXES SetGCClipRegion request */
+    pub fn xfixes_set_gc_clip_region(&mut self, gc: u32, region: u32, x_origin: i16, y_origin: i16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_GC_CLIP_REGION);
+        self.write_u16(4);
+        self.write_u32(gc);
+        self.write_u32(region);
+        self.write_i16(x_origin);
+        self.write_i16(y_origin);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES SetWindowShapeRegion request */
+    pub fn xfixes_set_window_shape_region(&mut self, dest: u32, dest_kind: u8, x_offset: i16, y_offset: i16, region: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_WINDOW_SHAPE_REGION);
+        self.write_u16(5);
+        self.write_u32(dest);
+        self.write_u8(dest_kind);
+        self.write_pad(3);
+        self.write_i16(x_offset);
+        self.write_i16(y_offset);
+        self.write_u32(region);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES SetPictureClipRegion request */
+    pub fn xfixes_set_picture_clip_region(&mut self, picture: u32, region: u32, x_origin: i16, y_origin: i16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_PICTURE_CLIP_REGION);
+        self.write_u16(4);
+        self.write_u32(picture);
+        self.write_u32(region);
+        self.write_i16(x_origin);
+        self.write_i16(y_origin);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES SetCursorName request */
+    pub fn xfixes_set_cursor_name(&mut self, cursor: u32, name: &str) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_CURSOR_NAME);
+        let pad = self.write_dynamic_len(3, name.len());
+        self.write_u32(cursor);
+        self.write_u16(name.len() as u16);
+        self.write_pad(2);
+        self.write_str(name);
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES GetCursorName request */
+    pub fn xfixes_get_cursor_name(&mut self, cursor: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_CURSOR_NAME);
+        self.write_u16(2);
+        self.write_u32(cursor);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_CURSOR_NAME)))
+    }
+
+    /** Sends the XFIXES GetCursorImageAndName request */
+    pub fn xfixes_get_cursor_image_and_name(&mut self) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_CURSOR_IMAGE_AND_NAME);
+        self.write_u16(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_CURSOR_IMAGE_AND_NAME)))
+    }
+
+    /** Sends the XFIXES ChangeCursor request */
+    pub fn xfixes_change_cursor(&mut self, source: u32, destination: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CHANGE_CURSOR);
+        self.write_u16(3);
+        self.write_u32(source);
+        self.write_u32(destination);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES ChangeCursorByName request */
+    pub fn xfixes_change_cursor_by_name(&mut self, src: u32, name: &str) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CHANGE_CURSOR_BY_NAME);
+        let pad = self.write_dynamic_len(3, name.len());
+        self.write_u32(src);
+        self.write_u16(name.len() as u16);
+        self.write_pad(2);
+        self.write_str(name);
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES ExpandRegion request */
+    pub fn xfixes_expand_region(&mut self, source: u32, destination: u32, left: u16, right: u16, top: u16, bottom: u16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_EXPAND_REGION);
+        self.write_u16(5);
+        self.write_u32(source);
+        self.write_u32(destination);
+        self.write_u16(left);
+        self.write_u16(right);
+        self.write_u16(top);
+        self.write_u16(bottom);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES HideCursor request */
+    pub fn xfixes_hide_cursor(&mut self, window: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_HIDE_CURSOR);
+        self.write_u16(2);
+        self.write_u32(window);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES ShowCursor request */
+    pub fn xfixes_show_cursor(&mut self, window: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SHOW_CURSOR);
+        self.write_u16(2);
+        self.write_u32(window);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES CreatePointerBarrier request */
+    pub fn xfixes_create_pointer_barrier(&mut self, barrier: u32, window: u32, x1: u16, y1: u16, x2: u16, y2: u16, directions: u32, devices: &Vec<u16>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CREATE_POINTER_BARRIER);
+        let pad = self.write_dynamic_len(7, devices.len() * 2);
+        self.write_u32(barrier);
+        self.write_u32(window);
+        self.write_u16(x1);
+        self.write_u16(y1);
+        self.write_u16(x2);
+        self.write_u16(y2);
+        self.write_u32(directions);
+        self.write_pad(2);
+        self.write_u16(devices.len() as u16);
+        for value in devices {
+            self.write_u16(*value);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XFIXES DeletePointerBarrier request */
+    pub fn xfixes_delete_pointer_barrier(&mut self, barrier: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XFixes>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_DELETE_POINTER_BARRIER);
+        self.write_u16(2);
+        self.write_u32(barrier);
+
+        self.write_request();
+        Some(())
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to XFIXES QueryVersion */
+    fn read_xfixes_query_version_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let major_version = self.read_u32();
+        let minor_version = self.read_u32();
+        self.read_pad(16);
+        Some(ServerReply::XFixes(XFixesReply::QueryVersion { major_version, minor_version }))
+    }
+
+    /** Reads a reply to XFIXES GetCursorImage */
+    fn read_xfixes_get_cursor_image_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let x = self.read_i16();
+        let y = self.read_i16();
+        let width = self.read_u16();
+        let height = self.read_u16();
+        let xhot = self.read_u16();
+        let yhot = self.read_u16();
+        let cursor_serial = self.read_u32();
+        self.read_pad(8);
+        let mut cursor_image = Vec::with_capacity((width as usize) * (height as usize));
+        for _ in 0..(width as usize) * (height as usize) {
+            cursor_image.push(self.read_u32());
+        }
+        Some(ServerReply::XFixes(XFixesReply::GetCursorImage { x, y, width, height, xhot, yhot, cursor_serial, cursor_image }))
+    }
+
+    /** Reads a reply to XFIXES FetchRegion */
+    fn read_xfixes_fetch_region_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let length = self.extra_length();
+        let extents = Rectangle { x: self.read_i16(), y: self.read_i16(), width: self.read_u16(), height: self.read_u16() };
+        self.read_pad(16);
+        let mut rectangles = Vec::with_capacity((length as usize) / 2);
+        for _ in 0..(length as usize) / 2 {
+            rectangles.push(Rectangle { x: self.read_i16(), y: self.read_i16(), width: self.read_u16(), height: self.read_u16() });
+        }
+        Some(ServerReply::XFixes(XFixesReply::FetchRegion { extents, rectangles }))
+    }
+
+    /** Reads a reply to XFIXES GetCursorName */
+    fn read_xfixes_get_cursor_name_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let atom = self.read_u32();
+        let nbytes = self.read_u16();
+        self.read_pad(18);
+        let name = self.read_str(nbytes as usize);
+        Some(ServerReply::XFixes(XFixesReply::GetCursorName { atom, name }))
+    }
+
+    /** Reads a reply to XFIXES GetCursorImageAndName */
+    fn read_xfixes_get_cursor_image_and_name_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let x = self.read_i16();
+        let y = self.read_i16();
+        let width = self.read_u16();
+        let height = self.read_u16();
+        let xhot = self.read_u16();
+        let yhot = self.read_u16();
+        let cursor_serial = self.read_u32();
+        let cursor_atom = self.read_u32();
+        let nbytes = self.read_u16();
+        self.read_pad(2);
+        let mut cursor_image = Vec::with_capacity((width as usize) * (height as usize));
+        for _ in 0..(width as usize) * (height as usize) {
+            cursor_image.push(self.read_u32());
+        }
+        let name = self.read_str(nbytes as usize);
+        Some(ServerReply::XFixes(XFixesReply::GetCursorImageAndName { x, y, width, height, xhot, yhot, cursor_serial, cursor_atom, cursor_image, name }))
+    }
+
+    /** Reads a XFIXES SelectionNotify event */
+    fn read_xfixes_selection_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let subtype = detail;
+        let subtype = match SelectionEvent::get(subtype as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let window = self.read_u32();
+        let owner = self.read_u32();
+        let selection = self.read_u32();
+        let timestamp = self.read_u32();
+        let selection_timestamp = self.read_u32();
+        self.read_pad(8);
+        Some(ServerEvent::XFixes(XFixesEvent::SelectionNotify { subtype, window, owner, selection, timestamp, selection_timestamp }))
+    }
+
+    /** Reads a XFIXES CursorNotify event */
+    fn read_xfixes_cursor_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let subtype = detail;
+        let subtype = match CursorNotify::get(subtype as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let window = self.read_u32();
+        let cursor_serial = self.read_u32();
+        let timestamp = self.read_u32();
+        let name = self.read_u32();
+        self.read_pad(12);
+        Some(ServerEvent::XFixes(XFixesEvent::CursorNotify { subtype, window, cursor_serial, timestamp, name }))
+    }
+}
@@ -0,0 +1,109 @@
+// ServerRegion and cursor images. The server answers XFIXES requests sent before QueryVersion with BadRequest errors, so send that first.
+
+use models::{Rectangle, ServerReply, ServerResponse, Window};
+use XClient;
+
+use super::render::DrawablePicture;
+use super::shape::Sk;
+use super::xfixes::*;
+
+/** A region (a set of rectangles) stored on the server, like XserverRegion in libXfixes */
+#[derive(Debug)]
+pub struct ServerRegion {
+    pub rid: u32 // Region's ID, from the same allocator as every other resource
+}
+
+impl ServerRegion {
+    /** Creates a region from some rectangles. None if the server doesn't have XFIXES. */
+    pub fn create(client: &mut XClient, rectangles: &[Rectangle]) -> Option<ServerRegion> {
+        let rid = client.new_resource_id();
+        client.xfixes_create_region(rid, &rectangles.to_vec())?;
+        Some(ServerRegion { rid })
+    }
+
+    /** Creates a region from the shape of a window. None if the server doesn't have XFIXES. */
+    pub fn from_window(client: &mut XClient, window: u32, kind: &Sk) -> Option<ServerRegion> {
+        let rid = client.new_resource_id();
+        client.xfixes_create_region_from_window(rid, window, kind.val() as u8)?;
+        Some(ServerRegion { rid })
+    }
+
+    /** Destroys the region */
+    pub fn destroy(&self, client: &mut XClient) {
+        client.xfixes_destroy_region(self.rid);
+    }
+
+    /** Replaces what's in the region */
+    pub fn set(&self, client: &mut XClient, rectangles: &[Rectangle]) {
+        client.xfixes_set_region(self.rid, &rectangles.to_vec());
+    }
+
+    /** Adds another region to this one */
+    pub fn union(&self, client: &mut XClient, other: &ServerRegion) {
+        client.xfixes_union_region(self.rid, other.rid, self.rid);
+    }
+
+    /** Keeps only the parts of this region that are also in another one */
+    pub fn intersect(&self, client: &mut XClient, other: &ServerRegion) {
+        client.xfixes_intersect_region(self.rid, other.rid, self.rid);
+    }
+
+    /** Removes another region from this one */
+    pub fn subtract(&self, client: &mut XClient, other: &ServerRegion) {
+        client.xfixes_subtract_region(self.rid, other.rid, self.rid);
+    }
+
+    /** Gets the rectangles in the region. Blocks. */
+    pub fn fetch_sync(&self, client: &mut XClient) -> Vec<Rectangle> {
+        let seq = match client.xfixes_fetch_region(self.rid) {
+            Some(seq) => seq,
+            None => return vec![]
+        };
+        match client.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::XFixes(XFixesReply::FetchRegion { rectangles, .. }), _) => rectangles,
+            _ => vec![]
+        }
+    }
+}
+
+/** The image of the cursor, from GetCursorImage */
+#[derive(Debug, Clone)]
+pub struct CursorImage {
+    pub x: i16, // Where the cursor's hotspot is on the screen
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub xhot: u16,
+    pub yhot: u16,
+    pub cursor_serial: u32, // Changes when the cursor does (see CursorNotify)
+    pub pixels: Vec<u32> // ARGB32 with premultiplied alpha, row by row
+}
+
+impl XClient {
+    /** Gets the current cursor image, ie to draw it into a screen recording. Blocks. */
+    pub fn xfixes_get_cursor_image_sync(&mut self) -> Option<CursorImage> {
+        let seq = self.xfixes_get_cursor_image()?;
+        match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::XFixes(XFixesReply::GetCursorImage { x, y, width, height, xhot, yhot, cursor_serial, cursor_image }), _) => {
+                Some(CursorImage { x, y, width, height, xhot, yhot, cursor_serial, pixels: cursor_image })
+            },
+            _ => None
+        }
+    }
+}
+
+impl Window {
+    /** Sets a kind of shape of the window to a region, or resets it if `region` is None */
+    pub fn set_shape_region(&self, client: &mut XClient, kind: &Sk, region: Option<&ServerRegion>) -> Option<()> {
+        let region = region.map_or(Region::None.val(), |region| region.rid);
+        client.xfixes_set_window_shape_region(self.wid, kind.val() as u8, 0, 0, region)
+    }
+}
+
+impl DrawablePicture {
+    /** Only draws to the parts of the picture in a region, or everywhere if `region` is None */
+    pub fn set_clip_region(&self, client: &mut XClient, region: Option<&ServerRegion>) -> Option<()> {
+        let region = region.map_or(Region::None.val(), |region| region.rid);
+        client.xfixes_set_picture_clip_region(self.pid, region, 0, 0)
+    }
+}
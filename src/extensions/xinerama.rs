@@ -0,0 +1,213 @@
+// XINERAMA extension, version 1.1
+// Generated by tools/xcbgen from tools/xcb-proto/xinerama.xml. Don't edit this file, change the generator or
+// add a xinerama_extra.rs for anything that has to be written by hand.
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+pub const EXTENSION_NAME: &str = "XINERAMA";
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 1;
+
+pub const OP_QUERY_VERSION: u8 = 0;
+pub const OP_GET_STATE: u8 = 1;
+pub const OP_GET_SCREEN_COUNT: u8 = 2;
+pub const OP_GET_SCREEN_SIZE: u8 = 3;
+pub const OP_IS_ACTIVE: u8 = 4;
+pub const OP_QUERY_SCREENS: u8 = 5;
+
+#[derive(Debug, Copy, Clone)]
+pub struct ScreenInfo {
+    pub x_org: i16,
+    pub y_org: i16,
+    pub width: u16,
+    pub height: u16
+}
+impl ScreenInfo {
+    pub fn read(reader: &mut XReadHelper) -> ScreenInfo {
+        let x_org = reader.read_i16();
+        let y_org = reader.read_i16();
+        let width = reader.read_u16();
+        let height = reader.read_u16();
+        ScreenInfo { x_org, y_org, width, height }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_i16(self.x_org);
+        client.write_i16(self.y_org);
+        client.write_u16(self.width);
+        client.write_u16(self.height);
+    }
+}
+
+#[derive(Debug)]
+pub enum XineramaReply {
+    QueryVersion {
+        major: u16,
+        minor: u16
+    },
+    GetState {
+        state: u8,
+        window: u32
+    },
+    GetScreenCount {
+        screen_count: u8,
+        window: u32
+    },
+    GetScreenSize {
+        width: u32,
+        height: u32,
+        window: u32,
+        screen: u32
+    },
+    IsActive {
+        state: u32
+    },
+    QueryScreens {
+        screen_info: Vec<ScreenInfo>
+    }
+}
+
+/** Decodes XINERAMA replies and events */
+#[derive(Debug, Default)]
+pub struct Xinerama;
+
+impl Extension for Xinerama {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_QUERY_VERSION => reader.read_xinerama_query_version_reply(detail),
+            OP_GET_STATE => reader.read_xinerama_get_state_reply(detail),
+            OP_GET_SCREEN_COUNT => reader.read_xinerama_get_screen_count_reply(detail),
+            OP_GET_SCREEN_SIZE => reader.read_xinerama_get_screen_size_reply(detail),
+            OP_IS_ACTIVE => reader.read_xinerama_is_active_reply(detail),
+            OP_QUERY_SCREENS => reader.read_xinerama_query_screens_reply(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the XINERAMA QueryVersion request. Returns None without sending anything if the server doesn't have XINERAMA, like every request here. */
+    pub fn xinerama_query_version(&mut self, major: u8, minor: u8) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xinerama>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_VERSION);
+        self.write_u16(2);
+        self.write_u8(major);
+        self.write_u8(minor);
+        self.write_pad(2);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_VERSION)))
+    }
+
+    /** Sends the XINERAMA GetState request */
+    pub fn xinerama_get_state(&mut self, window: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xinerama>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_STATE);
+        self.write_u16(2);
+        self.write_u32(window);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_STATE)))
+    }
+
+    /** Sends the XINERAMA GetScreenCount request */
+    pub fn xinerama_get_screen_count(&mut self, window: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xinerama>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_SCREEN_COUNT);
+        self.write_u16(2);
+        self.write_u32(window);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_SCREEN_COUNT)))
+    }
+
+    /** Sends the XINERAMA GetScreenSize request */
+    pub fn xinerama_get_screen_size(&mut self, window: u32, screen: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xinerama>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_SCREEN_SIZE);
+        self.write_u16(3);
+        self.write_u32(window);
+        self.write_u32(screen);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_SCREEN_SIZE)))
+    }
+
+    /** Sends the XINERAMA IsActive request */
+    pub fn xinerama_is_active(&mut self) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xinerama>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_IS_ACTIVE);
+        self.write_u16(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_IS_ACTIVE)))
+    }
+
+    /** Sends the XINERAMA QueryScreens request */
+    pub fn xinerama_query_screens(&mut self) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xinerama>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_SCREENS);
+        self.write_u16(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_SCREENS)))
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to XINERAMA QueryVersion */
+    fn read_xinerama_query_version_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let major = self.read_u16();
+        let minor = self.read_u16();
+        Some(ServerReply::Xinerama(XineramaReply::QueryVersion { major, minor }))
+    }
+
+    /** Reads a reply to XINERAMA GetState */
+    fn read_xinerama_get_state_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let state = detail;
+        let window = self.read_u32();
+        Some(ServerReply::Xinerama(XineramaReply::GetState { state, window }))
+    }
+
+    /** Reads a reply to XINERAMA GetScreenCount */
+    fn read_xinerama_get_screen_count_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let screen_count = detail;
+        let window = self.read_u32();
+        Some(ServerReply::Xinerama(XineramaReply::GetScreenCount { screen_count, window }))
+    }
+
+    /** Reads a reply to XINERAMA GetScreenSize */
+    fn read_xinerama_get_screen_size_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let width = self.read_u32();
+        let height = self.read_u32();
+        let window = self.read_u32();
+        let screen = self.read_u32();
+        Some(ServerReply::Xinerama(XineramaReply::GetScreenSize { width, height, window, screen }))
+    }
+
+    /** Reads a reply to XINERAMA IsActive */
+    fn read_xinerama_is_active_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let state = self.read_u32();
+        Some(ServerReply::Xinerama(XineramaReply::IsActive { state }))
+    }
+
+    /** Reads a reply to XINERAMA QueryScreens */
+    fn read_xinerama_query_screens_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let number = self.read_u32();
+        self.read_pad(20);
+        let mut screen_info = Vec::with_capacity(number as usize);
+        for _ in 0..number as usize {
+            screen_info.push(ScreenInfo::read(self));
+        }
+        Some(ServerReply::Xinerama(XineramaReply::QueryScreens { screen_info }))
+    }
+}
@@ -0,0 +1,5249 @@
+// XInputExtension extension, version 2.3
+// Generated by tools/xcbgen from tools/xcb-proto/xinput.xml. Don't edit this file, change the generator or
+// xinput_extra.rs (which has everything written by hand) instead.
+//
+// Written by hand in xinput_extra.rs:
+//   - request XISelectEvents
+//   - reply to XIQueryDevice
+//
+// Not generated:
+//   - <eventstruct> EventForSend (not understood)
+//   - struct InputInfo: <switch> with <case>
+//   - struct DeviceTimeCoord: <paramref>
+//   - struct FeedbackState: <switch> with <case>
+//   - struct FeedbackCtl: <switch> with <case>
+//   - struct InputState: <switch> with <case>
+//   - struct DeviceState: <switch> with <case>
+//   - struct DeviceCtl: <switch> with <case>
+//   - struct HierarchyChange: <switch> with <case>
+//   - struct DeviceClass: <switch> with <case>
+//   - struct XIDeviceInfo: <switch> with <case>
+//   - reply to ListInputDevices (comes back as ServerReply::Unknown): a length refers to `num_class_info`, which isn't available
+//   - reply to GetDeviceMotionEvents (comes back as ServerReply::Unknown): <paramref>
+//   - reply to GetFeedbackControl (comes back as ServerReply::Unknown): <switch> with <case>
+//   - request ChangeFeedbackControl: <switch> with <case>
+//   - reply to QueryDeviceState (comes back as ServerReply::Unknown): <switch> with <case>
+//   - reply to GetDeviceControl (comes back as ServerReply::Unknown): <switch> with <case>
+//   - request ChangeDeviceControl: <switch> with <case>
+//   - request ChangeDeviceProperty: <switch> with <case>
+//   - reply to GetDeviceProperty (comes back as ServerReply::Unknown): <switch> with <case>
+//   - request XIChangeHierarchy: list changes of variable sized items
+//   - request XIChangeProperty: <switch> with <case>
+//   - reply to XIGetProperty (comes back as ServerReply::Unknown): <switch> with <case>
+//   - request SendExtensionEvent: unknown type EventForSend
+//   - event DeviceChanged (comes back as ServerEvent::Unknown or ServerEvent::Generic): <switch> with <case>
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{ServerEvent, ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+#[allow(unused_imports)] // Some extra modules only add methods
+pub use super::xinput_extra::*;
+
+pub const EXTENSION_NAME: &str = "XInputExtension";
+pub const MAJOR_VERSION: u32 = 2;
+pub const MINOR_VERSION: u32 = 3;
+
+pub const OP_GET_EXTENSION_VERSION: u8 = 1;
+pub const OP_LIST_INPUT_DEVICES: u8 = 2;
+pub const OP_OPEN_DEVICE: u8 = 3;
+pub const OP_CLOSE_DEVICE: u8 = 4;
+pub const OP_SET_DEVICE_MODE: u8 = 5;
+pub const OP_SELECT_EXTENSION_EVENT: u8 = 6;
+pub const OP_GET_SELECTED_EXTENSION_EVENTS: u8 = 7;
+pub const OP_CHANGE_DEVICE_DONT_PROPAGATE_LIST: u8 = 8;
+pub const OP_GET_DEVICE_DONT_PROPAGATE_LIST: u8 = 9;
+pub const OP_GET_DEVICE_MOTION_EVENTS: u8 = 10;
+pub const OP_CHANGE_KEYBOARD_DEVICE: u8 = 11;
+pub const OP_CHANGE_POINTER_DEVICE: u8 = 12;
+pub const OP_GRAB_DEVICE: u8 = 13;
+pub const OP_UNGRAB_DEVICE: u8 = 14;
+pub const OP_GRAB_DEVICE_KEY: u8 = 15;
+pub const OP_UNGRAB_DEVICE_KEY: u8 = 16;
+pub const OP_GRAB_DEVICE_BUTTON: u8 = 17;
+pub const OP_UNGRAB_DEVICE_BUTTON: u8 = 18;
+pub const OP_ALLOW_DEVICE_EVENTS: u8 = 19;
+pub const OP_GET_DEVICE_FOCUS: u8 = 20;
+pub const OP_SET_DEVICE_FOCUS: u8 = 21;
+pub const OP_GET_FEEDBACK_CONTROL: u8 = 22;
+pub const OP_CHANGE_FEEDBACK_CONTROL: u8 = 23;
+pub const OP_GET_DEVICE_KEY_MAPPING: u8 = 24;
+pub const OP_CHANGE_DEVICE_KEY_MAPPING: u8 = 25;
+pub const OP_GET_DEVICE_MODIFIER_MAPPING: u8 = 26;
+pub const OP_SET_DEVICE_MODIFIER_MAPPING: u8 = 27;
+pub const OP_GET_DEVICE_BUTTON_MAPPING: u8 = 28;
+pub const OP_SET_DEVICE_BUTTON_MAPPING: u8 = 29;
+pub const OP_QUERY_DEVICE_STATE: u8 = 30;
+pub const OP_DEVICE_BELL: u8 = 32;
+pub const OP_SET_DEVICE_VALUATORS: u8 = 33;
+pub const OP_GET_DEVICE_CONTROL: u8 = 34;
+pub const OP_CHANGE_DEVICE_CONTROL: u8 = 35;
+pub const OP_LIST_DEVICE_PROPERTIES: u8 = 36;
+pub const OP_CHANGE_DEVICE_PROPERTY: u8 = 37;
+pub const OP_DELETE_DEVICE_PROPERTY: u8 = 38;
+pub const OP_GET_DEVICE_PROPERTY: u8 = 39;
+pub const OP_XI_QUERY_POINTER: u8 = 40;
+pub const OP_XI_WARP_POINTER: u8 = 41;
+pub const OP_XI_CHANGE_CURSOR: u8 = 42;
+pub const OP_XI_CHANGE_HIERARCHY: u8 = 43;
+pub const OP_XI_SET_CLIENT_POINTER: u8 = 44;
+pub const OP_XI_GET_CLIENT_POINTER: u8 = 45;
+pub const OP_XI_SELECT_EVENTS: u8 = 46;
+pub const OP_XI_QUERY_VERSION: u8 = 47;
+pub const OP_XI_QUERY_DEVICE: u8 = 48;
+pub const OP_XI_SET_FOCUS: u8 = 49;
+pub const OP_XI_GET_FOCUS: u8 = 50;
+pub const OP_XI_GRAB_DEVICE: u8 = 51;
+pub const OP_XI_UNGRAB_DEVICE: u8 = 52;
+pub const OP_XI_ALLOW_EVENTS: u8 = 53;
+pub const OP_XI_PASSIVE_GRAB_DEVICE: u8 = 54;
+pub const OP_XI_PASSIVE_UNGRAB_DEVICE: u8 = 55;
+pub const OP_XI_LIST_PROPERTIES: u8 = 56;
+pub const OP_XI_CHANGE_PROPERTY: u8 = 57;
+pub const OP_XI_DELETE_PROPERTY: u8 = 58;
+pub const OP_XI_GET_PROPERTY: u8 = 59;
+pub const OP_XI_GET_SELECTED_EVENTS: u8 = 60;
+pub const OP_XI_BARRIER_RELEASE_POINTER: u8 = 61;
+pub const OP_SEND_EXTENSION_EVENT: u8 = 31;
+
+pub const EVENT_DEVICE_VALUATOR: u8 = 0;
+pub const EVENT_DEVICE_KEY_PRESS: u8 = 1;
+pub const EVENT_DEVICE_FOCUS_IN: u8 = 6;
+pub const EVENT_DEVICE_STATE_NOTIFY: u8 = 10;
+pub const EVENT_DEVICE_MAPPING_NOTIFY: u8 = 11;
+pub const EVENT_CHANGE_DEVICE_NOTIFY: u8 = 12;
+pub const EVENT_DEVICE_KEY_STATE_NOTIFY: u8 = 13;
+pub const EVENT_DEVICE_BUTTON_STATE_NOTIFY: u8 = 14;
+pub const EVENT_DEVICE_PRESENCE_NOTIFY: u8 = 15;
+pub const EVENT_DEVICE_PROPERTY_NOTIFY: u8 = 16;
+pub const GENERIC_EVENT_DEVICE_CHANGED: u16 = 1;
+pub const GENERIC_EVENT_KEY_PRESS: u16 = 2;
+pub const GENERIC_EVENT_BUTTON_PRESS: u16 = 4;
+pub const GENERIC_EVENT_ENTER: u16 = 7;
+pub const GENERIC_EVENT_HIERARCHY: u16 = 11;
+pub const GENERIC_EVENT_PROPERTY: u16 = 12;
+pub const GENERIC_EVENT_RAW_KEY_PRESS: u16 = 13;
+pub const GENERIC_EVENT_RAW_BUTTON_PRESS: u16 = 15;
+pub const GENERIC_EVENT_TOUCH_BEGIN: u16 = 18;
+pub const GENERIC_EVENT_TOUCH_OWNERSHIP: u16 = 21;
+pub const GENERIC_EVENT_RAW_TOUCH_BEGIN: u16 = 22;
+pub const GENERIC_EVENT_BARRIER_HIT: u16 = 25;
+pub const EVENT_DEVICE_KEY_RELEASE: u8 = 2;
+pub const EVENT_DEVICE_BUTTON_PRESS: u8 = 3;
+pub const EVENT_DEVICE_BUTTON_RELEASE: u8 = 4;
+pub const EVENT_DEVICE_MOTION_NOTIFY: u8 = 5;
+pub const EVENT_DEVICE_FOCUS_OUT: u8 = 7;
+pub const EVENT_PROXIMITY_IN: u8 = 8;
+pub const EVENT_PROXIMITY_OUT: u8 = 9;
+pub const GENERIC_EVENT_KEY_RELEASE: u16 = 3;
+pub const GENERIC_EVENT_BUTTON_RELEASE: u16 = 5;
+pub const GENERIC_EVENT_MOTION: u16 = 6;
+pub const GENERIC_EVENT_LEAVE: u16 = 8;
+pub const GENERIC_EVENT_FOCUS_IN: u16 = 9;
+pub const GENERIC_EVENT_FOCUS_OUT: u16 = 10;
+pub const GENERIC_EVENT_RAW_KEY_RELEASE: u16 = 14;
+pub const GENERIC_EVENT_RAW_BUTTON_RELEASE: u16 = 16;
+pub const GENERIC_EVENT_RAW_MOTION: u16 = 17;
+pub const GENERIC_EVENT_TOUCH_UPDATE: u16 = 19;
+pub const GENERIC_EVENT_TOUCH_END: u16 = 20;
+pub const GENERIC_EVENT_RAW_TOUCH_UPDATE: u16 = 23;
+pub const GENERIC_EVENT_RAW_TOUCH_END: u16 = 24;
+pub const GENERIC_EVENT_BARRIER_LEAVE: u16 = 26;
+
+pub const ERROR_DEVICE: u8 = 0;
+pub const ERROR_EVENT: u8 = 1;
+pub const ERROR_MODE: u8 = 2;
+pub const ERROR_DEVICE_BUSY: u8 = 3;
+pub const ERROR_CLASS: u8 = 4;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DeviceUse {
+    IsXPointer,
+    IsXKeyboard,
+    IsXExtensionDevice,
+    IsXExtensionKeyboard,
+    IsXExtensionPointer
+}
+impl DeviceUse {
+    pub fn get(id: u32) -> Option<DeviceUse> {
+        match id {
+            0 => Some(DeviceUse::IsXPointer),
+            1 => Some(DeviceUse::IsXKeyboard),
+            2 => Some(DeviceUse::IsXExtensionDevice),
+            3 => Some(DeviceUse::IsXExtensionKeyboard),
+            4 => Some(DeviceUse::IsXExtensionPointer),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &DeviceUse::IsXPointer => 0,
+            &DeviceUse::IsXKeyboard => 1,
+            &DeviceUse::IsXExtensionDevice => 2,
+            &DeviceUse::IsXExtensionKeyboard => 3,
+            &DeviceUse::IsXExtensionPointer => 4
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum InputClass {
+    Key,
+    Button,
+    Valuator,
+    Feedback,
+    Proximity,
+    Focus,
+    Other
+}
+impl InputClass {
+    pub fn get(id: u32) -> Option<InputClass> {
+        match id {
+            0 => Some(InputClass::Key),
+            1 => Some(InputClass::Button),
+            2 => Some(InputClass::Valuator),
+            3 => Some(InputClass::Feedback),
+            4 => Some(InputClass::Proximity),
+            5 => Some(InputClass::Focus),
+            6 => Some(InputClass::Other),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &InputClass::Key => 0,
+            &InputClass::Button => 1,
+            &InputClass::Valuator => 2,
+            &InputClass::Feedback => 3,
+            &InputClass::Proximity => 4,
+            &InputClass::Focus => 5,
+            &InputClass::Other => 6
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ValuatorMode {
+    Relative,
+    Absolute
+}
+impl ValuatorMode {
+    pub fn get(id: u32) -> Option<ValuatorMode> {
+        match id {
+            0 => Some(ValuatorMode::Relative),
+            1 => Some(ValuatorMode::Absolute),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &ValuatorMode::Relative => 0,
+            &ValuatorMode::Absolute => 1
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PropagateMode {
+    AddToList,
+    DeleteFromList
+}
+impl PropagateMode {
+    pub fn get(id: u32) -> Option<PropagateMode> {
+        match id {
+            0 => Some(PropagateMode::AddToList),
+            1 => Some(PropagateMode::DeleteFromList),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &PropagateMode::AddToList => 0,
+            &PropagateMode::DeleteFromList => 1
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ModifierDevice {
+    UseXKeyboard
+}
+impl ModifierDevice {
+    pub fn get(id: u32) -> Option<ModifierDevice> {
+        match id {
+            255 => Some(ModifierDevice::UseXKeyboard),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &ModifierDevice::UseXKeyboard => 255
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DeviceInputMode {
+    AsyncThisDevice,
+    SyncThisDevice,
+    ReplayThisDevice,
+    AsyncOtherDevices,
+    AsyncAll,
+    SyncAll
+}
+impl DeviceInputMode {
+    pub fn get(id: u32) -> Option<DeviceInputMode> {
+        match id {
+            0 => Some(DeviceInputMode::AsyncThisDevice),
+            1 => Some(DeviceInputMode::SyncThisDevice),
+            2 => Some(DeviceInputMode::ReplayThisDevice),
+            3 => Some(DeviceInputMode::AsyncOtherDevices),
+            4 => Some(DeviceInputMode::AsyncAll),
+            5 => Some(DeviceInputMode::SyncAll),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &DeviceInputMode::AsyncThisDevice => 0,
+            &DeviceInputMode::SyncThisDevice => 1,
+            &DeviceInputMode::ReplayThisDevice => 2,
+            &DeviceInputMode::AsyncOtherDevices => 3,
+            &DeviceInputMode::AsyncAll => 4,
+            &DeviceInputMode::SyncAll => 5
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FeedbackClass {
+    Keyboard,
+    Pointer,
+    String,
+    Integer,
+    Led,
+    Bell
+}
+impl FeedbackClass {
+    pub fn get(id: u32) -> Option<FeedbackClass> {
+        match id {
+            0 => Some(FeedbackClass::Keyboard),
+            1 => Some(FeedbackClass::Pointer),
+            2 => Some(FeedbackClass::String),
+            3 => Some(FeedbackClass::Integer),
+            4 => Some(FeedbackClass::Led),
+            5 => Some(FeedbackClass::Bell),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &FeedbackClass::Keyboard => 0,
+            &FeedbackClass::Pointer => 1,
+            &FeedbackClass::String => 2,
+            &FeedbackClass::Integer => 3,
+            &FeedbackClass::Led => 4,
+            &FeedbackClass::Bell => 5
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ChangeFeedbackControlMask {
+    KeyClickPercent,
+    Percent,
+    Pitch,
+    Duration,
+    Led,
+    LedMode,
+    Key,
+    AutoRepeatMode,
+    String,
+    Integer,
+    AccelNum,
+    AccelDenom,
+    Threshold
+}
+impl ChangeFeedbackControlMask {
+    pub fn val(&self) -> u32 {
+        match self {
+            &ChangeFeedbackControlMask::KeyClickPercent => 0x00000001,
+            &ChangeFeedbackControlMask::Percent => 0x00000002,
+            &ChangeFeedbackControlMask::Pitch => 0x00000004,
+            &ChangeFeedbackControlMask::Duration => 0x00000008,
+            &ChangeFeedbackControlMask::Led => 0x00000010,
+            &ChangeFeedbackControlMask::LedMode => 0x00000020,
+            &ChangeFeedbackControlMask::Key => 0x00000040,
+            &ChangeFeedbackControlMask::AutoRepeatMode => 0x00000080,
+            &ChangeFeedbackControlMask::String => 0x00000001,
+            &ChangeFeedbackControlMask::Integer => 0x00000001,
+            &ChangeFeedbackControlMask::AccelNum => 0x00000001,
+            &ChangeFeedbackControlMask::AccelDenom => 0x00000002,
+            &ChangeFeedbackControlMask::Threshold => 0x00000004
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ValuatorStateModeMask {
+    DeviceModeAbsolute,
+    OutOfProximity
+}
+impl ValuatorStateModeMask {
+    pub fn val(&self) -> u32 {
+        match self {
+            &ValuatorStateModeMask::DeviceModeAbsolute => 0x00000001,
+            &ValuatorStateModeMask::OutOfProximity => 0x00000002
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DeviceControl {
+    Resolution,
+    AbsCalib,
+    Core,
+    Enable,
+    AbsArea
+}
+impl DeviceControl {
+    pub fn get(id: u32) -> Option<DeviceControl> {
+        match id {
+            1 => Some(DeviceControl::Resolution),
+            2 => Some(DeviceControl::AbsCalib),
+            3 => Some(DeviceControl::Core),
+            4 => Some(DeviceControl::Enable),
+            5 => Some(DeviceControl::AbsArea),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &DeviceControl::Resolution => 1,
+            &DeviceControl::AbsCalib => 2,
+            &DeviceControl::Core => 3,
+            &DeviceControl::Enable => 4,
+            &DeviceControl::AbsArea => 5
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PropertyFormat {
+    N8Bits,
+    N16Bits,
+    N32Bits
+}
+impl PropertyFormat {
+    pub fn get(id: u32) -> Option<PropertyFormat> {
+        match id {
+            8 => Some(PropertyFormat::N8Bits),
+            16 => Some(PropertyFormat::N16Bits),
+            32 => Some(PropertyFormat::N32Bits),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &PropertyFormat::N8Bits => 8,
+            &PropertyFormat::N16Bits => 16,
+            &PropertyFormat::N32Bits => 32
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Device {
+    All,
+    AllMaster
+}
+impl Device {
+    pub fn get(id: u32) -> Option<Device> {
+        match id {
+            0 => Some(Device::All),
+            1 => Some(Device::AllMaster),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &Device::All => 0,
+            &Device::AllMaster => 1
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum HierarchyChangeType {
+    AddMaster,
+    RemoveMaster,
+    AttachSlave,
+    DetachSlave
+}
+impl HierarchyChangeType {
+    pub fn get(id: u32) -> Option<HierarchyChangeType> {
+        match id {
+            1 => Some(HierarchyChangeType::AddMaster),
+            2 => Some(HierarchyChangeType::RemoveMaster),
+            3 => Some(HierarchyChangeType::AttachSlave),
+            4 => Some(HierarchyChangeType::DetachSlave),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &HierarchyChangeType::AddMaster => 1,
+            &HierarchyChangeType::RemoveMaster => 2,
+            &HierarchyChangeType::AttachSlave => 3,
+            &HierarchyChangeType::DetachSlave => 4
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ChangeMode {
+    Attach,
+    Float
+}
+impl ChangeMode {
+    pub fn get(id: u32) -> Option<ChangeMode> {
+        match id {
+            1 => Some(ChangeMode::Attach),
+            2 => Some(ChangeMode::Float),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &ChangeMode::Attach => 1,
+            &ChangeMode::Float => 2
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum XIEventMask {
+    DeviceChanged,
+    KeyPress,
+    KeyRelease,
+    ButtonPress,
+    ButtonRelease,
+    Motion,
+    Enter,
+    Leave,
+    FocusIn,
+    FocusOut,
+    Hierarchy,
+    Property,
+    RawKeyPress,
+    RawKeyRelease,
+    RawButtonPress,
+    RawButtonRelease,
+    RawMotion,
+    TouchBegin,
+    TouchUpdate,
+    TouchEnd,
+    TouchOwnership,
+    RawTouchBegin,
+    RawTouchUpdate,
+    RawTouchEnd,
+    BarrierHit,
+    BarrierLeave
+}
+impl XIEventMask {
+    pub fn val(&self) -> u32 {
+        match self {
+            &XIEventMask::DeviceChanged => 0x00000002,
+            &XIEventMask::KeyPress => 0x00000004,
+            &XIEventMask::KeyRelease => 0x00000008,
+            &XIEventMask::ButtonPress => 0x00000010,
+            &XIEventMask::ButtonRelease => 0x00000020,
+            &XIEventMask::Motion => 0x00000040,
+            &XIEventMask::Enter => 0x00000080,
+            &XIEventMask::Leave => 0x00000100,
+            &XIEventMask::FocusIn => 0x00000200,
+            &XIEventMask::FocusOut => 0x00000400,
+            &XIEventMask::Hierarchy => 0x00000800,
+            &XIEventMask::Property => 0x00001000,
+            &XIEventMask::RawKeyPress => 0x00002000,
+            &XIEventMask::RawKeyRelease => 0x00004000,
+            &XIEventMask::RawButtonPress => 0x00008000,
+            &XIEventMask::RawButtonRelease => 0x00010000,
+            &XIEventMask::RawMotion => 0x00020000,
+            &XIEventMask::TouchBegin => 0x00040000,
+            &XIEventMask::TouchUpdate => 0x00080000,
+            &XIEventMask::TouchEnd => 0x00100000,
+            &XIEventMask::TouchOwnership => 0x00200000,
+            &XIEventMask::RawTouchBegin => 0x00400000,
+            &XIEventMask::RawTouchUpdate => 0x00800000,
+            &XIEventMask::RawTouchEnd => 0x01000000,
+            &XIEventMask::BarrierHit => 0x02000000,
+            &XIEventMask::BarrierLeave => 0x04000000
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DeviceClassType {
+    Key,
+    Button,
+    Valuator,
+    Scroll,
+    Touch
+}
+impl DeviceClassType {
+    pub fn get(id: u32) -> Option<DeviceClassType> {
+        match id {
+            0 => Some(DeviceClassType::Key),
+            1 => Some(DeviceClassType::Button),
+            2 => Some(DeviceClassType::Valuator),
+            3 => Some(DeviceClassType::Scroll),
+            8 => Some(DeviceClassType::Touch),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &DeviceClassType::Key => 0,
+            &DeviceClassType::Button => 1,
+            &DeviceClassType::Valuator => 2,
+            &DeviceClassType::Scroll => 3,
+            &DeviceClassType::Touch => 8
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DeviceType {
+    MasterPointer,
+    MasterKeyboard,
+    SlavePointer,
+    SlaveKeyboard,
+    FloatingSlave
+}
+impl DeviceType {
+    pub fn get(id: u32) -> Option<DeviceType> {
+        match id {
+            1 => Some(DeviceType::MasterPointer),
+            2 => Some(DeviceType::MasterKeyboard),
+            3 => Some(DeviceType::SlavePointer),
+            4 => Some(DeviceType::SlaveKeyboard),
+            5 => Some(DeviceType::FloatingSlave),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &DeviceType::MasterPointer => 1,
+            &DeviceType::MasterKeyboard => 2,
+            &DeviceType::SlavePointer => 3,
+            &DeviceType::SlaveKeyboard => 4,
+            &DeviceType::FloatingSlave => 5
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ScrollFlags {
+    NoEmulation,
+    Preferred
+}
+impl ScrollFlags {
+    pub fn val(&self) -> u32 {
+        match self {
+            &ScrollFlags::NoEmulation => 0x00000001,
+            &ScrollFlags::Preferred => 0x00000002
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ScrollType {
+    Vertical,
+    Horizontal
+}
+impl ScrollType {
+    pub fn get(id: u32) -> Option<ScrollType> {
+        match id {
+            1 => Some(ScrollType::Vertical),
+            2 => Some(ScrollType::Horizontal),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &ScrollType::Vertical => 1,
+            &ScrollType::Horizontal => 2
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TouchMode {
+    Direct,
+    Dependent
+}
+impl TouchMode {
+    pub fn get(id: u32) -> Option<TouchMode> {
+        match id {
+            1 => Some(TouchMode::Direct),
+            2 => Some(TouchMode::Dependent),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &TouchMode::Direct => 1,
+            &TouchMode::Dependent => 2
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GrabOwner {
+    NoOwner,
+    Owner
+}
+impl GrabOwner {
+    pub fn get(id: u32) -> Option<GrabOwner> {
+        match id {
+            0 => Some(GrabOwner::NoOwner),
+            1 => Some(GrabOwner::Owner),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &GrabOwner::NoOwner => 0,
+            &GrabOwner::Owner => 1
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum EventMode {
+    AsyncDevice,
+    SyncDevice,
+    ReplayDevice,
+    AsyncPairedDevice,
+    AsyncPair,
+    SyncPair,
+    AcceptTouch,
+    RejectTouch
+}
+impl EventMode {
+    pub fn get(id: u32) -> Option<EventMode> {
+        match id {
+            0 => Some(EventMode::AsyncDevice),
+            1 => Some(EventMode::SyncDevice),
+            2 => Some(EventMode::ReplayDevice),
+            3 => Some(EventMode::AsyncPairedDevice),
+            4 => Some(EventMode::AsyncPair),
+            5 => Some(EventMode::SyncPair),
+            6 => Some(EventMode::AcceptTouch),
+            7 => Some(EventMode::RejectTouch),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &EventMode::AsyncDevice => 0,
+            &EventMode::SyncDevice => 1,
+            &EventMode::ReplayDevice => 2,
+            &EventMode::AsyncPairedDevice => 3,
+            &EventMode::AsyncPair => 4,
+            &EventMode::SyncPair => 5,
+            &EventMode::AcceptTouch => 6,
+            &EventMode::RejectTouch => 7
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GrabMode {
+    Sync,
+    Async,
+    Touch
+}
+impl GrabMode {
+    pub fn get(id: u32) -> Option<GrabMode> {
+        match id {
+            0 => Some(GrabMode::Sync),
+            1 => Some(GrabMode::Async),
+            2 => Some(GrabMode::Touch),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &GrabMode::Sync => 0,
+            &GrabMode::Async => 1,
+            &GrabMode::Touch => 2
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GrabType {
+    Button,
+    Keycode,
+    Enter,
+    FocusIn,
+    TouchBegin
+}
+impl GrabType {
+    pub fn get(id: u32) -> Option<GrabType> {
+        match id {
+            0 => Some(GrabType::Button),
+            1 => Some(GrabType::Keycode),
+            2 => Some(GrabType::Enter),
+            3 => Some(GrabType::FocusIn),
+            4 => Some(GrabType::TouchBegin),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &GrabType::Button => 0,
+            &GrabType::Keycode => 1,
+            &GrabType::Enter => 2,
+            &GrabType::FocusIn => 3,
+            &GrabType::TouchBegin => 4
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ModifierMask {
+    Any
+}
+impl ModifierMask {
+    pub fn val(&self) -> u32 {
+        match self {
+            &ModifierMask::Any => 0x80000000
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum MoreEventsMask {
+    MoreEvents
+}
+impl MoreEventsMask {
+    pub fn val(&self) -> u32 {
+        match self {
+            &MoreEventsMask::MoreEvents => 0x00000080
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ClassesReportedMask {
+    OutOfProximity,
+    DeviceModeAbsolute,
+    ReportingValuators,
+    ReportingButtons,
+    ReportingKeys
+}
+impl ClassesReportedMask {
+    pub fn val(&self) -> u32 {
+        match self {
+            &ClassesReportedMask::OutOfProximity => 0x00000080,
+            &ClassesReportedMask::DeviceModeAbsolute => 0x00000040,
+            &ClassesReportedMask::ReportingValuators => 0x00000004,
+            &ClassesReportedMask::ReportingButtons => 0x00000002,
+            &ClassesReportedMask::ReportingKeys => 0x00000001
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ChangeDevice {
+    NewPointer,
+    NewKeyboard
+}
+impl ChangeDevice {
+    pub fn get(id: u32) -> Option<ChangeDevice> {
+        match id {
+            0 => Some(ChangeDevice::NewPointer),
+            1 => Some(ChangeDevice::NewKeyboard),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &ChangeDevice::NewPointer => 0,
+            &ChangeDevice::NewKeyboard => 1
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DeviceChange {
+    Added,
+    Removed,
+    Enabled,
+    Disabled,
+    Unrecoverable,
+    ControlChanged
+}
+impl DeviceChange {
+    pub fn get(id: u32) -> Option<DeviceChange> {
+        match id {
+            0 => Some(DeviceChange::Added),
+            1 => Some(DeviceChange::Removed),
+            2 => Some(DeviceChange::Enabled),
+            3 => Some(DeviceChange::Disabled),
+            4 => Some(DeviceChange::Unrecoverable),
+            5 => Some(DeviceChange::ControlChanged),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &DeviceChange::Added => 0,
+            &DeviceChange::Removed => 1,
+            &DeviceChange::Enabled => 2,
+            &DeviceChange::Disabled => 3,
+            &DeviceChange::Unrecoverable => 4,
+            &DeviceChange::ControlChanged => 5
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ChangeReason {
+    SlaveSwitch,
+    DeviceChange
+}
+impl ChangeReason {
+    pub fn get(id: u32) -> Option<ChangeReason> {
+        match id {
+            1 => Some(ChangeReason::SlaveSwitch),
+            2 => Some(ChangeReason::DeviceChange),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &ChangeReason::SlaveSwitch => 1,
+            &ChangeReason::DeviceChange => 2
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum KeyEventFlags {
+    KeyRepeat
+}
+impl KeyEventFlags {
+    pub fn val(&self) -> u32 {
+        match self {
+            &KeyEventFlags::KeyRepeat => 0x00010000
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PointerEventFlags {
+    PointerEmulated
+}
+impl PointerEventFlags {
+    pub fn val(&self) -> u32 {
+        match self {
+            &PointerEventFlags::PointerEmulated => 0x00010000
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum NotifyMode {
+    Normal,
+    Grab,
+    Ungrab,
+    WhileGrabbed,
+    PassiveGrab,
+    PassiveUngrab
+}
+impl NotifyMode {
+    pub fn get(id: u32) -> Option<NotifyMode> {
+        match id {
+            0 => Some(NotifyMode::Normal),
+            1 => Some(NotifyMode::Grab),
+            2 => Some(NotifyMode::Ungrab),
+            3 => Some(NotifyMode::WhileGrabbed),
+            4 => Some(NotifyMode::PassiveGrab),
+            5 => Some(NotifyMode::PassiveUngrab),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &NotifyMode::Normal => 0,
+            &NotifyMode::Grab => 1,
+            &NotifyMode::Ungrab => 2,
+            &NotifyMode::WhileGrabbed => 3,
+            &NotifyMode::PassiveGrab => 4,
+            &NotifyMode::PassiveUngrab => 5
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum NotifyDetail {
+    Ancestor,
+    Virtual,
+    Inferior,
+    Nonlinear,
+    NonlinearVirtual,
+    Pointer,
+    PointerRoot,
+    None
+}
+impl NotifyDetail {
+    pub fn get(id: u32) -> Option<NotifyDetail> {
+        match id {
+            0 => Some(NotifyDetail::Ancestor),
+            1 => Some(NotifyDetail::Virtual),
+            2 => Some(NotifyDetail::Inferior),
+            3 => Some(NotifyDetail::Nonlinear),
+            4 => Some(NotifyDetail::NonlinearVirtual),
+            5 => Some(NotifyDetail::Pointer),
+            6 => Some(NotifyDetail::PointerRoot),
+            7 => Some(NotifyDetail::None),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &NotifyDetail::Ancestor => 0,
+            &NotifyDetail::Virtual => 1,
+            &NotifyDetail::Inferior => 2,
+            &NotifyDetail::Nonlinear => 3,
+            &NotifyDetail::NonlinearVirtual => 4,
+            &NotifyDetail::Pointer => 5,
+            &NotifyDetail::PointerRoot => 6,
+            &NotifyDetail::None => 7
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum HierarchyMask {
+    MasterAdded,
+    MasterRemoved,
+    SlaveAdded,
+    SlaveRemoved,
+    SlaveAttached,
+    SlaveDetached,
+    DeviceEnabled,
+    DeviceDisabled
+}
+impl HierarchyMask {
+    pub fn val(&self) -> u32 {
+        match self {
+            &HierarchyMask::MasterAdded => 0x00000001,
+            &HierarchyMask::MasterRemoved => 0x00000002,
+            &HierarchyMask::SlaveAdded => 0x00000004,
+            &HierarchyMask::SlaveRemoved => 0x00000008,
+            &HierarchyMask::SlaveAttached => 0x00000010,
+            &HierarchyMask::SlaveDetached => 0x00000020,
+            &HierarchyMask::DeviceEnabled => 0x00000040,
+            &HierarchyMask::DeviceDisabled => 0x00000080
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PropertyFlag {
+    Deleted,
+    Created,
+    Modified
+}
+impl PropertyFlag {
+    pub fn get(id: u32) -> Option<PropertyFlag> {
+        match id {
+            0 => Some(PropertyFlag::Deleted),
+            1 => Some(PropertyFlag::Created),
+            2 => Some(PropertyFlag::Modified),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &PropertyFlag::Deleted => 0,
+            &PropertyFlag::Created => 1,
+            &PropertyFlag::Modified => 2
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TouchEventFlags {
+    TouchPendingEnd,
+    TouchEmulatingPointer
+}
+impl TouchEventFlags {
+    pub fn val(&self) -> u32 {
+        match self {
+            &TouchEventFlags::TouchPendingEnd => 0x00010000,
+            &TouchEventFlags::TouchEmulatingPointer => 0x00020000
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TouchOwnershipFlags {
+    None
+}
+impl TouchOwnershipFlags {
+    pub fn get(id: u32) -> Option<TouchOwnershipFlags> {
+        match id {
+            0 => Some(TouchOwnershipFlags::None),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &TouchOwnershipFlags::None => 0
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BarrierFlags {
+    PointerReleased,
+    DeviceIsGrabbed
+}
+impl BarrierFlags {
+    pub fn val(&self) -> u32 {
+        match self {
+            &BarrierFlags::PointerReleased => 0x00000001,
+            &BarrierFlags::DeviceIsGrabbed => 0x00000002
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct DeviceInfo {
+    pub device_type: u32,
+    pub device_id: u8,
+    pub num_class_info: u8,
+    pub device_use: u8
+}
+impl DeviceInfo {
+    pub fn read(reader: &mut XReadHelper) -> DeviceInfo {
+        let device_type = reader.read_u32();
+        let device_id = reader.read_u8();
+        let num_class_info = reader.read_u8();
+        let device_use = reader.read_u8();
+        reader.read_pad(1);
+        DeviceInfo { device_type, device_id, num_class_info, device_use }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.device_type);
+        client.write_u8(self.device_id);
+        client.write_u8(self.num_class_info);
+        client.write_u8(self.device_use);
+        client.write_pad(1);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct KeyInfo {
+    pub class_id: u8,
+    pub len: u8,
+    pub min_keycode: u8,
+    pub max_keycode: u8,
+    pub num_keys: u16
+}
+impl KeyInfo {
+    pub fn read(reader: &mut XReadHelper) -> KeyInfo {
+        let class_id = reader.read_u8();
+        let len = reader.read_u8();
+        let min_keycode = reader.read_u8();
+        let max_keycode = reader.read_u8();
+        let num_keys = reader.read_u16();
+        reader.read_pad(2);
+        KeyInfo { class_id, len, min_keycode, max_keycode, num_keys }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.len);
+        client.write_u8(self.min_keycode);
+        client.write_u8(self.max_keycode);
+        client.write_u16(self.num_keys);
+        client.write_pad(2);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ButtonInfo {
+    pub class_id: u8,
+    pub len: u8,
+    pub num_buttons: u16
+}
+impl ButtonInfo {
+    pub fn read(reader: &mut XReadHelper) -> ButtonInfo {
+        let class_id = reader.read_u8();
+        let len = reader.read_u8();
+        let num_buttons = reader.read_u16();
+        ButtonInfo { class_id, len, num_buttons }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.len);
+        client.write_u16(self.num_buttons);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct AxisInfo {
+    pub resolution: u32,
+    pub minimum: i32,
+    pub maximum: i32
+}
+impl AxisInfo {
+    pub fn read(reader: &mut XReadHelper) -> AxisInfo {
+        let resolution = reader.read_u32();
+        let minimum = reader.read_i32();
+        let maximum = reader.read_i32();
+        AxisInfo { resolution, minimum, maximum }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.resolution);
+        client.write_i32(self.minimum);
+        client.write_i32(self.maximum);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ValuatorInfo {
+    pub class_id: u8,
+    pub len: u8,
+    pub mode: u8,
+    pub motion_size: u32,
+    pub axes: Vec<AxisInfo>
+}
+impl ValuatorInfo {
+    pub fn read(reader: &mut XReadHelper) -> ValuatorInfo {
+        let class_id = reader.read_u8();
+        let len = reader.read_u8();
+        let axes_len = reader.read_u8();
+        let mode = reader.read_u8();
+        let motion_size = reader.read_u32();
+        let mut axes = Vec::with_capacity(axes_len as usize);
+        for _ in 0..axes_len as usize {
+            axes.push(AxisInfo::read(reader));
+        }
+        ValuatorInfo { class_id, len, mode, motion_size, axes }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.len);
+        client.write_u8(self.axes.len() as u8);
+        client.write_u8(self.mode);
+        client.write_u32(self.motion_size);
+        for value in &self.axes {
+            value.write(client);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeviceName {
+    pub string: String
+}
+impl DeviceName {
+    pub fn read(reader: &mut XReadHelper) -> DeviceName {
+        let len = reader.read_u8();
+        let string = reader.read_str(len as usize);
+        DeviceName { string }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.string.len() as u8);
+        client.write_str(&self.string);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct InputClassInfo {
+    pub class_id: u8,
+    pub event_type_base: u8
+}
+impl InputClassInfo {
+    pub fn read(reader: &mut XReadHelper) -> InputClassInfo {
+        let class_id = reader.read_u8();
+        let event_type_base = reader.read_u8();
+        InputClassInfo { class_id, event_type_base }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.event_type_base);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KbdFeedbackState {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub pitch: u16,
+    pub duration: u16,
+    pub led_mask: u32,
+    pub led_values: u32,
+    pub global_auto_repeat: bool,
+    pub click: u8,
+    pub percent: u8,
+    pub auto_repeats: Vec<u8>
+}
+impl KbdFeedbackState {
+    pub fn read(reader: &mut XReadHelper) -> KbdFeedbackState {
+        let class_id = reader.read_u8();
+        let feedback_id = reader.read_u8();
+        let len = reader.read_u16();
+        let pitch = reader.read_u16();
+        let duration = reader.read_u16();
+        let led_mask = reader.read_u32();
+        let led_values = reader.read_u32();
+        let global_auto_repeat = reader.read_bool();
+        let click = reader.read_u8();
+        let percent = reader.read_u8();
+        reader.read_pad(1);
+        let auto_repeats = reader.read_raw(32);
+        KbdFeedbackState { class_id, feedback_id, len, pitch, duration, led_mask, led_values, global_auto_repeat, click, percent, auto_repeats }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.feedback_id);
+        client.write_u16(self.len);
+        client.write_u16(self.pitch);
+        client.write_u16(self.duration);
+        client.write_u32(self.led_mask);
+        client.write_u32(self.led_values);
+        client.write_bool(self.global_auto_repeat);
+        client.write_u8(self.click);
+        client.write_u8(self.percent);
+        client.write_pad(1);
+        client.write_raw(&self.auto_repeats);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct PtrFeedbackState {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub accel_num: u16,
+    pub accel_denom: u16,
+    pub threshold: u16
+}
+impl PtrFeedbackState {
+    pub fn read(reader: &mut XReadHelper) -> PtrFeedbackState {
+        let class_id = reader.read_u8();
+        let feedback_id = reader.read_u8();
+        let len = reader.read_u16();
+        reader.read_pad(2);
+        let accel_num = reader.read_u16();
+        let accel_denom = reader.read_u16();
+        let threshold = reader.read_u16();
+        PtrFeedbackState { class_id, feedback_id, len, accel_num, accel_denom, threshold }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.feedback_id);
+        client.write_u16(self.len);
+        client.write_pad(2);
+        client.write_u16(self.accel_num);
+        client.write_u16(self.accel_denom);
+        client.write_u16(self.threshold);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct IntegerFeedbackState {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub resolution: u32,
+    pub min_value: i32,
+    pub max_value: i32
+}
+impl IntegerFeedbackState {
+    pub fn read(reader: &mut XReadHelper) -> IntegerFeedbackState {
+        let class_id = reader.read_u8();
+        let feedback_id = reader.read_u8();
+        let len = reader.read_u16();
+        let resolution = reader.read_u32();
+        let min_value = reader.read_i32();
+        let max_value = reader.read_i32();
+        IntegerFeedbackState { class_id, feedback_id, len, resolution, min_value, max_value }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.feedback_id);
+        client.write_u16(self.len);
+        client.write_u32(self.resolution);
+        client.write_i32(self.min_value);
+        client.write_i32(self.max_value);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StringFeedbackState {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub max_symbols: u16,
+    pub keysyms: Vec<u32>
+}
+impl StringFeedbackState {
+    pub fn read(reader: &mut XReadHelper) -> StringFeedbackState {
+        let class_id = reader.read_u8();
+        let feedback_id = reader.read_u8();
+        let len = reader.read_u16();
+        let max_symbols = reader.read_u16();
+        let num_keysyms = reader.read_u16();
+        let mut keysyms = Vec::with_capacity(num_keysyms as usize);
+        for _ in 0..num_keysyms as usize {
+            keysyms.push(reader.read_u32());
+        }
+        StringFeedbackState { class_id, feedback_id, len, max_symbols, keysyms }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.feedback_id);
+        client.write_u16(self.len);
+        client.write_u16(self.max_symbols);
+        client.write_u16(self.keysyms.len() as u16);
+        for value in &self.keysyms {
+            client.write_u32(*value);
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct BellFeedbackState {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub percent: u8,
+    pub pitch: u16,
+    pub duration: u16
+}
+impl BellFeedbackState {
+    pub fn read(reader: &mut XReadHelper) -> BellFeedbackState {
+        let class_id = reader.read_u8();
+        let feedback_id = reader.read_u8();
+        let len = reader.read_u16();
+        let percent = reader.read_u8();
+        reader.read_pad(3);
+        let pitch = reader.read_u16();
+        let duration = reader.read_u16();
+        BellFeedbackState { class_id, feedback_id, len, percent, pitch, duration }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.feedback_id);
+        client.write_u16(self.len);
+        client.write_u8(self.percent);
+        client.write_pad(3);
+        client.write_u16(self.pitch);
+        client.write_u16(self.duration);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct LedFeedbackState {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub led_mask: u32,
+    pub led_values: u32
+}
+impl LedFeedbackState {
+    pub fn read(reader: &mut XReadHelper) -> LedFeedbackState {
+        let class_id = reader.read_u8();
+        let feedback_id = reader.read_u8();
+        let len = reader.read_u16();
+        let led_mask = reader.read_u32();
+        let led_values = reader.read_u32();
+        LedFeedbackState { class_id, feedback_id, len, led_mask, led_values }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.feedback_id);
+        client.write_u16(self.len);
+        client.write_u32(self.led_mask);
+        client.write_u32(self.led_values);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct KbdFeedbackCtl {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub key: u8,
+    pub auto_repeat_mode: u8,
+    pub key_click_percent: i8,
+    pub bell_percent: i8,
+    pub bell_pitch: i16,
+    pub bell_duration: i16,
+    pub led_mask: u32,
+    pub led_values: u32
+}
+impl KbdFeedbackCtl {
+    pub fn read(reader: &mut XReadHelper) -> KbdFeedbackCtl {
+        let class_id = reader.read_u8();
+        let feedback_id = reader.read_u8();
+        let len = reader.read_u16();
+        let key = reader.read_u8();
+        let auto_repeat_mode = reader.read_u8();
+        let key_click_percent = reader.read_u8() as i8;
+        let bell_percent = reader.read_u8() as i8;
+        let bell_pitch = reader.read_i16();
+        let bell_duration = reader.read_i16();
+        let led_mask = reader.read_u32();
+        let led_values = reader.read_u32();
+        KbdFeedbackCtl { class_id, feedback_id, len, key, auto_repeat_mode, key_click_percent, bell_percent, bell_pitch, bell_duration, led_mask, led_values }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.feedback_id);
+        client.write_u16(self.len);
+        client.write_u8(self.key);
+        client.write_u8(self.auto_repeat_mode);
+        client.write_i8(self.key_click_percent);
+        client.write_i8(self.bell_percent);
+        client.write_i16(self.bell_pitch);
+        client.write_i16(self.bell_duration);
+        client.write_u32(self.led_mask);
+        client.write_u32(self.led_values);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct PtrFeedbackCtl {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub num: i16,
+    pub denom: i16,
+    pub threshold: i16
+}
+impl PtrFeedbackCtl {
+    pub fn read(reader: &mut XReadHelper) -> PtrFeedbackCtl {
+        let class_id = reader.read_u8();
+        let feedback_id = reader.read_u8();
+        let len = reader.read_u16();
+        reader.read_pad(2);
+        let num = reader.read_i16();
+        let denom = reader.read_i16();
+        let threshold = reader.read_i16();
+        PtrFeedbackCtl { class_id, feedback_id, len, num, denom, threshold }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.feedback_id);
+        client.write_u16(self.len);
+        client.write_pad(2);
+        client.write_i16(self.num);
+        client.write_i16(self.denom);
+        client.write_i16(self.threshold);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct IntegerFeedbackCtl {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub int_to_display: i32
+}
+impl IntegerFeedbackCtl {
+    pub fn read(reader: &mut XReadHelper) -> IntegerFeedbackCtl {
+        let class_id = reader.read_u8();
+        let feedback_id = reader.read_u8();
+        let len = reader.read_u16();
+        let int_to_display = reader.read_i32();
+        IntegerFeedbackCtl { class_id, feedback_id, len, int_to_display }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.feedback_id);
+        client.write_u16(self.len);
+        client.write_i32(self.int_to_display);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StringFeedbackCtl {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub keysyms: Vec<u32>
+}
+impl StringFeedbackCtl {
+    pub fn read(reader: &mut XReadHelper) -> StringFeedbackCtl {
+        let class_id = reader.read_u8();
+        let feedback_id = reader.read_u8();
+        let len = reader.read_u16();
+        reader.read_pad(2);
+        let num_keysyms = reader.read_u16();
+        let mut keysyms = Vec::with_capacity(num_keysyms as usize);
+        for _ in 0..num_keysyms as usize {
+            keysyms.push(reader.read_u32());
+        }
+        StringFeedbackCtl { class_id, feedback_id, len, keysyms }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.feedback_id);
+        client.write_u16(self.len);
+        client.write_pad(2);
+        client.write_u16(self.keysyms.len() as u16);
+        for value in &self.keysyms {
+            client.write_u32(*value);
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct BellFeedbackCtl {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub percent: i8,
+    pub pitch: i16,
+    pub duration: i16
+}
+impl BellFeedbackCtl {
+    pub fn read(reader: &mut XReadHelper) -> BellFeedbackCtl {
+        let class_id = reader.read_u8();
+        let feedback_id = reader.read_u8();
+        let len = reader.read_u16();
+        let percent = reader.read_u8() as i8;
+        reader.read_pad(3);
+        let pitch = reader.read_i16();
+        let duration = reader.read_i16();
+        BellFeedbackCtl { class_id, feedback_id, len, percent, pitch, duration }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.feedback_id);
+        client.write_u16(self.len);
+        client.write_i8(self.percent);
+        client.write_pad(3);
+        client.write_i16(self.pitch);
+        client.write_i16(self.duration);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct LedFeedbackCtl {
+    pub class_id: u8,
+    pub feedback_id: u8,
+    pub len: u16,
+    pub led_mask: u32,
+    pub led_values: u32
+}
+impl LedFeedbackCtl {
+    pub fn read(reader: &mut XReadHelper) -> LedFeedbackCtl {
+        let class_id = reader.read_u8();
+        let feedback_id = reader.read_u8();
+        let len = reader.read_u16();
+        let led_mask = reader.read_u32();
+        let led_values = reader.read_u32();
+        LedFeedbackCtl { class_id, feedback_id, len, led_mask, led_values }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.feedback_id);
+        client.write_u16(self.len);
+        client.write_u32(self.led_mask);
+        client.write_u32(self.led_values);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KeyState {
+    pub class_id: u8,
+    pub len: u8,
+    pub num_keys: u8,
+    pub keys: Vec<u8>
+}
+impl KeyState {
+    pub fn read(reader: &mut XReadHelper) -> KeyState {
+        let class_id = reader.read_u8();
+        let len = reader.read_u8();
+        let num_keys = reader.read_u8();
+        reader.read_pad(1);
+        let keys = reader.read_raw(32);
+        KeyState { class_id, len, num_keys, keys }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.len);
+        client.write_u8(self.num_keys);
+        client.write_pad(1);
+        client.write_raw(&self.keys);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ButtonState {
+    pub class_id: u8,
+    pub len: u8,
+    pub num_buttons: u8,
+    pub buttons: Vec<u8>
+}
+impl ButtonState {
+    pub fn read(reader: &mut XReadHelper) -> ButtonState {
+        let class_id = reader.read_u8();
+        let len = reader.read_u8();
+        let num_buttons = reader.read_u8();
+        reader.read_pad(1);
+        let buttons = reader.read_raw(32);
+        ButtonState { class_id, len, num_buttons, buttons }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.len);
+        client.write_u8(self.num_buttons);
+        client.write_pad(1);
+        client.write_raw(&self.buttons);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ValuatorState {
+    pub class_id: u8,
+    pub len: u8,
+    pub mode: u8,
+    pub valuators: Vec<i32>
+}
+impl ValuatorState {
+    pub fn read(reader: &mut XReadHelper) -> ValuatorState {
+        let class_id = reader.read_u8();
+        let len = reader.read_u8();
+        let num_valuators = reader.read_u8();
+        let mode = reader.read_u8();
+        let mut valuators = Vec::with_capacity(num_valuators as usize);
+        for _ in 0..num_valuators as usize {
+            valuators.push(reader.read_i32());
+        }
+        ValuatorState { class_id, len, mode, valuators }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.class_id);
+        client.write_u8(self.len);
+        client.write_u8(self.valuators.len() as u8);
+        client.write_u8(self.mode);
+        for value in &self.valuators {
+            client.write_i32(*value);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeviceResolutionState {
+    pub control_id: u16,
+    pub len: u16,
+    pub resolution_values: Vec<u32>,
+    pub resolution_min: Vec<u32>,
+    pub resolution_max: Vec<u32>
+}
+impl DeviceResolutionState {
+    pub fn read(reader: &mut XReadHelper) -> DeviceResolutionState {
+        let control_id = reader.read_u16();
+        let len = reader.read_u16();
+        let num_valuators = reader.read_u32();
+        let mut resolution_values = Vec::with_capacity(num_valuators as usize);
+        for _ in 0..num_valuators as usize {
+            resolution_values.push(reader.read_u32());
+        }
+        let mut resolution_min = Vec::with_capacity(num_valuators as usize);
+        for _ in 0..num_valuators as usize {
+            resolution_min.push(reader.read_u32());
+        }
+        let mut resolution_max = Vec::with_capacity(num_valuators as usize);
+        for _ in 0..num_valuators as usize {
+            resolution_max.push(reader.read_u32());
+        }
+        DeviceResolutionState { control_id, len, resolution_values, resolution_min, resolution_max }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.control_id);
+        client.write_u16(self.len);
+        client.write_u32(self.resolution_values.len() as u32);
+        for value in &self.resolution_values {
+            client.write_u32(*value);
+        }
+        for value in &self.resolution_min {
+            client.write_u32(*value);
+        }
+        for value in &self.resolution_max {
+            client.write_u32(*value);
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct DeviceAbsCalibState {
+    pub control_id: u16,
+    pub len: u16,
+    pub min_x: i32,
+    pub max_x: i32,
+    pub min_y: i32,
+    pub max_y: i32,
+    pub flip_x: u32,
+    pub flip_y: u32,
+    pub rotation: u32,
+    pub button_threshold: u32
+}
+impl DeviceAbsCalibState {
+    pub fn read(reader: &mut XReadHelper) -> DeviceAbsCalibState {
+        let control_id = reader.read_u16();
+        let len = reader.read_u16();
+        let min_x = reader.read_i32();
+        let max_x = reader.read_i32();
+        let min_y = reader.read_i32();
+        let max_y = reader.read_i32();
+        let flip_x = reader.read_u32();
+        let flip_y = reader.read_u32();
+        let rotation = reader.read_u32();
+        let button_threshold = reader.read_u32();
+        DeviceAbsCalibState { control_id, len, min_x, max_x, min_y, max_y, flip_x, flip_y, rotation, button_threshold }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.control_id);
+        client.write_u16(self.len);
+        client.write_i32(self.min_x);
+        client.write_i32(self.max_x);
+        client.write_i32(self.min_y);
+        client.write_i32(self.max_y);
+        client.write_u32(self.flip_x);
+        client.write_u32(self.flip_y);
+        client.write_u32(self.rotation);
+        client.write_u32(self.button_threshold);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct DeviceAbsAreaState {
+    pub control_id: u16,
+    pub len: u16,
+    pub offset_x: u32,
+    pub offset_y: u32,
+    pub width: u32,
+    pub height: u32,
+    pub screen: u32,
+    pub following: u32
+}
+impl DeviceAbsAreaState {
+    pub fn read(reader: &mut XReadHelper) -> DeviceAbsAreaState {
+        let control_id = reader.read_u16();
+        let len = reader.read_u16();
+        let offset_x = reader.read_u32();
+        let offset_y = reader.read_u32();
+        let width = reader.read_u32();
+        let height = reader.read_u32();
+        let screen = reader.read_u32();
+        let following = reader.read_u32();
+        DeviceAbsAreaState { control_id, len, offset_x, offset_y, width, height, screen, following }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.control_id);
+        client.write_u16(self.len);
+        client.write_u32(self.offset_x);
+        client.write_u32(self.offset_y);
+        client.write_u32(self.width);
+        client.write_u32(self.height);
+        client.write_u32(self.screen);
+        client.write_u32(self.following);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct DeviceCoreState {
+    pub control_id: u16,
+    pub len: u16,
+    pub status: u8,
+    pub iscore: u8
+}
+impl DeviceCoreState {
+    pub fn read(reader: &mut XReadHelper) -> DeviceCoreState {
+        let control_id = reader.read_u16();
+        let len = reader.read_u16();
+        let status = reader.read_u8();
+        let iscore = reader.read_u8();
+        reader.read_pad(2);
+        DeviceCoreState { control_id, len, status, iscore }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.control_id);
+        client.write_u16(self.len);
+        client.write_u8(self.status);
+        client.write_u8(self.iscore);
+        client.write_pad(2);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct DeviceEnableState {
+    pub control_id: u16,
+    pub len: u16,
+    pub enable: u8
+}
+impl DeviceEnableState {
+    pub fn read(reader: &mut XReadHelper) -> DeviceEnableState {
+        let control_id = reader.read_u16();
+        let len = reader.read_u16();
+        let enable = reader.read_u8();
+        reader.read_pad(3);
+        DeviceEnableState { control_id, len, enable }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.control_id);
+        client.write_u16(self.len);
+        client.write_u8(self.enable);
+        client.write_pad(3);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeviceResolutionCtl {
+    pub control_id: u16,
+    pub len: u16,
+    pub first_valuator: u8,
+    pub resolution_values: Vec<u32>
+}
+impl DeviceResolutionCtl {
+    pub fn read(reader: &mut XReadHelper) -> DeviceResolutionCtl {
+        let control_id = reader.read_u16();
+        let len = reader.read_u16();
+        let first_valuator = reader.read_u8();
+        let num_valuators = reader.read_u8();
+        reader.read_pad(2);
+        let mut resolution_values = Vec::with_capacity(num_valuators as usize);
+        for _ in 0..num_valuators as usize {
+            resolution_values.push(reader.read_u32());
+        }
+        DeviceResolutionCtl { control_id, len, first_valuator, resolution_values }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.control_id);
+        client.write_u16(self.len);
+        client.write_u8(self.first_valuator);
+        client.write_u8(self.resolution_values.len() as u8);
+        client.write_pad(2);
+        for value in &self.resolution_values {
+            client.write_u32(*value);
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct DeviceAbsCalibCtl {
+    pub control_id: u16,
+    pub len: u16,
+    pub min_x: i32,
+    pub max_x: i32,
+    pub min_y: i32,
+    pub max_y: i32,
+    pub flip_x: u32,
+    pub flip_y: u32,
+    pub rotation: u32,
+    pub button_threshold: u32
+}
+impl DeviceAbsCalibCtl {
+    pub fn read(reader: &mut XReadHelper) -> DeviceAbsCalibCtl {
+        let control_id = reader.read_u16();
+        let len = reader.read_u16();
+        let min_x = reader.read_i32();
+        let max_x = reader.read_i32();
+        let min_y = reader.read_i32();
+        let max_y = reader.read_i32();
+        let flip_x = reader.read_u32();
+        let flip_y = reader.read_u32();
+        let rotation = reader.read_u32();
+        let button_threshold = reader.read_u32();
+        DeviceAbsCalibCtl { control_id, len, min_x, max_x, min_y, max_y, flip_x, flip_y, rotation, button_threshold }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.control_id);
+        client.write_u16(self.len);
+        client.write_i32(self.min_x);
+        client.write_i32(self.max_x);
+        client.write_i32(self.min_y);
+        client.write_i32(self.max_y);
+        client.write_u32(self.flip_x);
+        client.write_u32(self.flip_y);
+        client.write_u32(self.rotation);
+        client.write_u32(self.button_threshold);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct DeviceAbsAreaCtrl {
+    pub control_id: u16,
+    pub len: u16,
+    pub offset_x: u32,
+    pub offset_y: u32,
+    pub width: i32,
+    pub height: i32,
+    pub screen: i32,
+    pub following: u32
+}
+impl DeviceAbsAreaCtrl {
+    pub fn read(reader: &mut XReadHelper) -> DeviceAbsAreaCtrl {
+        let control_id = reader.read_u16();
+        let len = reader.read_u16();
+        let offset_x = reader.read_u32();
+        let offset_y = reader.read_u32();
+        let width = reader.read_i32();
+        let height = reader.read_i32();
+        let screen = reader.read_i32();
+        let following = reader.read_u32();
+        DeviceAbsAreaCtrl { control_id, len, offset_x, offset_y, width, height, screen, following }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.control_id);
+        client.write_u16(self.len);
+        client.write_u32(self.offset_x);
+        client.write_u32(self.offset_y);
+        client.write_i32(self.width);
+        client.write_i32(self.height);
+        client.write_i32(self.screen);
+        client.write_u32(self.following);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct DeviceCoreCtrl {
+    pub control_id: u16,
+    pub len: u16,
+    pub status: u8
+}
+impl DeviceCoreCtrl {
+    pub fn read(reader: &mut XReadHelper) -> DeviceCoreCtrl {
+        let control_id = reader.read_u16();
+        let len = reader.read_u16();
+        let status = reader.read_u8();
+        reader.read_pad(3);
+        DeviceCoreCtrl { control_id, len, status }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.control_id);
+        client.write_u16(self.len);
+        client.write_u8(self.status);
+        client.write_pad(3);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct DeviceEnableCtrl {
+    pub control_id: u16,
+    pub len: u16,
+    pub enable: u8
+}
+impl DeviceEnableCtrl {
+    pub fn read(reader: &mut XReadHelper) -> DeviceEnableCtrl {
+        let control_id = reader.read_u16();
+        let len = reader.read_u16();
+        let enable = reader.read_u8();
+        reader.read_pad(3);
+        DeviceEnableCtrl { control_id, len, enable }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.control_id);
+        client.write_u16(self.len);
+        client.write_u8(self.enable);
+        client.write_pad(3);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GroupInfo {
+    pub base: u8,
+    pub latched: u8,
+    pub locked: u8,
+    pub effective: u8
+}
+impl GroupInfo {
+    pub fn read(reader: &mut XReadHelper) -> GroupInfo {
+        let base = reader.read_u8();
+        let latched = reader.read_u8();
+        let locked = reader.read_u8();
+        let effective = reader.read_u8();
+        GroupInfo { base, latched, locked, effective }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.base);
+        client.write_u8(self.latched);
+        client.write_u8(self.locked);
+        client.write_u8(self.effective);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ModifierInfo {
+    pub base: u32,
+    pub latched: u32,
+    pub locked: u32,
+    pub effective: u32
+}
+impl ModifierInfo {
+    pub fn read(reader: &mut XReadHelper) -> ModifierInfo {
+        let base = reader.read_u32();
+        let latched = reader.read_u32();
+        let locked = reader.read_u32();
+        let effective = reader.read_u32();
+        ModifierInfo { base, latched, locked, effective }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.base);
+        client.write_u32(self.latched);
+        client.write_u32(self.locked);
+        client.write_u32(self.effective);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AddMaster {
+    pub kind: u16,
+    pub len: u16,
+    pub send_core: bool,
+    pub enable: bool,
+    pub name: String
+}
+impl AddMaster {
+    pub fn read(reader: &mut XReadHelper) -> AddMaster {
+        let kind = reader.read_u16();
+        let len = reader.read_u16();
+        let name_len = reader.read_u16();
+        let send_core = reader.read_bool();
+        let enable = reader.read_bool();
+        let name = reader.read_str(name_len as usize);
+        reader.read_pad((4 - (8 + name.len()) % 4) % 4);
+        AddMaster { kind, len, send_core, enable, name }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.kind);
+        client.write_u16(self.len);
+        client.write_u16(self.name.len() as u16);
+        client.write_bool(self.send_core);
+        client.write_bool(self.enable);
+        client.write_str(&self.name);
+        client.write_pad_op((4 - (8 + self.name.len()) % 4) % 4);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct RemoveMaster {
+    pub kind: u16,
+    pub len: u16,
+    pub deviceid: u16,
+    pub return_mode: u8,
+    pub return_pointer: u16,
+    pub return_keyboard: u16
+}
+impl RemoveMaster {
+    pub fn read(reader: &mut XReadHelper) -> RemoveMaster {
+        let kind = reader.read_u16();
+        let len = reader.read_u16();
+        let deviceid = reader.read_u16();
+        let return_mode = reader.read_u8();
+        reader.read_pad(1);
+        let return_pointer = reader.read_u16();
+        let return_keyboard = reader.read_u16();
+        RemoveMaster { kind, len, deviceid, return_mode, return_pointer, return_keyboard }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.kind);
+        client.write_u16(self.len);
+        client.write_u16(self.deviceid);
+        client.write_u8(self.return_mode);
+        client.write_pad(1);
+        client.write_u16(self.return_pointer);
+        client.write_u16(self.return_keyboard);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct AttachSlave {
+    pub kind: u16,
+    pub len: u16,
+    pub deviceid: u16,
+    pub master: u16
+}
+impl AttachSlave {
+    pub fn read(reader: &mut XReadHelper) -> AttachSlave {
+        let kind = reader.read_u16();
+        let len = reader.read_u16();
+        let deviceid = reader.read_u16();
+        let master = reader.read_u16();
+        AttachSlave { kind, len, deviceid, master }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.kind);
+        client.write_u16(self.len);
+        client.write_u16(self.deviceid);
+        client.write_u16(self.master);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct DetachSlave {
+    pub kind: u16,
+    pub len: u16,
+    pub deviceid: u16
+}
+impl DetachSlave {
+    pub fn read(reader: &mut XReadHelper) -> DetachSlave {
+        let kind = reader.read_u16();
+        let len = reader.read_u16();
+        let deviceid = reader.read_u16();
+        reader.read_pad(2);
+        DetachSlave { kind, len, deviceid }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.kind);
+        client.write_u16(self.len);
+        client.write_u16(self.deviceid);
+        client.write_pad(2);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EventMask {
+    pub deviceid: u16,
+    pub mask: Vec<u32>
+}
+impl EventMask {
+    pub fn read(reader: &mut XReadHelper) -> EventMask {
+        let deviceid = reader.read_u16();
+        let mask_len = reader.read_u16();
+        let mut mask = Vec::with_capacity(mask_len as usize);
+        for _ in 0..mask_len as usize {
+            mask.push(reader.read_u32());
+        }
+        EventMask { deviceid, mask }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.deviceid);
+        client.write_u16(self.mask.len() as u16);
+        for value in &self.mask {
+            client.write_u32(*value);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ButtonClass {
+    pub kind: u16,
+    pub len: u16,
+    pub sourceid: u16,
+    pub state: Vec<u32>,
+    pub labels: Vec<u32>
+}
+impl ButtonClass {
+    pub fn read(reader: &mut XReadHelper) -> ButtonClass {
+        let kind = reader.read_u16();
+        let len = reader.read_u16();
+        let sourceid = reader.read_u16();
+        let num_buttons = reader.read_u16();
+        let mut state = Vec::with_capacity((num_buttons as usize).div_ceil(32));
+        for _ in 0..(num_buttons as usize).div_ceil(32) {
+            state.push(reader.read_u32());
+        }
+        let mut labels = Vec::with_capacity(num_buttons as usize);
+        for _ in 0..num_buttons as usize {
+            labels.push(reader.read_u32());
+        }
+        ButtonClass { kind, len, sourceid, state, labels }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.kind);
+        client.write_u16(self.len);
+        client.write_u16(self.sourceid);
+        client.write_u16(self.labels.len() as u16);
+        for value in &self.state {
+            client.write_u32(*value);
+        }
+        for value in &self.labels {
+            client.write_u32(*value);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KeyClass {
+    pub kind: u16,
+    pub len: u16,
+    pub sourceid: u16,
+    pub keys: Vec<u32>
+}
+impl KeyClass {
+    pub fn read(reader: &mut XReadHelper) -> KeyClass {
+        let kind = reader.read_u16();
+        let len = reader.read_u16();
+        let sourceid = reader.read_u16();
+        let num_keys = reader.read_u16();
+        let mut keys = Vec::with_capacity(num_keys as usize);
+        for _ in 0..num_keys as usize {
+            keys.push(reader.read_u32());
+        }
+        KeyClass { kind, len, sourceid, keys }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.kind);
+        client.write_u16(self.len);
+        client.write_u16(self.sourceid);
+        client.write_u16(self.keys.len() as u16);
+        for value in &self.keys {
+            client.write_u32(*value);
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ScrollClass {
+    pub kind: u16,
+    pub len: u16,
+    pub sourceid: u16,
+    pub number: u16,
+    pub scroll_type: u16,
+    pub flags: u32,
+    pub increment: f64
+}
+impl ScrollClass {
+    pub fn read(reader: &mut XReadHelper) -> ScrollClass {
+        let kind = reader.read_u16();
+        let len = reader.read_u16();
+        let sourceid = reader.read_u16();
+        let number = reader.read_u16();
+        let scroll_type = reader.read_u16();
+        reader.read_pad(2);
+        let flags = reader.read_u32();
+        let increment = reader.read_fp3232();
+        ScrollClass { kind, len, sourceid, number, scroll_type, flags, increment }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.kind);
+        client.write_u16(self.len);
+        client.write_u16(self.sourceid);
+        client.write_u16(self.number);
+        client.write_u16(self.scroll_type);
+        client.write_pad(2);
+        client.write_u32(self.flags);
+        client.write_fp3232(self.increment);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct TouchClass {
+    pub kind: u16,
+    pub len: u16,
+    pub sourceid: u16,
+    pub mode: u8,
+    pub num_touches: u8
+}
+impl TouchClass {
+    pub fn read(reader: &mut XReadHelper) -> TouchClass {
+        let kind = reader.read_u16();
+        let len = reader.read_u16();
+        let sourceid = reader.read_u16();
+        let mode = reader.read_u8();
+        let num_touches = reader.read_u8();
+        TouchClass { kind, len, sourceid, mode, num_touches }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.kind);
+        client.write_u16(self.len);
+        client.write_u16(self.sourceid);
+        client.write_u8(self.mode);
+        client.write_u8(self.num_touches);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ValuatorClass {
+    pub kind: u16,
+    pub len: u16,
+    pub sourceid: u16,
+    pub number: u16,
+    pub label: u32,
+    pub min: f64,
+    pub max: f64,
+    pub value: f64,
+    pub resolution: u32,
+    pub mode: u8
+}
+impl ValuatorClass {
+    pub fn read(reader: &mut XReadHelper) -> ValuatorClass {
+        let kind = reader.read_u16();
+        let len = reader.read_u16();
+        let sourceid = reader.read_u16();
+        let number = reader.read_u16();
+        let label = reader.read_u32();
+        let min = reader.read_fp3232();
+        let max = reader.read_fp3232();
+        let value = reader.read_fp3232();
+        let resolution = reader.read_u32();
+        let mode = reader.read_u8();
+        reader.read_pad(3);
+        ValuatorClass { kind, len, sourceid, number, label, min, max, value, resolution, mode }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.kind);
+        client.write_u16(self.len);
+        client.write_u16(self.sourceid);
+        client.write_u16(self.number);
+        client.write_u32(self.label);
+        client.write_fp3232(self.min);
+        client.write_fp3232(self.max);
+        client.write_fp3232(self.value);
+        client.write_u32(self.resolution);
+        client.write_u8(self.mode);
+        client.write_pad(3);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GrabModifierInfo {
+    pub modifiers: u32,
+    pub status: u8
+}
+impl GrabModifierInfo {
+    pub fn read(reader: &mut XReadHelper) -> GrabModifierInfo {
+        let modifiers = reader.read_u32();
+        let status = reader.read_u8();
+        reader.read_pad(3);
+        GrabModifierInfo { modifiers, status }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.modifiers);
+        client.write_u8(self.status);
+        client.write_pad(3);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct BarrierReleasePointerInfo {
+    pub deviceid: u16,
+    pub barrier: u32,
+    pub eventid: u32
+}
+impl BarrierReleasePointerInfo {
+    pub fn read(reader: &mut XReadHelper) -> BarrierReleasePointerInfo {
+        let deviceid = reader.read_u16();
+        reader.read_pad(2);
+        let barrier = reader.read_u32();
+        let eventid = reader.read_u32();
+        BarrierReleasePointerInfo { deviceid, barrier, eventid }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.deviceid);
+        client.write_pad(2);
+        client.write_u32(self.barrier);
+        client.write_u32(self.eventid);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct HierarchyInfo {
+    pub deviceid: u16,
+    pub attachment: u16,
+    pub kind: u8,
+    pub enabled: bool,
+    pub flags: u32
+}
+impl HierarchyInfo {
+    pub fn read(reader: &mut XReadHelper) -> HierarchyInfo {
+        let deviceid = reader.read_u16();
+        let attachment = reader.read_u16();
+        let kind = reader.read_u8();
+        let enabled = reader.read_bool();
+        reader.read_pad(2);
+        let flags = reader.read_u32();
+        HierarchyInfo { deviceid, attachment, kind, enabled, flags }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.deviceid);
+        client.write_u16(self.attachment);
+        client.write_u8(self.kind);
+        client.write_bool(self.enabled);
+        client.write_pad(2);
+        client.write_u32(self.flags);
+    }
+}
+
+#[derive(Debug)]
+pub enum XInputReply {
+    GetExtensionVersion {
+        xi_reply_type: u8,
+        server_major: u16,
+        server_minor: u16,
+        present: bool
+    },
+    OpenDevice {
+        xi_reply_type: u8,
+        class_info: Vec<InputClassInfo>
+    },
+    SetDeviceMode {
+        xi_reply_type: u8,
+        status: u8
+    },
+    GetSelectedExtensionEvents {
+        xi_reply_type: u8,
+        this_classes: Vec<u32>,
+        all_classes: Vec<u32>
+    },
+    GetDeviceDontPropagateList {
+        xi_reply_type: u8,
+        classes: Vec<u32>
+    },
+    ChangeKeyboardDevice {
+        xi_reply_type: u8,
+        status: u8
+    },
+    ChangePointerDevice {
+        xi_reply_type: u8,
+        status: u8
+    },
+    GrabDevice {
+        xi_reply_type: u8,
+        status: u8
+    },
+    GetDeviceFocus {
+        xi_reply_type: u8,
+        focus: u32,
+        time: u32,
+        revert_to: u8
+    },
+    GetDeviceKeyMapping {
+        xi_reply_type: u8,
+        keysyms_per_keycode: u8,
+        keysyms: Vec<u32>
+    },
+    GetDeviceModifierMapping {
+        xi_reply_type: u8,
+        keycodes_per_modifier: u8,
+        keymaps: Vec<u8>
+    },
+    SetDeviceModifierMapping {
+        xi_reply_type: u8,
+        status: u8
+    },
+    GetDeviceButtonMapping {
+        xi_reply_type: u8,
+        map: Vec<u8>
+    },
+    SetDeviceButtonMapping {
+        xi_reply_type: u8,
+        status: u8
+    },
+    SetDeviceValuators {
+        xi_reply_type: u8,
+        status: u8
+    },
+    ListDeviceProperties {
+        xi_reply_type: u8,
+        atoms: Vec<u32>
+    },
+    XIQueryPointer {
+        root: u32,
+        child: u32,
+        root_x: f64,
+        root_y: f64,
+        win_x: f64,
+        win_y: f64,
+        same_screen: bool,
+        mods: ModifierInfo,
+        group: GroupInfo,
+        buttons: Vec<u32>
+    },
+    XIGetClientPointer {
+        set: bool,
+        deviceid: u16
+    },
+    XIQueryVersion {
+        major_version: u16,
+        minor_version: u16
+    },
+    XIGetFocus {
+        focus: u32
+    },
+    XIGrabDevice {
+        status: u8
+    },
+    XIPassiveGrabDevice {
+        modifiers: Vec<GrabModifierInfo>
+    },
+    XIListProperties {
+        properties: Vec<u32>
+    },
+    XIGetSelectedEvents {
+        masks: Vec<EventMask>
+    },
+    XIQueryDevice(XIQueryDeviceReply)
+}
+
+#[derive(Debug)]
+pub enum XInputEvent {
+    DeviceValuator {
+        device_id: u8,
+        device_state: u16,
+        num_valuators: u8,
+        first_valuator: u8,
+        valuators: Vec<i32>
+    },
+    DeviceKeyPress {
+        detail: u8,
+        time: u32,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: i16,
+        root_y: i16,
+        event_x: i16,
+        event_y: i16,
+        state: u16,
+        same_screen: bool,
+        device_id: u8
+    },
+    DeviceFocusIn {
+        detail: u8,
+        time: u32,
+        window: u32,
+        mode: u8,
+        device_id: u8
+    },
+    DeviceStateNotify {
+        device_id: u8,
+        time: u32,
+        num_keys: u8,
+        num_buttons: u8,
+        num_valuators: u8,
+        classes_reported: u8,
+        buttons: Vec<u8>,
+        keys: Vec<u8>,
+        valuators: Vec<u32>
+    },
+    DeviceMappingNotify {
+        device_id: u8,
+        request: u8,
+        first_keycode: u8,
+        count: u8,
+        time: u32
+    },
+    ChangeDeviceNotify {
+        device_id: u8,
+        time: u32,
+        request: ChangeDevice
+    },
+    DeviceKeyStateNotify {
+        device_id: u8,
+        keys: Vec<u8>
+    },
+    DeviceButtonStateNotify {
+        device_id: u8,
+        buttons: Vec<u8>
+    },
+    DevicePresenceNotify {
+        time: u32,
+        devchange: DeviceChange,
+        device_id: u8,
+        control: u16
+    },
+    DevicePropertyNotify {
+        state: u8,
+        time: u32,
+        property: u32,
+        device_id: u8
+    },
+    KeyPress {
+        deviceid: u16,
+        time: u32,
+        detail: u32,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: f64,
+        root_y: f64,
+        event_x: f64,
+        event_y: f64,
+        sourceid: u16,
+        flags: u32,
+        mods: ModifierInfo,
+        group: GroupInfo,
+        button_mask: Vec<u32>,
+        valuator_mask: Vec<u32>,
+        axisvalues: Vec<f64>
+    },
+    ButtonPress {
+        deviceid: u16,
+        time: u32,
+        detail: u32,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: f64,
+        root_y: f64,
+        event_x: f64,
+        event_y: f64,
+        sourceid: u16,
+        flags: u32,
+        mods: ModifierInfo,
+        group: GroupInfo,
+        button_mask: Vec<u32>,
+        valuator_mask: Vec<u32>,
+        axisvalues: Vec<f64>
+    },
+    Enter {
+        deviceid: u16,
+        time: u32,
+        sourceid: u16,
+        mode: u8,
+        detail: u8,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: f64,
+        root_y: f64,
+        event_x: f64,
+        event_y: f64,
+        same_screen: bool,
+        focus: bool,
+        mods: ModifierInfo,
+        group: GroupInfo,
+        buttons: Vec<u32>
+    },
+    Hierarchy {
+        deviceid: u16,
+        time: u32,
+        flags: u32,
+        infos: Vec<HierarchyInfo>
+    },
+    Property {
+        deviceid: u16,
+        time: u32,
+        property: u32,
+        what: PropertyFlag
+    },
+    RawKeyPress {
+        deviceid: u16,
+        time: u32,
+        detail: u32,
+        sourceid: u16,
+        flags: u32,
+        valuator_mask: Vec<u32>,
+        axisvalues: Vec<f64>,
+        axisvalues_raw: Vec<f64>
+    },
+    RawButtonPress {
+        deviceid: u16,
+        time: u32,
+        detail: u32,
+        sourceid: u16,
+        flags: u32,
+        valuator_mask: Vec<u32>,
+        axisvalues: Vec<f64>,
+        axisvalues_raw: Vec<f64>
+    },
+    TouchBegin {
+        deviceid: u16,
+        time: u32,
+        detail: u32,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: f64,
+        root_y: f64,
+        event_x: f64,
+        event_y: f64,
+        sourceid: u16,
+        flags: u32,
+        mods: ModifierInfo,
+        group: GroupInfo,
+        button_mask: Vec<u32>,
+        valuator_mask: Vec<u32>,
+        axisvalues: Vec<f64>
+    },
+    TouchOwnership {
+        deviceid: u16,
+        time: u32,
+        touchid: u32,
+        root: u32,
+        event: u32,
+        child: u32,
+        sourceid: u16,
+        flags: TouchOwnershipFlags
+    },
+    RawTouchBegin {
+        deviceid: u16,
+        time: u32,
+        detail: u32,
+        sourceid: u16,
+        flags: u32,
+        valuator_mask: Vec<u32>,
+        axisvalues: Vec<f64>,
+        axisvalues_raw: Vec<f64>
+    },
+    BarrierHit {
+        deviceid: u16,
+        time: u32,
+        eventid: u32,
+        root: u32,
+        event: u32,
+        barrier: u32,
+        dtime: u32,
+        flags: u32,
+        sourceid: u16,
+        root_x: f64,
+        root_y: f64,
+        dx: f64,
+        dy: f64
+    },
+    DeviceKeyRelease {
+        detail: u8,
+        time: u32,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: i16,
+        root_y: i16,
+        event_x: i16,
+        event_y: i16,
+        state: u16,
+        same_screen: bool,
+        device_id: u8
+    },
+    DeviceButtonPress {
+        detail: u8,
+        time: u32,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: i16,
+        root_y: i16,
+        event_x: i16,
+        event_y: i16,
+        state: u16,
+        same_screen: bool,
+        device_id: u8
+    },
+    DeviceButtonRelease {
+        detail: u8,
+        time: u32,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: i16,
+        root_y: i16,
+        event_x: i16,
+        event_y: i16,
+        state: u16,
+        same_screen: bool,
+        device_id: u8
+    },
+    DeviceMotionNotify {
+        detail: u8,
+        time: u32,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: i16,
+        root_y: i16,
+        event_x: i16,
+        event_y: i16,
+        state: u16,
+        same_screen: bool,
+        device_id: u8
+    },
+    DeviceFocusOut {
+        detail: u8,
+        time: u32,
+        window: u32,
+        mode: u8,
+        device_id: u8
+    },
+    ProximityIn {
+        detail: u8,
+        time: u32,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: i16,
+        root_y: i16,
+        event_x: i16,
+        event_y: i16,
+        state: u16,
+        same_screen: bool,
+        device_id: u8
+    },
+    ProximityOut {
+        detail: u8,
+        time: u32,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: i16,
+        root_y: i16,
+        event_x: i16,
+        event_y: i16,
+        state: u16,
+        same_screen: bool,
+        device_id: u8
+    },
+    KeyRelease {
+        deviceid: u16,
+        time: u32,
+        detail: u32,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: f64,
+        root_y: f64,
+        event_x: f64,
+        event_y: f64,
+        sourceid: u16,
+        flags: u32,
+        mods: ModifierInfo,
+        group: GroupInfo,
+        button_mask: Vec<u32>,
+        valuator_mask: Vec<u32>,
+        axisvalues: Vec<f64>
+    },
+    ButtonRelease {
+        deviceid: u16,
+        time: u32,
+        detail: u32,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: f64,
+        root_y: f64,
+        event_x: f64,
+        event_y: f64,
+        sourceid: u16,
+        flags: u32,
+        mods: ModifierInfo,
+        group: GroupInfo,
+        button_mask: Vec<u32>,
+        valuator_mask: Vec<u32>,
+        axisvalues: Vec<f64>
+    },
+    Motion {
+        deviceid: u16,
+        time: u32,
+        detail: u32,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: f64,
+        root_y: f64,
+        event_x: f64,
+        event_y: f64,
+        sourceid: u16,
+        flags: u32,
+        mods: ModifierInfo,
+        group: GroupInfo,
+        button_mask: Vec<u32>,
+        valuator_mask: Vec<u32>,
+        axisvalues: Vec<f64>
+    },
+    Leave {
+        deviceid: u16,
+        time: u32,
+        sourceid: u16,
+        mode: u8,
+        detail: u8,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: f64,
+        root_y: f64,
+        event_x: f64,
+        event_y: f64,
+        same_screen: bool,
+        focus: bool,
+        mods: ModifierInfo,
+        group: GroupInfo,
+        buttons: Vec<u32>
+    },
+    FocusIn {
+        deviceid: u16,
+        time: u32,
+        sourceid: u16,
+        mode: u8,
+        detail: u8,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: f64,
+        root_y: f64,
+        event_x: f64,
+        event_y: f64,
+        same_screen: bool,
+        focus: bool,
+        mods: ModifierInfo,
+        group: GroupInfo,
+        buttons: Vec<u32>
+    },
+    FocusOut {
+        deviceid: u16,
+        time: u32,
+        sourceid: u16,
+        mode: u8,
+        detail: u8,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: f64,
+        root_y: f64,
+        event_x: f64,
+        event_y: f64,
+        same_screen: bool,
+        focus: bool,
+        mods: ModifierInfo,
+        group: GroupInfo,
+        buttons: Vec<u32>
+    },
+    RawKeyRelease {
+        deviceid: u16,
+        time: u32,
+        detail: u32,
+        sourceid: u16,
+        flags: u32,
+        valuator_mask: Vec<u32>,
+        axisvalues: Vec<f64>,
+        axisvalues_raw: Vec<f64>
+    },
+    RawButtonRelease {
+        deviceid: u16,
+        time: u32,
+        detail: u32,
+        sourceid: u16,
+        flags: u32,
+        valuator_mask: Vec<u32>,
+        axisvalues: Vec<f64>,
+        axisvalues_raw: Vec<f64>
+    },
+    RawMotion {
+        deviceid: u16,
+        time: u32,
+        detail: u32,
+        sourceid: u16,
+        flags: u32,
+        valuator_mask: Vec<u32>,
+        axisvalues: Vec<f64>,
+        axisvalues_raw: Vec<f64>
+    },
+    TouchUpdate {
+        deviceid: u16,
+        time: u32,
+        detail: u32,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: f64,
+        root_y: f64,
+        event_x: f64,
+        event_y: f64,
+        sourceid: u16,
+        flags: u32,
+        mods: ModifierInfo,
+        group: GroupInfo,
+        button_mask: Vec<u32>,
+        valuator_mask: Vec<u32>,
+        axisvalues: Vec<f64>
+    },
+    TouchEnd {
+        deviceid: u16,
+        time: u32,
+        detail: u32,
+        root: u32,
+        event: u32,
+        child: u32,
+        root_x: f64,
+        root_y: f64,
+        event_x: f64,
+        event_y: f64,
+        sourceid: u16,
+        flags: u32,
+        mods: ModifierInfo,
+        group: GroupInfo,
+        button_mask: Vec<u32>,
+        valuator_mask: Vec<u32>,
+        axisvalues: Vec<f64>
+    },
+    RawTouchUpdate {
+        deviceid: u16,
+        time: u32,
+        detail: u32,
+        sourceid: u16,
+        flags: u32,
+        valuator_mask: Vec<u32>,
+        axisvalues: Vec<f64>,
+        axisvalues_raw: Vec<f64>
+    },
+    RawTouchEnd {
+        deviceid: u16,
+        time: u32,
+        detail: u32,
+        sourceid: u16,
+        flags: u32,
+        valuator_mask: Vec<u32>,
+        axisvalues: Vec<f64>,
+        axisvalues_raw: Vec<f64>
+    },
+    BarrierLeave {
+        deviceid: u16,
+        time: u32,
+        eventid: u32,
+        root: u32,
+        event: u32,
+        barrier: u32,
+        dtime: u32,
+        flags: u32,
+        sourceid: u16,
+        root_x: f64,
+        root_y: f64,
+        dx: f64,
+        dy: f64
+    }
+}
+
+/** Decodes XInputExtension replies and events */
+#[derive(Debug, Default)]
+pub struct XInput;
+
+impl Extension for XInput {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn event_count(&self) -> u8 {
+        17
+    }
+
+    fn error_names(&self) -> &'static [&'static str] {
+        &["BadDevice", "BadEvent", "BadMode", "BadDeviceBusy", "BadClass"]
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_GET_EXTENSION_VERSION => reader.read_xinput_get_extension_version_reply(detail),
+            OP_OPEN_DEVICE => reader.read_xinput_open_device_reply(detail),
+            OP_SET_DEVICE_MODE => reader.read_xinput_set_device_mode_reply(detail),
+            OP_GET_SELECTED_EXTENSION_EVENTS => reader.read_xinput_get_selected_extension_events_reply(detail),
+            OP_GET_DEVICE_DONT_PROPAGATE_LIST => reader.read_xinput_get_device_dont_propagate_list_reply(detail),
+            OP_CHANGE_KEYBOARD_DEVICE => reader.read_xinput_change_keyboard_device_reply(detail),
+            OP_CHANGE_POINTER_DEVICE => reader.read_xinput_change_pointer_device_reply(detail),
+            OP_GRAB_DEVICE => reader.read_xinput_grab_device_reply(detail),
+            OP_GET_DEVICE_FOCUS => reader.read_xinput_get_device_focus_reply(detail),
+            OP_GET_DEVICE_KEY_MAPPING => reader.read_xinput_get_device_key_mapping_reply(detail),
+            OP_GET_DEVICE_MODIFIER_MAPPING => reader.read_xinput_get_device_modifier_mapping_reply(detail),
+            OP_SET_DEVICE_MODIFIER_MAPPING => reader.read_xinput_set_device_modifier_mapping_reply(detail),
+            OP_GET_DEVICE_BUTTON_MAPPING => reader.read_xinput_get_device_button_mapping_reply(detail),
+            OP_SET_DEVICE_BUTTON_MAPPING => reader.read_xinput_set_device_button_mapping_reply(detail),
+            OP_SET_DEVICE_VALUATORS => reader.read_xinput_set_device_valuators_reply(detail),
+            OP_LIST_DEVICE_PROPERTIES => reader.read_xinput_list_device_properties_reply(detail),
+            OP_XI_QUERY_POINTER => reader.read_xinput_xi_query_pointer_reply(detail),
+            OP_XI_GET_CLIENT_POINTER => reader.read_xinput_xi_get_client_pointer_reply(detail),
+            OP_XI_QUERY_VERSION => reader.read_xinput_xi_query_version_reply(detail),
+            OP_XI_GET_FOCUS => reader.read_xinput_xi_get_focus_reply(detail),
+            OP_XI_GRAB_DEVICE => reader.read_xinput_xi_grab_device_reply(detail),
+            OP_XI_PASSIVE_GRAB_DEVICE => reader.read_xinput_xi_passive_grab_device_reply(detail),
+            OP_XI_LIST_PROPERTIES => reader.read_xinput_xi_list_properties_reply(detail),
+            OP_XI_GET_SELECTED_EVENTS => reader.read_xinput_xi_get_selected_events_reply(detail),
+            OP_XI_QUERY_DEVICE => reader.read_xinput_xi_query_device_reply(detail),
+            _ => None
+        }
+    }
+
+    fn read_event(&self, reader: &mut XReadHelper, code: u8, detail: u8) -> Option<ServerEvent> {
+        match code {
+            EVENT_DEVICE_VALUATOR => reader.read_xinput_device_valuator_event(detail),
+            EVENT_DEVICE_KEY_PRESS => reader.read_xinput_device_key_press_event(detail),
+            EVENT_DEVICE_FOCUS_IN => reader.read_xinput_device_focus_in_event(detail),
+            EVENT_DEVICE_STATE_NOTIFY => reader.read_xinput_device_state_notify_event(detail),
+            EVENT_DEVICE_MAPPING_NOTIFY => reader.read_xinput_device_mapping_notify_event(detail),
+            EVENT_CHANGE_DEVICE_NOTIFY => reader.read_xinput_change_device_notify_event(detail),
+            EVENT_DEVICE_KEY_STATE_NOTIFY => reader.read_xinput_device_key_state_notify_event(detail),
+            EVENT_DEVICE_BUTTON_STATE_NOTIFY => reader.read_xinput_device_button_state_notify_event(detail),
+            EVENT_DEVICE_PRESENCE_NOTIFY => reader.read_xinput_device_presence_notify_event(detail),
+            EVENT_DEVICE_PROPERTY_NOTIFY => reader.read_xinput_device_property_notify_event(detail),
+            EVENT_DEVICE_KEY_RELEASE => reader.read_xinput_device_key_release_event(detail),
+            EVENT_DEVICE_BUTTON_PRESS => reader.read_xinput_device_button_press_event(detail),
+            EVENT_DEVICE_BUTTON_RELEASE => reader.read_xinput_device_button_release_event(detail),
+            EVENT_DEVICE_MOTION_NOTIFY => reader.read_xinput_device_motion_notify_event(detail),
+            EVENT_DEVICE_FOCUS_OUT => reader.read_xinput_device_focus_out_event(detail),
+            EVENT_PROXIMITY_IN => reader.read_xinput_proximity_in_event(detail),
+            EVENT_PROXIMITY_OUT => reader.read_xinput_proximity_out_event(detail),
+            _ => None
+        }
+    }
+
+    fn read_generic_event(&self, reader: &mut XReadHelper, evtype: u16) -> Option<ServerEvent> {
+        match evtype {
+            GENERIC_EVENT_KEY_PRESS => reader.read_xinput_key_press_event(),
+            GENERIC_EVENT_BUTTON_PRESS => reader.read_xinput_button_press_event(),
+            GENERIC_EVENT_ENTER => reader.read_xinput_enter_event(),
+            GENERIC_EVENT_HIERARCHY => reader.read_xinput_hierarchy_event(),
+            GENERIC_EVENT_PROPERTY => reader.read_xinput_property_event(),
+            GENERIC_EVENT_RAW_KEY_PRESS => reader.read_xinput_raw_key_press_event(),
+            GENERIC_EVENT_RAW_BUTTON_PRESS => reader.read_xinput_raw_button_press_event(),
+            GENERIC_EVENT_TOUCH_BEGIN => reader.read_xinput_touch_begin_event(),
+            GENERIC_EVENT_TOUCH_OWNERSHIP => reader.read_xinput_touch_ownership_event(),
+            GENERIC_EVENT_RAW_TOUCH_BEGIN => reader.read_xinput_raw_touch_begin_event(),
+            GENERIC_EVENT_BARRIER_HIT => reader.read_xinput_barrier_hit_event(),
+            GENERIC_EVENT_KEY_RELEASE => reader.read_xinput_key_release_event(),
+            GENERIC_EVENT_BUTTON_RELEASE => reader.read_xinput_button_release_event(),
+            GENERIC_EVENT_MOTION => reader.read_xinput_motion_event(),
+            GENERIC_EVENT_LEAVE => reader.read_xinput_leave_event(),
+            GENERIC_EVENT_FOCUS_IN => reader.read_xinput_focus_in_event(),
+            GENERIC_EVENT_FOCUS_OUT => reader.read_xinput_focus_out_event(),
+            GENERIC_EVENT_RAW_KEY_RELEASE => reader.read_xinput_raw_key_release_event(),
+            GENERIC_EVENT_RAW_BUTTON_RELEASE => reader.read_xinput_raw_button_release_event(),
+            GENERIC_EVENT_RAW_MOTION => reader.read_xinput_raw_motion_event(),
+            GENERIC_EVENT_TOUCH_UPDATE => reader.read_xinput_touch_update_event(),
+            GENERIC_EVENT_TOUCH_END => reader.read_xinput_touch_end_event(),
+            GENERIC_EVENT_RAW_TOUCH_UPDATE => reader.read_xinput_raw_touch_update_event(),
+            GENERIC_EVENT_RAW_TOUCH_END => reader.read_xinput_raw_touch_end_event(),
+            GENERIC_EVENT_BARRIER_LEAVE => reader.read_xinput_barrier_leave_event(),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the XInputExtension GetExtensionVersion request. Returns None without sending anything if the server doesn't have XInputExtension, like every request here. */
+    pub fn xinput_get_extension_version(&mut self, name: &str) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_EXTENSION_VERSION);
+        let pad = self.write_dynamic_len(2, name.len());
+        self.write_u16(name.len() as u16);
+        self.write_pad(2);
+        self.write_str(name);
+        self.write_pad_op(pad);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_EXTENSION_VERSION)))
+    }
+
+    /** Sends the XInputExtension ListInputDevices request */
+    pub fn xinput_list_input_devices(&mut self) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_LIST_INPUT_DEVICES);
+        self.write_u16(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_LIST_INPUT_DEVICES)))
+    }
+
+    /** Sends the XInputExtension OpenDevice request */
+    pub fn xinput_open_device(&mut self, device_id: u8) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_OPEN_DEVICE);
+        self.write_u16(2);
+        self.write_u8(device_id);
+        self.write_pad(3);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_OPEN_DEVICE)))
+    }
+
+    /** Sends the XInputExtension CloseDevice request */
+    pub fn xinput_close_device(&mut self, device_id: u8) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CLOSE_DEVICE);
+        self.write_u16(2);
+        self.write_u8(device_id);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension SetDeviceMode request */
+    pub fn xinput_set_device_mode(&mut self, device_id: u8, mode: &ValuatorMode) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_DEVICE_MODE);
+        self.write_u16(2);
+        self.write_u8(device_id);
+        self.write_u8(mode.val() as u8);
+        self.write_pad(2);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_SET_DEVICE_MODE)))
+    }
+
+    /** Sends the XInputExtension SelectExtensionEvent request */
+    pub fn xinput_select_extension_event(&mut self, window: u32, classes: &Vec<u32>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SELECT_EXTENSION_EVENT);
+        let pad = self.write_dynamic_len(3, classes.len() * 4);
+        self.write_u32(window);
+        self.write_u16(classes.len() as u16);
+        self.write_pad(2);
+        for value in classes {
+            self.write_u32(*value);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension GetSelectedExtensionEvents request */
+    pub fn xinput_get_selected_extension_events(&mut self, window: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_SELECTED_EXTENSION_EVENTS);
+        self.write_u16(2);
+        self.write_u32(window);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_SELECTED_EXTENSION_EVENTS)))
+    }
+
+    /** Sends the XInputExtension ChangeDeviceDontPropagateList request */
+    pub fn xinput_change_device_dont_propagate_list(&mut self, window: u32, mode: &PropagateMode, classes: &Vec<u32>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CHANGE_DEVICE_DONT_PROPAGATE_LIST);
+        let pad = self.write_dynamic_len(3, classes.len() * 4);
+        self.write_u32(window);
+        self.write_u16(classes.len() as u16);
+        self.write_u8(mode.val() as u8);
+        self.write_pad(1);
+        for value in classes {
+            self.write_u32(*value);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension GetDeviceDontPropagateList request */
+    pub fn xinput_get_device_dont_propagate_list(&mut self, window: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_DEVICE_DONT_PROPAGATE_LIST);
+        self.write_u16(2);
+        self.write_u32(window);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_DEVICE_DONT_PROPAGATE_LIST)))
+    }
+
+    /** Sends the XInputExtension GetDeviceMotionEvents request */
+    pub fn xinput_get_device_motion_events(&mut self, start: u32, stop: u32, device_id: u8) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_DEVICE_MOTION_EVENTS);
+        self.write_u16(4);
+        self.write_u32(start);
+        self.write_u32(stop);
+        self.write_u8(device_id);
+        self.write_pad(3);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_DEVICE_MOTION_EVENTS)))
+    }
+
+    /** Sends the XInputExtension ChangeKeyboardDevice request */
+    pub fn xinput_change_keyboard_device(&mut self, device_id: u8) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CHANGE_KEYBOARD_DEVICE);
+        self.write_u16(2);
+        self.write_u8(device_id);
+        self.write_pad(3);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_CHANGE_KEYBOARD_DEVICE)))
+    }
+
+    /** Sends the XInputExtension ChangePointerDevice request */
+    pub fn xinput_change_pointer_device(&mut self, x_axis: u8, y_axis: u8, device_id: u8) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CHANGE_POINTER_DEVICE);
+        self.write_u16(2);
+        self.write_u8(x_axis);
+        self.write_u8(y_axis);
+        self.write_u8(device_id);
+        self.write_pad(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_CHANGE_POINTER_DEVICE)))
+    }
+
+    /** Sends the XInputExtension GrabDevice request */
+    pub fn xinput_grab_device(&mut self, grab_window: u32, time: u32, this_device_mode: u8, other_device_mode: u8, owner_events: bool, device_id: u8, classes: &Vec<u32>) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GRAB_DEVICE);
+        let pad = self.write_dynamic_len(5, classes.len() * 4);
+        self.write_u32(grab_window);
+        self.write_u32(time);
+        self.write_u16(classes.len() as u16);
+        self.write_u8(this_device_mode);
+        self.write_u8(other_device_mode);
+        self.write_bool(owner_events);
+        self.write_u8(device_id);
+        self.write_pad(2);
+        for value in classes {
+            self.write_u32(*value);
+        }
+        self.write_pad_op(pad);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GRAB_DEVICE)))
+    }
+
+    /** Sends the XInputExtension UngrabDevice request */
+    pub fn xinput_ungrab_device(&mut self, time: u32, device_id: u8) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_UNGRAB_DEVICE);
+        self.write_u16(3);
+        self.write_u32(time);
+        self.write_u8(device_id);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension GrabDeviceKey request */
+    pub fn xinput_grab_device_key(&mut self, grab_window: u32, modifiers: u16, modifier_device: u8, grabbed_device: u8, key: u8, this_device_mode: u8, other_device_mode: u8, owner_events: bool, classes: &Vec<u32>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GRAB_DEVICE_KEY);
+        let pad = self.write_dynamic_len(5, classes.len() * 4);
+        self.write_u32(grab_window);
+        self.write_u16(classes.len() as u16);
+        self.write_u16(modifiers);
+        self.write_u8(modifier_device);
+        self.write_u8(grabbed_device);
+        self.write_u8(key);
+        self.write_u8(this_device_mode);
+        self.write_u8(other_device_mode);
+        self.write_bool(owner_events);
+        self.write_pad(2);
+        for value in classes {
+            self.write_u32(*value);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension UngrabDeviceKey request */
+    pub fn xinput_ungrab_device_key(&mut self, grab_window: u32, modifiers: u16, modifier_device: u8, key: u8, grabbed_device: u8) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_UNGRAB_DEVICE_KEY);
+        self.write_u16(4);
+        self.write_u32(grab_window);
+        self.write_u16(modifiers);
+        self.write_u8(modifier_device);
+        self.write_u8(key);
+        self.write_u8(grabbed_device);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension GrabDeviceButton request */
+    pub fn xinput_grab_device_button(&mut self, grab_window: u32, grabbed_device: u8, modifier_device: u8, modifiers: u16, this_device_mode: u8, other_device_mode: u8, button: u8, owner_events: bool, classes: &Vec<u32>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GRAB_DEVICE_BUTTON);
+        let pad = self.write_dynamic_len(5, classes.len() * 4);
+        self.write_u32(grab_window);
+        self.write_u8(grabbed_device);
+        self.write_u8(modifier_device);
+        self.write_u16(classes.len() as u16);
+        self.write_u16(modifiers);
+        self.write_u8(this_device_mode);
+        self.write_u8(other_device_mode);
+        self.write_u8(button);
+        self.write_bool(owner_events);
+        self.write_pad(2);
+        for value in classes {
+            self.write_u32(*value);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension UngrabDeviceButton request */
+    pub fn xinput_ungrab_device_button(&mut self, grab_window: u32, modifiers: u16, modifier_device: u8, button: u8, grabbed_device: u8) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_UNGRAB_DEVICE_BUTTON);
+        self.write_u16(4);
+        self.write_u32(grab_window);
+        self.write_u16(modifiers);
+        self.write_u8(modifier_device);
+        self.write_u8(button);
+        self.write_u8(grabbed_device);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension AllowDeviceEvents request */
+    pub fn xinput_allow_device_events(&mut self, time: u32, mode: &DeviceInputMode, device_id: u8) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_ALLOW_DEVICE_EVENTS);
+        self.write_u16(3);
+        self.write_u32(time);
+        self.write_u8(mode.val() as u8);
+        self.write_u8(device_id);
+        self.write_pad(2);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension GetDeviceFocus request */
+    pub fn xinput_get_device_focus(&mut self, device_id: u8) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_DEVICE_FOCUS);
+        self.write_u16(2);
+        self.write_u8(device_id);
+        self.write_pad(3);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_DEVICE_FOCUS)))
+    }
+
+    /** Sends the XInputExtension SetDeviceFocus request */
+    pub fn xinput_set_device_focus(&mut self, focus: u32, time: u32, revert_to: u8, device_id: u8) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_DEVICE_FOCUS);
+        self.write_u16(4);
+        self.write_u32(focus);
+        self.write_u32(time);
+        self.write_u8(revert_to);
+        self.write_u8(device_id);
+        self.write_pad(2);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension GetFeedbackControl request */
+    pub fn xinput_get_feedback_control(&mut self, device_id: u8) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_FEEDBACK_CONTROL);
+        self.write_u16(2);
+        self.write_u8(device_id);
+        self.write_pad(3);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_FEEDBACK_CONTROL)))
+    }
+
+    /** Sends the XInputExtension GetDeviceKeyMapping request */
+    pub fn xinput_get_device_key_mapping(&mut self, device_id: u8, first_keycode: u8, count: u8) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_DEVICE_KEY_MAPPING);
+        self.write_u16(2);
+        self.write_u8(device_id);
+        self.write_u8(first_keycode);
+        self.write_u8(count);
+        self.write_pad(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_DEVICE_KEY_MAPPING)))
+    }
+
+    /** Sends the XInputExtension ChangeDeviceKeyMapping request */
+    pub fn xinput_change_device_key_mapping(&mut self, device_id: u8, first_keycode: u8, keysyms_per_keycode: u8, keycode_count: u8, keysyms: &Vec<u32>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_CHANGE_DEVICE_KEY_MAPPING);
+        let pad = self.write_dynamic_len(2, keysyms.len() * 4);
+        self.write_u8(device_id);
+        self.write_u8(first_keycode);
+        self.write_u8(keysyms_per_keycode);
+        self.write_u8(keycode_count);
+        for value in keysyms {
+            self.write_u32(*value);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension GetDeviceModifierMapping request */
+    pub fn xinput_get_device_modifier_mapping(&mut self, device_id: u8) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_DEVICE_MODIFIER_MAPPING);
+        self.write_u16(2);
+        self.write_u8(device_id);
+        self.write_pad(3);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_DEVICE_MODIFIER_MAPPING)))
+    }
+
+    /** Sends the XInputExtension SetDeviceModifierMapping request */
+    pub fn xinput_set_device_modifier_mapping(&mut self, device_id: u8, keycodes_per_modifier: u8, keymaps: &[u8]) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_DEVICE_MODIFIER_MAPPING);
+        let pad = self.write_dynamic_len(2, keymaps.len());
+        self.write_u8(device_id);
+        self.write_u8(keycodes_per_modifier);
+        self.write_pad(2);
+        self.write_raw(keymaps);
+        self.write_pad_op(pad);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_SET_DEVICE_MODIFIER_MAPPING)))
+    }
+
+    /** Sends the XInputExtension GetDeviceButtonMapping request */
+    pub fn xinput_get_device_button_mapping(&mut self, device_id: u8) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_DEVICE_BUTTON_MAPPING);
+        self.write_u16(2);
+        self.write_u8(device_id);
+        self.write_pad(3);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_DEVICE_BUTTON_MAPPING)))
+    }
+
+    /** Sends the XInputExtension SetDeviceButtonMapping request */
+    pub fn xinput_set_device_button_mapping(&mut self, device_id: u8, map: &[u8]) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_DEVICE_BUTTON_MAPPING);
+        let pad = self.write_dynamic_len(2, map.len());
+        self.write_u8(device_id);
+        self.write_u8(map.len() as u8);
+        self.write_pad(2);
+        self.write_raw(map);
+        self.write_pad_op(pad);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_SET_DEVICE_BUTTON_MAPPING)))
+    }
+
+    /** Sends the XInputExtension QueryDeviceState request */
+    pub fn xinput_query_device_state(&mut self, device_id: u8) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_DEVICE_STATE);
+        self.write_u16(2);
+        self.write_u8(device_id);
+        self.write_pad(3);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_DEVICE_STATE)))
+    }
+
+    /** Sends the XInputExtension DeviceBell request */
+    pub fn xinput_device_bell(&mut self, device_id: u8, feedback_id: u8, feedback_class: u8, percent: i8) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_DEVICE_BELL);
+        self.write_u16(2);
+        self.write_u8(device_id);
+        self.write_u8(feedback_id);
+        self.write_u8(feedback_class);
+        self.write_i8(percent);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension SetDeviceValuators request */
+    pub fn xinput_set_device_valuators(&mut self, device_id: u8, first_valuator: u8, valuators: &Vec<i32>) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_DEVICE_VALUATORS);
+        let pad = self.write_dynamic_len(2, valuators.len() * 4);
+        self.write_u8(device_id);
+        self.write_u8(first_valuator);
+        self.write_u8(valuators.len() as u8);
+        self.write_pad(1);
+        for value in valuators {
+            self.write_i32(*value);
+        }
+        self.write_pad_op(pad);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_SET_DEVICE_VALUATORS)))
+    }
+
+    /** Sends the XInputExtension GetDeviceControl request */
+    pub fn xinput_get_device_control(&mut self, control_id: &DeviceControl, device_id: u8) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_DEVICE_CONTROL);
+        self.write_u16(2);
+        self.write_u16(control_id.val() as u16);
+        self.write_u8(device_id);
+        self.write_pad(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_DEVICE_CONTROL)))
+    }
+
+    /** Sends the XInputExtension ListDeviceProperties request */
+    pub fn xinput_list_device_properties(&mut self, device_id: u8) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_LIST_DEVICE_PROPERTIES);
+        self.write_u16(2);
+        self.write_u8(device_id);
+        self.write_pad(3);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_LIST_DEVICE_PROPERTIES)))
+    }
+
+    /** Sends the XInputExtension DeleteDeviceProperty request */
+    pub fn xinput_delete_device_property(&mut self, property: u32, device_id: u8) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_DELETE_DEVICE_PROPERTY);
+        self.write_u16(3);
+        self.write_u32(property);
+        self.write_u8(device_id);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension GetDeviceProperty request */
+    pub fn xinput_get_device_property(&mut self, property: u32, kind: u32, offset: u32, len: u32, device_id: u8, delete: bool) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_DEVICE_PROPERTY);
+        self.write_u16(6);
+        self.write_u32(property);
+        self.write_u32(kind);
+        self.write_u32(offset);
+        self.write_u32(len);
+        self.write_u8(device_id);
+        self.write_bool(delete);
+        self.write_pad(2);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_DEVICE_PROPERTY)))
+    }
+
+    /** Sends the XInputExtension XIQueryPointer request */
+    pub fn xinput_xi_query_pointer(&mut self, window: u32, deviceid: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_QUERY_POINTER);
+        self.write_u16(3);
+        self.write_u32(window);
+        self.write_u16(deviceid);
+        self.write_pad(2);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_XI_QUERY_POINTER)))
+    }
+
+    /** Sends the XInputExtension XIWarpPointer request */
+    pub fn xinput_xi_warp_pointer(&mut self, src_win: u32, dst_win: u32, src_x: f64, src_y: f64, src_width: u16, src_height: u16, dst_x: f64, dst_y: f64, deviceid: u16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_WARP_POINTER);
+        self.write_u16(9);
+        self.write_u32(src_win);
+        self.write_u32(dst_win);
+        self.write_fp1616(src_x);
+        self.write_fp1616(src_y);
+        self.write_u16(src_width);
+        self.write_u16(src_height);
+        self.write_fp1616(dst_x);
+        self.write_fp1616(dst_y);
+        self.write_u16(deviceid);
+        self.write_pad(2);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension XIChangeCursor request */
+    pub fn xinput_xi_change_cursor(&mut self, window: u32, cursor: u32, deviceid: u16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_CHANGE_CURSOR);
+        self.write_u16(4);
+        self.write_u32(window);
+        self.write_u32(cursor);
+        self.write_u16(deviceid);
+        self.write_pad(2);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension XISetClientPointer request */
+    pub fn xinput_xi_set_client_pointer(&mut self, window: u32, deviceid: u16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_SET_CLIENT_POINTER);
+        self.write_u16(3);
+        self.write_u32(window);
+        self.write_u16(deviceid);
+        self.write_pad(2);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension XIGetClientPointer request */
+    pub fn xinput_xi_get_client_pointer(&mut self, window: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_GET_CLIENT_POINTER);
+        self.write_u16(2);
+        self.write_u32(window);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_XI_GET_CLIENT_POINTER)))
+    }
+
+    /** Sends the XInputExtension XIQueryVersion request. Returns None without sending anything if the server doesn't have XInputExtension, like every request here. */
+    pub fn xinput_xi_query_version(&mut self, major_version: u16, minor_version: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_QUERY_VERSION);
+        self.write_u16(2);
+        self.write_u16(major_version);
+        self.write_u16(minor_version);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_XI_QUERY_VERSION)))
+    }
+
+    /** Sends the XInputExtension XIQueryDevice request */
+    pub fn xinput_xi_query_device(&mut self, deviceid: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_QUERY_DEVICE);
+        self.write_u16(2);
+        self.write_u16(deviceid);
+        self.write_pad(2);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_XI_QUERY_DEVICE)))
+    }
+
+    /** Sends the XInputExtension XISetFocus request */
+    pub fn xinput_xi_set_focus(&mut self, window: u32, time: u32, deviceid: u16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_SET_FOCUS);
+        self.write_u16(4);
+        self.write_u32(window);
+        self.write_u32(time);
+        self.write_u16(deviceid);
+        self.write_pad(2);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension XIGetFocus request */
+    pub fn xinput_xi_get_focus(&mut self, deviceid: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_GET_FOCUS);
+        self.write_u16(2);
+        self.write_u16(deviceid);
+        self.write_pad(2);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_XI_GET_FOCUS)))
+    }
+
+    /** Sends the XInputExtension XIGrabDevice request */
+    pub fn xinput_xi_grab_device(&mut self, window: u32, time: u32, cursor: u32, deviceid: u16, mode: u8, paired_device_mode: u8, owner_events: bool, mask: &Vec<u32>) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_GRAB_DEVICE);
+        let pad = self.write_dynamic_len(6, mask.len() * 4);
+        self.write_u32(window);
+        self.write_u32(time);
+        self.write_u32(cursor);
+        self.write_u16(deviceid);
+        self.write_u8(mode);
+        self.write_u8(paired_device_mode);
+        self.write_bool(owner_events);
+        self.write_pad(1);
+        self.write_u16(mask.len() as u16);
+        for value in mask {
+            self.write_u32(*value);
+        }
+        self.write_pad_op(pad);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_XI_GRAB_DEVICE)))
+    }
+
+    /** Sends the XInputExtension XIUngrabDevice request */
+    pub fn xinput_xi_ungrab_device(&mut self, time: u32, deviceid: u16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_UNGRAB_DEVICE);
+        self.write_u16(3);
+        self.write_u32(time);
+        self.write_u16(deviceid);
+        self.write_pad(2);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension XIAllowEvents request */
+    pub fn xinput_xi_allow_events(&mut self, time: u32, deviceid: u16, event_mode: &EventMode, touchid: u32, grab_window: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_ALLOW_EVENTS);
+        self.write_u16(5);
+        self.write_u32(time);
+        self.write_u16(deviceid);
+        self.write_u8(event_mode.val() as u8);
+        self.write_pad(1);
+        self.write_u32(touchid);
+        self.write_u32(grab_window);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension XIPassiveGrabDevice request */
+    pub fn xinput_xi_passive_grab_device(&mut self, time: u32, grab_window: u32, cursor: u32, detail: u32, deviceid: u16, grab_type: &GrabType, grab_mode: &GrabMode, paired_device_mode: u8, owner_events: bool, mask: &Vec<u32>, modifiers: &Vec<u32>) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_PASSIVE_GRAB_DEVICE);
+        let pad = self.write_dynamic_len(8, mask.len() * 4 + modifiers.len() * 4);
+        self.write_u32(time);
+        self.write_u32(grab_window);
+        self.write_u32(cursor);
+        self.write_u32(detail);
+        self.write_u16(deviceid);
+        self.write_u16(modifiers.len() as u16);
+        self.write_u16(mask.len() as u16);
+        self.write_u8(grab_type.val() as u8);
+        self.write_u8(grab_mode.val() as u8);
+        self.write_u8(paired_device_mode);
+        self.write_bool(owner_events);
+        self.write_pad(2);
+        for value in mask {
+            self.write_u32(*value);
+        }
+        for value in modifiers {
+            self.write_u32(*value);
+        }
+        self.write_pad_op(pad);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_XI_PASSIVE_GRAB_DEVICE)))
+    }
+
+    /** Sends the XInputExtension XIPassiveUngrabDevice request */
+    pub fn xinput_xi_passive_ungrab_device(&mut self, grab_window: u32, detail: u32, deviceid: u16, grab_type: &GrabType, modifiers: &Vec<u32>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_PASSIVE_UNGRAB_DEVICE);
+        let pad = self.write_dynamic_len(5, modifiers.len() * 4);
+        self.write_u32(grab_window);
+        self.write_u32(detail);
+        self.write_u16(deviceid);
+        self.write_u16(modifiers.len() as u16);
+        self.write_u8(grab_type.val() as u8);
+        self.write_pad(3);
+        for value in modifiers {
+            self.write_u32(*value);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension XIListProperties request */
+    pub fn xinput_xi_list_properties(&mut self, deviceid: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_LIST_PROPERTIES);
+        self.write_u16(2);
+        self.write_u16(deviceid);
+        self.write_pad(2);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_XI_LIST_PROPERTIES)))
+    }
+
+    /** Sends the XInputExtension XIDeleteProperty request */
+    pub fn xinput_xi_delete_property(&mut self, deviceid: u16, property: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_DELETE_PROPERTY);
+        self.write_u16(3);
+        self.write_u16(deviceid);
+        self.write_pad(2);
+        self.write_u32(property);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XInputExtension XIGetProperty request */
+    pub fn xinput_xi_get_property(&mut self, deviceid: u16, delete: bool, property: u32, kind: u32, offset: u32, len: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_GET_PROPERTY);
+        self.write_u16(6);
+        self.write_u16(deviceid);
+        self.write_bool(delete);
+        self.write_pad(1);
+        self.write_u32(property);
+        self.write_u32(kind);
+        self.write_u32(offset);
+        self.write_u32(len);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_XI_GET_PROPERTY)))
+    }
+
+    /** Sends the XInputExtension XIGetSelectedEvents request */
+    pub fn xinput_xi_get_selected_events(&mut self, window: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_GET_SELECTED_EVENTS);
+        self.write_u16(2);
+        self.write_u32(window);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_XI_GET_SELECTED_EVENTS)))
+    }
+
+    /** Sends the XInputExtension XIBarrierReleasePointer request */
+    pub fn xinput_xi_barrier_release_pointer(&mut self, barriers: &Vec<BarrierReleasePointerInfo>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_BARRIER_RELEASE_POINTER);
+        let pad = self.write_dynamic_len(2, barriers.len() * 12);
+        self.write_u32(barriers.len() as u32);
+        for value in barriers {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to XInputExtension GetExtensionVersion */
+    fn read_xinput_get_extension_version_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let xi_reply_type = detail;
+        let server_major = self.read_u16();
+        let server_minor = self.read_u16();
+        let present = self.read_bool();
+        self.read_pad(19);
+        Some(ServerReply::XInput(XInputReply::GetExtensionVersion { xi_reply_type, server_major, server_minor, present }))
+    }
+
+    /** Reads a reply to XInputExtension OpenDevice */
+    fn read_xinput_open_device_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let xi_reply_type = detail;
+        let num_classes = self.read_u8();
+        self.read_pad(23);
+        let mut class_info = Vec::with_capacity(num_classes as usize);
+        for _ in 0..num_classes as usize {
+            class_info.push(InputClassInfo::read(self));
+        }
+        self.read_pad((4 - (32 + class_info.len() * 2) % 4) % 4);
+        Some(ServerReply::XInput(XInputReply::OpenDevice { xi_reply_type, class_info }))
+    }
+
+    /** Reads a reply to XInputExtension SetDeviceMode */
+    fn read_xinput_set_device_mode_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let xi_reply_type = detail;
+        let status = self.read_u8();
+        self.read_pad(23);
+        Some(ServerReply::XInput(XInputReply::SetDeviceMode { xi_reply_type, status }))
+    }
+
+    /** Reads a reply to XInputExtension GetSelectedExtensionEvents */
+    fn read_xinput_get_selected_extension_events_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let xi_reply_type = detail;
+        let num_this_classes = self.read_u16();
+        let num_all_classes = self.read_u16();
+        self.read_pad(20);
+        let mut this_classes = Vec::with_capacity(num_this_classes as usize);
+        for _ in 0..num_this_classes as usize {
+            this_classes.push(self.read_u32());
+        }
+        let mut all_classes = Vec::with_capacity(num_all_classes as usize);
+        for _ in 0..num_all_classes as usize {
+            all_classes.push(self.read_u32());
+        }
+        Some(ServerReply::XInput(XInputReply::GetSelectedExtensionEvents { xi_reply_type, this_classes, all_classes }))
+    }
+
+    /** Reads a reply to XInputExtension GetDeviceDontPropagateList */
+    fn read_xinput_get_device_dont_propagate_list_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let xi_reply_type = detail;
+        let num_classes = self.read_u16();
+        self.read_pad(22);
+        let mut classes = Vec::with_capacity(num_classes as usize);
+        for _ in 0..num_classes as usize {
+            classes.push(self.read_u32());
+        }
+        Some(ServerReply::XInput(XInputReply::GetDeviceDontPropagateList { xi_reply_type, classes }))
+    }
+
+    /** Reads a reply to XInputExtension ChangeKeyboardDevice */
+    fn read_xinput_change_keyboard_device_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let xi_reply_type = detail;
+        let status = self.read_u8();
+        self.read_pad(23);
+        Some(ServerReply::XInput(XInputReply::ChangeKeyboardDevice { xi_reply_type, status }))
+    }
+
+    /** Reads a reply to XInputExtension ChangePointerDevice */
+    fn read_xinput_change_pointer_device_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let xi_reply_type = detail;
+        let status = self.read_u8();
+        self.read_pad(23);
+        Some(ServerReply::XInput(XInputReply::ChangePointerDevice { xi_reply_type, status }))
+    }
+
+    /** Reads a reply to XInputExtension GrabDevice */
+    fn read_xinput_grab_device_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let xi_reply_type = detail;
+        let status = self.read_u8();
+        self.read_pad(23);
+        Some(ServerReply::XInput(XInputReply::GrabDevice { xi_reply_type, status }))
+    }
+
+    /** Reads a reply to XInputExtension GetDeviceFocus */
+    fn read_xinput_get_device_focus_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let xi_reply_type = detail;
+        let focus = self.read_u32();
+        let time = self.read_u32();
+        let revert_to = self.read_u8();
+        self.read_pad(15);
+        Some(ServerReply::XInput(XInputReply::GetDeviceFocus { xi_reply_type, focus, time, revert_to }))
+    }
+
+    /** Reads a reply to XInputExtension GetDeviceKeyMapping */
+    fn read_xinput_get_device_key_mapping_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let length = self.extra_length();
+        let xi_reply_type = detail;
+        let keysyms_per_keycode = self.read_u8();
+        self.read_pad(23);
+        let mut keysyms = Vec::with_capacity(length as usize);
+        for _ in 0..length as usize {
+            keysyms.push(self.read_u32());
+        }
+        Some(ServerReply::XInput(XInputReply::GetDeviceKeyMapping { xi_reply_type, keysyms_per_keycode, keysyms }))
+    }
+
+    /** Reads a reply to XInputExtension GetDeviceModifierMapping */
+    fn read_xinput_get_device_modifier_mapping_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let xi_reply_type = detail;
+        let keycodes_per_modifier = self.read_u8();
+        self.read_pad(23);
+        let keymaps = self.read_raw((keycodes_per_modifier as usize) * 8);
+        Some(ServerReply::XInput(XInputReply::GetDeviceModifierMapping { xi_reply_type, keycodes_per_modifier, keymaps }))
+    }
+
+    /** Reads a reply to XInputExtension SetDeviceModifierMapping */
+    fn read_xinput_set_device_modifier_mapping_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let xi_reply_type = detail;
+        let status = self.read_u8();
+        self.read_pad(23);
+        Some(ServerReply::XInput(XInputReply::SetDeviceModifierMapping { xi_reply_type, status }))
+    }
+
+    /** Reads a reply to XInputExtension GetDeviceButtonMapping */
+    fn read_xinput_get_device_button_mapping_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let xi_reply_type = detail;
+        let map_size = self.read_u8();
+        self.read_pad(23);
+        let map = self.read_raw(map_size as usize);
+        self.read_pad((4 - (32 + map.len()) % 4) % 4);
+        Some(ServerReply::XInput(XInputReply::GetDeviceButtonMapping { xi_reply_type, map }))
+    }
+
+    /** Reads a reply to XInputExtension SetDeviceButtonMapping */
+    fn read_xinput_set_device_button_mapping_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let xi_reply_type = detail;
+        let status = self.read_u8();
+        self.read_pad(23);
+        Some(ServerReply::XInput(XInputReply::SetDeviceButtonMapping { xi_reply_type, status }))
+    }
+
+    /** Reads a reply to XInputExtension SetDeviceValuators */
+    fn read_xinput_set_device_valuators_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let xi_reply_type = detail;
+        let status = self.read_u8();
+        self.read_pad(23);
+        Some(ServerReply::XInput(XInputReply::SetDeviceValuators { xi_reply_type, status }))
+    }
+
+    /** Reads a reply to XInputExtension ListDeviceProperties */
+    fn read_xinput_list_device_properties_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let xi_reply_type = detail;
+        let num_atoms = self.read_u16();
+        self.read_pad(22);
+        let mut atoms = Vec::with_capacity(num_atoms as usize);
+        for _ in 0..num_atoms as usize {
+            atoms.push(self.read_u32());
+        }
+        Some(ServerReply::XInput(XInputReply::ListDeviceProperties { xi_reply_type, atoms }))
+    }
+
+    /** Reads a reply to XInputExtension XIQueryPointer */
+    fn read_xinput_xi_query_pointer_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let root = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_fp1616();
+        let root_y = self.read_fp1616();
+        let win_x = self.read_fp1616();
+        let win_y = self.read_fp1616();
+        let same_screen = self.read_bool();
+        self.read_pad(1);
+        let buttons_len = self.read_u16();
+        let mods = ModifierInfo::read(self);
+        let group = GroupInfo::read(self);
+        let mut buttons = Vec::with_capacity(buttons_len as usize);
+        for _ in 0..buttons_len as usize {
+            buttons.push(self.read_u32());
+        }
+        Some(ServerReply::XInput(XInputReply::XIQueryPointer { root, child, root_x, root_y, win_x, win_y, same_screen, mods, group, buttons }))
+    }
+
+    /** Reads a reply to XInputExtension XIGetClientPointer */
+    fn read_xinput_xi_get_client_pointer_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let set = self.read_bool();
+        self.read_pad(1);
+        let deviceid = self.read_u16();
+        self.read_pad(20);
+        Some(ServerReply::XInput(XInputReply::XIGetClientPointer { set, deviceid }))
+    }
+
+    /** Reads a reply to XInputExtension XIQueryVersion */
+    fn read_xinput_xi_query_version_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let major_version = self.read_u16();
+        let minor_version = self.read_u16();
+        self.read_pad(20);
+        Some(ServerReply::XInput(XInputReply::XIQueryVersion { major_version, minor_version }))
+    }
+
+    /** Reads a reply to XInputExtension XIGetFocus */
+    fn read_xinput_xi_get_focus_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let focus = self.read_u32();
+        self.read_pad(20);
+        Some(ServerReply::XInput(XInputReply::XIGetFocus { focus }))
+    }
+
+    /** Reads a reply to XInputExtension XIGrabDevice */
+    fn read_xinput_xi_grab_device_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let status = self.read_u8();
+        self.read_pad(23);
+        Some(ServerReply::XInput(XInputReply::XIGrabDevice { status }))
+    }
+
+    /** Reads a reply to XInputExtension XIPassiveGrabDevice */
+    fn read_xinput_xi_passive_grab_device_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let num_modifiers = self.read_u16();
+        self.read_pad(22);
+        let mut modifiers = Vec::with_capacity(num_modifiers as usize);
+        for _ in 0..num_modifiers as usize {
+            modifiers.push(GrabModifierInfo::read(self));
+        }
+        Some(ServerReply::XInput(XInputReply::XIPassiveGrabDevice { modifiers }))
+    }
+
+    /** Reads a reply to XInputExtension XIListProperties */
+    fn read_xinput_xi_list_properties_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let num_properties = self.read_u16();
+        self.read_pad(22);
+        let mut properties = Vec::with_capacity(num_properties as usize);
+        for _ in 0..num_properties as usize {
+            properties.push(self.read_u32());
+        }
+        Some(ServerReply::XInput(XInputReply::XIListProperties { properties }))
+    }
+
+    /** Reads a reply to XInputExtension XIGetSelectedEvents */
+    fn read_xinput_xi_get_selected_events_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let num_masks = self.read_u16();
+        self.read_pad(22);
+        let mut masks = Vec::with_capacity(num_masks as usize);
+        for _ in 0..num_masks as usize {
+            masks.push(EventMask::read(self));
+        }
+        Some(ServerReply::XInput(XInputReply::XIGetSelectedEvents { masks }))
+    }
+
+    /** Reads a XInputExtension DeviceValuator event */
+    fn read_xinput_device_valuator_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let device_id = detail;
+        let device_state = self.read_u16();
+        let num_valuators = self.read_u8();
+        let first_valuator = self.read_u8();
+        let mut valuators = Vec::with_capacity(6);
+        for _ in 0..6 {
+            valuators.push(self.read_i32());
+        }
+        Some(ServerEvent::XInput(XInputEvent::DeviceValuator { device_id, device_state, num_valuators, first_valuator, valuators }))
+    }
+
+    /** Reads a XInputExtension DeviceKeyPress event */
+    fn read_xinput_device_key_press_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let time = self.read_u32();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_i16();
+        let root_y = self.read_i16();
+        let event_x = self.read_i16();
+        let event_y = self.read_i16();
+        let state = self.read_u16();
+        let same_screen = self.read_bool();
+        let device_id = self.read_u8();
+        Some(ServerEvent::XInput(XInputEvent::DeviceKeyPress { detail, time, root, event, child, root_x, root_y, event_x, event_y, state, same_screen, device_id }))
+    }
+
+    /** Reads a XInputExtension DeviceFocusIn event */
+    fn read_xinput_device_focus_in_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let time = self.read_u32();
+        let window = self.read_u32();
+        let mode = self.read_u8();
+        let device_id = self.read_u8();
+        self.read_pad(18);
+        Some(ServerEvent::XInput(XInputEvent::DeviceFocusIn { detail, time, window, mode, device_id }))
+    }
+
+    /** Reads a XInputExtension DeviceStateNotify event */
+    fn read_xinput_device_state_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let device_id = detail;
+        let time = self.read_u32();
+        let num_keys = self.read_u8();
+        let num_buttons = self.read_u8();
+        let num_valuators = self.read_u8();
+        let classes_reported = self.read_u8();
+        let buttons = self.read_raw(4);
+        let keys = self.read_raw(4);
+        let mut valuators = Vec::with_capacity(3);
+        for _ in 0..3 {
+            valuators.push(self.read_u32());
+        }
+        Some(ServerEvent::XInput(XInputEvent::DeviceStateNotify { device_id, time, num_keys, num_buttons, num_valuators, classes_reported, buttons, keys, valuators }))
+    }
+
+    /** Reads a XInputExtension DeviceMappingNotify event */
+    fn read_xinput_device_mapping_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let device_id = detail;
+        let request = self.read_u8();
+        let first_keycode = self.read_u8();
+        let count = self.read_u8();
+        self.read_pad(1);
+        let time = self.read_u32();
+        self.read_pad(20);
+        Some(ServerEvent::XInput(XInputEvent::DeviceMappingNotify { device_id, request, first_keycode, count, time }))
+    }
+
+    /** Reads a XInputExtension ChangeDeviceNotify event */
+    fn read_xinput_change_device_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let device_id = detail;
+        let time = self.read_u32();
+        let request = self.read_u8();
+        let request = match ChangeDevice::get(request as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        self.read_pad(23);
+        Some(ServerEvent::XInput(XInputEvent::ChangeDeviceNotify { device_id, time, request }))
+    }
+
+    /** Reads a XInputExtension DeviceKeyStateNotify event */
+    fn read_xinput_device_key_state_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let device_id = detail;
+        let keys = self.read_raw(28);
+        Some(ServerEvent::XInput(XInputEvent::DeviceKeyStateNotify { device_id, keys }))
+    }
+
+    /** Reads a XInputExtension DeviceButtonStateNotify event */
+    fn read_xinput_device_button_state_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let device_id = detail;
+        let buttons = self.read_raw(28);
+        Some(ServerEvent::XInput(XInputEvent::DeviceButtonStateNotify { device_id, buttons }))
+    }
+
+    /** Reads a XInputExtension DevicePresenceNotify event */
+    fn read_xinput_device_presence_notify_event(&mut self, _detail: u8) -> Option<ServerEvent> {
+        let time = self.read_u32();
+        let devchange = self.read_u8();
+        let devchange = match DeviceChange::get(devchange as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let device_id = self.read_u8();
+        let control = self.read_u16();
+        self.read_pad(20);
+        Some(ServerEvent::XInput(XInputEvent::DevicePresenceNotify { time, devchange, device_id, control }))
+    }
+
+    /** Reads a XInputExtension DevicePropertyNotify event */
+    fn read_xinput_device_property_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let state = detail;
+        let time = self.read_u32();
+        let property = self.read_u32();
+        self.read_pad(19);
+        let device_id = self.read_u8();
+        Some(ServerEvent::XInput(XInputEvent::DevicePropertyNotify { state, time, property, device_id }))
+    }
+
+    /** Reads a XInputExtension KeyPress event */
+    fn read_xinput_key_press_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let detail = self.read_u32();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_fp1616();
+        let root_y = self.read_fp1616();
+        let event_x = self.read_fp1616();
+        let event_y = self.read_fp1616();
+        let buttons_len = self.read_u16();
+        let valuators_len = self.read_u16();
+        let sourceid = self.read_u16();
+        self.read_pad(2);
+        let flags = self.read_u32();
+        let mods = ModifierInfo::read(self);
+        let group = GroupInfo::read(self);
+        let mut button_mask = Vec::with_capacity(buttons_len as usize);
+        for _ in 0..buttons_len as usize {
+            button_mask.push(self.read_u32());
+        }
+        let mut valuator_mask = Vec::with_capacity(valuators_len as usize);
+        for _ in 0..valuators_len as usize {
+            valuator_mask.push(self.read_u32());
+        }
+        let mut axisvalues = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues.push(self.read_fp3232());
+        }
+        Some(ServerEvent::XInput(XInputEvent::KeyPress { deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, sourceid, flags, mods, group, button_mask, valuator_mask, axisvalues }))
+    }
+
+    /** Reads a XInputExtension ButtonPress event */
+    fn read_xinput_button_press_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let detail = self.read_u32();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_fp1616();
+        let root_y = self.read_fp1616();
+        let event_x = self.read_fp1616();
+        let event_y = self.read_fp1616();
+        let buttons_len = self.read_u16();
+        let valuators_len = self.read_u16();
+        let sourceid = self.read_u16();
+        self.read_pad(2);
+        let flags = self.read_u32();
+        let mods = ModifierInfo::read(self);
+        let group = GroupInfo::read(self);
+        let mut button_mask = Vec::with_capacity(buttons_len as usize);
+        for _ in 0..buttons_len as usize {
+            button_mask.push(self.read_u32());
+        }
+        let mut valuator_mask = Vec::with_capacity(valuators_len as usize);
+        for _ in 0..valuators_len as usize {
+            valuator_mask.push(self.read_u32());
+        }
+        let mut axisvalues = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues.push(self.read_fp3232());
+        }
+        Some(ServerEvent::XInput(XInputEvent::ButtonPress { deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, sourceid, flags, mods, group, button_mask, valuator_mask, axisvalues }))
+    }
+
+    /** Reads a XInputExtension Enter event */
+    fn read_xinput_enter_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let sourceid = self.read_u16();
+        let mode = self.read_u8();
+        let detail = self.read_u8();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_fp1616();
+        let root_y = self.read_fp1616();
+        let event_x = self.read_fp1616();
+        let event_y = self.read_fp1616();
+        let same_screen = self.read_bool();
+        let focus = self.read_bool();
+        let buttons_len = self.read_u16();
+        let mods = ModifierInfo::read(self);
+        let group = GroupInfo::read(self);
+        let mut buttons = Vec::with_capacity(buttons_len as usize);
+        for _ in 0..buttons_len as usize {
+            buttons.push(self.read_u32());
+        }
+        Some(ServerEvent::XInput(XInputEvent::Enter { deviceid, time, sourceid, mode, detail, root, event, child, root_x, root_y, event_x, event_y, same_screen, focus, mods, group, buttons }))
+    }
+
+    /** Reads a XInputExtension Hierarchy event */
+    fn read_xinput_hierarchy_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let flags = self.read_u32();
+        let num_infos = self.read_u16();
+        self.read_pad(10);
+        let mut infos = Vec::with_capacity(num_infos as usize);
+        for _ in 0..num_infos as usize {
+            infos.push(HierarchyInfo::read(self));
+        }
+        Some(ServerEvent::XInput(XInputEvent::Hierarchy { deviceid, time, flags, infos }))
+    }
+
+    /** Reads a XInputExtension Property event */
+    fn read_xinput_property_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let property = self.read_u32();
+        let what = self.read_u8();
+        let what = match PropertyFlag::get(what as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        self.read_pad(11);
+        Some(ServerEvent::XInput(XInputEvent::Property { deviceid, time, property, what }))
+    }
+
+    /** Reads a XInputExtension RawKeyPress event */
+    fn read_xinput_raw_key_press_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let detail = self.read_u32();
+        let sourceid = self.read_u16();
+        let valuators_len = self.read_u16();
+        let flags = self.read_u32();
+        self.read_pad(4);
+        let mut valuator_mask = Vec::with_capacity(valuators_len as usize);
+        for _ in 0..valuators_len as usize {
+            valuator_mask.push(self.read_u32());
+        }
+        let mut axisvalues = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues.push(self.read_fp3232());
+        }
+        let mut axisvalues_raw = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues_raw.push(self.read_fp3232());
+        }
+        Some(ServerEvent::XInput(XInputEvent::RawKeyPress { deviceid, time, detail, sourceid, flags, valuator_mask, axisvalues, axisvalues_raw }))
+    }
+
+    /** Reads a XInputExtension RawButtonPress event */
+    fn read_xinput_raw_button_press_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let detail = self.read_u32();
+        let sourceid = self.read_u16();
+        let valuators_len = self.read_u16();
+        let flags = self.read_u32();
+        self.read_pad(4);
+        let mut valuator_mask = Vec::with_capacity(valuators_len as usize);
+        for _ in 0..valuators_len as usize {
+            valuator_mask.push(self.read_u32());
+        }
+        let mut axisvalues = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues.push(self.read_fp3232());
+        }
+        let mut axisvalues_raw = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues_raw.push(self.read_fp3232());
+        }
+        Some(ServerEvent::XInput(XInputEvent::RawButtonPress { deviceid, time, detail, sourceid, flags, valuator_mask, axisvalues, axisvalues_raw }))
+    }
+
+    /** Reads a XInputExtension TouchBegin event */
+    fn read_xinput_touch_begin_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let detail = self.read_u32();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_fp1616();
+        let root_y = self.read_fp1616();
+        let event_x = self.read_fp1616();
+        let event_y = self.read_fp1616();
+        let buttons_len = self.read_u16();
+        let valuators_len = self.read_u16();
+        let sourceid = self.read_u16();
+        self.read_pad(2);
+        let flags = self.read_u32();
+        let mods = ModifierInfo::read(self);
+        let group = GroupInfo::read(self);
+        let mut button_mask = Vec::with_capacity(buttons_len as usize);
+        for _ in 0..buttons_len as usize {
+            button_mask.push(self.read_u32());
+        }
+        let mut valuator_mask = Vec::with_capacity(valuators_len as usize);
+        for _ in 0..valuators_len as usize {
+            valuator_mask.push(self.read_u32());
+        }
+        let mut axisvalues = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues.push(self.read_fp3232());
+        }
+        Some(ServerEvent::XInput(XInputEvent::TouchBegin { deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, sourceid, flags, mods, group, button_mask, valuator_mask, axisvalues }))
+    }
+
+    /** Reads a XInputExtension TouchOwnership event */
+    fn read_xinput_touch_ownership_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let touchid = self.read_u32();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let sourceid = self.read_u16();
+        self.read_pad(2);
+        let flags = self.read_u32();
+        let flags = match TouchOwnershipFlags::get(flags) {
+            Some(x) => x,
+            None => return None
+        };
+        self.read_pad(8);
+        Some(ServerEvent::XInput(XInputEvent::TouchOwnership { deviceid, time, touchid, root, event, child, sourceid, flags }))
+    }
+
+    /** Reads a XInputExtension RawTouchBegin event */
+    fn read_xinput_raw_touch_begin_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let detail = self.read_u32();
+        let sourceid = self.read_u16();
+        let valuators_len = self.read_u16();
+        let flags = self.read_u32();
+        self.read_pad(4);
+        let mut valuator_mask = Vec::with_capacity(valuators_len as usize);
+        for _ in 0..valuators_len as usize {
+            valuator_mask.push(self.read_u32());
+        }
+        let mut axisvalues = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues.push(self.read_fp3232());
+        }
+        let mut axisvalues_raw = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues_raw.push(self.read_fp3232());
+        }
+        Some(ServerEvent::XInput(XInputEvent::RawTouchBegin { deviceid, time, detail, sourceid, flags, valuator_mask, axisvalues, axisvalues_raw }))
+    }
+
+    /** Reads a XInputExtension BarrierHit event */
+    fn read_xinput_barrier_hit_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let eventid = self.read_u32();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let barrier = self.read_u32();
+        let dtime = self.read_u32();
+        let flags = self.read_u32();
+        let sourceid = self.read_u16();
+        self.read_pad(2);
+        let root_x = self.read_fp1616();
+        let root_y = self.read_fp1616();
+        let dx = self.read_fp3232();
+        let dy = self.read_fp3232();
+        Some(ServerEvent::XInput(XInputEvent::BarrierHit { deviceid, time, eventid, root, event, barrier, dtime, flags, sourceid, root_x, root_y, dx, dy }))
+    }
+
+    /** Reads a XInputExtension DeviceKeyRelease event */
+    fn read_xinput_device_key_release_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let time = self.read_u32();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_i16();
+        let root_y = self.read_i16();
+        let event_x = self.read_i16();
+        let event_y = self.read_i16();
+        let state = self.read_u16();
+        let same_screen = self.read_bool();
+        let device_id = self.read_u8();
+        Some(ServerEvent::XInput(XInputEvent::DeviceKeyRelease { detail, time, root, event, child, root_x, root_y, event_x, event_y, state, same_screen, device_id }))
+    }
+
+    /** Reads a XInputExtension DeviceButtonPress event */
+    fn read_xinput_device_button_press_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let time = self.read_u32();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_i16();
+        let root_y = self.read_i16();
+        let event_x = self.read_i16();
+        let event_y = self.read_i16();
+        let state = self.read_u16();
+        let same_screen = self.read_bool();
+        let device_id = self.read_u8();
+        Some(ServerEvent::XInput(XInputEvent::DeviceButtonPress { detail, time, root, event, child, root_x, root_y, event_x, event_y, state, same_screen, device_id }))
+    }
+
+    /** Reads a XInputExtension DeviceButtonRelease event */
+    fn read_xinput_device_button_release_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let time = self.read_u32();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_i16();
+        let root_y = self.read_i16();
+        let event_x = self.read_i16();
+        let event_y = self.read_i16();
+        let state = self.read_u16();
+        let same_screen = self.read_bool();
+        let device_id = self.read_u8();
+        Some(ServerEvent::XInput(XInputEvent::DeviceButtonRelease { detail, time, root, event, child, root_x, root_y, event_x, event_y, state, same_screen, device_id }))
+    }
+
+    /** Reads a XInputExtension DeviceMotionNotify event */
+    fn read_xinput_device_motion_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let time = self.read_u32();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_i16();
+        let root_y = self.read_i16();
+        let event_x = self.read_i16();
+        let event_y = self.read_i16();
+        let state = self.read_u16();
+        let same_screen = self.read_bool();
+        let device_id = self.read_u8();
+        Some(ServerEvent::XInput(XInputEvent::DeviceMotionNotify { detail, time, root, event, child, root_x, root_y, event_x, event_y, state, same_screen, device_id }))
+    }
+
+    /** Reads a XInputExtension DeviceFocusOut event */
+    fn read_xinput_device_focus_out_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let time = self.read_u32();
+        let window = self.read_u32();
+        let mode = self.read_u8();
+        let device_id = self.read_u8();
+        self.read_pad(18);
+        Some(ServerEvent::XInput(XInputEvent::DeviceFocusOut { detail, time, window, mode, device_id }))
+    }
+
+    /** Reads a XInputExtension ProximityIn event */
+    fn read_xinput_proximity_in_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let time = self.read_u32();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_i16();
+        let root_y = self.read_i16();
+        let event_x = self.read_i16();
+        let event_y = self.read_i16();
+        let state = self.read_u16();
+        let same_screen = self.read_bool();
+        let device_id = self.read_u8();
+        Some(ServerEvent::XInput(XInputEvent::ProximityIn { detail, time, root, event, child, root_x, root_y, event_x, event_y, state, same_screen, device_id }))
+    }
+
+    /** Reads a XInputExtension ProximityOut event */
+    fn read_xinput_proximity_out_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let time = self.read_u32();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_i16();
+        let root_y = self.read_i16();
+        let event_x = self.read_i16();
+        let event_y = self.read_i16();
+        let state = self.read_u16();
+        let same_screen = self.read_bool();
+        let device_id = self.read_u8();
+        Some(ServerEvent::XInput(XInputEvent::ProximityOut { detail, time, root, event, child, root_x, root_y, event_x, event_y, state, same_screen, device_id }))
+    }
+
+    /** Reads a XInputExtension KeyRelease event */
+    fn read_xinput_key_release_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let detail = self.read_u32();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_fp1616();
+        let root_y = self.read_fp1616();
+        let event_x = self.read_fp1616();
+        let event_y = self.read_fp1616();
+        let buttons_len = self.read_u16();
+        let valuators_len = self.read_u16();
+        let sourceid = self.read_u16();
+        self.read_pad(2);
+        let flags = self.read_u32();
+        let mods = ModifierInfo::read(self);
+        let group = GroupInfo::read(self);
+        let mut button_mask = Vec::with_capacity(buttons_len as usize);
+        for _ in 0..buttons_len as usize {
+            button_mask.push(self.read_u32());
+        }
+        let mut valuator_mask = Vec::with_capacity(valuators_len as usize);
+        for _ in 0..valuators_len as usize {
+            valuator_mask.push(self.read_u32());
+        }
+        let mut axisvalues = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues.push(self.read_fp3232());
+        }
+        Some(ServerEvent::XInput(XInputEvent::KeyRelease { deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, sourceid, flags, mods, group, button_mask, valuator_mask, axisvalues }))
+    }
+
+    /** Reads a XInputExtension ButtonRelease event */
+    fn read_xinput_button_release_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let detail = self.read_u32();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_fp1616();
+        let root_y = self.read_fp1616();
+        let event_x = self.read_fp1616();
+        let event_y = self.read_fp1616();
+        let buttons_len = self.read_u16();
+        let valuators_len = self.read_u16();
+        let sourceid = self.read_u16();
+        self.read_pad(2);
+        let flags = self.read_u32();
+        let mods = ModifierInfo::read(self);
+        let group = GroupInfo::read(self);
+        let mut button_mask = Vec::with_capacity(buttons_len as usize);
+        for _ in 0..buttons_len as usize {
+            button_mask.push(self.read_u32());
+        }
+        let mut valuator_mask = Vec::with_capacity(valuators_len as usize);
+        for _ in 0..valuators_len as usize {
+            valuator_mask.push(self.read_u32());
+        }
+        let mut axisvalues = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues.push(self.read_fp3232());
+        }
+        Some(ServerEvent::XInput(XInputEvent::ButtonRelease { deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, sourceid, flags, mods, group, button_mask, valuator_mask, axisvalues }))
+    }
+
+    /** Reads a XInputExtension Motion event */
+    fn read_xinput_motion_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let detail = self.read_u32();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_fp1616();
+        let root_y = self.read_fp1616();
+        let event_x = self.read_fp1616();
+        let event_y = self.read_fp1616();
+        let buttons_len = self.read_u16();
+        let valuators_len = self.read_u16();
+        let sourceid = self.read_u16();
+        self.read_pad(2);
+        let flags = self.read_u32();
+        let mods = ModifierInfo::read(self);
+        let group = GroupInfo::read(self);
+        let mut button_mask = Vec::with_capacity(buttons_len as usize);
+        for _ in 0..buttons_len as usize {
+            button_mask.push(self.read_u32());
+        }
+        let mut valuator_mask = Vec::with_capacity(valuators_len as usize);
+        for _ in 0..valuators_len as usize {
+            valuator_mask.push(self.read_u32());
+        }
+        let mut axisvalues = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues.push(self.read_fp3232());
+        }
+        Some(ServerEvent::XInput(XInputEvent::Motion { deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, sourceid, flags, mods, group, button_mask, valuator_mask, axisvalues }))
+    }
+
+    /** Reads a XInputExtension Leave event */
+    fn read_xinput_leave_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let sourceid = self.read_u16();
+        let mode = self.read_u8();
+        let detail = self.read_u8();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_fp1616();
+        let root_y = self.read_fp1616();
+        let event_x = self.read_fp1616();
+        let event_y = self.read_fp1616();
+        let same_screen = self.read_bool();
+        let focus = self.read_bool();
+        let buttons_len = self.read_u16();
+        let mods = ModifierInfo::read(self);
+        let group = GroupInfo::read(self);
+        let mut buttons = Vec::with_capacity(buttons_len as usize);
+        for _ in 0..buttons_len as usize {
+            buttons.push(self.read_u32());
+        }
+        Some(ServerEvent::XInput(XInputEvent::Leave { deviceid, time, sourceid, mode, detail, root, event, child, root_x, root_y, event_x, event_y, same_screen, focus, mods, group, buttons }))
+    }
+
+    /** Reads a XInputExtension FocusIn event */
+    fn read_xinput_focus_in_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let sourceid = self.read_u16();
+        let mode = self.read_u8();
+        let detail = self.read_u8();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_fp1616();
+        let root_y = self.read_fp1616();
+        let event_x = self.read_fp1616();
+        let event_y = self.read_fp1616();
+        let same_screen = self.read_bool();
+        let focus = self.read_bool();
+        let buttons_len = self.read_u16();
+        let mods = ModifierInfo::read(self);
+        let group = GroupInfo::read(self);
+        let mut buttons = Vec::with_capacity(buttons_len as usize);
+        for _ in 0..buttons_len as usize {
+            buttons.push(self.read_u32());
+        }
+        Some(ServerEvent::XInput(XInputEvent::FocusIn { deviceid, time, sourceid, mode, detail, root, event, child, root_x, root_y, event_x, event_y, same_screen, focus, mods, group, buttons }))
+    }
+
+    /** Reads a XInputExtension FocusOut event */
+    fn read_xinput_focus_out_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let sourceid = self.read_u16();
+        let mode = self.read_u8();
+        let detail = self.read_u8();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_fp1616();
+        let root_y = self.read_fp1616();
+        let event_x = self.read_fp1616();
+        let event_y = self.read_fp1616();
+        let same_screen = self.read_bool();
+        let focus = self.read_bool();
+        let buttons_len = self.read_u16();
+        let mods = ModifierInfo::read(self);
+        let group = GroupInfo::read(self);
+        let mut buttons = Vec::with_capacity(buttons_len as usize);
+        for _ in 0..buttons_len as usize {
+            buttons.push(self.read_u32());
+        }
+        Some(ServerEvent::XInput(XInputEvent::FocusOut { deviceid, time, sourceid, mode, detail, root, event, child, root_x, root_y, event_x, event_y, same_screen, focus, mods, group, buttons }))
+    }
+
+    /** Reads a XInputExtension RawKeyRelease event */
+    fn read_xinput_raw_key_release_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let detail = self.read_u32();
+        let sourceid = self.read_u16();
+        let valuators_len = self.read_u16();
+        let flags = self.read_u32();
+        self.read_pad(4);
+        let mut valuator_mask = Vec::with_capacity(valuators_len as usize);
+        for _ in 0..valuators_len as usize {
+            valuator_mask.push(self.read_u32());
+        }
+        let mut axisvalues = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues.push(self.read_fp3232());
+        }
+        let mut axisvalues_raw = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues_raw.push(self.read_fp3232());
+        }
+        Some(ServerEvent::XInput(XInputEvent::RawKeyRelease { deviceid, time, detail, sourceid, flags, valuator_mask, axisvalues, axisvalues_raw }))
+    }
+
+    /** Reads a XInputExtension RawButtonRelease event */
+    fn read_xinput_raw_button_release_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let detail = self.read_u32();
+        let sourceid = self.read_u16();
+        let valuators_len = self.read_u16();
+        let flags = self.read_u32();
+        self.read_pad(4);
+        let mut valuator_mask = Vec::with_capacity(valuators_len as usize);
+        for _ in 0..valuators_len as usize {
+            valuator_mask.push(self.read_u32());
+        }
+        let mut axisvalues = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues.push(self.read_fp3232());
+        }
+        let mut axisvalues_raw = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues_raw.push(self.read_fp3232());
+        }
+        Some(ServerEvent::XInput(XInputEvent::RawButtonRelease { deviceid, time, detail, sourceid, flags, valuator_mask, axisvalues, axisvalues_raw }))
+    }
+
+    /** Reads a XInputExtension RawMotion event */
+    fn read_xinput_raw_motion_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let detail = self.read_u32();
+        let sourceid = self.read_u16();
+        let valuators_len = self.read_u16();
+        let flags = self.read_u32();
+        self.read_pad(4);
+        let mut valuator_mask = Vec::with_capacity(valuators_len as usize);
+        for _ in 0..valuators_len as usize {
+            valuator_mask.push(self.read_u32());
+        }
+        let mut axisvalues = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues.push(self.read_fp3232());
+        }
+        let mut axisvalues_raw = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues_raw.push(self.read_fp3232());
+        }
+        Some(ServerEvent::XInput(XInputEvent::RawMotion { deviceid, time, detail, sourceid, flags, valuator_mask, axisvalues, axisvalues_raw }))
+    }
+
+    /** Reads a XInputExtension TouchUpdate event */
+    fn read_xinput_touch_update_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let detail = self.read_u32();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_fp1616();
+        let root_y = self.read_fp1616();
+        let event_x = self.read_fp1616();
+        let event_y = self.read_fp1616();
+        let buttons_len = self.read_u16();
+        let valuators_len = self.read_u16();
+        let sourceid = self.read_u16();
+        self.read_pad(2);
+        let flags = self.read_u32();
+        let mods = ModifierInfo::read(self);
+        let group = GroupInfo::read(self);
+        let mut button_mask = Vec::with_capacity(buttons_len as usize);
+        for _ in 0..buttons_len as usize {
+            button_mask.push(self.read_u32());
+        }
+        let mut valuator_mask = Vec::with_capacity(valuators_len as usize);
+        for _ in 0..valuators_len as usize {
+            valuator_mask.push(self.read_u32());
+        }
+        let mut axisvalues = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues.push(self.read_fp3232());
+        }
+        Some(ServerEvent::XInput(XInputEvent::TouchUpdate { deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, sourceid, flags, mods, group, button_mask, valuator_mask, axisvalues }))
+    }
+
+    /** Reads a XInputExtension TouchEnd event */
+    fn read_xinput_touch_end_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let detail = self.read_u32();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let child = self.read_u32();
+        let root_x = self.read_fp1616();
+        let root_y = self.read_fp1616();
+        let event_x = self.read_fp1616();
+        let event_y = self.read_fp1616();
+        let buttons_len = self.read_u16();
+        let valuators_len = self.read_u16();
+        let sourceid = self.read_u16();
+        self.read_pad(2);
+        let flags = self.read_u32();
+        let mods = ModifierInfo::read(self);
+        let group = GroupInfo::read(self);
+        let mut button_mask = Vec::with_capacity(buttons_len as usize);
+        for _ in 0..buttons_len as usize {
+            button_mask.push(self.read_u32());
+        }
+        let mut valuator_mask = Vec::with_capacity(valuators_len as usize);
+        for _ in 0..valuators_len as usize {
+            valuator_mask.push(self.read_u32());
+        }
+        let mut axisvalues = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues.push(self.read_fp3232());
+        }
+        Some(ServerEvent::XInput(XInputEvent::TouchEnd { deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, sourceid, flags, mods, group, button_mask, valuator_mask, axisvalues }))
+    }
+
+    /** Reads a XInputExtension RawTouchUpdate event */
+    fn read_xinput_raw_touch_update_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let detail = self.read_u32();
+        let sourceid = self.read_u16();
+        let valuators_len = self.read_u16();
+        let flags = self.read_u32();
+        self.read_pad(4);
+        let mut valuator_mask = Vec::with_capacity(valuators_len as usize);
+        for _ in 0..valuators_len as usize {
+            valuator_mask.push(self.read_u32());
+        }
+        let mut axisvalues = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues.push(self.read_fp3232());
+        }
+        let mut axisvalues_raw = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues_raw.push(self.read_fp3232());
+        }
+        Some(ServerEvent::XInput(XInputEvent::RawTouchUpdate { deviceid, time, detail, sourceid, flags, valuator_mask, axisvalues, axisvalues_raw }))
+    }
+
+    /** Reads a XInputExtension RawTouchEnd event */
+    fn read_xinput_raw_touch_end_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let detail = self.read_u32();
+        let sourceid = self.read_u16();
+        let valuators_len = self.read_u16();
+        let flags = self.read_u32();
+        self.read_pad(4);
+        let mut valuator_mask = Vec::with_capacity(valuators_len as usize);
+        for _ in 0..valuators_len as usize {
+            valuator_mask.push(self.read_u32());
+        }
+        let mut axisvalues = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues.push(self.read_fp3232());
+        }
+        let mut axisvalues_raw = Vec::with_capacity(valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>());
+        for _ in 0..valuator_mask.iter().map(|x| (*x as usize).count_ones() as usize).sum::<usize>() {
+            axisvalues_raw.push(self.read_fp3232());
+        }
+        Some(ServerEvent::XInput(XInputEvent::RawTouchEnd { deviceid, time, detail, sourceid, flags, valuator_mask, axisvalues, axisvalues_raw }))
+    }
+
+    /** Reads a XInputExtension BarrierLeave event */
+    fn read_xinput_barrier_leave_event(&mut self) -> Option<ServerEvent> {
+        let deviceid = self.read_u16();
+        let time = self.read_u32();
+        let eventid = self.read_u32();
+        let root = self.read_u32();
+        let event = self.read_u32();
+        let barrier = self.read_u32();
+        let dtime = self.read_u32();
+        let flags = self.read_u32();
+        let sourceid = self.read_u16();
+        self.read_pad(2);
+        let root_x = self.read_fp1616();
+        let root_y = self.read_fp1616();
+        let dx = self.read_fp3232();
+        let dy = self.read_fp3232();
+        Some(ServerEvent::XInput(XInputEvent::BarrierLeave { deviceid, time, eventid, root, event, barrier, dtime, flags, sourceid, root_x, root_y, dx, dy }))
+    }
+}
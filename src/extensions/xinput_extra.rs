@@ -0,0 +1,151 @@
+// XI2 event masks, device info, and XISelectEvents and XIQueryDevice, which xcbgen can't generate.
+
+#![allow(clippy::ptr_arg)]
+
+use models::{ServerReply, ServerResponse};
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+use super::xinput::*;
+
+impl EventMask {
+    /** Makes the mask to select some XI2 events on a device (ie Device::AllMaster.val() as u16) */
+    pub fn new(deviceid: u16, events: &[XIEventMask]) -> EventMask {
+        let bits = events.iter().fold(0, |bits, event| bits | event.val());
+        EventMask { deviceid, mask: vec![bits] }
+    }
+}
+
+/** One of the classes of an XIDeviceInfo (what the device can do) */
+#[derive(Debug, Clone)]
+pub enum DeviceClass {
+    Key(KeyClass),
+    Button(ButtonClass),
+    Valuator(ValuatorClass),
+    Scroll(ScrollClass),
+    Touch(TouchClass),
+    Unknown { kind: u16, sourceid: u16 } // A class added after XI 2.3
+}
+
+#[derive(Debug, Clone)]
+pub struct XIDeviceInfo {
+    pub deviceid: u16,
+    pub kind: DeviceType,
+    pub attachment: u16, // The paired master device, or the master a slave is attached to
+    pub enabled: bool,
+    pub name: String,
+    pub classes: Vec<DeviceClass>
+}
+
+#[derive(Debug, Clone)]
+pub struct XIQueryDeviceReply {
+    pub infos: Vec<XIDeviceInfo>
+}
+
+/**
+ * Pairs up the valuator_mask and axisvalues of a device or raw event.
+ * Returns (valuator number, value) for every valuator that is set.
+ */
+pub fn axis_values(valuator_mask: &Vec<u32>, axisvalues: &Vec<f64>) -> Vec<(u16, f64)> {
+    let mut values = axisvalues.iter();
+    let mut out = vec![];
+
+    for (i, mask) in valuator_mask.iter().enumerate() {
+        for bit in 0..32 {
+            if mask & (1 << bit) != 0 {
+                match values.next() {
+                    Some(value) => out.push(((i * 32 + bit) as u16, *value)),
+                    None => return out
+                }
+            }
+        }
+    }
+
+    out
+}
+
+impl XClient {
+    /** Selects XI2 events on a window. Each mask replaces what was selected before for its device. */
+    pub fn xinput_xi_select_events(&mut self, window: u32, masks: &Vec<EventMask>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XInput>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_XI_SELECT_EVENTS);
+        self.write_u16(3 + masks.iter().map(|mask| 1 + mask.mask.len() as u16).sum::<u16>());
+        self.write_u32(window);
+        self.write_u16(masks.len() as u16);
+        self.write_pad(2);
+        for mask in masks {
+            mask.write(self);
+        }
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Gets the devices that match `deviceid` (which can be Device::All or Device::AllMaster). Blocks. */
+    pub fn xinput_xi_query_device_sync(&mut self, deviceid: u16) -> Vec<XIDeviceInfo> {
+        let seq = match self.xinput_xi_query_device(deviceid) {
+            Some(seq) => seq,
+            None => return vec![]
+        };
+        match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::XInput(XInputReply::XIQueryDevice(reply)), _) => reply.infos,
+            _ => vec![]
+        }
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to XInputExtension XIQueryDevice */
+    pub(crate) fn read_xinput_xi_query_device_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let num_infos = self.read_u16();
+        self.read_pad(22);
+
+        let mut infos = Vec::with_capacity(num_infos as usize);
+        for _ in 0..num_infos {
+            infos.push(self.read_xinput_device_info()?);
+        }
+
+        Some(ServerReply::XInput(XInputReply::XIQueryDevice(XIQueryDeviceReply { infos })))
+    }
+
+    fn read_xinput_device_info(&mut self) -> Option<XIDeviceInfo> {
+        let deviceid = self.read_u16();
+        let kind = DeviceType::get(self.read_u16() as u32)?;
+        let attachment = self.read_u16();
+        let num_classes = self.read_u16();
+        let name_len = self.read_u16() as usize;
+        let enabled = self.read_bool();
+        self.read_pad(1);
+        let name = self.read_str(name_len);
+        self.read_pad((4 - name_len % 4) % 4);
+
+        let mut classes = Vec::with_capacity(num_classes as usize);
+        for _ in 0..num_classes {
+            classes.push(self.read_xinput_device_class());
+        }
+
+        Some(XIDeviceInfo { deviceid, kind, attachment, enabled, name, classes })
+    }
+
+    /** Reads a class, and skips past anything the server added to the end of it (`len` is in 4 byte units) */
+    fn read_xinput_device_class(&mut self) -> DeviceClass {
+        let start = self.position();
+        let kind = self.read_u16();
+        let len = self.read_u16() as usize;
+        let sourceid = self.read_u16();
+        self.seek(start);
+
+        let class = match DeviceClassType::get(kind as u32) {
+            Some(DeviceClassType::Key) => DeviceClass::Key(KeyClass::read(self)),
+            Some(DeviceClassType::Button) => DeviceClass::Button(ButtonClass::read(self)),
+            Some(DeviceClassType::Valuator) => DeviceClass::Valuator(ValuatorClass::read(self)),
+            Some(DeviceClassType::Scroll) => DeviceClass::Scroll(ScrollClass::read(self)),
+            Some(DeviceClassType::Touch) => DeviceClass::Touch(TouchClass::read(self)),
+            None => DeviceClass::Unknown { kind, sourceid }
+        };
+        self.seek(start + len * 4);
+
+        class
+    }
+}
@@ -0,0 +1,3531 @@
+// XKEYBOARD extension, version 1.0
+// Generated by tools/xcbgen from tools/xcb-proto/xkb.xml. Don't edit this file, change the generator or
+// xkb_extra.rs (which has everything written by hand) instead.
+//
+// Written by hand in xkb_extra.rs:
+//   - request SelectEvents
+//   - reply to GetMap
+//   - reply to GetNames
+//
+// Not generated:
+//   - <union> Behavior (not understood)
+//   - <union> Action (not understood)
+//   - struct SetBehavior: unknown type Behavior
+//   - struct Shape: list outlines of variable sized items
+//   - struct Overlay: list rows of variable sized items
+//   - request SetMap: <switch> not right after its CARD32 mask
+//   - request SetNames: <switch> not right after its CARD32 mask
+//   - reply to GetKbdByName (comes back as ServerReply::Unknown): <switch> with <case>
+//   - reply to GetDeviceInfo (comes back as ServerReply::Unknown): unknown type Action
+//   - request SetDeviceInfo: unknown type Action
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{Point, ServerEvent, ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+#[allow(unused_imports)] // Some extra modules only add methods
+pub use super::xkb_extra::*;
+
+pub const EXTENSION_NAME: &str = "XKEYBOARD";
+pub const MAJOR_VERSION: u32 = 1;
+pub const MINOR_VERSION: u32 = 0;
+
+pub const OP_USE_EXTENSION: u8 = 0;
+pub const OP_SELECT_EVENTS: u8 = 1;
+pub const OP_BELL: u8 = 3;
+pub const OP_GET_STATE: u8 = 4;
+pub const OP_LATCH_LOCK_STATE: u8 = 5;
+pub const OP_GET_CONTROLS: u8 = 6;
+pub const OP_SET_CONTROLS: u8 = 7;
+pub const OP_GET_MAP: u8 = 8;
+pub const OP_SET_MAP: u8 = 9;
+pub const OP_GET_COMPAT_MAP: u8 = 10;
+pub const OP_SET_COMPAT_MAP: u8 = 11;
+pub const OP_GET_INDICATOR_STATE: u8 = 12;
+pub const OP_GET_INDICATOR_MAP: u8 = 13;
+pub const OP_SET_INDICATOR_MAP: u8 = 14;
+pub const OP_GET_NAMED_INDICATOR: u8 = 15;
+pub const OP_SET_NAMED_INDICATOR: u8 = 16;
+pub const OP_GET_NAMES: u8 = 17;
+pub const OP_SET_NAMES: u8 = 18;
+pub const OP_PER_CLIENT_FLAGS: u8 = 21;
+pub const OP_LIST_COMPONENTS: u8 = 22;
+pub const OP_GET_KBD_BY_NAME: u8 = 23;
+pub const OP_GET_DEVICE_INFO: u8 = 24;
+pub const OP_SET_DEVICE_INFO: u8 = 25;
+pub const OP_SET_DEBUGGING_FLAGS: u8 = 101;
+
+pub const EVENT_NEW_KEYBOARD_NOTIFY: u8 = 0;
+pub const EVENT_MAP_NOTIFY: u8 = 1;
+pub const EVENT_STATE_NOTIFY: u8 = 2;
+pub const EVENT_CONTROLS_NOTIFY: u8 = 3;
+pub const EVENT_INDICATOR_STATE_NOTIFY: u8 = 4;
+pub const EVENT_INDICATOR_MAP_NOTIFY: u8 = 5;
+pub const EVENT_NAMES_NOTIFY: u8 = 6;
+pub const EVENT_COMPAT_MAP_NOTIFY: u8 = 7;
+pub const EVENT_BELL_NOTIFY: u8 = 8;
+pub const EVENT_ACTION_MESSAGE: u8 = 9;
+pub const EVENT_ACCESS_X_NOTIFY: u8 = 10;
+pub const EVENT_EXTENSION_DEVICE_NOTIFY: u8 = 11;
+
+pub const ERROR_KEYBOARD: u8 = 0;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Const {
+    MaxLegalKeyCode,
+    PerKeyBitArraySize,
+    KeyNameLength
+}
+impl Const {
+    pub fn get(id: u32) -> Option<Const> {
+        match id {
+            255 => Some(Const::MaxLegalKeyCode),
+            32 => Some(Const::PerKeyBitArraySize),
+            4 => Some(Const::KeyNameLength),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &Const::MaxLegalKeyCode => 255,
+            &Const::PerKeyBitArraySize => 32,
+            &Const::KeyNameLength => 4
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum EventType {
+    NewKeyboardNotify,
+    MapNotify,
+    StateNotify,
+    ControlsNotify,
+    IndicatorStateNotify,
+    IndicatorMapNotify,
+    NamesNotify,
+    CompatMapNotify,
+    BellNotify,
+    ActionMessage,
+    AccessXNotify,
+    ExtensionDeviceNotify
+}
+impl EventType {
+    pub fn val(&self) -> u32 {
+        match self {
+            &EventType::NewKeyboardNotify => 0x00000001,
+            &EventType::MapNotify => 0x00000002,
+            &EventType::StateNotify => 0x00000004,
+            &EventType::ControlsNotify => 0x00000008,
+            &EventType::IndicatorStateNotify => 0x00000010,
+            &EventType::IndicatorMapNotify => 0x00000020,
+            &EventType::NamesNotify => 0x00000040,
+            &EventType::CompatMapNotify => 0x00000080,
+            &EventType::BellNotify => 0x00000100,
+            &EventType::ActionMessage => 0x00000200,
+            &EventType::AccessXNotify => 0x00000400,
+            &EventType::ExtensionDeviceNotify => 0x00000800
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum NKNDetail {
+    Keycodes,
+    Geometry,
+    DeviceID
+}
+impl NKNDetail {
+    pub fn val(&self) -> u32 {
+        match self {
+            &NKNDetail::Keycodes => 0x00000001,
+            &NKNDetail::Geometry => 0x00000002,
+            &NKNDetail::DeviceID => 0x00000004
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AXNDetail {
+    SKPress,
+    SKAccept,
+    SKReject,
+    SKRelease,
+    BKAccept,
+    BKReject,
+    AXKWarning
+}
+impl AXNDetail {
+    pub fn val(&self) -> u32 {
+        match self {
+            &AXNDetail::SKPress => 0x00000001,
+            &AXNDetail::SKAccept => 0x00000002,
+            &AXNDetail::SKReject => 0x00000004,
+            &AXNDetail::SKRelease => 0x00000008,
+            &AXNDetail::BKAccept => 0x00000010,
+            &AXNDetail::BKReject => 0x00000020,
+            &AXNDetail::AXKWarning => 0x00000040
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum MapPart {
+    KeyTypes,
+    KeySyms,
+    ModifierMap,
+    ExplicitComponents,
+    KeyActions,
+    KeyBehaviors,
+    VirtualMods,
+    VirtualModMap
+}
+impl MapPart {
+    pub fn val(&self) -> u32 {
+        match self {
+            &MapPart::KeyTypes => 0x00000001,
+            &MapPart::KeySyms => 0x00000002,
+            &MapPart::ModifierMap => 0x00000004,
+            &MapPart::ExplicitComponents => 0x00000008,
+            &MapPart::KeyActions => 0x00000010,
+            &MapPart::KeyBehaviors => 0x00000020,
+            &MapPart::VirtualMods => 0x00000040,
+            &MapPart::VirtualModMap => 0x00000080
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SetMapFlags {
+    ResizeTypes,
+    RecomputeActions
+}
+impl SetMapFlags {
+    pub fn val(&self) -> u32 {
+        match self {
+            &SetMapFlags::ResizeTypes => 0x00000001,
+            &SetMapFlags::RecomputeActions => 0x00000002
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum StatePart {
+    ModifierState,
+    ModifierBase,
+    ModifierLatch,
+    ModifierLock,
+    GroupState,
+    GroupBase,
+    GroupLatch,
+    GroupLock,
+    CompatState,
+    GrabMods,
+    CompatGrabMods,
+    LookupMods,
+    CompatLookupMods,
+    PointerButtons
+}
+impl StatePart {
+    pub fn val(&self) -> u32 {
+        match self {
+            &StatePart::ModifierState => 0x00000001,
+            &StatePart::ModifierBase => 0x00000002,
+            &StatePart::ModifierLatch => 0x00000004,
+            &StatePart::ModifierLock => 0x00000008,
+            &StatePart::GroupState => 0x00000010,
+            &StatePart::GroupBase => 0x00000020,
+            &StatePart::GroupLatch => 0x00000040,
+            &StatePart::GroupLock => 0x00000080,
+            &StatePart::CompatState => 0x00000100,
+            &StatePart::GrabMods => 0x00000200,
+            &StatePart::CompatGrabMods => 0x00000400,
+            &StatePart::LookupMods => 0x00000800,
+            &StatePart::CompatLookupMods => 0x00001000,
+            &StatePart::PointerButtons => 0x00002000
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BoolCtrl {
+    RepeatKeys,
+    SlowKeys,
+    BounceKeys,
+    StickyKeys,
+    MouseKeys,
+    MouseKeysAccel,
+    AccessXKeys,
+    AccessXTimeoutMask,
+    AccessXFeedbackMask,
+    AudibleBellMask,
+    Overlay1Mask,
+    Overlay2Mask,
+    IgnoreGroupLockMask
+}
+impl BoolCtrl {
+    pub fn val(&self) -> u32 {
+        match self {
+            &BoolCtrl::RepeatKeys => 0x00000001,
+            &BoolCtrl::SlowKeys => 0x00000002,
+            &BoolCtrl::BounceKeys => 0x00000004,
+            &BoolCtrl::StickyKeys => 0x00000008,
+            &BoolCtrl::MouseKeys => 0x00000010,
+            &BoolCtrl::MouseKeysAccel => 0x00000020,
+            &BoolCtrl::AccessXKeys => 0x00000040,
+            &BoolCtrl::AccessXTimeoutMask => 0x00000080,
+            &BoolCtrl::AccessXFeedbackMask => 0x00000100,
+            &BoolCtrl::AudibleBellMask => 0x00000200,
+            &BoolCtrl::Overlay1Mask => 0x00000400,
+            &BoolCtrl::Overlay2Mask => 0x00000800,
+            &BoolCtrl::IgnoreGroupLockMask => 0x00001000
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Control {
+    GroupsWrap,
+    InternalMods,
+    IgnoreLockMods,
+    PerKeyRepeat,
+    ControlsEnabled
+}
+impl Control {
+    pub fn val(&self) -> u32 {
+        match self {
+            &Control::GroupsWrap => 0x08000000,
+            &Control::InternalMods => 0x10000000,
+            &Control::IgnoreLockMods => 0x20000000,
+            &Control::PerKeyRepeat => 0x40000000,
+            &Control::ControlsEnabled => 0x80000000
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AXOption {
+    SKPressFB,
+    SKAcceptFB,
+    FeatureFB,
+    SlowWarnFB,
+    IndicatorFB,
+    StickyKeysFB,
+    TwoKeys,
+    LatchToLock,
+    SKReleaseFB,
+    SKRejectFB,
+    BKRejectFB,
+    DumbBell
+}
+impl AXOption {
+    pub fn val(&self) -> u32 {
+        match self {
+            &AXOption::SKPressFB => 0x00000001,
+            &AXOption::SKAcceptFB => 0x00000002,
+            &AXOption::FeatureFB => 0x00000004,
+            &AXOption::SlowWarnFB => 0x00000008,
+            &AXOption::IndicatorFB => 0x00000010,
+            &AXOption::StickyKeysFB => 0x00000020,
+            &AXOption::TwoKeys => 0x00000040,
+            &AXOption::LatchToLock => 0x00000080,
+            &AXOption::SKReleaseFB => 0x00000100,
+            &AXOption::SKRejectFB => 0x00000200,
+            &AXOption::BKRejectFB => 0x00000400,
+            &AXOption::DumbBell => 0x00000800
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LedClassResult {
+    KbdFeedbackClass,
+    LedFeedbackClass
+}
+impl LedClassResult {
+    pub fn get(id: u32) -> Option<LedClassResult> {
+        match id {
+            0 => Some(LedClassResult::KbdFeedbackClass),
+            4 => Some(LedClassResult::LedFeedbackClass),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &LedClassResult::KbdFeedbackClass => 0,
+            &LedClassResult::LedFeedbackClass => 4
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LedClass {
+    KbdFeedbackClass,
+    LedFeedbackClass,
+    DfltXIClass,
+    AllXIClasses
+}
+impl LedClass {
+    pub fn get(id: u32) -> Option<LedClass> {
+        match id {
+            0 => Some(LedClass::KbdFeedbackClass),
+            4 => Some(LedClass::LedFeedbackClass),
+            768 => Some(LedClass::DfltXIClass),
+            1280 => Some(LedClass::AllXIClasses),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &LedClass::KbdFeedbackClass => 0,
+            &LedClass::LedFeedbackClass => 4,
+            &LedClass::DfltXIClass => 768,
+            &LedClass::AllXIClasses => 1280
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BellClassResult {
+    KbdFeedbackClass,
+    BellFeedbackClass
+}
+impl BellClassResult {
+    pub fn get(id: u32) -> Option<BellClassResult> {
+        match id {
+            0 => Some(BellClassResult::KbdFeedbackClass),
+            5 => Some(BellClassResult::BellFeedbackClass),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &BellClassResult::KbdFeedbackClass => 0,
+            &BellClassResult::BellFeedbackClass => 5
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BellClass {
+    KbdFeedbackClass,
+    BellFeedbackClass,
+    DfltXIClass
+}
+impl BellClass {
+    pub fn get(id: u32) -> Option<BellClass> {
+        match id {
+            0 => Some(BellClass::KbdFeedbackClass),
+            5 => Some(BellClass::BellFeedbackClass),
+            768 => Some(BellClass::DfltXIClass),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &BellClass::KbdFeedbackClass => 0,
+            &BellClass::BellFeedbackClass => 5,
+            &BellClass::DfltXIClass => 768
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Id {
+    UseCoreKbd,
+    UseCorePtr,
+    DfltXIClass,
+    DfltXIId,
+    AllXIClass,
+    AllXIId,
+    XINone
+}
+impl Id {
+    pub fn get(id: u32) -> Option<Id> {
+        match id {
+            256 => Some(Id::UseCoreKbd),
+            512 => Some(Id::UseCorePtr),
+            768 => Some(Id::DfltXIClass),
+            1024 => Some(Id::DfltXIId),
+            1280 => Some(Id::AllXIClass),
+            1536 => Some(Id::AllXIId),
+            65280 => Some(Id::XINone),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &Id::UseCoreKbd => 256,
+            &Id::UseCorePtr => 512,
+            &Id::DfltXIClass => 768,
+            &Id::DfltXIId => 1024,
+            &Id::AllXIClass => 1280,
+            &Id::AllXIId => 1536,
+            &Id::XINone => 65280
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Group {
+    N1,
+    N2,
+    N3,
+    N4
+}
+impl Group {
+    pub fn get(id: u32) -> Option<Group> {
+        match id {
+            0 => Some(Group::N1),
+            1 => Some(Group::N2),
+            2 => Some(Group::N3),
+            3 => Some(Group::N4),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &Group::N1 => 0,
+            &Group::N2 => 1,
+            &Group::N3 => 2,
+            &Group::N4 => 3
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Groups {
+    Any,
+    All
+}
+impl Groups {
+    pub fn get(id: u32) -> Option<Groups> {
+        match id {
+            254 => Some(Groups::Any),
+            255 => Some(Groups::All),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &Groups::Any => 254,
+            &Groups::All => 255
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SetOfGroup {
+    Group1,
+    Group2,
+    Group3,
+    Group4
+}
+impl SetOfGroup {
+    pub fn val(&self) -> u32 {
+        match self {
+            &SetOfGroup::Group1 => 0x00000001,
+            &SetOfGroup::Group2 => 0x00000002,
+            &SetOfGroup::Group3 => 0x00000004,
+            &SetOfGroup::Group4 => 0x00000008
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SetOfGroups {
+    Any
+}
+impl SetOfGroups {
+    pub fn val(&self) -> u32 {
+        match self {
+            &SetOfGroups::Any => 0x00000080
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GroupsWrap {
+    WrapIntoRange,
+    ClampIntoRange,
+    RedirectIntoRange
+}
+impl GroupsWrap {
+    pub fn val(&self) -> u32 {
+        match self {
+            &GroupsWrap::WrapIntoRange => 0x00000000,
+            &GroupsWrap::ClampIntoRange => 0x00000040,
+            &GroupsWrap::RedirectIntoRange => 0x00000080
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum VModsHigh {
+    N15,
+    N14,
+    N13,
+    N12,
+    N11,
+    N10,
+    N9,
+    N8
+}
+impl VModsHigh {
+    pub fn val(&self) -> u32 {
+        match self {
+            &VModsHigh::N15 => 0x00000080,
+            &VModsHigh::N14 => 0x00000040,
+            &VModsHigh::N13 => 0x00000020,
+            &VModsHigh::N12 => 0x00000010,
+            &VModsHigh::N11 => 0x00000008,
+            &VModsHigh::N10 => 0x00000004,
+            &VModsHigh::N9 => 0x00000002,
+            &VModsHigh::N8 => 0x00000001
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum VModsLow {
+    N7,
+    N6,
+    N5,
+    N4,
+    N3,
+    N2,
+    N1,
+    N0
+}
+impl VModsLow {
+    pub fn val(&self) -> u32 {
+        match self {
+            &VModsLow::N7 => 0x00000080,
+            &VModsLow::N6 => 0x00000040,
+            &VModsLow::N5 => 0x00000020,
+            &VModsLow::N4 => 0x00000010,
+            &VModsLow::N3 => 0x00000008,
+            &VModsLow::N2 => 0x00000004,
+            &VModsLow::N1 => 0x00000002,
+            &VModsLow::N0 => 0x00000001
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum VMod {
+    N15,
+    N14,
+    N13,
+    N12,
+    N11,
+    N10,
+    N9,
+    N8,
+    N7,
+    N6,
+    N5,
+    N4,
+    N3,
+    N2,
+    N1,
+    N0
+}
+impl VMod {
+    pub fn val(&self) -> u32 {
+        match self {
+            &VMod::N15 => 0x00008000,
+            &VMod::N14 => 0x00004000,
+            &VMod::N13 => 0x00002000,
+            &VMod::N12 => 0x00001000,
+            &VMod::N11 => 0x00000800,
+            &VMod::N10 => 0x00000400,
+            &VMod::N9 => 0x00000200,
+            &VMod::N8 => 0x00000100,
+            &VMod::N7 => 0x00000080,
+            &VMod::N6 => 0x00000040,
+            &VMod::N5 => 0x00000020,
+            &VMod::N4 => 0x00000010,
+            &VMod::N3 => 0x00000008,
+            &VMod::N2 => 0x00000004,
+            &VMod::N1 => 0x00000002,
+            &VMod::N0 => 0x00000001
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Explicit {
+    VModMap,
+    Behavior,
+    AutoRepeat,
+    Interpret,
+    KeyType4,
+    KeyType3,
+    KeyType2,
+    KeyType1
+}
+impl Explicit {
+    pub fn val(&self) -> u32 {
+        match self {
+            &Explicit::VModMap => 0x00000080,
+            &Explicit::Behavior => 0x00000040,
+            &Explicit::AutoRepeat => 0x00000020,
+            &Explicit::Interpret => 0x00000010,
+            &Explicit::KeyType4 => 0x00000008,
+            &Explicit::KeyType3 => 0x00000004,
+            &Explicit::KeyType2 => 0x00000002,
+            &Explicit::KeyType1 => 0x00000001
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SymInterpretMatch {
+    NoneOf,
+    AnyOfOrNone,
+    AnyOf,
+    AllOf,
+    Exactly
+}
+impl SymInterpretMatch {
+    pub fn get(id: u32) -> Option<SymInterpretMatch> {
+        match id {
+            0 => Some(SymInterpretMatch::NoneOf),
+            1 => Some(SymInterpretMatch::AnyOfOrNone),
+            2 => Some(SymInterpretMatch::AnyOf),
+            3 => Some(SymInterpretMatch::AllOf),
+            4 => Some(SymInterpretMatch::Exactly),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &SymInterpretMatch::NoneOf => 0,
+            &SymInterpretMatch::AnyOfOrNone => 1,
+            &SymInterpretMatch::AnyOf => 2,
+            &SymInterpretMatch::AllOf => 3,
+            &SymInterpretMatch::Exactly => 4
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SymInterpMatch {
+    LevelOneOnly,
+    OpMask
+}
+impl SymInterpMatch {
+    pub fn val(&self) -> u32 {
+        match self {
+            &SymInterpMatch::LevelOneOnly => 0x00000080,
+            &SymInterpMatch::OpMask => 0x0000007F
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum IMFlag {
+    NoExplicit,
+    NoAutomatic,
+    LEDDrivesKB
+}
+impl IMFlag {
+    pub fn val(&self) -> u32 {
+        match self {
+            &IMFlag::NoExplicit => 0x00000080,
+            &IMFlag::NoAutomatic => 0x00000040,
+            &IMFlag::LEDDrivesKB => 0x00000020
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum IMModsWhich {
+    UseCompat,
+    UseEffective,
+    UseLocked,
+    UseLatched,
+    UseBase
+}
+impl IMModsWhich {
+    pub fn val(&self) -> u32 {
+        match self {
+            &IMModsWhich::UseCompat => 0x00000010,
+            &IMModsWhich::UseEffective => 0x00000008,
+            &IMModsWhich::UseLocked => 0x00000004,
+            &IMModsWhich::UseLatched => 0x00000002,
+            &IMModsWhich::UseBase => 0x00000001
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum IMGroupsWhich {
+    UseCompat,
+    UseEffective,
+    UseLocked,
+    UseLatched,
+    UseBase
+}
+impl IMGroupsWhich {
+    pub fn val(&self) -> u32 {
+        match self {
+            &IMGroupsWhich::UseCompat => 0x00000010,
+            &IMGroupsWhich::UseEffective => 0x00000008,
+            &IMGroupsWhich::UseLocked => 0x00000004,
+            &IMGroupsWhich::UseLatched => 0x00000002,
+            &IMGroupsWhich::UseBase => 0x00000001
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CMDetail {
+    SymInterp,
+    GroupCompat
+}
+impl CMDetail {
+    pub fn val(&self) -> u32 {
+        match self {
+            &CMDetail::SymInterp => 0x00000001,
+            &CMDetail::GroupCompat => 0x00000002
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum NameDetail {
+    Keycodes,
+    Geometry,
+    Symbols,
+    PhysSymbols,
+    Types,
+    Compat,
+    KeyTypeNames,
+    KTLevelNames,
+    IndicatorNames,
+    KeyNames,
+    KeyAliases,
+    VirtualModNames,
+    GroupNames,
+    RGNames
+}
+impl NameDetail {
+    pub fn val(&self) -> u32 {
+        match self {
+            &NameDetail::Keycodes => 0x00000001,
+            &NameDetail::Geometry => 0x00000002,
+            &NameDetail::Symbols => 0x00000004,
+            &NameDetail::PhysSymbols => 0x00000008,
+            &NameDetail::Types => 0x00000010,
+            &NameDetail::Compat => 0x00000020,
+            &NameDetail::KeyTypeNames => 0x00000040,
+            &NameDetail::KTLevelNames => 0x00000080,
+            &NameDetail::IndicatorNames => 0x00000100,
+            &NameDetail::KeyNames => 0x00000200,
+            &NameDetail::KeyAliases => 0x00000400,
+            &NameDetail::VirtualModNames => 0x00000800,
+            &NameDetail::GroupNames => 0x00001000,
+            &NameDetail::RGNames => 0x00002000
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GBNDetail {
+    Types,
+    CompatMap,
+    ClientSymbols,
+    ServerSymbols,
+    IndicatorMaps,
+    KeyNames,
+    Geometry,
+    OtherNames
+}
+impl GBNDetail {
+    pub fn val(&self) -> u32 {
+        match self {
+            &GBNDetail::Types => 0x00000001,
+            &GBNDetail::CompatMap => 0x00000002,
+            &GBNDetail::ClientSymbols => 0x00000004,
+            &GBNDetail::ServerSymbols => 0x00000008,
+            &GBNDetail::IndicatorMaps => 0x00000010,
+            &GBNDetail::KeyNames => 0x00000020,
+            &GBNDetail::Geometry => 0x00000040,
+            &GBNDetail::OtherNames => 0x00000080
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum XIFeature {
+    Keyboards,
+    ButtonActions,
+    IndicatorNames,
+    IndicatorMaps,
+    IndicatorState
+}
+impl XIFeature {
+    pub fn val(&self) -> u32 {
+        match self {
+            &XIFeature::Keyboards => 0x00000001,
+            &XIFeature::ButtonActions => 0x00000002,
+            &XIFeature::IndicatorNames => 0x00000004,
+            &XIFeature::IndicatorMaps => 0x00000008,
+            &XIFeature::IndicatorState => 0x00000010
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PerClientFlag {
+    DetectableAutoRepeat,
+    GrabsUseXKBState,
+    AutoResetControls,
+    LookupStateWhenGrabbed,
+    SendEventUsesXKBState
+}
+impl PerClientFlag {
+    pub fn val(&self) -> u32 {
+        match self {
+            &PerClientFlag::DetectableAutoRepeat => 0x00000001,
+            &PerClientFlag::GrabsUseXKBState => 0x00000002,
+            &PerClientFlag::AutoResetControls => 0x00000004,
+            &PerClientFlag::LookupStateWhenGrabbed => 0x00000008,
+            &PerClientFlag::SendEventUsesXKBState => 0x00000010
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BehaviorType {
+    Default,
+    Lock,
+    RadioGroup,
+    Overlay1,
+    Overlay2,
+    PermamentLock,
+    PermamentRadioGroup,
+    PermamentOverlay1,
+    PermamentOverlay2
+}
+impl BehaviorType {
+    pub fn get(id: u32) -> Option<BehaviorType> {
+        match id {
+            0 => Some(BehaviorType::Default),
+            1 => Some(BehaviorType::Lock),
+            2 => Some(BehaviorType::RadioGroup),
+            3 => Some(BehaviorType::Overlay1),
+            4 => Some(BehaviorType::Overlay2),
+            129 => Some(BehaviorType::PermamentLock),
+            130 => Some(BehaviorType::PermamentRadioGroup),
+            131 => Some(BehaviorType::PermamentOverlay1),
+            132 => Some(BehaviorType::PermamentOverlay2),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &BehaviorType::Default => 0,
+            &BehaviorType::Lock => 1,
+            &BehaviorType::RadioGroup => 2,
+            &BehaviorType::Overlay1 => 3,
+            &BehaviorType::Overlay2 => 4,
+            &BehaviorType::PermamentLock => 129,
+            &BehaviorType::PermamentRadioGroup => 130,
+            &BehaviorType::PermamentOverlay1 => 131,
+            &BehaviorType::PermamentOverlay2 => 132
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DoodadType {
+    Outline,
+    Solid,
+    Text,
+    Indicator,
+    Logo
+}
+impl DoodadType {
+    pub fn get(id: u32) -> Option<DoodadType> {
+        match id {
+            1 => Some(DoodadType::Outline),
+            2 => Some(DoodadType::Solid),
+            3 => Some(DoodadType::Text),
+            4 => Some(DoodadType::Indicator),
+            5 => Some(DoodadType::Logo),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &DoodadType::Outline => 1,
+            &DoodadType::Solid => 2,
+            &DoodadType::Text => 3,
+            &DoodadType::Indicator => 4,
+            &DoodadType::Logo => 5
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Error {
+    BadDevice,
+    BadClass,
+    BadId
+}
+impl Error {
+    pub fn get(id: u32) -> Option<Error> {
+        match id {
+            255 => Some(Error::BadDevice),
+            254 => Some(Error::BadClass),
+            253 => Some(Error::BadId),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &Error::BadDevice => 255,
+            &Error::BadClass => 254,
+            &Error::BadId => 253
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Sa {
+    ClearLocks,
+    LatchToLock,
+    UseModMapMods,
+    GroupAbsolute
+}
+impl Sa {
+    pub fn val(&self) -> u32 {
+        match self {
+            &Sa::ClearLocks => 0x00000001,
+            &Sa::LatchToLock => 0x00000002,
+            &Sa::UseModMapMods => 0x00000004,
+            &Sa::GroupAbsolute => 0x00000004
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SAType {
+    NoAction,
+    SetMods,
+    LatchMods,
+    LockMods,
+    SetGroup,
+    LatchGroup,
+    LockGroup,
+    MovePtr,
+    PtrBtn,
+    LockPtrBtn,
+    SetPtrDflt,
+    ISOLock,
+    Terminate,
+    SwitchScreen,
+    SetControls,
+    LockControls,
+    Message,
+    RedirectKey,
+    DeviceBtn,
+    LockDeviceBtn,
+    DeviceValuator
+}
+impl SAType {
+    pub fn get(id: u32) -> Option<SAType> {
+        match id {
+            0 => Some(SAType::NoAction),
+            1 => Some(SAType::SetMods),
+            2 => Some(SAType::LatchMods),
+            3 => Some(SAType::LockMods),
+            4 => Some(SAType::SetGroup),
+            5 => Some(SAType::LatchGroup),
+            6 => Some(SAType::LockGroup),
+            7 => Some(SAType::MovePtr),
+            8 => Some(SAType::PtrBtn),
+            9 => Some(SAType::LockPtrBtn),
+            10 => Some(SAType::SetPtrDflt),
+            11 => Some(SAType::ISOLock),
+            12 => Some(SAType::Terminate),
+            13 => Some(SAType::SwitchScreen),
+            14 => Some(SAType::SetControls),
+            15 => Some(SAType::LockControls),
+            16 => Some(SAType::Message),
+            17 => Some(SAType::RedirectKey),
+            18 => Some(SAType::DeviceBtn),
+            19 => Some(SAType::LockDeviceBtn),
+            20 => Some(SAType::DeviceValuator),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &SAType::NoAction => 0,
+            &SAType::SetMods => 1,
+            &SAType::LatchMods => 2,
+            &SAType::LockMods => 3,
+            &SAType::SetGroup => 4,
+            &SAType::LatchGroup => 5,
+            &SAType::LockGroup => 6,
+            &SAType::MovePtr => 7,
+            &SAType::PtrBtn => 8,
+            &SAType::LockPtrBtn => 9,
+            &SAType::SetPtrDflt => 10,
+            &SAType::ISOLock => 11,
+            &SAType::Terminate => 12,
+            &SAType::SwitchScreen => 13,
+            &SAType::SetControls => 14,
+            &SAType::LockControls => 15,
+            &SAType::Message => 16,
+            &SAType::RedirectKey => 17,
+            &SAType::DeviceBtn => 18,
+            &SAType::LockDeviceBtn => 19,
+            &SAType::DeviceValuator => 20
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SAMovePtrFlag {
+    NoAcceleration,
+    MoveAbsoluteX,
+    MoveAbsoluteY
+}
+impl SAMovePtrFlag {
+    pub fn val(&self) -> u32 {
+        match self {
+            &SAMovePtrFlag::NoAcceleration => 0x00000001,
+            &SAMovePtrFlag::MoveAbsoluteX => 0x00000002,
+            &SAMovePtrFlag::MoveAbsoluteY => 0x00000004
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SASetPtrDfltFlag {
+    DfltBtnAbsolute,
+    AffectDfltButton
+}
+impl SASetPtrDfltFlag {
+    pub fn val(&self) -> u32 {
+        match self {
+            &SASetPtrDfltFlag::DfltBtnAbsolute => 0x00000004,
+            &SASetPtrDfltFlag::AffectDfltButton => 0x00000001
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SAIsoLockFlag {
+    NoLock,
+    NoUnlock,
+    UseModMapMods,
+    GroupAbsolute,
+    ISODfltIsGroup
+}
+impl SAIsoLockFlag {
+    pub fn val(&self) -> u32 {
+        match self {
+            &SAIsoLockFlag::NoLock => 0x00000001,
+            &SAIsoLockFlag::NoUnlock => 0x00000002,
+            &SAIsoLockFlag::UseModMapMods => 0x00000004,
+            &SAIsoLockFlag::GroupAbsolute => 0x00000004,
+            &SAIsoLockFlag::ISODfltIsGroup => 0x00000008
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SAIsoLockNoAffect {
+    Ctrls,
+    Ptr,
+    Group,
+    Mods
+}
+impl SAIsoLockNoAffect {
+    pub fn val(&self) -> u32 {
+        match self {
+            &SAIsoLockNoAffect::Ctrls => 0x00000008,
+            &SAIsoLockNoAffect::Ptr => 0x00000010,
+            &SAIsoLockNoAffect::Group => 0x00000020,
+            &SAIsoLockNoAffect::Mods => 0x00000040
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SwitchScreenFlag {
+    Application,
+    Absolute
+}
+impl SwitchScreenFlag {
+    pub fn val(&self) -> u32 {
+        match self {
+            &SwitchScreenFlag::Application => 0x00000001,
+            &SwitchScreenFlag::Absolute => 0x00000004
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BoolCtrlsHigh {
+    AccessXFeedback,
+    AudibleBell,
+    Overlay1,
+    Overlay2,
+    IgnoreGroupLock
+}
+impl BoolCtrlsHigh {
+    pub fn val(&self) -> u32 {
+        match self {
+            &BoolCtrlsHigh::AccessXFeedback => 0x00000001,
+            &BoolCtrlsHigh::AudibleBell => 0x00000002,
+            &BoolCtrlsHigh::Overlay1 => 0x00000004,
+            &BoolCtrlsHigh::Overlay2 => 0x00000008,
+            &BoolCtrlsHigh::IgnoreGroupLock => 0x00000010
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BoolCtrlsLow {
+    RepeatKeys,
+    SlowKeys,
+    BounceKeys,
+    StickyKeys,
+    MouseKeys,
+    MouseKeysAccel,
+    AccessXKeys,
+    AccessXTimeout
+}
+impl BoolCtrlsLow {
+    pub fn val(&self) -> u32 {
+        match self {
+            &BoolCtrlsLow::RepeatKeys => 0x00000001,
+            &BoolCtrlsLow::SlowKeys => 0x00000002,
+            &BoolCtrlsLow::BounceKeys => 0x00000004,
+            &BoolCtrlsLow::StickyKeys => 0x00000008,
+            &BoolCtrlsLow::MouseKeys => 0x00000010,
+            &BoolCtrlsLow::MouseKeysAccel => 0x00000020,
+            &BoolCtrlsLow::AccessXKeys => 0x00000040,
+            &BoolCtrlsLow::AccessXTimeout => 0x00000080
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ActionMessageFlag {
+    OnPress,
+    OnRelease,
+    GenKeyEvent
+}
+impl ActionMessageFlag {
+    pub fn val(&self) -> u32 {
+        match self {
+            &ActionMessageFlag::OnPress => 0x00000001,
+            &ActionMessageFlag::OnRelease => 0x00000002,
+            &ActionMessageFlag::GenKeyEvent => 0x00000004
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LockDeviceFlags {
+    NoLock,
+    NoUnlock
+}
+impl LockDeviceFlags {
+    pub fn val(&self) -> u32 {
+        match self {
+            &LockDeviceFlags::NoLock => 0x00000001,
+            &LockDeviceFlags::NoUnlock => 0x00000002
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SAValWhat {
+    IgnoreVal,
+    SetValMin,
+    SetValCenter,
+    SetValMax,
+    SetValRelative,
+    SetValAbsolute
+}
+impl SAValWhat {
+    pub fn get(id: u32) -> Option<SAValWhat> {
+        match id {
+            0 => Some(SAValWhat::IgnoreVal),
+            1 => Some(SAValWhat::SetValMin),
+            2 => Some(SAValWhat::SetValCenter),
+            3 => Some(SAValWhat::SetValMax),
+            4 => Some(SAValWhat::SetValRelative),
+            5 => Some(SAValWhat::SetValAbsolute),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &SAValWhat::IgnoreVal => 0,
+            &SAValWhat::SetValMin => 1,
+            &SAValWhat::SetValCenter => 2,
+            &SAValWhat::SetValMax => 3,
+            &SAValWhat::SetValRelative => 4,
+            &SAValWhat::SetValAbsolute => 5
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct IndicatorMap {
+    pub flags: u8,
+    pub which_groups: u8,
+    pub groups: u8,
+    pub which_mods: u8,
+    pub mods: u8,
+    pub real_mods: u8,
+    pub vmods: u16,
+    pub ctrls: u32
+}
+impl IndicatorMap {
+    pub fn read(reader: &mut XReadHelper) -> IndicatorMap {
+        let flags = reader.read_u8();
+        let which_groups = reader.read_u8();
+        let groups = reader.read_u8();
+        let which_mods = reader.read_u8();
+        let mods = reader.read_u8();
+        let real_mods = reader.read_u8();
+        let vmods = reader.read_u16();
+        let ctrls = reader.read_u32();
+        IndicatorMap { flags, which_groups, groups, which_mods, mods, real_mods, vmods, ctrls }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.flags);
+        client.write_u8(self.which_groups);
+        client.write_u8(self.groups);
+        client.write_u8(self.which_mods);
+        client.write_u8(self.mods);
+        client.write_u8(self.real_mods);
+        client.write_u16(self.vmods);
+        client.write_u32(self.ctrls);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ModDef {
+    pub mask: u8,
+    pub real_mods: u8,
+    pub vmods: u16
+}
+impl ModDef {
+    pub fn read(reader: &mut XReadHelper) -> ModDef {
+        let mask = reader.read_u8();
+        let real_mods = reader.read_u8();
+        let vmods = reader.read_u16();
+        ModDef { mask, real_mods, vmods }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.mask);
+        client.write_u8(self.real_mods);
+        client.write_u16(self.vmods);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KeyName {
+    pub name: String
+}
+impl KeyName {
+    pub fn read(reader: &mut XReadHelper) -> KeyName {
+        let name = reader.read_str(4);
+        KeyName { name }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_str(&self.name);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KeyAlias {
+    pub real: String,
+    pub alias: String
+}
+impl KeyAlias {
+    pub fn read(reader: &mut XReadHelper) -> KeyAlias {
+        let real = reader.read_str(4);
+        let alias = reader.read_str(4);
+        KeyAlias { real, alias }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_str(&self.real);
+        client.write_str(&self.alias);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CountedString16 {
+    pub string: String,
+    pub alignment_pad: Vec<u8>
+}
+impl CountedString16 {
+    pub fn read(reader: &mut XReadHelper) -> CountedString16 {
+        let length = reader.read_u16();
+        let string = reader.read_str(length as usize);
+        let alignment_pad = reader.read_raw((((length as usize) + 5) & (!3)) - ((length as usize) + 2));
+        CountedString16 { string, alignment_pad }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.string.len() as u16);
+        client.write_str(&self.string);
+        client.write_raw(&self.alignment_pad);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct KTMapEntry {
+    pub active: bool,
+    pub mods_mask: u8,
+    pub level: u8,
+    pub mods_mods: u8,
+    pub mods_vmods: u16
+}
+impl KTMapEntry {
+    pub fn read(reader: &mut XReadHelper) -> KTMapEntry {
+        let active = reader.read_bool();
+        let mods_mask = reader.read_u8();
+        let level = reader.read_u8();
+        let mods_mods = reader.read_u8();
+        let mods_vmods = reader.read_u16();
+        reader.read_pad(2);
+        KTMapEntry { active, mods_mask, level, mods_mods, mods_vmods }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_bool(self.active);
+        client.write_u8(self.mods_mask);
+        client.write_u8(self.level);
+        client.write_u8(self.mods_mods);
+        client.write_u16(self.mods_vmods);
+        client.write_pad(2);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KeyType {
+    pub mods_mask: u8,
+    pub mods_mods: u8,
+    pub mods_vmods: u16,
+    pub num_levels: u8,
+    pub has_preserve: bool,
+    pub map: Vec<KTMapEntry>,
+    pub preserve: Vec<ModDef>
+}
+impl KeyType {
+    pub fn read(reader: &mut XReadHelper) -> KeyType {
+        let mods_mask = reader.read_u8();
+        let mods_mods = reader.read_u8();
+        let mods_vmods = reader.read_u16();
+        let num_levels = reader.read_u8();
+        let n_map_entries = reader.read_u8();
+        let has_preserve = reader.read_bool();
+        reader.read_pad(1);
+        let mut map = Vec::with_capacity(n_map_entries as usize);
+        for _ in 0..n_map_entries as usize {
+            map.push(KTMapEntry::read(reader));
+        }
+        let mut preserve = Vec::with_capacity((has_preserve as usize) * (n_map_entries as usize));
+        for _ in 0..(has_preserve as usize) * (n_map_entries as usize) {
+            preserve.push(ModDef::read(reader));
+        }
+        KeyType { mods_mask, mods_mods, mods_vmods, num_levels, has_preserve, map, preserve }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.mods_mask);
+        client.write_u8(self.mods_mods);
+        client.write_u16(self.mods_vmods);
+        client.write_u8(self.num_levels);
+        client.write_u8(self.map.len() as u8);
+        client.write_bool(self.has_preserve);
+        client.write_pad(1);
+        for value in &self.map {
+            value.write(client);
+        }
+        for value in &self.preserve {
+            value.write(client);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KeySymMap {
+    pub kt_index: Vec<u8>,
+    pub group_info: u8,
+    pub width: u8,
+    pub syms: Vec<u32>
+}
+impl KeySymMap {
+    pub fn read(reader: &mut XReadHelper) -> KeySymMap {
+        let kt_index = reader.read_raw(4);
+        let group_info = reader.read_u8();
+        let width = reader.read_u8();
+        let n_syms = reader.read_u16();
+        let mut syms = Vec::with_capacity(n_syms as usize);
+        for _ in 0..n_syms as usize {
+            syms.push(reader.read_u32());
+        }
+        KeySymMap { kt_index, group_info, width, syms }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_raw(&self.kt_index);
+        client.write_u8(self.group_info);
+        client.write_u8(self.width);
+        client.write_u16(self.syms.len() as u16);
+        for value in &self.syms {
+            client.write_u32(*value);
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct CommonBehavior {
+    pub kind: u8,
+    pub data: u8
+}
+impl CommonBehavior {
+    pub fn read(reader: &mut XReadHelper) -> CommonBehavior {
+        let kind = reader.read_u8();
+        let data = reader.read_u8();
+        CommonBehavior { kind, data }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_u8(self.data);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct DefaultBehavior {
+    pub kind: u8
+}
+impl DefaultBehavior {
+    pub fn read(reader: &mut XReadHelper) -> DefaultBehavior {
+        let kind = reader.read_u8();
+        reader.read_pad(1);
+        DefaultBehavior { kind }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_pad(1);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct RadioGroupBehavior {
+    pub kind: u8,
+    pub group: u8
+}
+impl RadioGroupBehavior {
+    pub fn read(reader: &mut XReadHelper) -> RadioGroupBehavior {
+        let kind = reader.read_u8();
+        let group = reader.read_u8();
+        RadioGroupBehavior { kind, group }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_u8(self.group);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct OverlayBehavior {
+    pub kind: u8,
+    pub key: u8
+}
+impl OverlayBehavior {
+    pub fn read(reader: &mut XReadHelper) -> OverlayBehavior {
+        let kind = reader.read_u8();
+        let key = reader.read_u8();
+        OverlayBehavior { kind, key }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_u8(self.key);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SetExplicit {
+    pub keycode: u8,
+    pub explicit: u8
+}
+impl SetExplicit {
+    pub fn read(reader: &mut XReadHelper) -> SetExplicit {
+        let keycode = reader.read_u8();
+        let explicit = reader.read_u8();
+        SetExplicit { keycode, explicit }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.keycode);
+        client.write_u8(self.explicit);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct KeyModMap {
+    pub keycode: u8,
+    pub mods: u8
+}
+impl KeyModMap {
+    pub fn read(reader: &mut XReadHelper) -> KeyModMap {
+        let keycode = reader.read_u8();
+        let mods = reader.read_u8();
+        KeyModMap { keycode, mods }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.keycode);
+        client.write_u8(self.mods);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct KeyVModMap {
+    pub keycode: u8,
+    pub vmods: u16
+}
+impl KeyVModMap {
+    pub fn read(reader: &mut XReadHelper) -> KeyVModMap {
+        let keycode = reader.read_u8();
+        reader.read_pad(1);
+        let vmods = reader.read_u16();
+        KeyVModMap { keycode, vmods }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.keycode);
+        client.write_pad(1);
+        client.write_u16(self.vmods);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct KTSetMapEntry {
+    pub level: u8,
+    pub real_mods: u8,
+    pub virtual_mods: u16
+}
+impl KTSetMapEntry {
+    pub fn read(reader: &mut XReadHelper) -> KTSetMapEntry {
+        let level = reader.read_u8();
+        let real_mods = reader.read_u8();
+        let virtual_mods = reader.read_u16();
+        KTSetMapEntry { level, real_mods, virtual_mods }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.level);
+        client.write_u8(self.real_mods);
+        client.write_u16(self.virtual_mods);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SetKeyType {
+    pub mask: u8,
+    pub real_mods: u8,
+    pub virtual_mods: u16,
+    pub num_levels: u8,
+    pub preserve: bool,
+    pub entries: Vec<KTSetMapEntry>,
+    pub preserve_entries: Vec<KTSetMapEntry>
+}
+impl SetKeyType {
+    pub fn read(reader: &mut XReadHelper) -> SetKeyType {
+        let mask = reader.read_u8();
+        let real_mods = reader.read_u8();
+        let virtual_mods = reader.read_u16();
+        let num_levels = reader.read_u8();
+        let n_map_entries = reader.read_u8();
+        let preserve = reader.read_bool();
+        reader.read_pad(1);
+        let mut entries = Vec::with_capacity(n_map_entries as usize);
+        for _ in 0..n_map_entries as usize {
+            entries.push(KTSetMapEntry::read(reader));
+        }
+        let mut preserve_entries = Vec::with_capacity((preserve as usize) * (n_map_entries as usize));
+        for _ in 0..(preserve as usize) * (n_map_entries as usize) {
+            preserve_entries.push(KTSetMapEntry::read(reader));
+        }
+        SetKeyType { mask, real_mods, virtual_mods, num_levels, preserve, entries, preserve_entries }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.mask);
+        client.write_u8(self.real_mods);
+        client.write_u16(self.virtual_mods);
+        client.write_u8(self.num_levels);
+        client.write_u8(self.entries.len() as u8);
+        client.write_bool(self.preserve);
+        client.write_pad(1);
+        for value in &self.entries {
+            value.write(client);
+        }
+        for value in &self.preserve_entries {
+            value.write(client);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Outline {
+    pub corner_radius: u8,
+    pub points: Vec<Point>
+}
+impl Outline {
+    pub fn read(reader: &mut XReadHelper) -> Outline {
+        let n_points = reader.read_u8();
+        let corner_radius = reader.read_u8();
+        reader.read_pad(2);
+        let mut points = Vec::with_capacity(n_points as usize);
+        for _ in 0..n_points as usize {
+            points.push(Point { x: reader.read_i16(), y: reader.read_i16() });
+        }
+        Outline { corner_radius, points }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.points.len() as u8);
+        client.write_u8(self.corner_radius);
+        client.write_pad(2);
+        for value in &self.points {
+            value.write(client);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Key {
+    pub name: String,
+    pub gap: i16,
+    pub shape_ndx: u8,
+    pub color_ndx: u8
+}
+impl Key {
+    pub fn read(reader: &mut XReadHelper) -> Key {
+        let name = reader.read_str(4);
+        let gap = reader.read_i16();
+        let shape_ndx = reader.read_u8();
+        let color_ndx = reader.read_u8();
+        Key { name, gap, shape_ndx, color_ndx }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_str(&self.name);
+        client.write_i16(self.gap);
+        client.write_u8(self.shape_ndx);
+        client.write_u8(self.color_ndx);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OverlayKey {
+    pub over: String,
+    pub under: String
+}
+impl OverlayKey {
+    pub fn read(reader: &mut XReadHelper) -> OverlayKey {
+        let over = reader.read_str(4);
+        let under = reader.read_str(4);
+        OverlayKey { over, under }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_str(&self.over);
+        client.write_str(&self.under);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OverlayRow {
+    pub row_under: u8,
+    pub keys: Vec<OverlayKey>
+}
+impl OverlayRow {
+    pub fn read(reader: &mut XReadHelper) -> OverlayRow {
+        let row_under = reader.read_u8();
+        let n_keys = reader.read_u8();
+        reader.read_pad(2);
+        let mut keys = Vec::with_capacity(n_keys as usize);
+        for _ in 0..n_keys as usize {
+            keys.push(OverlayKey::read(reader));
+        }
+        OverlayRow { row_under, keys }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.row_under);
+        client.write_u8(self.keys.len() as u8);
+        client.write_pad(2);
+        for value in &self.keys {
+            value.write(client);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Row {
+    pub top: i16,
+    pub left: i16,
+    pub vertical: bool,
+    pub keys: Vec<Key>
+}
+impl Row {
+    pub fn read(reader: &mut XReadHelper) -> Row {
+        let top = reader.read_i16();
+        let left = reader.read_i16();
+        let n_keys = reader.read_u8();
+        let vertical = reader.read_bool();
+        reader.read_pad(2);
+        let mut keys = Vec::with_capacity(n_keys as usize);
+        for _ in 0..n_keys as usize {
+            keys.push(Key::read(reader));
+        }
+        Row { top, left, vertical, keys }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_i16(self.top);
+        client.write_i16(self.left);
+        client.write_u8(self.keys.len() as u8);
+        client.write_bool(self.vertical);
+        client.write_pad(2);
+        for value in &self.keys {
+            value.write(client);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Listing {
+    pub flags: u16,
+    pub string: String
+}
+impl Listing {
+    pub fn read(reader: &mut XReadHelper) -> Listing {
+        let flags = reader.read_u16();
+        let length = reader.read_u16();
+        let string = reader.read_str(length as usize);
+        reader.read_pad((2 - (4 + string.len()) % 2) % 2);
+        Listing { flags, string }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.flags);
+        client.write_u16(self.string.len() as u16);
+        client.write_str(&self.string);
+        client.write_pad_op((2 - (4 + self.string.len()) % 2) % 2);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeviceLedInfo {
+    pub led_class: u16,
+    pub led_id: u16,
+    pub names_present: u32,
+    pub maps_present: u32,
+    pub phys_indicators: u32,
+    pub state: u32,
+    pub names: Vec<u32>,
+    pub maps: Vec<IndicatorMap>
+}
+impl DeviceLedInfo {
+    pub fn read(reader: &mut XReadHelper) -> DeviceLedInfo {
+        let led_class = reader.read_u16();
+        let led_id = reader.read_u16();
+        let names_present = reader.read_u32();
+        let maps_present = reader.read_u32();
+        let phys_indicators = reader.read_u32();
+        let state = reader.read_u32();
+        let mut names = Vec::with_capacity((names_present as usize).count_ones() as usize);
+        for _ in 0..(names_present as usize).count_ones() as usize {
+            names.push(reader.read_u32());
+        }
+        let mut maps = Vec::with_capacity((maps_present as usize).count_ones() as usize);
+        for _ in 0..(maps_present as usize).count_ones() as usize {
+            maps.push(IndicatorMap::read(reader));
+        }
+        DeviceLedInfo { led_class, led_id, names_present, maps_present, phys_indicators, state, names, maps }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u16(self.led_class);
+        client.write_u16(self.led_id);
+        client.write_u32(self.names_present);
+        client.write_u32(self.maps_present);
+        client.write_u32(self.phys_indicators);
+        client.write_u32(self.state);
+        for value in &self.names {
+            client.write_u32(*value);
+        }
+        for value in &self.maps {
+            value.write(client);
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SANoAction {
+    pub kind: u8
+}
+impl SANoAction {
+    pub fn read(reader: &mut XReadHelper) -> SANoAction {
+        let kind = reader.read_u8();
+        reader.read_pad(7);
+        SANoAction { kind }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_pad(7);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SASetMods {
+    pub kind: u8,
+    pub flags: u8,
+    pub mask: u8,
+    pub real_mods: u8,
+    pub vmods_high: u8,
+    pub vmods_low: u8
+}
+impl SASetMods {
+    pub fn read(reader: &mut XReadHelper) -> SASetMods {
+        let kind = reader.read_u8();
+        let flags = reader.read_u8();
+        let mask = reader.read_u8();
+        let real_mods = reader.read_u8();
+        let vmods_high = reader.read_u8();
+        let vmods_low = reader.read_u8();
+        reader.read_pad(2);
+        SASetMods { kind, flags, mask, real_mods, vmods_high, vmods_low }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_u8(self.flags);
+        client.write_u8(self.mask);
+        client.write_u8(self.real_mods);
+        client.write_u8(self.vmods_high);
+        client.write_u8(self.vmods_low);
+        client.write_pad(2);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SASetGroup {
+    pub kind: u8,
+    pub flags: u8,
+    pub group: i8
+}
+impl SASetGroup {
+    pub fn read(reader: &mut XReadHelper) -> SASetGroup {
+        let kind = reader.read_u8();
+        let flags = reader.read_u8();
+        let group = reader.read_u8() as i8;
+        reader.read_pad(5);
+        SASetGroup { kind, flags, group }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_u8(self.flags);
+        client.write_i8(self.group);
+        client.write_pad(5);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SAMovePtr {
+    pub kind: u8,
+    pub flags: u8,
+    pub x_high: i8,
+    pub x_low: u8,
+    pub y_high: i8,
+    pub y_low: u8
+}
+impl SAMovePtr {
+    pub fn read(reader: &mut XReadHelper) -> SAMovePtr {
+        let kind = reader.read_u8();
+        let flags = reader.read_u8();
+        let x_high = reader.read_u8() as i8;
+        let x_low = reader.read_u8();
+        let y_high = reader.read_u8() as i8;
+        let y_low = reader.read_u8();
+        reader.read_pad(2);
+        SAMovePtr { kind, flags, x_high, x_low, y_high, y_low }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_u8(self.flags);
+        client.write_i8(self.x_high);
+        client.write_u8(self.x_low);
+        client.write_i8(self.y_high);
+        client.write_u8(self.y_low);
+        client.write_pad(2);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SAPtrBtn {
+    pub kind: u8,
+    pub flags: u8,
+    pub count: u8,
+    pub button: u8
+}
+impl SAPtrBtn {
+    pub fn read(reader: &mut XReadHelper) -> SAPtrBtn {
+        let kind = reader.read_u8();
+        let flags = reader.read_u8();
+        let count = reader.read_u8();
+        let button = reader.read_u8();
+        reader.read_pad(4);
+        SAPtrBtn { kind, flags, count, button }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_u8(self.flags);
+        client.write_u8(self.count);
+        client.write_u8(self.button);
+        client.write_pad(4);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SALockPtrBtn {
+    pub kind: u8,
+    pub flags: u8,
+    pub button: u8
+}
+impl SALockPtrBtn {
+    pub fn read(reader: &mut XReadHelper) -> SALockPtrBtn {
+        let kind = reader.read_u8();
+        let flags = reader.read_u8();
+        reader.read_pad(1);
+        let button = reader.read_u8();
+        reader.read_pad(4);
+        SALockPtrBtn { kind, flags, button }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_u8(self.flags);
+        client.write_pad(1);
+        client.write_u8(self.button);
+        client.write_pad(4);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SASetPtrDflt {
+    pub kind: u8,
+    pub flags: u8,
+    pub affect: u8,
+    pub value: i8
+}
+impl SASetPtrDflt {
+    pub fn read(reader: &mut XReadHelper) -> SASetPtrDflt {
+        let kind = reader.read_u8();
+        let flags = reader.read_u8();
+        let affect = reader.read_u8();
+        let value = reader.read_u8() as i8;
+        reader.read_pad(4);
+        SASetPtrDflt { kind, flags, affect, value }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_u8(self.flags);
+        client.write_u8(self.affect);
+        client.write_i8(self.value);
+        client.write_pad(4);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SAIsoLock {
+    pub kind: u8,
+    pub flags: u8,
+    pub mask: u8,
+    pub real_mods: u8,
+    pub group: i8,
+    pub affect: u8,
+    pub vmods_high: u8,
+    pub vmods_low: u8
+}
+impl SAIsoLock {
+    pub fn read(reader: &mut XReadHelper) -> SAIsoLock {
+        let kind = reader.read_u8();
+        let flags = reader.read_u8();
+        let mask = reader.read_u8();
+        let real_mods = reader.read_u8();
+        let group = reader.read_u8() as i8;
+        let affect = reader.read_u8();
+        let vmods_high = reader.read_u8();
+        let vmods_low = reader.read_u8();
+        SAIsoLock { kind, flags, mask, real_mods, group, affect, vmods_high, vmods_low }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_u8(self.flags);
+        client.write_u8(self.mask);
+        client.write_u8(self.real_mods);
+        client.write_i8(self.group);
+        client.write_u8(self.affect);
+        client.write_u8(self.vmods_high);
+        client.write_u8(self.vmods_low);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SATerminate {
+    pub kind: u8
+}
+impl SATerminate {
+    pub fn read(reader: &mut XReadHelper) -> SATerminate {
+        let kind = reader.read_u8();
+        reader.read_pad(7);
+        SATerminate { kind }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_pad(7);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SASwitchScreen {
+    pub kind: u8,
+    pub flags: u8,
+    pub new_screen: i8
+}
+impl SASwitchScreen {
+    pub fn read(reader: &mut XReadHelper) -> SASwitchScreen {
+        let kind = reader.read_u8();
+        let flags = reader.read_u8();
+        let new_screen = reader.read_u8() as i8;
+        reader.read_pad(5);
+        SASwitchScreen { kind, flags, new_screen }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_u8(self.flags);
+        client.write_i8(self.new_screen);
+        client.write_pad(5);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SASetControls {
+    pub kind: u8,
+    pub bool_ctrls_high: u8,
+    pub bool_ctrls_low: u8
+}
+impl SASetControls {
+    pub fn read(reader: &mut XReadHelper) -> SASetControls {
+        let kind = reader.read_u8();
+        reader.read_pad(3);
+        let bool_ctrls_high = reader.read_u8();
+        let bool_ctrls_low = reader.read_u8();
+        reader.read_pad(2);
+        SASetControls { kind, bool_ctrls_high, bool_ctrls_low }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_pad(3);
+        client.write_u8(self.bool_ctrls_high);
+        client.write_u8(self.bool_ctrls_low);
+        client.write_pad(2);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SAActionMessage {
+    pub kind: u8,
+    pub flags: u8,
+    pub message: Vec<u8>
+}
+impl SAActionMessage {
+    pub fn read(reader: &mut XReadHelper) -> SAActionMessage {
+        let kind = reader.read_u8();
+        let flags = reader.read_u8();
+        let message = reader.read_raw(6);
+        SAActionMessage { kind, flags, message }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_u8(self.flags);
+        client.write_raw(&self.message);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SARedirectKey {
+    pub kind: u8,
+    pub newkey: u8,
+    pub mask: u8,
+    pub real_modifiers: u8,
+    pub vmods_mask_high: u8,
+    pub vmods_mask_low: u8,
+    pub vmods_high: u8,
+    pub vmods_low: u8
+}
+impl SARedirectKey {
+    pub fn read(reader: &mut XReadHelper) -> SARedirectKey {
+        let kind = reader.read_u8();
+        let newkey = reader.read_u8();
+        let mask = reader.read_u8();
+        let real_modifiers = reader.read_u8();
+        let vmods_mask_high = reader.read_u8();
+        let vmods_mask_low = reader.read_u8();
+        let vmods_high = reader.read_u8();
+        let vmods_low = reader.read_u8();
+        SARedirectKey { kind, newkey, mask, real_modifiers, vmods_mask_high, vmods_mask_low, vmods_high, vmods_low }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_u8(self.newkey);
+        client.write_u8(self.mask);
+        client.write_u8(self.real_modifiers);
+        client.write_u8(self.vmods_mask_high);
+        client.write_u8(self.vmods_mask_low);
+        client.write_u8(self.vmods_high);
+        client.write_u8(self.vmods_low);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SADeviceBtn {
+    pub kind: u8,
+    pub flags: u8,
+    pub count: u8,
+    pub button: u8,
+    pub device: u8
+}
+impl SADeviceBtn {
+    pub fn read(reader: &mut XReadHelper) -> SADeviceBtn {
+        let kind = reader.read_u8();
+        let flags = reader.read_u8();
+        let count = reader.read_u8();
+        let button = reader.read_u8();
+        let device = reader.read_u8();
+        reader.read_pad(3);
+        SADeviceBtn { kind, flags, count, button, device }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_u8(self.flags);
+        client.write_u8(self.count);
+        client.write_u8(self.button);
+        client.write_u8(self.device);
+        client.write_pad(3);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SALockDeviceBtn {
+    pub kind: u8,
+    pub flags: u8,
+    pub button: u8,
+    pub device: u8
+}
+impl SALockDeviceBtn {
+    pub fn read(reader: &mut XReadHelper) -> SALockDeviceBtn {
+        let kind = reader.read_u8();
+        let flags = reader.read_u8();
+        reader.read_pad(1);
+        let button = reader.read_u8();
+        let device = reader.read_u8();
+        reader.read_pad(3);
+        SALockDeviceBtn { kind, flags, button, device }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_u8(self.flags);
+        client.write_pad(1);
+        client.write_u8(self.button);
+        client.write_u8(self.device);
+        client.write_pad(3);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SADeviceValuator {
+    pub kind: u8,
+    pub device: u8,
+    pub val1what: u8,
+    pub val1index: u8,
+    pub val1value: u8,
+    pub val2what: u8,
+    pub val2index: u8,
+    pub val2value: u8
+}
+impl SADeviceValuator {
+    pub fn read(reader: &mut XReadHelper) -> SADeviceValuator {
+        let kind = reader.read_u8();
+        let device = reader.read_u8();
+        let val1what = reader.read_u8();
+        let val1index = reader.read_u8();
+        let val1value = reader.read_u8();
+        let val2what = reader.read_u8();
+        let val2index = reader.read_u8();
+        let val2value = reader.read_u8();
+        SADeviceValuator { kind, device, val1what, val1index, val1value, val2what, val2index, val2value }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_u8(self.device);
+        client.write_u8(self.val1what);
+        client.write_u8(self.val1index);
+        client.write_u8(self.val1value);
+        client.write_u8(self.val2what);
+        client.write_u8(self.val2index);
+        client.write_u8(self.val2value);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SIAction {
+    pub kind: u8,
+    pub data: Vec<u8>
+}
+impl SIAction {
+    pub fn read(reader: &mut XReadHelper) -> SIAction {
+        let kind = reader.read_u8();
+        let data = reader.read_raw(7);
+        SIAction { kind, data }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u8(self.kind);
+        client.write_raw(&self.data);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SymInterpret {
+    pub sym: u32,
+    pub mods: u8,
+    pub match_: u8,
+    pub virtual_mod: u8,
+    pub flags: u8,
+    pub action: SIAction
+}
+impl SymInterpret {
+    pub fn read(reader: &mut XReadHelper) -> SymInterpret {
+        let sym = reader.read_u32();
+        let mods = reader.read_u8();
+        let match_ = reader.read_u8();
+        let virtual_mod = reader.read_u8();
+        let flags = reader.read_u8();
+        let action = SIAction::read(reader);
+        SymInterpret { sym, mods, match_, virtual_mod, flags, action }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.sym);
+        client.write_u8(self.mods);
+        client.write_u8(self.match_);
+        client.write_u8(self.virtual_mod);
+        client.write_u8(self.flags);
+        self.action.write(client);
+    }
+}
+
+#[derive(Debug)]
+pub enum XkbReply {
+    UseExtension {
+        supported: bool,
+        server_major: u16,
+        server_minor: u16
+    },
+    GetState {
+        device_id: u8,
+        mods: u8,
+        base_mods: u8,
+        latched_mods: u8,
+        locked_mods: u8,
+        group: Group,
+        locked_group: Group,
+        base_group: i16,
+        latched_group: i16,
+        compat_state: u8,
+        grab_mods: u8,
+        compat_grab_mods: u8,
+        lookup_mods: u8,
+        compat_lookup_mods: u8,
+        ptr_btn_state: u16
+    },
+    GetControls {
+        device_id: u8,
+        mouse_keys_dflt_btn: u8,
+        num_groups: u8,
+        groups_wrap: u8,
+        internal_mods_mask: u8,
+        ignore_lock_mods_mask: u8,
+        internal_mods_real_mods: u8,
+        ignore_lock_mods_real_mods: u8,
+        internal_mods_vmods: u16,
+        ignore_lock_mods_vmods: u16,
+        repeat_delay: u16,
+        repeat_interval: u16,
+        slow_keys_delay: u16,
+        debounce_delay: u16,
+        mouse_keys_delay: u16,
+        mouse_keys_interval: u16,
+        mouse_keys_time_to_max: u16,
+        mouse_keys_max_speed: u16,
+        mouse_keys_curve: i16,
+        access_x_option: u16,
+        access_x_timeout: u16,
+        access_x_timeout_options_mask: u16,
+        access_x_timeout_options_values: u16,
+        access_x_timeout_mask: u32,
+        access_x_timeout_values: u32,
+        enabled_controls: u32,
+        per_key_repeat: Vec<u8>
+    },
+    GetCompatMap {
+        device_id: u8,
+        groups_rtrn: u8,
+        first_si_rtrn: u16,
+        n_total_si: u16,
+        si_rtrn: Vec<SymInterpret>,
+        group_rtrn: Vec<ModDef>
+    },
+    GetIndicatorState {
+        device_id: u8,
+        state: u32
+    },
+    GetIndicatorMap {
+        device_id: u8,
+        which: u32,
+        real_indicators: u32,
+        n_indicators: u8,
+        maps: Vec<IndicatorMap>
+    },
+    GetNamedIndicator {
+        device_id: u8,
+        indicator: u32,
+        found: bool,
+        on: bool,
+        real_indicator: bool,
+        ndx: u8,
+        map_flags: u8,
+        map_which_groups: u8,
+        map_groups: u8,
+        map_which_mods: u8,
+        map_mods: u8,
+        map_real_mods: u8,
+        map_vmod: u16,
+        map_ctrls: u32,
+        supported: bool
+    },
+    PerClientFlags {
+        device_id: u8,
+        supported: u32,
+        value: u32,
+        auto_ctrls: u32,
+        auto_ctrls_values: u32
+    },
+    ListComponents {
+        device_id: u8,
+        extra: u16,
+        keymaps: Vec<Listing>,
+        keycodes: Vec<Listing>,
+        types: Vec<Listing>,
+        compat_maps: Vec<Listing>,
+        symbols: Vec<Listing>,
+        geometries: Vec<Listing>
+    },
+    SetDebuggingFlags {
+        current_flags: u32,
+        current_ctrls: u32,
+        supported_flags: u32,
+        supported_ctrls: u32
+    },
+    GetMap(GetMapReply),
+    GetNames(GetNamesReply)
+}
+
+#[derive(Debug)]
+pub enum XkbEvent {
+    NewKeyboardNotify {
+        xkb_type: u8,
+        time: u32,
+        device_id: u8,
+        old_device_id: u8,
+        min_key_code: u8,
+        max_key_code: u8,
+        old_min_key_code: u8,
+        old_max_key_code: u8,
+        request_major: u8,
+        request_minor: u8,
+        changed: u16
+    },
+    MapNotify {
+        xkb_type: u8,
+        time: u32,
+        device_id: u8,
+        ptr_btn_actions: u8,
+        changed: u16,
+        min_key_code: u8,
+        max_key_code: u8,
+        first_type: u8,
+        n_types: u8,
+        first_key_sym: u8,
+        n_key_syms: u8,
+        first_key_act: u8,
+        n_key_acts: u8,
+        first_key_behavior: u8,
+        n_key_behavior: u8,
+        first_key_explicit: u8,
+        n_key_explicit: u8,
+        first_mod_map_key: u8,
+        n_mod_map_keys: u8,
+        first_v_mod_map_key: u8,
+        n_v_mod_map_keys: u8,
+        virtual_mods: u16
+    },
+    StateNotify {
+        xkb_type: u8,
+        time: u32,
+        device_id: u8,
+        mods: u8,
+        base_mods: u8,
+        latched_mods: u8,
+        locked_mods: u8,
+        group: Group,
+        base_group: i16,
+        latched_group: i16,
+        locked_group: Group,
+        compat_state: u8,
+        grab_mods: u8,
+        compat_grab_mods: u8,
+        lookup_mods: u8,
+        compat_loockup_mods: u8,
+        ptr_btn_state: u16,
+        changed: u16,
+        keycode: u8,
+        event_type: u8,
+        request_major: u8,
+        request_minor: u8
+    },
+    ControlsNotify {
+        xkb_type: u8,
+        time: u32,
+        device_id: u8,
+        num_groups: u8,
+        changed_controls: u32,
+        enabled_controls: u32,
+        enabled_control_changes: u32,
+        keycode: u8,
+        event_type: u8,
+        request_major: u8,
+        request_minor: u8
+    },
+    IndicatorStateNotify {
+        xkb_type: u8,
+        time: u32,
+        device_id: u8,
+        state: u32,
+        state_changed: u32
+    },
+    IndicatorMapNotify {
+        xkb_type: u8,
+        time: u32,
+        device_id: u8,
+        state: u32,
+        map_changed: u32
+    },
+    NamesNotify {
+        xkb_type: u8,
+        time: u32,
+        device_id: u8,
+        changed: u16,
+        first_type: u8,
+        n_types: u8,
+        first_level_name: u8,
+        n_level_names: u8,
+        n_radio_groups: u8,
+        n_key_aliases: u8,
+        changed_group_names: u8,
+        changed_virtual_mods: u16,
+        first_key: u8,
+        n_keys: u8,
+        changed_indicators: u32
+    },
+    CompatMapNotify {
+        xkb_type: u8,
+        time: u32,
+        device_id: u8,
+        changed_groups: u8,
+        first_si: u16,
+        n_si: u16,
+        n_total_si: u16
+    },
+    BellNotify {
+        xkb_type: u8,
+        time: u32,
+        device_id: u8,
+        bell_class: BellClassResult,
+        bell_id: u8,
+        percent: u8,
+        pitch: u16,
+        duration: u16,
+        name: u32,
+        window: u32,
+        event_only: bool
+    },
+    ActionMessage {
+        xkb_type: u8,
+        time: u32,
+        device_id: u8,
+        keycode: u8,
+        press: bool,
+        key_event_follows: bool,
+        mods: u8,
+        group: Group,
+        message: String
+    },
+    AccessXNotify {
+        xkb_type: u8,
+        time: u32,
+        device_id: u8,
+        keycode: u8,
+        detailt: u16,
+        slow_keys_delay: u16,
+        debounce_delay: u16
+    },
+    ExtensionDeviceNotify {
+        xkb_type: u8,
+        time: u32,
+        device_id: u8,
+        reason: u16,
+        led_class: LedClassResult,
+        led_id: u16,
+        leds_defined: u32,
+        led_state: u32,
+        first_button: u8,
+        n_buttons: u8,
+        supported: u16,
+        unsupported: u16
+    }
+}
+
+/** Decodes XKEYBOARD replies and events */
+#[derive(Debug, Default)]
+pub struct Xkb;
+
+impl Extension for Xkb {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn event_count(&self) -> u8 {
+        1
+    }
+
+    fn error_names(&self) -> &'static [&'static str] {
+        &["BadKeyboard"]
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_USE_EXTENSION => reader.read_xkb_use_extension_reply(detail),
+            OP_GET_STATE => reader.read_xkb_get_state_reply(detail),
+            OP_GET_CONTROLS => reader.read_xkb_get_controls_reply(detail),
+            OP_GET_COMPAT_MAP => reader.read_xkb_get_compat_map_reply(detail),
+            OP_GET_INDICATOR_STATE => reader.read_xkb_get_indicator_state_reply(detail),
+            OP_GET_INDICATOR_MAP => reader.read_xkb_get_indicator_map_reply(detail),
+            OP_GET_NAMED_INDICATOR => reader.read_xkb_get_named_indicator_reply(detail),
+            OP_PER_CLIENT_FLAGS => reader.read_xkb_per_client_flags_reply(detail),
+            OP_LIST_COMPONENTS => reader.read_xkb_list_components_reply(detail),
+            OP_SET_DEBUGGING_FLAGS => reader.read_xkb_set_debugging_flags_reply(detail),
+            OP_GET_MAP => reader.read_xkb_get_map_reply(detail),
+            OP_GET_NAMES => reader.read_xkb_get_names_reply(detail),
+            _ => None
+        }
+    }
+
+    fn read_event(&self, reader: &mut XReadHelper, code: u8, detail: u8) -> Option<ServerEvent> {
+        // XKEYBOARD sends every event with the same code, with the kind of event in `detail`
+        if code != 0 {
+            return None;
+        }
+        match detail {
+            EVENT_NEW_KEYBOARD_NOTIFY => reader.read_xkb_new_keyboard_notify_event(detail),
+            EVENT_MAP_NOTIFY => reader.read_xkb_map_notify_event(detail),
+            EVENT_STATE_NOTIFY => reader.read_xkb_state_notify_event(detail),
+            EVENT_CONTROLS_NOTIFY => reader.read_xkb_controls_notify_event(detail),
+            EVENT_INDICATOR_STATE_NOTIFY => reader.read_xkb_indicator_state_notify_event(detail),
+            EVENT_INDICATOR_MAP_NOTIFY => reader.read_xkb_indicator_map_notify_event(detail),
+            EVENT_NAMES_NOTIFY => reader.read_xkb_names_notify_event(detail),
+            EVENT_COMPAT_MAP_NOTIFY => reader.read_xkb_compat_map_notify_event(detail),
+            EVENT_BELL_NOTIFY => reader.read_xkb_bell_notify_event(detail),
+            EVENT_ACTION_MESSAGE => reader.read_xkb_action_message_event(detail),
+            EVENT_ACCESS_X_NOTIFY => reader.read_xkb_access_x_notify_event(detail),
+            EVENT_EXTENSION_DEVICE_NOTIFY => reader.read_xkb_extension_device_notify_event(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the XKEYBOARD UseExtension request. Returns None without sending anything if the server doesn't have XKEYBOARD, like every request here. */
+    pub fn xkb_use_extension(&mut self, wanted_major: u16, wanted_minor: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_USE_EXTENSION);
+        self.write_u16(2);
+        self.write_u16(wanted_major);
+        self.write_u16(wanted_minor);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_USE_EXTENSION)))
+    }
+
+    /** Sends the XKEYBOARD Bell request */
+    pub fn xkb_bell(&mut self, device_spec: u16, bell_class: u16, bell_id: u16, percent: i8, force_sound: bool, event_only: bool, pitch: i16, duration: i16, name: u32, window: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_BELL);
+        self.write_u16(7);
+        self.write_u16(device_spec);
+        self.write_u16(bell_class);
+        self.write_u16(bell_id);
+        self.write_i8(percent);
+        self.write_bool(force_sound);
+        self.write_bool(event_only);
+        self.write_pad(1);
+        self.write_i16(pitch);
+        self.write_i16(duration);
+        self.write_pad(2);
+        self.write_u32(name);
+        self.write_u32(window);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XKEYBOARD GetState request */
+    pub fn xkb_get_state(&mut self, device_spec: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_STATE);
+        self.write_u16(2);
+        self.write_u16(device_spec);
+        self.write_pad(2);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_STATE)))
+    }
+
+    /** Sends the XKEYBOARD LatchLockState request */
+    pub fn xkb_latch_lock_state(&mut self, device_spec: u16, affect_mod_locks: u8, mod_locks: u8, lock_group: bool, group_lock: &Group, affect_mod_latches: u8, latch_group: bool, group_latch: u16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_LATCH_LOCK_STATE);
+        self.write_u16(4);
+        self.write_u16(device_spec);
+        self.write_u8(affect_mod_locks);
+        self.write_u8(mod_locks);
+        self.write_bool(lock_group);
+        self.write_u8(group_lock.val() as u8);
+        self.write_u8(affect_mod_latches);
+        self.write_pad(1);
+        self.write_pad(1);
+        self.write_bool(latch_group);
+        self.write_u16(group_latch);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XKEYBOARD GetControls request */
+    pub fn xkb_get_controls(&mut self, device_spec: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_CONTROLS);
+        self.write_u16(2);
+        self.write_u16(device_spec);
+        self.write_pad(2);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_CONTROLS)))
+    }
+
+    /** Sends the XKEYBOARD SetControls request */
+    pub fn xkb_set_controls(&mut self, device_spec: u16, affect_internal_real_mods: u8, internal_real_mods: u8, affect_ignore_lock_real_mods: u8, ignore_lock_real_mods: u8, affect_internal_virtual_mods: u16, internal_virtual_mods: u16, affect_ignore_lock_virtual_mods: u16, ignore_lock_virtual_mods: u16, mouse_keys_dflt_btn: u8, groups_wrap: u8, access_x_options: u16, affect_enabled_controls: u32, enabled_controls: u32, change_controls: u32, repeat_delay: u16, repeat_interval: u16, slow_keys_delay: u16, debounce_delay: u16, mouse_keys_delay: u16, mouse_keys_interval: u16, mouse_keys_time_to_max: u16, mouse_keys_max_speed: u16, mouse_keys_curve: i16, access_x_timeout: u16, access_x_timeout_mask: u32, access_x_timeout_values: u32, access_x_timeout_options_mask: u16, access_x_timeout_options_values: u16, per_key_repeat: &[u8]) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_CONTROLS);
+        let pad = self.write_dynamic_len(17, per_key_repeat.len());
+        self.write_u16(device_spec);
+        self.write_u8(affect_internal_real_mods);
+        self.write_u8(internal_real_mods);
+        self.write_u8(affect_ignore_lock_real_mods);
+        self.write_u8(ignore_lock_real_mods);
+        self.write_u16(affect_internal_virtual_mods);
+        self.write_u16(internal_virtual_mods);
+        self.write_u16(affect_ignore_lock_virtual_mods);
+        self.write_u16(ignore_lock_virtual_mods);
+        self.write_u8(mouse_keys_dflt_btn);
+        self.write_u8(groups_wrap);
+        self.write_u16(access_x_options);
+        self.write_pad(2);
+        self.write_u32(affect_enabled_controls);
+        self.write_u32(enabled_controls);
+        self.write_u32(change_controls);
+        self.write_u16(repeat_delay);
+        self.write_u16(repeat_interval);
+        self.write_u16(slow_keys_delay);
+        self.write_u16(debounce_delay);
+        self.write_u16(mouse_keys_delay);
+        self.write_u16(mouse_keys_interval);
+        self.write_u16(mouse_keys_time_to_max);
+        self.write_u16(mouse_keys_max_speed);
+        self.write_i16(mouse_keys_curve);
+        self.write_u16(access_x_timeout);
+        self.write_u32(access_x_timeout_mask);
+        self.write_u32(access_x_timeout_values);
+        self.write_u16(access_x_timeout_options_mask);
+        self.write_u16(access_x_timeout_options_values);
+        self.write_raw(per_key_repeat);
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XKEYBOARD GetMap request */
+    pub fn xkb_get_map(&mut self, device_spec: u16, full: u16, partial: u16, first_type: u8, n_types: u8, first_key_sym: u8, n_key_syms: u8, first_key_action: u8, n_key_actions: u8, first_key_behavior: u8, n_key_behaviors: u8, virtual_mods: u16, first_key_explicit: u8, n_key_explicit: u8, first_mod_map_key: u8, n_mod_map_keys: u8, first_v_mod_map_key: u8, n_v_mod_map_keys: u8) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_MAP);
+        self.write_u16(7);
+        self.write_u16(device_spec);
+        self.write_u16(full);
+        self.write_u16(partial);
+        self.write_u8(first_type);
+        self.write_u8(n_types);
+        self.write_u8(first_key_sym);
+        self.write_u8(n_key_syms);
+        self.write_u8(first_key_action);
+        self.write_u8(n_key_actions);
+        self.write_u8(first_key_behavior);
+        self.write_u8(n_key_behaviors);
+        self.write_u16(virtual_mods);
+        self.write_u8(first_key_explicit);
+        self.write_u8(n_key_explicit);
+        self.write_u8(first_mod_map_key);
+        self.write_u8(n_mod_map_keys);
+        self.write_u8(first_v_mod_map_key);
+        self.write_u8(n_v_mod_map_keys);
+        self.write_pad(2);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_MAP)))
+    }
+
+    /** Sends the XKEYBOARD GetCompatMap request */
+    pub fn xkb_get_compat_map(&mut self, device_spec: u16, groups: u8, get_all_si: bool, first_si: u16, n_si: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_COMPAT_MAP);
+        self.write_u16(3);
+        self.write_u16(device_spec);
+        self.write_u8(groups);
+        self.write_bool(get_all_si);
+        self.write_u16(first_si);
+        self.write_u16(n_si);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_COMPAT_MAP)))
+    }
+
+    /** Sends the XKEYBOARD SetCompatMap request */
+    pub fn xkb_set_compat_map(&mut self, device_spec: u16, recompute_actions: bool, truncate_si: bool, groups: u8, first_si: u16, si: &Vec<SymInterpret>, group_maps: &Vec<ModDef>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_COMPAT_MAP);
+        let pad = self.write_dynamic_len(4, si.len() * 16 + group_maps.len() * 4);
+        self.write_u16(device_spec);
+        self.write_pad(1);
+        self.write_bool(recompute_actions);
+        self.write_bool(truncate_si);
+        self.write_u8(groups);
+        self.write_u16(first_si);
+        self.write_u16(si.len() as u16);
+        self.write_pad(2);
+        for value in si {
+            value.write(self);
+        }
+        for value in group_maps {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XKEYBOARD GetIndicatorState request */
+    pub fn xkb_get_indicator_state(&mut self, device_spec: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_INDICATOR_STATE);
+        self.write_u16(2);
+        self.write_u16(device_spec);
+        self.write_pad(2);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_INDICATOR_STATE)))
+    }
+
+    /** Sends the XKEYBOARD GetIndicatorMap request */
+    pub fn xkb_get_indicator_map(&mut self, device_spec: u16, which: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_INDICATOR_MAP);
+        self.write_u16(3);
+        self.write_u16(device_spec);
+        self.write_pad(2);
+        self.write_u32(which);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_INDICATOR_MAP)))
+    }
+
+    /** Sends the XKEYBOARD SetIndicatorMap request */
+    pub fn xkb_set_indicator_map(&mut self, device_spec: u16, which: u32, maps: &Vec<IndicatorMap>) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_INDICATOR_MAP);
+        let pad = self.write_dynamic_len(3, maps.len() * 12);
+        self.write_u16(device_spec);
+        self.write_pad(2);
+        self.write_u32(which);
+        for value in maps {
+            value.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XKEYBOARD GetNamedIndicator request */
+    pub fn xkb_get_named_indicator(&mut self, device_spec: u16, led_class: &LedClass, led_id: u16, indicator: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_NAMED_INDICATOR);
+        self.write_u16(4);
+        self.write_u16(device_spec);
+        self.write_u16(led_class.val() as u16);
+        self.write_u16(led_id);
+        self.write_pad(2);
+        self.write_u32(indicator);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_NAMED_INDICATOR)))
+    }
+
+    /** Sends the XKEYBOARD SetNamedIndicator request */
+    pub fn xkb_set_named_indicator(&mut self, device_spec: u16, led_class: &LedClass, led_id: u16, indicator: u32, set_state: bool, on: bool, set_map: bool, create_map: bool, map_flags: u8, map_which_groups: u8, map_groups: u8, map_which_mods: u8, map_real_mods: u8, map_vmods: u16, map_ctrls: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_NAMED_INDICATOR);
+        self.write_u16(8);
+        self.write_u16(device_spec);
+        self.write_u16(led_class.val() as u16);
+        self.write_u16(led_id);
+        self.write_pad(2);
+        self.write_u32(indicator);
+        self.write_bool(set_state);
+        self.write_bool(on);
+        self.write_bool(set_map);
+        self.write_bool(create_map);
+        self.write_pad(1);
+        self.write_u8(map_flags);
+        self.write_u8(map_which_groups);
+        self.write_u8(map_groups);
+        self.write_u8(map_which_mods);
+        self.write_u8(map_real_mods);
+        self.write_u16(map_vmods);
+        self.write_u32(map_ctrls);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XKEYBOARD GetNames request */
+    pub fn xkb_get_names(&mut self, device_spec: u16, which: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_NAMES);
+        self.write_u16(3);
+        self.write_u16(device_spec);
+        self.write_pad(2);
+        self.write_u32(which);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_NAMES)))
+    }
+
+    /** Sends the XKEYBOARD PerClientFlags request */
+    pub fn xkb_per_client_flags(&mut self, device_spec: u16, change: u32, value: u32, ctrls_to_change: u32, auto_ctrls: u32, auto_ctrls_values: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_PER_CLIENT_FLAGS);
+        self.write_u16(7);
+        self.write_u16(device_spec);
+        self.write_pad(2);
+        self.write_u32(change);
+        self.write_u32(value);
+        self.write_u32(ctrls_to_change);
+        self.write_u32(auto_ctrls);
+        self.write_u32(auto_ctrls_values);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_PER_CLIENT_FLAGS)))
+    }
+
+    /** Sends the XKEYBOARD ListComponents request */
+    pub fn xkb_list_components(&mut self, device_spec: u16, max_names: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_LIST_COMPONENTS);
+        self.write_u16(2);
+        self.write_u16(device_spec);
+        self.write_u16(max_names);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_LIST_COMPONENTS)))
+    }
+
+    /** Sends the XKEYBOARD GetKbdByName request */
+    pub fn xkb_get_kbd_by_name(&mut self, device_spec: u16, need: u16, want: u16, load: bool) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_KBD_BY_NAME);
+        self.write_u16(3);
+        self.write_u16(device_spec);
+        self.write_u16(need);
+        self.write_u16(want);
+        self.write_bool(load);
+        self.write_pad(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_KBD_BY_NAME)))
+    }
+
+    /** Sends the XKEYBOARD GetDeviceInfo request */
+    pub fn xkb_get_device_info(&mut self, device_spec: u16, wanted: u16, all_buttons: bool, first_button: u8, n_buttons: u8, led_class: &LedClass, led_id: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_DEVICE_INFO);
+        self.write_u16(4);
+        self.write_u16(device_spec);
+        self.write_u16(wanted);
+        self.write_bool(all_buttons);
+        self.write_u8(first_button);
+        self.write_u8(n_buttons);
+        self.write_pad(1);
+        self.write_u16(led_class.val() as u16);
+        self.write_u16(led_id);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_DEVICE_INFO)))
+    }
+
+    /** Sends the XKEYBOARD SetDebuggingFlags request */
+    pub fn xkb_set_debugging_flags(&mut self, affect_flags: u32, flags: u32, affect_ctrls: u32, ctrls: u32, message: &str) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_DEBUGGING_FLAGS);
+        let pad = self.write_dynamic_len(6, message.len());
+        self.write_u16(message.len() as u16);
+        self.write_pad(2);
+        self.write_u32(affect_flags);
+        self.write_u32(flags);
+        self.write_u32(affect_ctrls);
+        self.write_u32(ctrls);
+        self.write_str(message);
+        self.write_pad_op(pad);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_SET_DEBUGGING_FLAGS)))
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to XKEYBOARD UseExtension */
+    fn read_xkb_use_extension_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let supported = detail != 0;
+        let server_major = self.read_u16();
+        let server_minor = self.read_u16();
+        self.read_pad(20);
+        Some(ServerReply::Xkb(XkbReply::UseExtension { supported, server_major, server_minor }))
+    }
+
+    /** Reads a reply to XKEYBOARD GetState */
+    fn read_xkb_get_state_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let device_id = detail;
+        let mods = self.read_u8();
+        let base_mods = self.read_u8();
+        let latched_mods = self.read_u8();
+        let locked_mods = self.read_u8();
+        let group = self.read_u8();
+        let group = match Group::get(group as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let locked_group = self.read_u8();
+        let locked_group = match Group::get(locked_group as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let base_group = self.read_i16();
+        let latched_group = self.read_i16();
+        let compat_state = self.read_u8();
+        let grab_mods = self.read_u8();
+        let compat_grab_mods = self.read_u8();
+        let lookup_mods = self.read_u8();
+        let compat_lookup_mods = self.read_u8();
+        self.read_pad(1);
+        let ptr_btn_state = self.read_u16();
+        self.read_pad(6);
+        Some(ServerReply::Xkb(XkbReply::GetState { device_id, mods, base_mods, latched_mods, locked_mods, group, locked_group, base_group, latched_group, compat_state, grab_mods, compat_grab_mods, lookup_mods, compat_lookup_mods, ptr_btn_state }))
+    }
+
+    /** Reads a reply to XKEYBOARD GetControls */
+    fn read_xkb_get_controls_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let device_id = detail;
+        let mouse_keys_dflt_btn = self.read_u8();
+        let num_groups = self.read_u8();
+        let groups_wrap = self.read_u8();
+        let internal_mods_mask = self.read_u8();
+        let ignore_lock_mods_mask = self.read_u8();
+        let internal_mods_real_mods = self.read_u8();
+        let ignore_lock_mods_real_mods = self.read_u8();
+        self.read_pad(1);
+        let internal_mods_vmods = self.read_u16();
+        let ignore_lock_mods_vmods = self.read_u16();
+        let repeat_delay = self.read_u16();
+        let repeat_interval = self.read_u16();
+        let slow_keys_delay = self.read_u16();
+        let debounce_delay = self.read_u16();
+        let mouse_keys_delay = self.read_u16();
+        let mouse_keys_interval = self.read_u16();
+        let mouse_keys_time_to_max = self.read_u16();
+        let mouse_keys_max_speed = self.read_u16();
+        let mouse_keys_curve = self.read_i16();
+        let access_x_option = self.read_u16();
+        let access_x_timeout = self.read_u16();
+        let access_x_timeout_options_mask = self.read_u16();
+        let access_x_timeout_options_values = self.read_u16();
+        self.read_pad(2);
+        let access_x_timeout_mask = self.read_u32();
+        let access_x_timeout_values = self.read_u32();
+        let enabled_controls = self.read_u32();
+        let per_key_repeat = self.read_raw(32);
+        Some(ServerReply::Xkb(XkbReply::GetControls { device_id, mouse_keys_dflt_btn, num_groups, groups_wrap, internal_mods_mask, ignore_lock_mods_mask, internal_mods_real_mods, ignore_lock_mods_real_mods, internal_mods_vmods, ignore_lock_mods_vmods, repeat_delay, repeat_interval, slow_keys_delay, debounce_delay, mouse_keys_delay, mouse_keys_interval, mouse_keys_time_to_max, mouse_keys_max_speed, mouse_keys_curve, access_x_option, access_x_timeout, access_x_timeout_options_mask, access_x_timeout_options_values, access_x_timeout_mask, access_x_timeout_values, enabled_controls, per_key_repeat }))
+    }
+
+    /** Reads a reply to XKEYBOARD GetCompatMap */
+    fn read_xkb_get_compat_map_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let device_id = detail;
+        let groups_rtrn = self.read_u8();
+        self.read_pad(1);
+        let first_si_rtrn = self.read_u16();
+        let n_si_rtrn = self.read_u16();
+        let n_total_si = self.read_u16();
+        self.read_pad(16);
+        let mut si_rtrn = Vec::with_capacity(n_si_rtrn as usize);
+        for _ in 0..n_si_rtrn as usize {
+            si_rtrn.push(SymInterpret::read(self));
+        }
+        let mut group_rtrn = Vec::with_capacity((groups_rtrn as usize).count_ones() as usize);
+        for _ in 0..(groups_rtrn as usize).count_ones() as usize {
+            group_rtrn.push(ModDef::read(self));
+        }
+        Some(ServerReply::Xkb(XkbReply::GetCompatMap { device_id, groups_rtrn, first_si_rtrn, n_total_si, si_rtrn, group_rtrn }))
+    }
+
+    /** Reads a reply to XKEYBOARD GetIndicatorState */
+    fn read_xkb_get_indicator_state_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let device_id = detail;
+        let state = self.read_u32();
+        self.read_pad(20);
+        Some(ServerReply::Xkb(XkbReply::GetIndicatorState { device_id, state }))
+    }
+
+    /** Reads a reply to XKEYBOARD GetIndicatorMap */
+    fn read_xkb_get_indicator_map_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let device_id = detail;
+        let which = self.read_u32();
+        let real_indicators = self.read_u32();
+        let n_indicators = self.read_u8();
+        self.read_pad(15);
+        let mut maps = Vec::with_capacity((which as usize).count_ones() as usize);
+        for _ in 0..(which as usize).count_ones() as usize {
+            maps.push(IndicatorMap::read(self));
+        }
+        Some(ServerReply::Xkb(XkbReply::GetIndicatorMap { device_id, which, real_indicators, n_indicators, maps }))
+    }
+
+    /** Reads a reply to XKEYBOARD GetNamedIndicator */
+    fn read_xkb_get_named_indicator_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let device_id = detail;
+        let indicator = self.read_u32();
+        let found = self.read_bool();
+        let on = self.read_bool();
+        let real_indicator = self.read_bool();
+        let ndx = self.read_u8();
+        let map_flags = self.read_u8();
+        let map_which_groups = self.read_u8();
+        let map_groups = self.read_u8();
+        let map_which_mods = self.read_u8();
+        let map_mods = self.read_u8();
+        let map_real_mods = self.read_u8();
+        let map_vmod = self.read_u16();
+        let map_ctrls = self.read_u32();
+        let supported = self.read_bool();
+        self.read_pad(3);
+        Some(ServerReply::Xkb(XkbReply::GetNamedIndicator { device_id, indicator, found, on, real_indicator, ndx, map_flags, map_which_groups, map_groups, map_which_mods, map_mods, map_real_mods, map_vmod, map_ctrls, supported }))
+    }
+
+    /** Reads a reply to XKEYBOARD PerClientFlags */
+    fn read_xkb_per_client_flags_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let device_id = detail;
+        let supported = self.read_u32();
+        let value = self.read_u32();
+        let auto_ctrls = self.read_u32();
+        let auto_ctrls_values = self.read_u32();
+        self.read_pad(8);
+        Some(ServerReply::Xkb(XkbReply::PerClientFlags { device_id, supported, value, auto_ctrls, auto_ctrls_values }))
+    }
+
+    /** Reads a reply to XKEYBOARD ListComponents */
+    fn read_xkb_list_components_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let device_id = detail;
+        let n_keymaps = self.read_u16();
+        let n_keycodes = self.read_u16();
+        let n_types = self.read_u16();
+        let n_compat_maps = self.read_u16();
+        let n_symbols = self.read_u16();
+        let n_geometries = self.read_u16();
+        let extra = self.read_u16();
+        self.read_pad(10);
+        let mut keymaps = Vec::with_capacity(n_keymaps as usize);
+        for _ in 0..n_keymaps as usize {
+            keymaps.push(Listing::read(self));
+        }
+        let mut keycodes = Vec::with_capacity(n_keycodes as usize);
+        for _ in 0..n_keycodes as usize {
+            keycodes.push(Listing::read(self));
+        }
+        let mut types = Vec::with_capacity(n_types as usize);
+        for _ in 0..n_types as usize {
+            types.push(Listing::read(self));
+        }
+        let mut compat_maps = Vec::with_capacity(n_compat_maps as usize);
+        for _ in 0..n_compat_maps as usize {
+            compat_maps.push(Listing::read(self));
+        }
+        let mut symbols = Vec::with_capacity(n_symbols as usize);
+        for _ in 0..n_symbols as usize {
+            symbols.push(Listing::read(self));
+        }
+        let mut geometries = Vec::with_capacity(n_geometries as usize);
+        for _ in 0..n_geometries as usize {
+            geometries.push(Listing::read(self));
+        }
+        Some(ServerReply::Xkb(XkbReply::ListComponents { device_id, extra, keymaps, keycodes, types, compat_maps, symbols, geometries }))
+    }
+
+    /** Reads a reply to XKEYBOARD SetDebuggingFlags */
+    fn read_xkb_set_debugging_flags_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let current_flags = self.read_u32();
+        let current_ctrls = self.read_u32();
+        let supported_flags = self.read_u32();
+        let supported_ctrls = self.read_u32();
+        self.read_pad(8);
+        Some(ServerReply::Xkb(XkbReply::SetDebuggingFlags { current_flags, current_ctrls, supported_flags, supported_ctrls }))
+    }
+
+    /** Reads a XKEYBOARD NewKeyboardNotify event */
+    fn read_xkb_new_keyboard_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let xkb_type = detail;
+        let time = self.read_u32();
+        let device_id = self.read_u8();
+        let old_device_id = self.read_u8();
+        let min_key_code = self.read_u8();
+        let max_key_code = self.read_u8();
+        let old_min_key_code = self.read_u8();
+        let old_max_key_code = self.read_u8();
+        let request_major = self.read_u8();
+        let request_minor = self.read_u8();
+        let changed = self.read_u16();
+        self.read_pad(14);
+        Some(ServerEvent::Xkb(XkbEvent::NewKeyboardNotify { xkb_type, time, device_id, old_device_id, min_key_code, max_key_code, old_min_key_code, old_max_key_code, request_major, request_minor, changed }))
+    }
+
+    /** Reads a XKEYBOARD MapNotify event */
+    fn read_xkb_map_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let xkb_type = detail;
+        let time = self.read_u32();
+        let device_id = self.read_u8();
+        let ptr_btn_actions = self.read_u8();
+        let changed = self.read_u16();
+        let min_key_code = self.read_u8();
+        let max_key_code = self.read_u8();
+        let first_type = self.read_u8();
+        let n_types = self.read_u8();
+        let first_key_sym = self.read_u8();
+        let n_key_syms = self.read_u8();
+        let first_key_act = self.read_u8();
+        let n_key_acts = self.read_u8();
+        let first_key_behavior = self.read_u8();
+        let n_key_behavior = self.read_u8();
+        let first_key_explicit = self.read_u8();
+        let n_key_explicit = self.read_u8();
+        let first_mod_map_key = self.read_u8();
+        let n_mod_map_keys = self.read_u8();
+        let first_v_mod_map_key = self.read_u8();
+        let n_v_mod_map_keys = self.read_u8();
+        let virtual_mods = self.read_u16();
+        self.read_pad(2);
+        Some(ServerEvent::Xkb(XkbEvent::MapNotify { xkb_type, time, device_id, ptr_btn_actions, changed, min_key_code, max_key_code, first_type, n_types, first_key_sym, n_key_syms, first_key_act, n_key_acts, first_key_behavior, n_key_behavior, first_key_explicit, n_key_explicit, first_mod_map_key, n_mod_map_keys, first_v_mod_map_key, n_v_mod_map_keys, virtual_mods }))
+    }
+
+    /** Reads a XKEYBOARD StateNotify event */
+    fn read_xkb_state_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let xkb_type = detail;
+        let time = self.read_u32();
+        let device_id = self.read_u8();
+        let mods = self.read_u8();
+        let base_mods = self.read_u8();
+        let latched_mods = self.read_u8();
+        let locked_mods = self.read_u8();
+        let group = self.read_u8();
+        let group = match Group::get(group as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let base_group = self.read_i16();
+        let latched_group = self.read_i16();
+        let locked_group = self.read_u8();
+        let locked_group = match Group::get(locked_group as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let compat_state = self.read_u8();
+        let grab_mods = self.read_u8();
+        let compat_grab_mods = self.read_u8();
+        let lookup_mods = self.read_u8();
+        let compat_loockup_mods = self.read_u8();
+        let ptr_btn_state = self.read_u16();
+        let changed = self.read_u16();
+        let keycode = self.read_u8();
+        let event_type = self.read_u8();
+        let request_major = self.read_u8();
+        let request_minor = self.read_u8();
+        Some(ServerEvent::Xkb(XkbEvent::StateNotify { xkb_type, time, device_id, mods, base_mods, latched_mods, locked_mods, group, base_group, latched_group, locked_group, compat_state, grab_mods, compat_grab_mods, lookup_mods, compat_loockup_mods, ptr_btn_state, changed, keycode, event_type, request_major, request_minor }))
+    }
+
+    /** Reads a XKEYBOARD ControlsNotify event */
+    fn read_xkb_controls_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let xkb_type = detail;
+        let time = self.read_u32();
+        let device_id = self.read_u8();
+        let num_groups = self.read_u8();
+        self.read_pad(2);
+        let changed_controls = self.read_u32();
+        let enabled_controls = self.read_u32();
+        let enabled_control_changes = self.read_u32();
+        let keycode = self.read_u8();
+        let event_type = self.read_u8();
+        let request_major = self.read_u8();
+        let request_minor = self.read_u8();
+        self.read_pad(4);
+        Some(ServerEvent::Xkb(XkbEvent::ControlsNotify { xkb_type, time, device_id, num_groups, changed_controls, enabled_controls, enabled_control_changes, keycode, event_type, request_major, request_minor }))
+    }
+
+    /** Reads a XKEYBOARD IndicatorStateNotify event */
+    fn read_xkb_indicator_state_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let xkb_type = detail;
+        let time = self.read_u32();
+        let device_id = self.read_u8();
+        self.read_pad(3);
+        let state = self.read_u32();
+        let state_changed = self.read_u32();
+        self.read_pad(12);
+        Some(ServerEvent::Xkb(XkbEvent::IndicatorStateNotify { xkb_type, time, device_id, state, state_changed }))
+    }
+
+    /** Reads a XKEYBOARD IndicatorMapNotify event */
+    fn read_xkb_indicator_map_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let xkb_type = detail;
+        let time = self.read_u32();
+        let device_id = self.read_u8();
+        self.read_pad(3);
+        let state = self.read_u32();
+        let map_changed = self.read_u32();
+        self.read_pad(12);
+        Some(ServerEvent::Xkb(XkbEvent::IndicatorMapNotify { xkb_type, time, device_id, state, map_changed }))
+    }
+
+    /** Reads a XKEYBOARD NamesNotify event */
+    fn read_xkb_names_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let xkb_type = detail;
+        let time = self.read_u32();
+        let device_id = self.read_u8();
+        self.read_pad(1);
+        let changed = self.read_u16();
+        let first_type = self.read_u8();
+        let n_types = self.read_u8();
+        let first_level_name = self.read_u8();
+        let n_level_names = self.read_u8();
+        self.read_pad(1);
+        let n_radio_groups = self.read_u8();
+        let n_key_aliases = self.read_u8();
+        let changed_group_names = self.read_u8();
+        let changed_virtual_mods = self.read_u16();
+        let first_key = self.read_u8();
+        let n_keys = self.read_u8();
+        let changed_indicators = self.read_u32();
+        self.read_pad(4);
+        Some(ServerEvent::Xkb(XkbEvent::NamesNotify { xkb_type, time, device_id, changed, first_type, n_types, first_level_name, n_level_names, n_radio_groups, n_key_aliases, changed_group_names, changed_virtual_mods, first_key, n_keys, changed_indicators }))
+    }
+
+    /** Reads a XKEYBOARD CompatMapNotify event */
+    fn read_xkb_compat_map_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let xkb_type = detail;
+        let time = self.read_u32();
+        let device_id = self.read_u8();
+        let changed_groups = self.read_u8();
+        let first_si = self.read_u16();
+        let n_si = self.read_u16();
+        let n_total_si = self.read_u16();
+        self.read_pad(16);
+        Some(ServerEvent::Xkb(XkbEvent::CompatMapNotify { xkb_type, time, device_id, changed_groups, first_si, n_si, n_total_si }))
+    }
+
+    /** Reads a XKEYBOARD BellNotify event */
+    fn read_xkb_bell_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let xkb_type = detail;
+        let time = self.read_u32();
+        let device_id = self.read_u8();
+        let bell_class = self.read_u8();
+        let bell_class = match BellClassResult::get(bell_class as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let bell_id = self.read_u8();
+        let percent = self.read_u8();
+        let pitch = self.read_u16();
+        let duration = self.read_u16();
+        let name = self.read_u32();
+        let window = self.read_u32();
+        let event_only = self.read_bool();
+        self.read_pad(7);
+        Some(ServerEvent::Xkb(XkbEvent::BellNotify { xkb_type, time, device_id, bell_class, bell_id, percent, pitch, duration, name, window, event_only }))
+    }
+
+    /** Reads a XKEYBOARD ActionMessage event */
+    fn read_xkb_action_message_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let xkb_type = detail;
+        let time = self.read_u32();
+        let device_id = self.read_u8();
+        let keycode = self.read_u8();
+        let press = self.read_bool();
+        let key_event_follows = self.read_bool();
+        let mods = self.read_u8();
+        let group = self.read_u8();
+        let group = match Group::get(group as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let message = self.read_str(8);
+        self.read_pad(10);
+        Some(ServerEvent::Xkb(XkbEvent::ActionMessage { xkb_type, time, device_id, keycode, press, key_event_follows, mods, group, message }))
+    }
+
+    /** Reads a XKEYBOARD AccessXNotify event */
+    fn read_xkb_access_x_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let xkb_type = detail;
+        let time = self.read_u32();
+        let device_id = self.read_u8();
+        let keycode = self.read_u8();
+        let detailt = self.read_u16();
+        let slow_keys_delay = self.read_u16();
+        let debounce_delay = self.read_u16();
+        self.read_pad(16);
+        Some(ServerEvent::Xkb(XkbEvent::AccessXNotify { xkb_type, time, device_id, keycode, detailt, slow_keys_delay, debounce_delay }))
+    }
+
+    /** Reads a XKEYBOARD ExtensionDeviceNotify event */
+    fn read_xkb_extension_device_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let xkb_type = detail;
+        let time = self.read_u32();
+        let device_id = self.read_u8();
+        self.read_pad(1);
+        let reason = self.read_u16();
+        let led_class = self.read_u16();
+        let led_class = match LedClassResult::get(led_class as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let led_id = self.read_u16();
+        let leds_defined = self.read_u32();
+        let led_state = self.read_u32();
+        let first_button = self.read_u8();
+        let n_buttons = self.read_u8();
+        let supported = self.read_u16();
+        let unsupported = self.read_u16();
+        self.read_pad(2);
+        Some(ServerEvent::Xkb(XkbEvent::ExtensionDeviceNotify { xkb_type, time, device_id, reason, led_class, led_id, leds_defined, led_state, first_button, n_buttons, supported, unsupported }))
+    }
+}
@@ -0,0 +1,453 @@
+// XKB SelectEvents, GetMap, and GetNames, which xcbgen can't generate, and Keymap, a client side keymap.
+
+#![allow(clippy::match_ref_pats, clippy::ptr_arg)]
+
+use models::{KeyButton, ServerReply, ServerResponse};
+use keysym;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+use super::xkb::*;
+
+/** Which events of a kind to select, for the kinds that have details (ie which parts of the state for StateNotify) */
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SelectEventsDetail {
+    NewKeyboardNotify { affect: u16, details: u16 }, // NKNDetail
+    StateNotify { affect: u16, details: u16 }, // StatePart
+    ControlsNotify { affect: u32, details: u32 }, // Control
+    IndicatorStateNotify { affect: u32, details: u32 }, // Indicators
+    IndicatorMapNotify { affect: u32, details: u32 }, // Indicators
+    NamesNotify { affect: u16, details: u16 }, // NameDetail
+    CompatMapNotify { affect: u8, details: u8 }, // CMDetail
+    BellNotify { affect: u8, details: u8 },
+    ActionMessage { affect: u8, details: u8 },
+    AccessXNotify { affect: u16, details: u16 }, // AXNDetail
+    ExtensionDeviceNotify { affect: u16, details: u16 } // XIFeature
+}
+impl SelectEventsDetail {
+    /** The EventType bit this is for */
+    pub fn event_type(&self) -> EventType {
+        match self {
+            &SelectEventsDetail::NewKeyboardNotify { .. } => EventType::NewKeyboardNotify,
+            &SelectEventsDetail::StateNotify { .. } => EventType::StateNotify,
+            &SelectEventsDetail::ControlsNotify { .. } => EventType::ControlsNotify,
+            &SelectEventsDetail::IndicatorStateNotify { .. } => EventType::IndicatorStateNotify,
+            &SelectEventsDetail::IndicatorMapNotify { .. } => EventType::IndicatorMapNotify,
+            &SelectEventsDetail::NamesNotify { .. } => EventType::NamesNotify,
+            &SelectEventsDetail::CompatMapNotify { .. } => EventType::CompatMapNotify,
+            &SelectEventsDetail::BellNotify { .. } => EventType::BellNotify,
+            &SelectEventsDetail::ActionMessage { .. } => EventType::ActionMessage,
+            &SelectEventsDetail::AccessXNotify { .. } => EventType::AccessXNotify,
+            &SelectEventsDetail::ExtensionDeviceNotify { .. } => EventType::ExtensionDeviceNotify
+        }
+    }
+
+    /** The number of bytes this takes in the request */
+    fn size(&self) -> usize {
+        match self {
+            &SelectEventsDetail::ControlsNotify { .. } | &SelectEventsDetail::IndicatorStateNotify { .. } | &SelectEventsDetail::IndicatorMapNotify { .. } => 8,
+            &SelectEventsDetail::CompatMapNotify { .. } | &SelectEventsDetail::BellNotify { .. } | &SelectEventsDetail::ActionMessage { .. } => 2,
+            _ => 4
+        }
+    }
+
+    fn write(&self, client: &mut XClient) {
+        match self {
+            &SelectEventsDetail::ControlsNotify { affect, details } | &SelectEventsDetail::IndicatorStateNotify { affect, details } | &SelectEventsDetail::IndicatorMapNotify { affect, details } => {
+                client.write_u32(affect);
+                client.write_u32(details);
+            },
+            &SelectEventsDetail::CompatMapNotify { affect, details } | &SelectEventsDetail::BellNotify { affect, details } | &SelectEventsDetail::ActionMessage { affect, details } => {
+                client.write_u8(affect);
+                client.write_u8(details);
+            },
+            &SelectEventsDetail::NewKeyboardNotify { affect, details } | &SelectEventsDetail::StateNotify { affect, details } | &SelectEventsDetail::NamesNotify { affect, details }
+                | &SelectEventsDetail::AccessXNotify { affect, details } | &SelectEventsDetail::ExtensionDeviceNotify { affect, details } => {
+                client.write_u16(affect);
+                client.write_u16(details);
+            }
+        };
+    }
+}
+
+/** The KeyBehaviors part of GetMap */
+#[derive(Debug, Copy, Clone)]
+pub struct SetBehavior {
+    pub keycode: u8,
+    pub kind: u8, // BehaviorType
+    pub data: u8
+}
+
+#[derive(Debug, Clone)]
+pub struct GetMapReply {
+    pub device_id: u8,
+    pub min_key_code: u8,
+    pub max_key_code: u8,
+    pub present: u16, // MapPart, the parts below that were sent
+    pub first_type: u8,
+    pub total_types: u8,
+    pub first_key_sym: u8,
+    pub total_syms: u16,
+    pub first_key_action: u8,
+    pub total_actions: u16,
+    pub first_key_behavior: u8,
+    pub total_key_behaviors: u8,
+    pub first_key_explicit: u8,
+    pub total_key_explicit: u8,
+    pub first_mod_map_key: u8,
+    pub total_mod_map_keys: u8,
+    pub first_v_mod_map_key: u8,
+    pub total_v_mod_map_keys: u8,
+    pub virtual_mods: u16,
+    pub types: Vec<KeyType>,
+    pub syms: Vec<KeySymMap>, // One per key, from first_key_sym
+    pub action_counts: Vec<u8>, // One per key, from first_key_action
+    pub actions: Vec<[u8; 8]>, // Raw actions, action_counts[i] of them for each key
+    pub behaviors: Vec<SetBehavior>,
+    pub vmods: Vec<u8>, // The real modifiers of each virtual modifier in virtual_mods
+    pub explicit: Vec<SetExplicit>,
+    pub modmap: Vec<KeyModMap>,
+    pub vmodmap: Vec<KeyVModMap>
+}
+
+#[derive(Debug, Clone)]
+pub struct GetNamesReply {
+    pub device_id: u8,
+    pub which: u32, // NameDetail, the names below that were sent
+    pub min_key_code: u8,
+    pub max_key_code: u8,
+    pub group_names: u8, // SetOfGroup
+    pub virtual_mods: u16,
+    pub first_key: u8,
+    pub indicators: u32,
+    pub keycodes_name: Option<u32>,
+    pub geometry_name: Option<u32>,
+    pub symbols_name: Option<u32>,
+    pub phys_symbols_name: Option<u32>,
+    pub types_name: Option<u32>,
+    pub compat_name: Option<u32>,
+    pub type_names: Vec<u32>,
+    pub n_levels_per_type: Vec<u8>,
+    pub kt_level_names: Vec<u32>,
+    pub indicator_names: Vec<u32>,
+    pub virtual_mod_names: Vec<u32>,
+    pub groups: Vec<u32>,
+    pub key_names: Vec<KeyName>,
+    pub key_aliases: Vec<KeyAlias>,
+    pub radio_group_names: Vec<u32>
+}
+
+/**
+ * A client side copy of a keyboard's key types and symbols, to turn key presses into keysyms and text.
+ * Get one with XClient::xkb_get_keymap_sync, and get a new one after a MapNotify or NewKeyboardNotify.
+ */
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    pub min_key_code: u8,
+    pub max_key_code: u8,
+    pub types: Vec<KeyType>,
+    first_key_sym: u8,
+    syms: Vec<KeySymMap>
+}
+
+impl Keymap {
+    /** Makes a keymap from a GetMap reply with at least the KeyTypes and KeySyms parts */
+    pub fn new(map: &GetMapReply) -> Keymap {
+        Keymap {
+            min_key_code: map.min_key_code,
+            max_key_code: map.max_key_code,
+            types: map.types.clone(),
+            first_key_sym: map.first_key_sym,
+            syms: map.syms.clone()
+        }
+    }
+
+    /** The XKB group in a key or button event's state (Group1 = 0) */
+    pub fn group(state: &[KeyButton]) -> u8 {
+        state.iter().filter_map(|button| match button {
+            &KeyButton::Group(group) => Some(group),
+            _ => None
+        }).next().unwrap_or(0)
+    }
+
+    /** Returns the keysym for a key, or keysym::NO_SYMBOL. `state` is from the KeyPress or KeyRelease. */
+    pub fn keysym(&self, key_code: u8, state: &[KeyButton]) -> u32 {
+        let mods = state.iter().fold(0u16, |mods, button| mods | button.val()) as u8;
+        let map = match key_code.checked_sub(self.first_key_sym).and_then(|i| self.syms.get(i as usize)) {
+            Some(map) => map,
+            None => return keysym::NO_SYMBOL
+        };
+
+        // Groups past the key's last group wrap around, clamp to the last group, or go to another group
+        let groups = map.group_info & 0x0F;
+        if groups == 0 || map.width == 0 {
+            return keysym::NO_SYMBOL;
+        }
+        let mut group = Keymap::group(state);
+        if group >= groups {
+            group = match map.group_info & 0xC0 {
+                0x40 => groups - 1,
+                0x80 if (map.group_info >> 4) & 0x03 < groups => (map.group_info >> 4) & 0x03,
+                0x80 => 0,
+                _ => group % groups
+            };
+        }
+
+        // The key type of the group picks the level from the modifiers
+        let key_type = match self.types.get(map.kt_index[group as usize] as usize) {
+            Some(key_type) => key_type,
+            None => return keysym::NO_SYMBOL
+        };
+        let mut level = 0;
+        let mut consumed = key_type.mods_mask;
+        for (i, entry) in key_type.map.iter().enumerate() {
+            if entry.active && mods & key_type.mods_mask == entry.mods_mask {
+                level = entry.level;
+                if let Some(preserve) = key_type.preserve.get(i) {
+                    consumed &= !preserve.mask;
+                }
+                break;
+            }
+        }
+        if level >= map.width {
+            return keysym::NO_SYMBOL;
+        }
+
+        let sym = map.syms.get(group as usize * map.width as usize + level as usize).cloned().unwrap_or(keysym::NO_SYMBOL);
+        // Caps Lock makes letters upper case, unless the key type already used it to pick a level
+        if mods & KeyButton::Lock.val() as u8 != 0 && consumed & KeyButton::Lock.val() as u8 == 0 {
+            keysym::to_upper(sym)
+        } else {
+            sym
+        }
+    }
+
+    /** Returns the text typed by a key, as UTF-8. Empty for keys that don't type anything (ie Shift). */
+    pub fn utf8(&self, key_code: u8, state: &[KeyButton]) -> String {
+        let mods = state.iter().fold(0u16, |mods, button| mods | button.val());
+        match keysym::to_char(self.keysym(key_code, state)) {
+            // Control turns letters into control characters, like Xlib does
+            Some(c) if mods & KeyButton::Control.val() != 0 && c.is_ascii() && (c as u8) >= 0x40 && (c as u8) < 0x80 => ((c as u8 & 0x1F) as char).to_string(),
+            Some(c) => c.to_string(),
+            None => String::new()
+        }
+    }
+}
+
+impl XClient {
+    /**
+     * Selects which XKB events to get. MapNotify is selected with affect_map and map, everything else with affect_which,
+     * clear, and select_all (EventType masks). Kinds that are in affect_which but not in clear or select_all need to be in `details`.
+     */
+    #[allow(clippy::too_many_arguments)]
+    pub fn xkb_select_events(&mut self, device_spec: u16, affect_which: u16, clear: u16, select_all: u16, affect_map: u16, map: u16, details: &Vec<SelectEventsDetail>) -> Option<()> {
+        // The server reads the details in the order of their EventType bit
+        let mut details = details.clone();
+        details.sort_by_key(|detail| detail.event_type().val());
+
+        let extension_opcode = self.extension_opcode::<Xkb>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SELECT_EVENTS);
+        let pad = self.write_dynamic_len(4, details.iter().map(|detail| detail.size()).sum());
+        self.write_u16(device_spec);
+        self.write_u16(affect_which);
+        self.write_u16(clear);
+        self.write_u16(select_all);
+        self.write_u16(affect_map);
+        self.write_u16(map);
+        for detail in &details {
+            detail.write(self);
+        }
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Gets the key types and symbols of a keyboard (ie Id::UseCoreKbd), and makes a Keymap from them. Blocks. Send xkb_use_extension first. */
+    pub fn xkb_get_keymap_sync(&mut self, device_spec: u16) -> Option<Keymap> {
+        let full = (MapPart::KeyTypes.val() | MapPart::KeySyms.val()) as u16;
+        let seq = self.xkb_get_map(device_spec, full, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0)?;
+        match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::Xkb(XkbReply::GetMap(map)), _) => Some(Keymap::new(&map)),
+            _ => None
+        }
+    }
+}
+
+/** Bytes needed after `len` bytes to get back to a multiple of 4 */
+fn pad(len: usize) -> usize {
+    (4 - len % 4) % 4
+}
+
+impl XReadHelper {
+    /** Reads a reply to XKEYBOARD GetMap */
+    pub(crate) fn read_xkb_get_map_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let device_id = detail;
+        self.read_pad(2);
+        let min_key_code = self.read_u8();
+        let max_key_code = self.read_u8();
+        let present = self.read_u16();
+        let first_type = self.read_u8();
+        let n_types = self.read_u8();
+        let total_types = self.read_u8();
+        let first_key_sym = self.read_u8();
+        let total_syms = self.read_u16();
+        let n_key_syms = self.read_u8();
+        let first_key_action = self.read_u8();
+        let total_actions = self.read_u16();
+        let n_key_actions = self.read_u8();
+        let first_key_behavior = self.read_u8();
+        self.read_pad(1); // nKeyBehaviors
+        let total_key_behaviors = self.read_u8();
+        let first_key_explicit = self.read_u8();
+        self.read_pad(1); // nKeyExplicit
+        let total_key_explicit = self.read_u8();
+        let first_mod_map_key = self.read_u8();
+        self.read_pad(1); // nModMapKeys
+        let total_mod_map_keys = self.read_u8();
+        let first_v_mod_map_key = self.read_u8();
+        self.read_pad(1); // nVModMapKeys
+        let total_v_mod_map_keys = self.read_u8();
+        self.read_pad(1);
+        let virtual_mods = self.read_u16();
+
+        // Each part is only there if its bit is in `present`, in this order
+        let has = |part: MapPart| present as u32 & part.val() != 0;
+        let mut types = Vec::new();
+        if has(MapPart::KeyTypes) {
+            for _ in 0..n_types {
+                types.push(KeyType::read(self));
+            }
+        }
+        let mut syms = Vec::new();
+        if has(MapPart::KeySyms) {
+            for _ in 0..n_key_syms {
+                syms.push(KeySymMap::read(self));
+            }
+        }
+        let mut action_counts = Vec::new();
+        let mut actions = Vec::new();
+        if has(MapPart::KeyActions) {
+            action_counts = self.read_raw(n_key_actions as usize);
+            self.read_pad(pad(n_key_actions as usize));
+            for _ in 0..total_actions {
+                let mut action = [0u8; 8];
+                self.read_raw_buf(&mut action);
+                actions.push(action);
+            }
+        }
+        let mut behaviors = Vec::new();
+        if has(MapPart::KeyBehaviors) {
+            for _ in 0..total_key_behaviors {
+                let keycode = self.read_u8();
+                let kind = self.read_u8();
+                let data = self.read_u8();
+                self.read_pad(1);
+                behaviors.push(SetBehavior { keycode, kind, data });
+            }
+        }
+        let mut vmods = Vec::new();
+        if has(MapPart::VirtualMods) {
+            let len = virtual_mods.count_ones() as usize;
+            vmods = self.read_raw(len);
+            self.read_pad(pad(len));
+        }
+        let mut explicit = Vec::new();
+        if has(MapPart::ExplicitComponents) {
+            for _ in 0..total_key_explicit {
+                explicit.push(SetExplicit::read(self));
+            }
+            self.read_pad(pad(total_key_explicit as usize * 2));
+        }
+        let mut modmap = Vec::new();
+        if has(MapPart::ModifierMap) {
+            for _ in 0..total_mod_map_keys {
+                modmap.push(KeyModMap::read(self));
+            }
+            self.read_pad(pad(total_mod_map_keys as usize * 2));
+        }
+        let mut vmodmap = Vec::new();
+        if has(MapPart::VirtualModMap) {
+            for _ in 0..total_v_mod_map_keys {
+                vmodmap.push(KeyVModMap::read(self));
+            }
+        }
+
+        Some(ServerReply::Xkb(XkbReply::GetMap(GetMapReply {
+            device_id, min_key_code, max_key_code, present, first_type, total_types, first_key_sym, total_syms, first_key_action,
+            total_actions, first_key_behavior, total_key_behaviors, first_key_explicit, total_key_explicit, first_mod_map_key,
+            total_mod_map_keys, first_v_mod_map_key, total_v_mod_map_keys, virtual_mods, types, syms, action_counts, actions,
+            behaviors, vmods, explicit, modmap, vmodmap
+        })))
+    }
+
+    /** Reads a reply to XKEYBOARD GetNames */
+    pub(crate) fn read_xkb_get_names_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let device_id = detail;
+        let which = self.read_u32();
+        let min_key_code = self.read_u8();
+        let max_key_code = self.read_u8();
+        let n_types = self.read_u8();
+        let group_names = self.read_u8();
+        let virtual_mods = self.read_u16();
+        let first_key = self.read_u8();
+        let n_keys = self.read_u8();
+        let indicators = self.read_u32();
+        let n_radio_groups = self.read_u8();
+        let n_key_aliases = self.read_u8();
+        self.read_pad(6); // nKTLevels, which isn't used (see nLevelsPerType)
+
+        // Each name is only there if its bit is in `which`, in this order
+        let has = |name: NameDetail| which & name.val() != 0;
+        let read_atom = |reader: &mut XReadHelper, name: NameDetail| if has(name) { Some(reader.read_u32()) } else { None };
+        let keycodes_name = read_atom(self, NameDetail::Keycodes);
+        let geometry_name = read_atom(self, NameDetail::Geometry);
+        let symbols_name = read_atom(self, NameDetail::Symbols);
+        let phys_symbols_name = read_atom(self, NameDetail::PhysSymbols);
+        let types_name = read_atom(self, NameDetail::Types);
+        let compat_name = read_atom(self, NameDetail::Compat);
+
+        let read_atoms = |reader: &mut XReadHelper, name: NameDetail, count: usize| {
+            let mut atoms = Vec::new();
+            if has(name) {
+                for _ in 0..count {
+                    atoms.push(reader.read_u32());
+                }
+            }
+            atoms
+        };
+        let type_names = read_atoms(self, NameDetail::KeyTypeNames, n_types as usize);
+        let mut n_levels_per_type = Vec::new();
+        let mut kt_level_names = Vec::new();
+        if has(NameDetail::KTLevelNames) {
+            // Xlib and the server use nTypes here, not nKTLevels like the spec says
+            n_levels_per_type = self.read_raw(n_types as usize);
+            self.read_pad(pad(n_types as usize));
+            let count = n_levels_per_type.iter().map(|&levels| levels as usize).sum();
+            kt_level_names = read_atoms(self, NameDetail::KTLevelNames, count);
+        }
+        let indicator_names = read_atoms(self, NameDetail::IndicatorNames, indicators.count_ones() as usize);
+        let virtual_mod_names = read_atoms(self, NameDetail::VirtualModNames, virtual_mods.count_ones() as usize);
+        let groups = read_atoms(self, NameDetail::GroupNames, group_names.count_ones() as usize);
+        let mut key_names = Vec::new();
+        if has(NameDetail::KeyNames) {
+            for _ in 0..n_keys {
+                key_names.push(KeyName::read(self));
+            }
+        }
+        let mut key_aliases = Vec::new();
+        if has(NameDetail::KeyAliases) {
+            for _ in 0..n_key_aliases {
+                key_aliases.push(KeyAlias::read(self));
+            }
+        }
+        let radio_group_names = read_atoms(self, NameDetail::RGNames, n_radio_groups as usize);
+
+        Some(ServerReply::Xkb(XkbReply::GetNames(GetNamesReply {
+            device_id, which, min_key_code, max_key_code, group_names, virtual_mods, first_key, indicators, keycodes_name,
+            geometry_name, symbols_name, phys_symbols_name, types_name, compat_name, type_names, n_levels_per_type,
+            kt_level_names, indicator_names, virtual_mod_names, groups, key_names, key_aliases, radio_group_names
+        })))
+    }
+}
@@ -0,0 +1,149 @@
+// XTEST extension, version 2.2
+// Generated by tools/xcbgen from tools/xcb-proto/xtest.xml. Don't edit this file, change the generator or
+// xtest_extra.rs (which has everything written by hand) instead.
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+#[allow(unused_imports)] // Some extra modules only add methods
+pub use super::xtest_extra::*;
+
+pub const EXTENSION_NAME: &str = "XTEST";
+pub const MAJOR_VERSION: u32 = 2;
+pub const MINOR_VERSION: u32 = 2;
+
+pub const OP_GET_VERSION: u8 = 0;
+pub const OP_COMPARE_CURSOR: u8 = 1;
+pub const OP_FAKE_INPUT: u8 = 2;
+pub const OP_GRAB_CONTROL: u8 = 3;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Cursor {
+    None,
+    Current
+}
+impl Cursor {
+    pub fn get(id: u32) -> Option<Cursor> {
+        match id {
+            0 => Some(Cursor::None),
+            1 => Some(Cursor::Current),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &Cursor::None => 0,
+            &Cursor::Current => 1
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum XTestReply {
+    GetVersion {
+        major_version: u8,
+        minor_version: u16
+    },
+    CompareCursor {
+        same: bool
+    }
+}
+
+/** Decodes XTEST replies and events */
+#[derive(Debug, Default)]
+pub struct XTest;
+
+impl Extension for XTest {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_GET_VERSION => reader.read_xtest_get_version_reply(detail),
+            OP_COMPARE_CURSOR => reader.read_xtest_compare_cursor_reply(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the XTEST GetVersion request. Returns None without sending anything if the server doesn't have XTEST, like every request here. */
+    pub fn xtest_get_version(&mut self, major_version: u8, minor_version: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XTest>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_VERSION);
+        self.write_u16(2);
+        self.write_u8(major_version);
+        self.write_pad(1);
+        self.write_u16(minor_version);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_VERSION)))
+    }
+
+    /** Sends the XTEST CompareCursor request */
+    pub fn xtest_compare_cursor(&mut self, window: u32, cursor: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<XTest>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_COMPARE_CURSOR);
+        self.write_u16(3);
+        self.write_u32(window);
+        self.write_u32(cursor);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_COMPARE_CURSOR)))
+    }
+
+    /** Sends the XTEST FakeInput request */
+    pub fn xtest_fake_input(&mut self, kind: u8, detail: u8, time: u32, root: u32, root_x: i16, root_y: i16, deviceid: u8) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XTest>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_FAKE_INPUT);
+        self.write_u16(9);
+        self.write_u8(kind);
+        self.write_u8(detail);
+        self.write_pad(2);
+        self.write_u32(time);
+        self.write_u32(root);
+        self.write_pad(8);
+        self.write_i16(root_x);
+        self.write_i16(root_y);
+        self.write_pad(7);
+        self.write_u8(deviceid);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XTEST GrabControl request */
+    pub fn xtest_grab_control(&mut self, impervious: bool) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<XTest>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GRAB_CONTROL);
+        self.write_u16(2);
+        self.write_bool(impervious);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to XTEST GetVersion */
+    fn read_xtest_get_version_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let major_version = detail;
+        let minor_version = self.read_u16();
+        Some(ServerReply::XTest(XTestReply::GetVersion { major_version, minor_version }))
+    }
+
+    /** Reads a reply to XTEST CompareCursor */
+    fn read_xtest_compare_cursor_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let same = detail != 0;
+        Some(ServerReply::XTest(XTestReply::CompareCursor { same }))
+    }
+}
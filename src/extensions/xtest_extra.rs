@@ -0,0 +1,129 @@
+// Faking key presses, pointer buttons, and motion, and typing text.
+
+use std::collections::HashMap;
+
+use keysym;
+use models::{ServerReply, ServerResponse};
+use protocol;
+use XClient;
+
+use super::xtest;
+
+impl XClient {
+    /** Presses or releases a key, as if the user did */
+    pub fn xtest_fake_key(&mut self, keycode: u8, press: bool) -> Option<()> {
+        let kind = if press { protocol::REPLY_KEY_PRESS } else { protocol::REPLY_KEY_RELEASE };
+        self.xtest_fake_input(kind, keycode, 0, 0, 0, 0, 0)
+    }
+
+    /** Presses or releases a pointer button (1 is left, 3 is right, 4 and 5 scroll) */
+    pub fn xtest_fake_button(&mut self, button: u8, press: bool) -> Option<()> {
+        let kind = if press { protocol::REPLY_BUTTON_PRESS } else { protocol::REPLY_BUTTON_RELEASE };
+        self.xtest_fake_input(kind, button, 0, 0, 0, 0, 0)
+    }
+
+    /** Moves the pointer to a position on the root window of the screen it's on */
+    pub fn xtest_fake_motion(&mut self, x: i16, y: i16) -> Option<()> {
+        self.xtest_fake_input(protocol::REPLY_MOTION_NOTIFY, 0, 0, 0, x, y, 0)
+    }
+
+    /** Moves the pointer relative to where it is */
+    pub fn xtest_fake_relative_motion(&mut self, dx: i16, dy: i16) -> Option<()> {
+        self.xtest_fake_input(protocol::REPLY_MOTION_NOTIFY, 1, 0, 0, dx, dy, 0)
+    }
+
+    /**
+     * Types some text with fake key presses, holding Shift for characters on the second level of a key. Blocks to get the keyboard mapping.
+     * Characters that aren't on the keyboard are typed by mapping them to unused keycodes, which are put back afterwards.
+     * '\n' and '\t' are typed with Return and Tab.
+     * Returns false if some characters couldn't be typed (there were no unused keycodes, or no Shift key), or if the
+     * server doesn't have XTEST.
+     */
+    pub fn type_text(&mut self, text: &str) -> bool {
+        if self.query_extension_sync(xtest::EXTENSION_NAME).is_none() {
+            return false;
+        }
+        let min_keycode = self.info.min_keycode;
+        let seq = self.get_keyboard_mapping(min_keycode, self.info.max_keycode - min_keycode + 1);
+        let mapping = match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::GetKeyboardMapping { mapping }, _) => mapping,
+            _ => return false
+        };
+
+        // (keysym, shift) -> keycode, for the first group of each key
+        let mut keys = HashMap::new();
+        for (i, keysyms) in mapping.iter().enumerate().rev() {
+            let keycode = min_keycode + i as u8;
+            let (lower, upper) = match (keysyms.first().cloned().unwrap_or(0), keysyms.get(1).cloned().unwrap_or(0)) {
+                (lower, 0) => (keysym::to_lower(lower), keysym::to_upper(lower)), // A lone letter has both cases
+                levels => levels
+            };
+            if lower != 0 {
+                keys.insert((lower, false), keycode);
+            }
+            if upper != 0 && upper != lower {
+                keys.insert((upper, true), keycode);
+            }
+        }
+        let shift = keys.get(&(keysym::SHIFT_L, false)).cloned();
+        let mut spare: Vec<u8> = mapping.iter().enumerate()
+            .filter(|&(_, keysyms)| keysyms.iter().all(|&keysym| keysym == 0))
+            .map(|(i, _)| min_keycode + i as u8)
+            .collect();
+        let mut remapped: Vec<u8> = vec![];
+        let mut typed_all = true;
+
+        for c in text.chars() {
+            let keysym = match c {
+                '\n' => keysym::RETURN,
+                '\t' => keysym::TAB,
+                c => keysym::from_char(c)
+            };
+            if let Some(&keycode) = keys.get(&(keysym, false)) {
+                self.xtest_fake_key(keycode, true);
+                self.xtest_fake_key(keycode, false);
+            } else if let (Some(&keycode), Some(shift)) = (keys.get(&(keysym, true)), shift) {
+                self.xtest_fake_key(shift, true);
+                self.xtest_fake_key(keycode, true);
+                self.xtest_fake_key(keycode, false);
+                self.xtest_fake_key(shift, false);
+            } else {
+                // Each missing keysym gets its own keycode while there are enough, so clients that look the mapping up late still see it
+                let keycode = match spare.pop() {
+                    Some(keycode) => keycode,
+                    None if !remapped.is_empty() => {
+                        self.sync_input();
+                        remapped[0]
+                    },
+                    None => {
+                        typed_all = false;
+                        continue;
+                    }
+                };
+                keys.retain(|_, &mut k| k != keycode);
+                keys.insert((keysym, false), keycode);
+                if !remapped.contains(&keycode) {
+                    remapped.push(keycode);
+                }
+
+                self.change_keyboard_mapping(keycode, 1, &vec![keysym, keysym]);
+                self.xtest_fake_key(keycode, true);
+                self.xtest_fake_key(keycode, false);
+            }
+        }
+
+        if !remapped.is_empty() {
+            self.sync_input();
+        }
+        for keycode in remapped {
+            self.change_keyboard_mapping(keycode, 1, &vec![0, 0]);
+        }
+        typed_all
+    }
+
+    /** Waits for the server to handle the fake key presses so far, so remapping their keycodes doesn't change them */
+    fn sync_input(&mut self) {
+        let seq = self.get_input_focus();
+        self.wait_for_response(seq);
+    }
+}
@@ -0,0 +1,1016 @@
+// XVideo extension, version 2.2
+// Generated by tools/xcbgen from tools/xcb-proto/xv.xml. Don't edit this file, change the generator or
+// xv_extra.rs (which has everything written by hand) instead.
+
+#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]
+
+use models::{ServerEvent, ServerReply, ServerReplyType};
+use extensions::Extension;
+use xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use XClient;
+
+#[allow(unused_imports)] // Some extra modules only add methods
+pub use super::xv_extra::*;
+
+pub const EXTENSION_NAME: &str = "XVideo";
+pub const MAJOR_VERSION: u32 = 2;
+pub const MINOR_VERSION: u32 = 2;
+
+pub const OP_QUERY_EXTENSION: u8 = 0;
+pub const OP_QUERY_ADAPTORS: u8 = 1;
+pub const OP_QUERY_ENCODINGS: u8 = 2;
+pub const OP_GRAB_PORT: u8 = 3;
+pub const OP_UNGRAB_PORT: u8 = 4;
+pub const OP_PUT_VIDEO: u8 = 5;
+pub const OP_PUT_STILL: u8 = 6;
+pub const OP_GET_VIDEO: u8 = 7;
+pub const OP_GET_STILL: u8 = 8;
+pub const OP_STOP_VIDEO: u8 = 9;
+pub const OP_SELECT_VIDEO_NOTIFY: u8 = 10;
+pub const OP_SELECT_PORT_NOTIFY: u8 = 11;
+pub const OP_QUERY_BEST_SIZE: u8 = 12;
+pub const OP_SET_PORT_ATTRIBUTE: u8 = 13;
+pub const OP_GET_PORT_ATTRIBUTE: u8 = 14;
+pub const OP_QUERY_PORT_ATTRIBUTES: u8 = 15;
+pub const OP_LIST_IMAGE_FORMATS: u8 = 16;
+pub const OP_QUERY_IMAGE_ATTRIBUTES: u8 = 17;
+pub const OP_PUT_IMAGE: u8 = 18;
+pub const OP_SHM_PUT_IMAGE: u8 = 19;
+
+pub const EVENT_VIDEO_NOTIFY: u8 = 0;
+pub const EVENT_PORT_NOTIFY: u8 = 1;
+
+pub const ERROR_BAD_PORT: u8 = 0;
+pub const ERROR_BAD_ENCODING: u8 = 1;
+pub const ERROR_BAD_CONTROL: u8 = 2;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Type {
+    InputMask,
+    OutputMask,
+    VideoMask,
+    StillMask,
+    ImageMask
+}
+impl Type {
+    pub fn val(&self) -> u32 {
+        match self {
+            &Type::InputMask => 0x00000001,
+            &Type::OutputMask => 0x00000002,
+            &Type::VideoMask => 0x00000004,
+            &Type::StillMask => 0x00000008,
+            &Type::ImageMask => 0x00000010
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ImageFormatInfoType {
+    Rgb,
+    Yuv
+}
+impl ImageFormatInfoType {
+    pub fn get(id: u32) -> Option<ImageFormatInfoType> {
+        match id {
+            0 => Some(ImageFormatInfoType::Rgb),
+            1 => Some(ImageFormatInfoType::Yuv),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &ImageFormatInfoType::Rgb => 0,
+            &ImageFormatInfoType::Yuv => 1
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ImageFormatInfoFormat {
+    Packed,
+    Planar
+}
+impl ImageFormatInfoFormat {
+    pub fn get(id: u32) -> Option<ImageFormatInfoFormat> {
+        match id {
+            0 => Some(ImageFormatInfoFormat::Packed),
+            1 => Some(ImageFormatInfoFormat::Planar),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &ImageFormatInfoFormat::Packed => 0,
+            &ImageFormatInfoFormat::Planar => 1
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AttributeFlag {
+    Gettable,
+    Settable
+}
+impl AttributeFlag {
+    pub fn val(&self) -> u32 {
+        match self {
+            &AttributeFlag::Gettable => 0x00000001,
+            &AttributeFlag::Settable => 0x00000002
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum VideoNotifyReason {
+    Started,
+    Stopped,
+    Busy,
+    Preempted,
+    HardError
+}
+impl VideoNotifyReason {
+    pub fn get(id: u32) -> Option<VideoNotifyReason> {
+        match id {
+            0 => Some(VideoNotifyReason::Started),
+            1 => Some(VideoNotifyReason::Stopped),
+            2 => Some(VideoNotifyReason::Busy),
+            3 => Some(VideoNotifyReason::Preempted),
+            4 => Some(VideoNotifyReason::HardError),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &VideoNotifyReason::Started => 0,
+            &VideoNotifyReason::Stopped => 1,
+            &VideoNotifyReason::Busy => 2,
+            &VideoNotifyReason::Preempted => 3,
+            &VideoNotifyReason::HardError => 4
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ScanlineOrder {
+    TopToBottom,
+    BottomToTop
+}
+impl ScanlineOrder {
+    pub fn get(id: u32) -> Option<ScanlineOrder> {
+        match id {
+            0 => Some(ScanlineOrder::TopToBottom),
+            1 => Some(ScanlineOrder::BottomToTop),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &ScanlineOrder::TopToBottom => 0,
+            &ScanlineOrder::BottomToTop => 1
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GrabPortStatus {
+    Success,
+    BadExtension,
+    AlreadyGrabbed,
+    InvalidTime,
+    BadReply,
+    BadAlloc
+}
+impl GrabPortStatus {
+    pub fn get(id: u32) -> Option<GrabPortStatus> {
+        match id {
+            0 => Some(GrabPortStatus::Success),
+            1 => Some(GrabPortStatus::BadExtension),
+            2 => Some(GrabPortStatus::AlreadyGrabbed),
+            3 => Some(GrabPortStatus::InvalidTime),
+            4 => Some(GrabPortStatus::BadReply),
+            5 => Some(GrabPortStatus::BadAlloc),
+            _ => None
+        }
+    }
+
+    pub fn val(&self) -> u32 {
+        match self {
+            &GrabPortStatus::Success => 0,
+            &GrabPortStatus::BadExtension => 1,
+            &GrabPortStatus::AlreadyGrabbed => 2,
+            &GrabPortStatus::InvalidTime => 3,
+            &GrabPortStatus::BadReply => 4,
+            &GrabPortStatus::BadAlloc => 5
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Rational {
+    pub numerator: i32,
+    pub denominator: i32
+}
+impl Rational {
+    pub fn read(reader: &mut XReadHelper) -> Rational {
+        let numerator = reader.read_i32();
+        let denominator = reader.read_i32();
+        Rational { numerator, denominator }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_i32(self.numerator);
+        client.write_i32(self.denominator);
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Format {
+    pub visual: u32,
+    pub depth: u8
+}
+impl Format {
+    pub fn read(reader: &mut XReadHelper) -> Format {
+        let visual = reader.read_u32();
+        let depth = reader.read_u8();
+        reader.read_pad(3);
+        Format { visual, depth }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.visual);
+        client.write_u8(self.depth);
+        client.write_pad(3);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AdaptorInfo {
+    pub base_id: u32,
+    pub num_ports: u16,
+    pub kind: u8,
+    pub name: String,
+    pub formats: Vec<Format>
+}
+impl AdaptorInfo {
+    pub fn read(reader: &mut XReadHelper) -> AdaptorInfo {
+        let base_id = reader.read_u32();
+        let name_size = reader.read_u16();
+        let num_ports = reader.read_u16();
+        let num_formats = reader.read_u16();
+        let kind = reader.read_u8();
+        reader.read_pad(1);
+        let name = reader.read_str(name_size as usize);
+        reader.read_pad((4 - (12 + name.len()) % 4) % 4);
+        let mut formats = Vec::with_capacity(num_formats as usize);
+        for _ in 0..num_formats as usize {
+            formats.push(Format::read(reader));
+        }
+        AdaptorInfo { base_id, num_ports, kind, name, formats }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.base_id);
+        client.write_u16(self.name.len() as u16);
+        client.write_u16(self.num_ports);
+        client.write_u16(self.formats.len() as u16);
+        client.write_u8(self.kind);
+        client.write_pad(1);
+        client.write_str(&self.name);
+        client.write_pad_op((4 - (12 + self.name.len()) % 4) % 4);
+        for value in &self.formats {
+            value.write(client);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EncodingInfo {
+    pub encoding: u32,
+    pub width: u16,
+    pub height: u16,
+    pub rate: Rational,
+    pub name: String
+}
+impl EncodingInfo {
+    pub fn read(reader: &mut XReadHelper) -> EncodingInfo {
+        let encoding = reader.read_u32();
+        let name_size = reader.read_u16();
+        let width = reader.read_u16();
+        let height = reader.read_u16();
+        reader.read_pad(2);
+        let rate = Rational::read(reader);
+        let name = reader.read_str(name_size as usize);
+        reader.read_pad((4 - (20 + name.len()) % 4) % 4);
+        EncodingInfo { encoding, width, height, rate, name }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.encoding);
+        client.write_u16(self.name.len() as u16);
+        client.write_u16(self.width);
+        client.write_u16(self.height);
+        client.write_pad(2);
+        self.rate.write(client);
+        client.write_str(&self.name);
+        client.write_pad_op((4 - (20 + self.name.len()) % 4) % 4);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Image {
+    pub id: u32,
+    pub width: u16,
+    pub height: u16,
+    pub pitches: Vec<u32>,
+    pub offsets: Vec<u32>,
+    pub data: Vec<u8>
+}
+impl Image {
+    pub fn read(reader: &mut XReadHelper) -> Image {
+        let id = reader.read_u32();
+        let width = reader.read_u16();
+        let height = reader.read_u16();
+        let data_size = reader.read_u32();
+        let num_planes = reader.read_u32();
+        let mut pitches = Vec::with_capacity(num_planes as usize);
+        for _ in 0..num_planes as usize {
+            pitches.push(reader.read_u32());
+        }
+        let mut offsets = Vec::with_capacity(num_planes as usize);
+        for _ in 0..num_planes as usize {
+            offsets.push(reader.read_u32());
+        }
+        let data = reader.read_raw(data_size as usize);
+        Image { id, width, height, pitches, offsets, data }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.id);
+        client.write_u16(self.width);
+        client.write_u16(self.height);
+        client.write_u32(self.data.len() as u32);
+        client.write_u32(self.pitches.len() as u32);
+        for value in &self.pitches {
+            client.write_u32(*value);
+        }
+        for value in &self.offsets {
+            client.write_u32(*value);
+        }
+        client.write_raw(&self.data);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AttributeInfo {
+    pub flags: u32,
+    pub min: i32,
+    pub max: i32,
+    pub name: String
+}
+impl AttributeInfo {
+    pub fn read(reader: &mut XReadHelper) -> AttributeInfo {
+        let flags = reader.read_u32();
+        let min = reader.read_i32();
+        let max = reader.read_i32();
+        let size = reader.read_u32();
+        let name = reader.read_str(size as usize);
+        reader.read_pad((4 - (16 + name.len()) % 4) % 4);
+        AttributeInfo { flags, min, max, name }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.flags);
+        client.write_i32(self.min);
+        client.write_i32(self.max);
+        client.write_u32(self.name.len() as u32);
+        client.write_str(&self.name);
+        client.write_pad_op((4 - (16 + self.name.len()) % 4) % 4);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ImageFormatInfo {
+    pub id: u32,
+    pub kind: u8,
+    pub byte_order: u8,
+    pub guid: Vec<u8>,
+    pub bpp: u8,
+    pub num_planes: u8,
+    pub depth: u8,
+    pub red_mask: u32,
+    pub green_mask: u32,
+    pub blue_mask: u32,
+    pub format: u8,
+    pub y_sample_bits: u32,
+    pub u_sample_bits: u32,
+    pub v_sample_bits: u32,
+    pub vhorz_y_period: u32,
+    pub vhorz_u_period: u32,
+    pub vhorz_v_period: u32,
+    pub vvert_y_period: u32,
+    pub vvert_u_period: u32,
+    pub vvert_v_period: u32,
+    pub vcomp_order: Vec<u8>,
+    pub vscanline_order: u8
+}
+impl ImageFormatInfo {
+    pub fn read(reader: &mut XReadHelper) -> ImageFormatInfo {
+        let id = reader.read_u32();
+        let kind = reader.read_u8();
+        let byte_order = reader.read_u8();
+        reader.read_pad(2);
+        let guid = reader.read_raw(16);
+        let bpp = reader.read_u8();
+        let num_planes = reader.read_u8();
+        reader.read_pad(2);
+        let depth = reader.read_u8();
+        reader.read_pad(3);
+        let red_mask = reader.read_u32();
+        let green_mask = reader.read_u32();
+        let blue_mask = reader.read_u32();
+        let format = reader.read_u8();
+        reader.read_pad(3);
+        let y_sample_bits = reader.read_u32();
+        let u_sample_bits = reader.read_u32();
+        let v_sample_bits = reader.read_u32();
+        let vhorz_y_period = reader.read_u32();
+        let vhorz_u_period = reader.read_u32();
+        let vhorz_v_period = reader.read_u32();
+        let vvert_y_period = reader.read_u32();
+        let vvert_u_period = reader.read_u32();
+        let vvert_v_period = reader.read_u32();
+        let vcomp_order = reader.read_raw(32);
+        let vscanline_order = reader.read_u8();
+        reader.read_pad(11);
+        ImageFormatInfo { id, kind, byte_order, guid, bpp, num_planes, depth, red_mask, green_mask, blue_mask, format, y_sample_bits, u_sample_bits, v_sample_bits, vhorz_y_period, vhorz_u_period, vhorz_v_period, vvert_y_period, vvert_u_period, vvert_v_period, vcomp_order, vscanline_order }
+    }
+
+    pub fn write(&self, client: &mut XClient) {
+        client.write_u32(self.id);
+        client.write_u8(self.kind);
+        client.write_u8(self.byte_order);
+        client.write_pad(2);
+        client.write_raw(&self.guid);
+        client.write_u8(self.bpp);
+        client.write_u8(self.num_planes);
+        client.write_pad(2);
+        client.write_u8(self.depth);
+        client.write_pad(3);
+        client.write_u32(self.red_mask);
+        client.write_u32(self.green_mask);
+        client.write_u32(self.blue_mask);
+        client.write_u8(self.format);
+        client.write_pad(3);
+        client.write_u32(self.y_sample_bits);
+        client.write_u32(self.u_sample_bits);
+        client.write_u32(self.v_sample_bits);
+        client.write_u32(self.vhorz_y_period);
+        client.write_u32(self.vhorz_u_period);
+        client.write_u32(self.vhorz_v_period);
+        client.write_u32(self.vvert_y_period);
+        client.write_u32(self.vvert_u_period);
+        client.write_u32(self.vvert_v_period);
+        client.write_raw(&self.vcomp_order);
+        client.write_u8(self.vscanline_order);
+        client.write_pad(11);
+    }
+}
+
+#[derive(Debug)]
+pub enum XvReply {
+    QueryExtension {
+        major: u16,
+        minor: u16
+    },
+    QueryAdaptors {
+        info: Vec<AdaptorInfo>
+    },
+    QueryEncodings {
+        info: Vec<EncodingInfo>
+    },
+    GrabPort {
+        result: GrabPortStatus
+    },
+    QueryBestSize {
+        actual_width: u16,
+        actual_height: u16
+    },
+    GetPortAttribute {
+        value: i32
+    },
+    QueryPortAttributes {
+        text_size: u32,
+        attributes: Vec<AttributeInfo>
+    },
+    ListImageFormats {
+        format: Vec<ImageFormatInfo>
+    },
+    QueryImageAttributes {
+        data_size: u32,
+        width: u16,
+        height: u16,
+        pitches: Vec<u32>,
+        offsets: Vec<u32>
+    }
+}
+
+#[derive(Debug)]
+pub enum XvEvent {
+    VideoNotify {
+        reason: VideoNotifyReason,
+        time: u32,
+        drawable: u32,
+        port: u32
+    },
+    PortNotify {
+        time: u32,
+        port: u32,
+        attribute: u32,
+        value: i32
+    }
+}
+
+/** Decodes XVideo replies and events */
+#[derive(Debug, Default)]
+pub struct Xv;
+
+impl Extension for Xv {
+    fn name(&self) -> &'static str {
+        EXTENSION_NAME
+    }
+
+    fn event_count(&self) -> u8 {
+        2
+    }
+
+    fn error_names(&self) -> &'static [&'static str] {
+        &["BadPort", "BadEncoding", "BadControl"]
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        match minor_opcode {
+            OP_QUERY_EXTENSION => reader.read_xv_query_extension_reply(detail),
+            OP_QUERY_ADAPTORS => reader.read_xv_query_adaptors_reply(detail),
+            OP_QUERY_ENCODINGS => reader.read_xv_query_encodings_reply(detail),
+            OP_GRAB_PORT => reader.read_xv_grab_port_reply(detail),
+            OP_QUERY_BEST_SIZE => reader.read_xv_query_best_size_reply(detail),
+            OP_GET_PORT_ATTRIBUTE => reader.read_xv_get_port_attribute_reply(detail),
+            OP_QUERY_PORT_ATTRIBUTES => reader.read_xv_query_port_attributes_reply(detail),
+            OP_LIST_IMAGE_FORMATS => reader.read_xv_list_image_formats_reply(detail),
+            OP_QUERY_IMAGE_ATTRIBUTES => reader.read_xv_query_image_attributes_reply(detail),
+            _ => None
+        }
+    }
+
+    fn read_event(&self, reader: &mut XReadHelper, code: u8, detail: u8) -> Option<ServerEvent> {
+        match code {
+            EVENT_VIDEO_NOTIFY => reader.read_xv_video_notify_event(detail),
+            EVENT_PORT_NOTIFY => reader.read_xv_port_notify_event(detail),
+            _ => None
+        }
+    }
+}
+
+impl XClient {
+    /** Sends the XVideo QueryExtension request. Returns None without sending anything if the server doesn't have XVideo, like every request here. */
+    pub fn xv_query_extension(&mut self) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_EXTENSION);
+        self.write_u16(1);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_EXTENSION)))
+    }
+
+    /** Sends the XVideo QueryAdaptors request */
+    pub fn xv_query_adaptors(&mut self, window: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_ADAPTORS);
+        self.write_u16(2);
+        self.write_u32(window);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_ADAPTORS)))
+    }
+
+    /** Sends the XVideo QueryEncodings request */
+    pub fn xv_query_encodings(&mut self, port: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_ENCODINGS);
+        self.write_u16(2);
+        self.write_u32(port);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_ENCODINGS)))
+    }
+
+    /** Sends the XVideo GrabPort request */
+    pub fn xv_grab_port(&mut self, port: u32, time: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GRAB_PORT);
+        self.write_u16(3);
+        self.write_u32(port);
+        self.write_u32(time);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GRAB_PORT)))
+    }
+
+    /** Sends the XVideo UngrabPort request */
+    pub fn xv_ungrab_port(&mut self, port: u32, time: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_UNGRAB_PORT);
+        self.write_u16(3);
+        self.write_u32(port);
+        self.write_u32(time);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XVideo PutVideo request */
+    pub fn xv_put_video(&mut self, port: u32, drawable: u32, gc: u32, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_PUT_VIDEO);
+        self.write_u16(8);
+        self.write_u32(port);
+        self.write_u32(drawable);
+        self.write_u32(gc);
+        self.write_i16(vid_x);
+        self.write_i16(vid_y);
+        self.write_u16(vid_w);
+        self.write_u16(vid_h);
+        self.write_i16(drw_x);
+        self.write_i16(drw_y);
+        self.write_u16(drw_w);
+        self.write_u16(drw_h);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XVideo PutStill request */
+    pub fn xv_put_still(&mut self, port: u32, drawable: u32, gc: u32, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_PUT_STILL);
+        self.write_u16(8);
+        self.write_u32(port);
+        self.write_u32(drawable);
+        self.write_u32(gc);
+        self.write_i16(vid_x);
+        self.write_i16(vid_y);
+        self.write_u16(vid_w);
+        self.write_u16(vid_h);
+        self.write_i16(drw_x);
+        self.write_i16(drw_y);
+        self.write_u16(drw_w);
+        self.write_u16(drw_h);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XVideo GetVideo request */
+    pub fn xv_get_video(&mut self, port: u32, drawable: u32, gc: u32, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_VIDEO);
+        self.write_u16(8);
+        self.write_u32(port);
+        self.write_u32(drawable);
+        self.write_u32(gc);
+        self.write_i16(vid_x);
+        self.write_i16(vid_y);
+        self.write_u16(vid_w);
+        self.write_u16(vid_h);
+        self.write_i16(drw_x);
+        self.write_i16(drw_y);
+        self.write_u16(drw_w);
+        self.write_u16(drw_h);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XVideo GetStill request */
+    pub fn xv_get_still(&mut self, port: u32, drawable: u32, gc: u32, vid_x: i16, vid_y: i16, vid_w: u16, vid_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_STILL);
+        self.write_u16(8);
+        self.write_u32(port);
+        self.write_u32(drawable);
+        self.write_u32(gc);
+        self.write_i16(vid_x);
+        self.write_i16(vid_y);
+        self.write_u16(vid_w);
+        self.write_u16(vid_h);
+        self.write_i16(drw_x);
+        self.write_i16(drw_y);
+        self.write_u16(drw_w);
+        self.write_u16(drw_h);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XVideo StopVideo request */
+    pub fn xv_stop_video(&mut self, port: u32, drawable: u32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_STOP_VIDEO);
+        self.write_u16(3);
+        self.write_u32(port);
+        self.write_u32(drawable);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XVideo SelectVideoNotify request */
+    pub fn xv_select_video_notify(&mut self, drawable: u32, onoff: bool) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SELECT_VIDEO_NOTIFY);
+        self.write_u16(3);
+        self.write_u32(drawable);
+        self.write_bool(onoff);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XVideo SelectPortNotify request */
+    pub fn xv_select_port_notify(&mut self, port: u32, onoff: bool) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SELECT_PORT_NOTIFY);
+        self.write_u16(3);
+        self.write_u32(port);
+        self.write_bool(onoff);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XVideo QueryBestSize request */
+    pub fn xv_query_best_size(&mut self, port: u32, vid_w: u16, vid_h: u16, drw_w: u16, drw_h: u16, motion: bool) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_BEST_SIZE);
+        self.write_u16(5);
+        self.write_u32(port);
+        self.write_u16(vid_w);
+        self.write_u16(vid_h);
+        self.write_u16(drw_w);
+        self.write_u16(drw_h);
+        self.write_bool(motion);
+        self.write_pad(3);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_BEST_SIZE)))
+    }
+
+    /** Sends the XVideo SetPortAttribute request */
+    pub fn xv_set_port_attribute(&mut self, port: u32, attribute: u32, value: i32) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SET_PORT_ATTRIBUTE);
+        self.write_u16(4);
+        self.write_u32(port);
+        self.write_u32(attribute);
+        self.write_i32(value);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XVideo GetPortAttribute request */
+    pub fn xv_get_port_attribute(&mut self, port: u32, attribute: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_GET_PORT_ATTRIBUTE);
+        self.write_u16(3);
+        self.write_u32(port);
+        self.write_u32(attribute);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_GET_PORT_ATTRIBUTE)))
+    }
+
+    /** Sends the XVideo QueryPortAttributes request */
+    pub fn xv_query_port_attributes(&mut self, port: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_PORT_ATTRIBUTES);
+        self.write_u16(2);
+        self.write_u32(port);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_PORT_ATTRIBUTES)))
+    }
+
+    /** Sends the XVideo ListImageFormats request */
+    pub fn xv_list_image_formats(&mut self, port: u32) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_LIST_IMAGE_FORMATS);
+        self.write_u16(2);
+        self.write_u32(port);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_LIST_IMAGE_FORMATS)))
+    }
+
+    /** Sends the XVideo QueryImageAttributes request */
+    pub fn xv_query_image_attributes(&mut self, port: u32, id: u32, width: u16, height: u16) -> Option<u16> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_QUERY_IMAGE_ATTRIBUTES);
+        self.write_u16(4);
+        self.write_u32(port);
+        self.write_u32(id);
+        self.write_u16(width);
+        self.write_u16(height);
+
+        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_QUERY_IMAGE_ATTRIBUTES)))
+    }
+
+    /** Sends the XVideo PutImage request */
+    pub fn xv_put_image(&mut self, port: u32, drawable: u32, gc: u32, id: u32, src_x: i16, src_y: i16, src_w: u16, src_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16, width: u16, height: u16, data: &[u8]) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_PUT_IMAGE);
+        let pad = self.write_dynamic_len(10, data.len());
+        self.write_u32(port);
+        self.write_u32(drawable);
+        self.write_u32(gc);
+        self.write_u32(id);
+        self.write_i16(src_x);
+        self.write_i16(src_y);
+        self.write_u16(src_w);
+        self.write_u16(src_h);
+        self.write_i16(drw_x);
+        self.write_i16(drw_y);
+        self.write_u16(drw_w);
+        self.write_u16(drw_h);
+        self.write_u16(width);
+        self.write_u16(height);
+        self.write_raw(data);
+        self.write_pad_op(pad);
+
+        self.write_request();
+        Some(())
+    }
+
+    /** Sends the XVideo ShmPutImage request */
+    pub fn xv_shm_put_image(&mut self, port: u32, drawable: u32, gc: u32, shmseg: u32, id: u32, offset: u32, src_x: i16, src_y: i16, src_w: u16, src_h: u16, drw_x: i16, drw_y: i16, drw_w: u16, drw_h: u16, width: u16, height: u16, send_event: u8) -> Option<()> {
+        let extension_opcode = self.extension_opcode::<Xv>()?;
+        self.write_u8(extension_opcode);
+        self.write_u8(OP_SHM_PUT_IMAGE);
+        self.write_u16(13);
+        self.write_u32(port);
+        self.write_u32(drawable);
+        self.write_u32(gc);
+        self.write_u32(shmseg);
+        self.write_u32(id);
+        self.write_u32(offset);
+        self.write_i16(src_x);
+        self.write_i16(src_y);
+        self.write_u16(src_w);
+        self.write_u16(src_h);
+        self.write_i16(drw_x);
+        self.write_i16(drw_y);
+        self.write_u16(drw_w);
+        self.write_u16(drw_h);
+        self.write_u16(width);
+        self.write_u16(height);
+        self.write_u8(send_event);
+        self.write_pad(3);
+
+        self.write_request();
+        Some(())
+    }
+}
+
+impl XReadHelper {
+    /** Reads a reply to XVideo QueryExtension */
+    fn read_xv_query_extension_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let major = self.read_u16();
+        let minor = self.read_u16();
+        Some(ServerReply::Xv(XvReply::QueryExtension { major, minor }))
+    }
+
+    /** Reads a reply to XVideo QueryAdaptors */
+    fn read_xv_query_adaptors_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let num_adaptors = self.read_u16();
+        self.read_pad(22);
+        let mut info = Vec::with_capacity(num_adaptors as usize);
+        for _ in 0..num_adaptors as usize {
+            info.push(AdaptorInfo::read(self));
+        }
+        Some(ServerReply::Xv(XvReply::QueryAdaptors { info }))
+    }
+
+    /** Reads a reply to XVideo QueryEncodings */
+    fn read_xv_query_encodings_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let num_encodings = self.read_u16();
+        self.read_pad(22);
+        let mut info = Vec::with_capacity(num_encodings as usize);
+        for _ in 0..num_encodings as usize {
+            info.push(EncodingInfo::read(self));
+        }
+        Some(ServerReply::Xv(XvReply::QueryEncodings { info }))
+    }
+
+    /** Reads a reply to XVideo GrabPort */
+    fn read_xv_grab_port_reply(&mut self, detail: u8) -> Option<ServerReply> {
+        let result = detail;
+        let result = match GrabPortStatus::get(result as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        Some(ServerReply::Xv(XvReply::GrabPort { result }))
+    }
+
+    /** Reads a reply to XVideo QueryBestSize */
+    fn read_xv_query_best_size_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let actual_width = self.read_u16();
+        let actual_height = self.read_u16();
+        Some(ServerReply::Xv(XvReply::QueryBestSize { actual_width, actual_height }))
+    }
+
+    /** Reads a reply to XVideo GetPortAttribute */
+    fn read_xv_get_port_attribute_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let value = self.read_i32();
+        Some(ServerReply::Xv(XvReply::GetPortAttribute { value }))
+    }
+
+    /** Reads a reply to XVideo QueryPortAttributes */
+    fn read_xv_query_port_attributes_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let num_attributes = self.read_u32();
+        let text_size = self.read_u32();
+        self.read_pad(16);
+        let mut attributes = Vec::with_capacity(num_attributes as usize);
+        for _ in 0..num_attributes as usize {
+            attributes.push(AttributeInfo::read(self));
+        }
+        Some(ServerReply::Xv(XvReply::QueryPortAttributes { text_size, attributes }))
+    }
+
+    /** Reads a reply to XVideo ListImageFormats */
+    fn read_xv_list_image_formats_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let num_formats = self.read_u32();
+        self.read_pad(20);
+        let mut format = Vec::with_capacity(num_formats as usize);
+        for _ in 0..num_formats as usize {
+            format.push(ImageFormatInfo::read(self));
+        }
+        Some(ServerReply::Xv(XvReply::ListImageFormats { format }))
+    }
+
+    /** Reads a reply to XVideo QueryImageAttributes */
+    fn read_xv_query_image_attributes_reply(&mut self, _detail: u8) -> Option<ServerReply> {
+        let num_planes = self.read_u32();
+        let data_size = self.read_u32();
+        let width = self.read_u16();
+        let height = self.read_u16();
+        self.read_pad(12);
+        let mut pitches = Vec::with_capacity(num_planes as usize);
+        for _ in 0..num_planes as usize {
+            pitches.push(self.read_u32());
+        }
+        let mut offsets = Vec::with_capacity(num_planes as usize);
+        for _ in 0..num_planes as usize {
+            offsets.push(self.read_u32());
+        }
+        Some(ServerReply::Xv(XvReply::QueryImageAttributes { data_size, width, height, pitches, offsets }))
+    }
+
+    /** Reads a XVideo VideoNotify event */
+    fn read_xv_video_notify_event(&mut self, detail: u8) -> Option<ServerEvent> {
+        let reason = detail;
+        let reason = match VideoNotifyReason::get(reason as u32) {
+            Some(x) => x,
+            None => return None
+        };
+        let time = self.read_u32();
+        let drawable = self.read_u32();
+        let port = self.read_u32();
+        Some(ServerEvent::Xv(XvEvent::VideoNotify { reason, time, drawable, port }))
+    }
+
+    /** Reads a XVideo PortNotify event */
+    fn read_xv_port_notify_event(&mut self, _detail: u8) -> Option<ServerEvent> {
+        let time = self.read_u32();
+        let port = self.read_u32();
+        let attribute = self.read_u32();
+        let value = self.read_i32();
+        Some(ServerEvent::Xv(XvEvent::PortNotify { time, port, attribute, value }))
+    }
+}
@@ -0,0 +1,75 @@
+// FourCC image IDs, finding and grabbing a port, and port attributes by name.
+
+use models::{ServerReply, ServerResponse};
+use XClient;
+
+use super::xv::*;
+
+/** Makes an image format ID from its FourCC code, ie fourcc(b"YV12") */
+pub fn fourcc(code: &[u8; 4]) -> u32 {
+    u32::from_le_bytes(*code)
+}
+
+pub const FOURCC_YUY2: u32 = 0x32595559;
+pub const FOURCC_UYVY: u32 = 0x59565955;
+pub const FOURCC_YV12: u32 = 0x32315659;
+pub const FOURCC_I420: u32 = 0x30323449;
+
+impl XClient {
+    /**
+     * Finds a port that can put images in the format `id` (ie FOURCC_YV12) on the screen of `window`, and grabs it.
+     * Returns None if no adaptor has the format, all of their ports are grabbed by someone else, or the server doesn't have XVideo. Blocks.
+     */
+    pub fn xv_grab_image_port_sync(&mut self, window: u32, id: u32) -> Option<u32> {
+        let seq = self.xv_query_adaptors(window)?;
+        let adaptors = match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::Xv(XvReply::QueryAdaptors { info }), _) => info,
+            _ => return None
+        };
+
+        let wanted = Type::InputMask.val() | Type::ImageMask.val();
+        for adaptor in adaptors.iter().filter(|a| a.kind as u32 & wanted == wanted) {
+            // Every port of an adaptor has the same formats
+            let seq = self.xv_list_image_formats(adaptor.base_id)?;
+            match self.wait_for_response(seq) {
+                ServerResponse::Reply(ServerReply::Xv(XvReply::ListImageFormats { format }), _) if format.iter().any(|f| f.id == id) => (),
+                _ => continue
+            }
+
+            for port in adaptor.base_id..adaptor.base_id + adaptor.num_ports as u32 {
+                let seq = self.xv_grab_port(port, 0)?;
+                if let ServerResponse::Reply(ServerReply::Xv(XvReply::GrabPort { result: GrabPortStatus::Success }), _) = self.wait_for_response(seq) {
+                    return Some(port);
+                }
+            }
+        }
+        None
+    }
+
+    /** Sets a port attribute by name (ie "XV_BRIGHTNESS"). Returns false if the server has never heard of the name. Blocks. */
+    pub fn xv_set_port_attribute_by_name(&mut self, port: u32, name: &str, value: i32) -> bool {
+        match self.xv_attribute_atom(name) {
+            Some(attribute) => self.xv_set_port_attribute(port, attribute, value).is_some(),
+            None => false
+        }
+    }
+
+    /** Gets a port attribute by name (ie "XV_BRIGHTNESS"). Blocks. */
+    pub fn xv_get_port_attribute_by_name_sync(&mut self, port: u32, name: &str) -> Option<i32> {
+        let attribute = self.xv_attribute_atom(name)?;
+        let seq = self.xv_get_port_attribute(port, attribute)?;
+        match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::Xv(XvReply::GetPortAttribute { value }), _) => Some(value),
+            _ => None
+        }
+    }
+
+    /** Port attributes are atoms. They exist once a port has them, so this doesn't make new ones. */
+    fn xv_attribute_atom(&mut self, name: &str) -> Option<u32> {
+        let seq = self.intern_atom(name, true);
+        match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::InternAtom { atom }, _) if atom != 0 => Some(atom),
+            _ => None
+        }
+    }
+}
@@ -1,25 +1,40 @@
+extern crate libc;
+
 pub mod models;
+pub mod extensions;
+pub mod keysym;
 mod protocol;
-mod xreaderwriter;
+pub mod xreaderwriter;
 
 use std::collections::VecDeque;
 use std::os::unix::net::UnixStream;
+use std::net::Shutdown;
 use std::io::prelude::*;
 use std::io::{BufWriter, BufReader};
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::{io, mem, ptr};
 
 use std::thread;
 use std::sync::mpsc;
+use std::sync;
+use std::sync::Mutex;
 
 use self::models::*;
+use self::extensions::{Extension, ExtensionInfo, ExtensionRegistry};
 use self::xreaderwriter::{XBufferedWriter, XBufferedReader, XReadHelper};
 
 pub struct XClient {
     pub connected: bool,
     pub info: ConnectInfo,
+    host: String, // What we connected to, for extensions that need a second connection (ie RECORD)
+    auth_name: String, // What we authorized with, for the same
+    auth_data: Vec<u8>,
     buf_out: BufWriter<UnixStream>,
     resp_receiver: mpsc::Receiver<ServerResponse>, // Receive errors, replies, and events from the X Server
     resp_queue: VecDeque<ServerResponse>, // Used to store errors when the user wants to skip to a certain event or error
     sq_sender: mpsc::Sender<(u16, ServerReplyType)>, // Send sequence IDs to the reader thread so it can properly parse replies
+    extensions: sync::Arc<Mutex<ExtensionRegistry>>, // Shared with the reader thread so it can decode extension replies, events, and errors
+    randr_version: Option<(u32, u32)>, // The RandR version monitors() negotiated, so it only asks once
     next_resource_id: u32,
     current_sequence: u16,
     buf_one_byte: Vec<u8>,
@@ -34,28 +49,42 @@ impl XClient {
      * Spawns a new 1:1 thread to constantly read input from the X Server, which prevents deadlocks.
      */
     pub fn connect(host: String) -> XClient {
-        let stream = UnixStream::connect(host).unwrap();
+        XClient::connect_with_authorization(host, protocol::CONNECT_AUTH_NAME, protocol::CONNECT_AUTH_DATA.as_bytes())
+    }
+
+    /**
+     * Connects to the given X server with an authorization, ie a cookie made with SECURITY GenerateAuthorization
+     * (`auth_name` is then "MIT-MAGIC-COOKIE-1", and `auth_data` the cookie).
+     * Blocks until the connection is complete.
+     */
+    pub fn connect_with_authorization(host: String, auth_name: &str, auth_data: &[u8]) -> XClient {
+        let stream = UnixStream::connect(&host).unwrap();
         let (resp_sender, resp_receiver) = mpsc::channel();
         let (sq_sender, sq_receiver) = mpsc::channel();
         let mut client = XClient {
             connected: false,
             info: ConnectInfo::empty(),
+            host,
+            auth_name: String::from(auth_name),
+            auth_data: auth_data.to_vec(),
             buf_out: BufWriter::new(stream),
             resp_receiver: resp_receiver,
             resp_queue: VecDeque::with_capacity(15),
             sq_sender: sq_sender,
+            extensions: sync::Arc::new(Mutex::new(ExtensionRegistry::new())),
+            randr_version: None,
             next_resource_id: 0,
             current_sequence: 0,
             buf_one_byte: vec![0u8; 1],
             buf_two_byte: vec![0u8; 2],
             buf_four_byte: vec![0u8; 4]
         };
-        client.setup(resp_sender, sq_receiver);
+        client.setup(resp_sender, sq_receiver, auth_name, auth_data);
         client
     }
 
     /** Sends the connection parameters and returns if it connected or not. */
-    fn setup(&mut self, resp_sender: mpsc::Sender<ServerResponse>, sq_receiver: mpsc::Receiver<(u16, ServerReplyType)>) {
+    fn setup(&mut self, resp_sender: mpsc::Sender<ServerResponse>, sq_receiver: mpsc::Receiver<(u16, ServerReplyType)>, auth_name: &str, auth_data: &[u8]) {
         let mut reader = XReadHelper::new(self.buf_out.get_ref().try_clone().unwrap());
 
         // Send connection string
@@ -64,12 +93,13 @@ impl XClient {
             self.write_pad(1);
             self.write_u16(protocol::CONNECT_MAJOR);
             self.write_u16(protocol::CONNECT_MINOR);
-            self.write_u16(0);
-            self.write_u16(0);
-            //self.write_pad(4); // Pad empty string
-            //self.write_pad(4); // Pad empty string
-            self.write_pad(1); // Pad empty string
-            self.write_pad(1); // Pad empty string
+            self.write_u16(auth_name.len() as u16);
+            self.write_u16(auth_data.len() as u16);
+            self.write_pad(2);
+            self.write_str(auth_name);
+            self.write_pad_op((4 - auth_name.len() % 4) % 4);
+            self.write_raw(auth_data);
+            self.write_pad_op((4 - auth_data.len() % 4) % 4);
 
             self.write_sequence(ServerReplyType::None);
         }
@@ -200,27 +230,42 @@ impl XClient {
 
         // Start event receiving thread
         {
-            thread::spawn(move || { // Moves `resp_sender`, `sq_receiver`, and `reader`
+            let extensions = self.extensions.clone();
+            thread::spawn(move || { // Moves `resp_sender`, `sq_receiver`, `reader`, and `extensions`
+                let mut last_reply: Option<(u16, ServerReplyType)> = None;
                 loop {
-                    // Read header
-                    reader.prep_read(32); // All are *at least* 32 bytes
+                    // Read header. Stop once the connection is closed (ie by XClient::shutdown).
+                    if !reader.try_prep_read(32) { // All are *at least* 32 bytes
+                        break;
+                    }
                     let opcode_original = reader.read_u8();
-                    let opcode = opcode_original & 0b0111111;
-                    let generated = opcode_original & 0b10000000 == 0x1;
+                    let opcode = opcode_original & 0b01111111; // The full 7-bit code (extension events can be 64-127)
+                    let source = if opcode_original & 0b10000000 == 0b10000000 {
+                        EventSource::SendEvent
+                    } else {
+                        EventSource::Server
+                    };
                     let detail = reader.read_u8();
                     let sequence_number = reader.read_u16();
 
                     let response = match opcode {
                         protocol::REPLY_ERROR => {
-                            match reader.read_error(detail) {
-                                Some(err) => ServerResponse::Error(err, sequence_number),
-                                None => continue
-                            }
+                            let error = reader.read_error(detail)
+                                .or_else(|| extensions.lock().unwrap().read_error(&mut reader, detail))
+                                .unwrap_or_else(|| reader.read_unknown_error(detail));
+                            ServerResponse::Error(error, sequence_number)
                         },
                         protocol::REPLY_REPLY => ServerResponse::Reply({
                             let reply_length = reader.read_u32();
                             reader.prep_read_extend((reply_length * 4) as usize); // Add additional length
-                            let (seq, method) = sq_receiver.recv().unwrap();
+                            let (seq, method) = match last_reply {
+                                Some((seq, method)) if seq == sequence_number => (seq, method), // Another reply to the same request (ie ListFontsWithInfo)
+                                _ => match sq_receiver.recv() {
+                                    Ok(sent) => sent,
+                                    Err(_) => break // The XClient is gone
+                                }
+                            };
+                            last_reply = Some((seq, method));
 
                             if seq == sequence_number {
                                 match match method {
@@ -266,6 +311,12 @@ impl XClient {
                                     ServerReplyType::GetPointerMapping => reader.read_get_pointer_mapping_reply(detail),
                                     ServerReplyType::SetModifierMapping => reader.read_set_modifier_mapping_reply(detail),
                                     ServerReplyType::GetModifierMapping => reader.read_get_modifier_mapping_reply(detail),
+                                    ServerReplyType::Extension(major_opcode, minor_opcode) => {
+                                        match extensions.lock().unwrap().read_reply(&mut reader, major_opcode, minor_opcode, detail) {
+                                            Some(reply) => Some(reply),
+                                            None => reader.read_unknown_reply(major_opcode, minor_opcode)
+                                        }
+                                    },
                                     ServerReplyType::None => panic!("Reply type should not be none.")
                                 } {
                                     Some(x) => x,
@@ -279,61 +330,113 @@ impl XClient {
                         }, sequence_number),
                         other => {
                             ServerResponse::Event(
-                                match match other { // MY EYES
-                                    protocol::REPLY_KEY_PRESS => reader.read_key_press(detail),
-                                    protocol::REPLY_KEY_RELEASE => reader.read_key_release(detail),
-                                    protocol::REPLY_BUTTON_PRESS => reader.read_button_press(detail),
-                                    protocol::REPLY_BUTTON_RELEASE => reader.read_button_release(detail),
-                                    protocol::REPLY_MOTION_NOTIFY => reader.read_motion_notify(detail),
-                                    protocol::REPLY_ENTER_NOTIFY => reader.read_enter_notify(detail),
-                                    protocol::REPLY_LEAVE_NOTIFY => reader.read_leave_notify(detail),
-                                    protocol::REPLY_FOCUS_IN => reader.read_focus_in(detail),
-                                    protocol::REPLY_FOCUS_OUT => reader.read_focus_out(detail),
-                                    protocol::REPLY_KEYMAP_NOTIFY => reader.read_keymap_notify(sequence_number, detail), // The one special case
-                                    protocol::REPLY_EXPOSE => reader.read_expose(),
-                                    protocol::REPLY_GRAPHICS_EXPOSURE => reader.read_graphics_exposure(),
-                                    protocol::REPLY_NO_EXPOSURE => reader.read_no_exposure(),
-                                    protocol::REPLY_VISIBILITY_NOTIFY => reader.read_visibility_notify(),
-                                    protocol::REPLY_CREATE_NOTIFY => reader.read_create_notify(),
-                                    protocol::REPLY_DESTROY_NOTIFY => reader.read_destroy_notify(),
-                                    protocol::REPLY_UNMAP_NOTIFY => reader.read_unmap_notify(),
-                                    protocol::REPLY_MAP_NOTIFY => reader.read_map_notify(),
-                                    protocol::REPLY_MAP_REQUEST => reader.read_map_request(),
-                                    protocol::REPLY_REPARENT_NOTIFY => reader.read_reparent_notify(),
-                                    protocol::REPLY_CONFIGURE_NOTIFY => reader.read_configure_notify(),
-                                    protocol::REPLY_CONFIGURE_REQUEST => reader.read_configure_request(detail),
-                                    protocol::REPLY_GRAVITY_NOTIFY => reader.read_gravity_notify(),
-                                    protocol::REPLY_RESIZE_REQUEST => reader.read_resize_request(),
-                                    protocol::REPLY_CIRCULATE_NOTIFY => reader.read_circulate_notify(),
-                                    protocol::REPLY_CIRCULATE_REQUEST => reader.read_circulate_request(),
-                                    protocol::REPLY_PROPERTY_NOTIFY => reader.read_property_notify(),
-                                    protocol::REPLY_SELECTION_CLEAR => reader.read_selection_clear(),
-                                    protocol::REPLY_SELECTION_REQUEST => reader.read_selection_request(),
-                                    protocol::REPLY_SELECTION_NOTIFY => reader.read_selection_notify(),
-                                    protocol::REPLY_COLORMAP_NOTIFY => reader.read_colormap_notify(),
-                                    protocol::REPLY_CLIENT_MESSAGE => reader.read_client_message(detail),
-                                    protocol::REPLY_MAPPING_NOTIFY => reader.read_mapping_notify(),
+                                match match other {
+                                    protocol::REPLY_KEY_PRESS..=protocol::REPLY_MAPPING_NOTIFY => reader.read_core_event(other, detail, sequence_number),
+                                    protocol::REPLY_GENERIC_EVENT => {
+                                        let evtype = reader.read_generic_event_header();
+                                        match extensions.lock().unwrap().read_generic_event(&mut reader, detail, evtype) {
+                                            Some(event) => Some(event),
+                                            None => reader.read_generic_event(detail, evtype)
+                                        }
+                                    },
                                     _ => {
-                                        println!("WARNING: Server sent unknown opcode {}", opcode);
-                                        continue;
+                                        match extensions.lock().unwrap().read_event(&mut reader, opcode, detail) {
+                                            Some(event) => Some(event),
+                                            None => reader.read_unknown_event(opcode)
+                                        }
                                     }
                                 } {
                                     Some(event) => event,
                                     None => continue
                                 }
-                            , sequence_number, generated)
+                            , sequence_number, source)
                         }
                     };
 
-                    match resp_sender.send(response) {
-                        Ok(_) => (),
-                        Err(e) => eprintln!("Failed to forward error, reply, or event to main thread: {:?}", e)
+                    if resp_sender.send(response).is_err() {
+                        break; // The XClient is gone
                     }
                 }
             });
         }
     }
 
+    /**
+     * Asks the X Server about an extension, and waits for the reply. Blocks.
+     * The result is cached, so QueryExtension is only sent once per name.
+     *
+     * Returns: None if the server does not support the extension.
+     */
+    pub fn query_extension_sync(&mut self, name: &'static str) -> Option<ExtensionInfo> {
+        if let Some(info) = self.extensions.lock().unwrap().get(name) {
+            return info;
+        }
+
+        let seq = self.query_extension(name);
+        let info = match self.wait_for_response(seq) {
+            ServerResponse::Reply(ServerReply::QueryExtension { present: true, major_opcode, first_event, first_error }, _) => {
+                Some(ExtensionInfo { major_opcode, first_event, first_error, events: 0, errors: 0 })
+            },
+            _ => None
+        };
+
+        let mut extensions = self.extensions.lock().unwrap();
+        extensions.insert(name, info);
+        extensions.get(name).unwrap()
+    }
+
+    /**
+     * Registers a decoder for an extension's replies, events, and errors, querying the extension if needed. Blocks.
+     * Replaces any decoder previously registered for the same extension.
+     *
+     * Returns: None if the server does not support the extension.
+     */
+    pub fn register_extension(&mut self, extension: Box<dyn Extension>) -> Option<ExtensionInfo> {
+        let info = self.query_extension_sync(extension.name());
+        self.extensions.lock().unwrap().register(extension);
+        info
+    }
+
+    /**
+     * Returns the major opcode of a generated extension, registering its decoder the first time. Blocks the first time.
+     * Used by the requests in src/extensions, which return None instead of sending anything when this is None.
+     *
+     * Returns: None if the server does not support the extension.
+     */
+    fn extension_opcode<E: Extension + Default + 'static>(&mut self) -> Option<u8> {
+        let extension = E::default();
+        let info = self.query_extension_sync(extension.name())?;
+
+        let mut extensions = self.extensions.lock().unwrap();
+        if !extensions.is_registered(extension.name()) {
+            extensions.register(Box::new(extension));
+        }
+        Some(info.major_opcode)
+    }
+
+    /**
+     * Describes an error like Xlib does, with the sequence number of the failed request (from ServerResponse::Error),
+     * and the name of any extension it belongs to that we've asked the server about.
+     */
+    pub fn describe_error(&self, error: &ServerError, sequence_number: u16) -> String {
+        let (major_opcode, _) = error.opcodes();
+        let message = match self.extensions.lock().unwrap().find_by_opcode(major_opcode) {
+            Some((name, _)) if major_opcode >= 128 => error.message(Some(name)),
+            _ => error.to_string()
+        };
+        format!("{}, Serial number {}", message, sequence_number)
+    }
+
+    /** Opens a second connection to the same server, with the same authorization (ie for RECORD data) */
+    pub(crate) fn connect_again(&self) -> XClient {
+        XClient::connect_with_authorization(self.host.clone(), &self.auth_name, &self.auth_data)
+    }
+
+    /** Closes the connection. Requests after this fail, and the reader thread stops. */
+    pub(crate) fn shutdown(&self) {
+        let _ = self.buf_out.get_ref().shutdown(Shutdown::Both);
+    }
+
     /** Generates a new resource ID */
     pub fn new_resource_id(&mut self) -> u32 {
         // TODO: Thread lock
@@ -349,6 +452,39 @@ impl XClient {
         self.info.resource_id_base | id
     }
 
+    /**
+     * Sends a whole request with file descriptors attached (the server gets its own copies of them).
+     * Used by the requests in src/extensions that take an <fd>, like MIT-SHM AttachFd.
+     */
+    fn write_request_with_fds(&mut self, request: &[u8], fds: &[RawFd]) -> io::Result<()> {
+        self.buf_out.flush()?;
+
+        let fds_len = mem::size_of_val(fds);
+        let mut control = vec![0u64; (unsafe { libc::CMSG_SPACE(fds_len as u32) } as usize).div_ceil(8)]; // u64 for cmsghdr's alignment
+        let mut iov = libc::iovec { iov_base: request.as_ptr() as *mut libc::c_void, iov_len: request.len() };
+        let mut msg: libc::msghdr = unsafe { mem::zeroed() };
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        msg.msg_control = control.as_mut_ptr() as *mut libc::c_void;
+        msg.msg_controllen = (control.len() * 8) as _;
+
+        let sent = unsafe {
+            let cmsg = libc::CMSG_FIRSTHDR(&msg);
+            (*cmsg).cmsg_level = libc::SOL_SOCKET;
+            (*cmsg).cmsg_type = libc::SCM_RIGHTS;
+            (*cmsg).cmsg_len = libc::CMSG_LEN(fds_len as u32) as _;
+            ptr::copy_nonoverlapping(fds.as_ptr(), libc::CMSG_DATA(cmsg) as *mut RawFd, fds.len());
+            libc::sendmsg(self.buf_out.get_ref().as_raw_fd(), &msg, 0)
+        };
+        if sent < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        self.buf_out.get_mut().write_all(&request[sent as usize..])?; // The fds went with the first part
+
+        self.write_request();
+        Ok(())
+    }
+
     /**
      * Waits for the next available event, error, or reply. Blocks.
      * Use get_message if you do not want to block.
@@ -454,6 +590,18 @@ impl XClient {
             }
         }
     }
+
+    /**
+     * Takes the error the server sent for the request `seq` out of the saved messages, if it sent one. Doesn't block, so
+     * only use it once a later request has been answered (the server handles requests in order).
+     */
+    pub(crate) fn take_error(&mut self, seq: u16) -> Option<ServerError> {
+        let index = self.resp_queue.iter().position(|res| matches!(*res, ServerResponse::Error(_, eseq) if eseq == seq))?;
+        match self.resp_queue.remove(index) {
+            Some(ServerResponse::Error(error, _)) => Some(error),
+            _ => None
+        }
+    }
 }
 
 // Spec Endpoints
@@ -641,12 +789,12 @@ impl XClient { // This is actually a pretty nice feature for organization
         self.write_sequence(ServerReplyType::InternAtom)
     }
 
-    /** Tells the X Server to [TODO] */
+    /** Tells the X Server to send us the name of an atom */
     pub fn get_atom_name(&mut self, atom: u32) -> u16 {
         self.write_u8(protocol::OP_GET_ATOM_NAME);
         self.write_pad(1);
         self.write_u16(2);
-        self.write_u32(4);
+        self.write_u32(atom);
 
         self.write_sequence(ServerReplyType::GetAtomName)
     }
@@ -1209,6 +1357,16 @@ impl XClient { // This is actually a pretty nice feature for organization
                 self.write_u8(*first_keycode);
                 self.write_u8(*count);
                 self.write_pad(25);
+            },
+            ServerEvent::Generic { .. } => {
+                panic!("GenericEvents can not be sent with SendEvent.");
+            },
+            ServerEvent::Extension { .. } | ServerEvent::Xkb(_) | ServerEvent::XInput(_) | ServerEvent::RandR(_) | ServerEvent::Sync(_) | ServerEvent::Shape(_) | ServerEvent::XFixes(_) | ServerEvent::Damage(_) | ServerEvent::Shm(_) | ServerEvent::ScreenSaver(_) | ServerEvent::Present(_) | ServerEvent::Xv(_) | ServerEvent::Security(_) => {
+                panic!("Extension events can not be sent with SendEvent. Use ServerEvent::Unknown with the raw event instead.");
+            },
+            ServerEvent::Unknown { code, raw } => {
+                self.write_u8(*code);
+                self.write_raw(&raw[1..]);
             }
         };
 
@@ -1690,6 +1848,24 @@ impl XClient { // This is actually a pretty nice feature for organization
         self.write_request();
     }
 
+    /** Copies a rectangle from one drawable to another (which must have the same root and depth) */
+    pub fn copy_area(&mut self, src: u32, dst: u32, gcid: u32, src_x: i16, src_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16) {
+        self.write_u8(protocol::OP_COPY_AREA);
+        self.write_pad(1);
+        self.write_u16(7);
+        self.write_u32(src);
+        self.write_u32(dst);
+        self.write_u32(gcid);
+        self.write_i16(src_x);
+        self.write_i16(src_y);
+        self.write_i16(dst_x);
+        self.write_i16(dst_y);
+        self.write_u16(width);
+        self.write_u16(height);
+
+        self.write_request();
+    }
+
     /** Tells the X Server to [TODO] */
     pub fn copy_plane(&mut self, src: u32, dst: u32, gcid: u32, src_x: i16, src_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16, bit_plane: u32) {
         self.write_u8(protocol::OP_COPY_PLANE);
@@ -2269,10 +2445,10 @@ impl XClient { // This is actually a pretty nice feature for organization
      *   - first: The first keycode
      *   - count: The number of key-codes following
      * 
-     * Returns Some(()) if `count` is a multiple of `keysyms.len()` and the given first key-code is valid, None if not.
+     * Returns Some(()) if `keysyms.len()` is a multiple of `count` and the given first key-code is valid, None if not.
      */
     pub fn change_keyboard_mapping(&mut self, first: u8, count: u8, keysyms: &Vec<u32>) -> Option<()> {
-        if count as usize % keysyms.len() != 0 || first < 7 {
+        if count == 0 || !keysyms.len().is_multiple_of(count as usize) || first < 7 {
             return None;
         }
 
@@ -2280,6 +2456,7 @@ impl XClient { // This is actually a pretty nice feature for organization
         self.write_u8(protocol::OP_CHANGE_KEYBOARD_MAPPING);
         self.write_u8(count);
         self.write_u16((2 + keysyms.len()) as u16);
+        self.write_u8(first);
         self.write_u8(keysyms_per_keycode);
         self.write_pad(2);
         
@@ -2650,6 +2827,66 @@ impl XBufferedWriter for XClient {
         self.buf_out.write_all(&self.buf_four_byte).unwrap();
     }
 
+    /**
+     * Writes a i64 to the buffer.
+     * Expects little endian.
+     */
+    fn write_i64(&mut self, input: i64) {
+        self.write_u64(input as u64);
+    }
+
+    /**
+     * Writes a u64 to the buffer.
+     * Expects little endian.
+     */
+    fn write_u64(&mut self, input: u64) {
+        self.write_u32(input as u32);
+        self.write_u32((input >> 32) as u32);
+    }
+
+    /**
+     * Writes a f32 to the buffer.
+     * Expects little endian.
+     */
+    fn write_f32(&mut self, input: f32) {
+        self.write_u32(input.to_bits());
+    }
+
+    /**
+     * Writes a f64 to the buffer.
+     * Expects little endian.
+     */
+    fn write_f64(&mut self, input: f64) {
+        self.write_u64(input.to_bits());
+    }
+
+    /**
+     * Writes a 16.16 fixed point number to the buffer.
+     * Expects little endian.
+     */
+    fn write_fp1616(&mut self, input: f64) {
+        self.write_i32((input * 65536.0).round() as i32);
+    }
+
+    /**
+     * Writes a 32.32 fixed point number to the buffer.
+     * Expects little endian.
+     */
+    fn write_fp3232(&mut self, input: f64) {
+        let integral = input.floor();
+        self.write_i32(integral as i32);
+        self.write_u32(((input - integral) * 4294967296.0) as u32);
+    }
+
+    /**
+     * Writes an i64 as its high i32 followed by its low u32.
+     * Expects little endian.
+     */
+    fn write_hi_lo_i64(&mut self, input: i64) {
+        self.write_i32((input >> 32) as i32);
+        self.write_u32(input as u32);
+    }
+
     /**
      * Writes a string to the buffer.
      * This does not write the length of the string or any padding required after it.
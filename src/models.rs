@@ -1,6 +1,31 @@
+use std::any::Any;
+use std::{error, fmt};
 use std::mem::discriminant;
 
 use XClient;
+use protocol;
+use extensions;
+use extensions::xc_misc::XCMiscReply;
+use extensions::xkb::{XkbReply, XkbEvent};
+use extensions::xinput::{XInputReply, XInputEvent};
+use extensions::composite::CompositeReply;
+use extensions::randr::{RandRReply, RandREvent};
+use extensions::xinerama::XineramaReply;
+use extensions::sync::{SyncReply, SyncEvent};
+use extensions::shape::{ShapeReply, ShapeEvent};
+use extensions::render::RenderReply;
+use extensions::xfixes::{XFixesReply, XFixesEvent};
+use extensions::damage::{DamageReply, DamageEvent};
+use extensions::shm::{ShmReply, ShmEvent, ShmSegment};
+use extensions::xtest::XTestReply;
+use extensions::record::RecordReply;
+use extensions::dpms::DPMSReply;
+use extensions::screensaver::{ScreenSaverReply, ScreenSaverEvent};
+use extensions::present::{PresentReply, PresentEvent};
+use extensions::res::XResReply;
+use extensions::xv::{XvReply, XvEvent};
+use extensions::dbe::DbeReply;
+use extensions::security::{SecurityReply, SecurityEvent};
 use xreaderwriter::XBufferedWriter;
 
 // Root trait for all values (ie GraphicsContextValue)
@@ -106,10 +131,142 @@ pub enum ServerError {
     IDChoice { minor_opcode: u16, major_opcode: u8, bad_resource_id: u32 },
     Name { minor_opcode: u16, major_opcode: u8 },
     Length { minor_opcode: u16, major_opcode: u8 },
-    Implementation { minor_opcode: u16, major_opcode: u8 }
+    Implementation { minor_opcode: u16, major_opcode: u8 },
+    Extension { // An error from an extension that was queried. `code` is relative to the extension's first_error (ie render::ERROR_PICT_FORMAT).
+        extension: &'static str,
+        code: u8,
+        bad_value: u32, // The bad resource ID or value, if the error has one
+        minor_opcode: u16,
+        major_opcode: u8
+    },
+    Unknown { // An error nothing could decode
+        code: u8,
+        bad_value: u32,
+        minor_opcode: u16,
+        major_opcode: u8,
+        raw: Vec<u8> // The entire error, including the header
+    }
+}
+
+impl ServerError {
+    /** The opcodes of the request that failed, as (major_opcode, minor_opcode). The minor opcode only means something for extension requests. */
+    pub fn opcodes(&self) -> (u8, u16) {
+        match *self {
+            ServerError::Request { minor_opcode, major_opcode } |
+            ServerError::Value { minor_opcode, major_opcode, .. } |
+            ServerError::Window { minor_opcode, major_opcode, .. } |
+            ServerError::Pixmap { minor_opcode, major_opcode, .. } |
+            ServerError::Atom { minor_opcode, major_opcode, .. } |
+            ServerError::Cursor { minor_opcode, major_opcode, .. } |
+            ServerError::Font { minor_opcode, major_opcode, .. } |
+            ServerError::Match { minor_opcode, major_opcode } |
+            ServerError::Drawable { minor_opcode, major_opcode, .. } |
+            ServerError::Access { minor_opcode, major_opcode } |
+            ServerError::Alloc { minor_opcode, major_opcode } |
+            ServerError::Colormap { minor_opcode, major_opcode, .. } |
+            ServerError::GContext { minor_opcode, major_opcode, .. } |
+            ServerError::IDChoice { minor_opcode, major_opcode, .. } |
+            ServerError::Name { minor_opcode, major_opcode } |
+            ServerError::Length { minor_opcode, major_opcode } |
+            ServerError::Implementation { minor_opcode, major_opcode } |
+            ServerError::Extension { minor_opcode, major_opcode, .. } |
+            ServerError::Unknown { minor_opcode, major_opcode, .. } => (major_opcode, minor_opcode)
+        }
+    }
+
+    /** The name Xlib gives the error (ie "BadWindow"), and what it means */
+    fn xlib_name(&self) -> Option<(&'static str, &'static str)> {
+        match *self {
+            ServerError::Request { .. } => Some(("BadRequest", "invalid request code or no such operation")),
+            ServerError::Value { .. } => Some(("BadValue", "integer parameter out of range for operation")),
+            ServerError::Window { .. } => Some(("BadWindow", "invalid Window parameter")),
+            ServerError::Pixmap { .. } => Some(("BadPixmap", "invalid Pixmap parameter")),
+            ServerError::Atom { .. } => Some(("BadAtom", "invalid Atom parameter")),
+            ServerError::Cursor { .. } => Some(("BadCursor", "invalid Cursor parameter")),
+            ServerError::Font { .. } => Some(("BadFont", "invalid Font parameter")),
+            ServerError::Match { .. } => Some(("BadMatch", "invalid parameter attributes")),
+            ServerError::Drawable { .. } => Some(("BadDrawable", "invalid Pixmap or Window parameter")),
+            ServerError::Access { .. } => Some(("BadAccess", "attempt to access private resource denied")),
+            ServerError::Alloc { .. } => Some(("BadAlloc", "insufficient resources for operation")),
+            ServerError::Colormap { .. } => Some(("BadColor", "invalid Colormap parameter")),
+            ServerError::GContext { .. } => Some(("BadGC", "invalid GC parameter")),
+            ServerError::IDChoice { .. } => Some(("BadIDChoice", "invalid resource ID chosen for this connection")),
+            ServerError::Name { .. } => Some(("BadName", "named color or font does not exist")),
+            ServerError::Length { .. } => Some(("BadLength", "poly request too large or internal Xlib length error")),
+            ServerError::Implementation { .. } => Some(("BadImplementation", "server does not implement operation")),
+            ServerError::Extension { .. } | ServerError::Unknown { .. } => None
+        }
+    }
+
+    /** The bad resource ID, atom, or value in the failed request, and what Xlib calls it */
+    fn bad_value(&self) -> Option<(&'static str, u32)> {
+        match *self {
+            ServerError::Value { bad_value, .. } => Some(("Value", bad_value)),
+            ServerError::Atom { bad_atom_id, .. } => Some(("Atom id", bad_atom_id)),
+            ServerError::Window { bad_resource_id, .. } |
+            ServerError::Pixmap { bad_resource_id, .. } |
+            ServerError::Cursor { bad_resource_id, .. } |
+            ServerError::Font { bad_resource_id, .. } |
+            ServerError::Drawable { bad_resource_id, .. } |
+            ServerError::Colormap { bad_resource_id, .. } |
+            ServerError::GContext { bad_resource_id, .. } |
+            ServerError::IDChoice { bad_resource_id, .. } => Some(("Resource id", bad_resource_id)),
+            ServerError::Extension { bad_value, .. } |
+            ServerError::Unknown { bad_value, .. } => Some(("Resource id", bad_value)),
+            _ => None
+        }
+    }
+
+    /**
+     * Describes the error like Xlib does. `extension` is the name of the extension the failed request belongs to,
+     * if its major opcode is 128 or more.
+     */
+    pub fn message(&self, extension: Option<&str>) -> String {
+        let mut message = String::from("X Error of failed request: ");
+        match (self.xlib_name(), self) {
+            (Some((name, meaning)), _) => message.push_str(&format!("{} ({})", name, meaning)),
+            (None, &ServerError::Extension { extension, code, .. }) => match extensions::error_name(extension, code) {
+                Some(name) => message.push_str(&format!("{} {}", extension, name)),
+                None => message.push_str(&format!("{} error {}", extension, code))
+            },
+            (None, &ServerError::Unknown { code, .. }) => message.push_str(&format!("error {}", code)),
+            (None, _) => ()
+        }
+
+        let (major_opcode, minor_opcode) = self.opcodes();
+        message.push_str(&format!(", Major opcode {}", major_opcode));
+        if major_opcode < 128 {
+            if let Some(name) = protocol::request_name(major_opcode) {
+                message.push_str(&format!(" ({})", name));
+            }
+        } else {
+            if let Some(name) = extension {
+                message.push_str(&format!(" ({})", name));
+            }
+            message.push_str(&format!(", Minor opcode {}", minor_opcode));
+        }
+
+        if let Some((kind, value)) = self.bad_value() {
+            message.push_str(&format!(", {} 0x{:x}", kind, value));
+        }
+        message
+    }
 }
 
-#[derive(Debug)]
+impl fmt::Display for ServerError {
+    /** Without the client, the only extension name we know is that of an extension error */
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let extension = match *self {
+            ServerError::Extension { extension, .. } => Some(extension),
+            _ => None
+        };
+        f.write_str(&self.message(extension))
+    }
+}
+
+impl error::Error for ServerError {}
+
+#[derive(Debug, Copy, Clone)]
 pub enum ServerReplyType { // Used to specify a ServerReply type without creating the entire object
     GetWindowAttributes,
     GetGeometry,
@@ -151,11 +308,42 @@ pub enum ServerReplyType { // Used to specify a ServerReply type without creatin
     GetPointerMapping,
     SetModifierMapping,
     GetModifierMapping,
+    Extension(u8, u8), // Major and minor opcode of an extension request, decoded by the registered Extension
     None
 }
 
 #[derive(Debug)]
 pub enum ServerReply {
+    Extension { // Decoded by an Extension registered from outside this crate
+        extension: &'static str,
+        data: Box<dyn Any + Send>
+    },
+    Unknown { // An extension reply nothing could decode
+        major_opcode: u8,
+        minor_opcode: u8,
+        raw: Vec<u8> // The entire reply, including the header
+    },
+    XCMisc(XCMiscReply),
+    Xkb(XkbReply),
+    XInput(XInputReply),
+    Composite(CompositeReply),
+    RandR(RandRReply),
+    Xinerama(XineramaReply),
+    Sync(SyncReply),
+    Shape(ShapeReply),
+    Render(RenderReply),
+    XFixes(XFixesReply),
+    Damage(DamageReply),
+    Shm(ShmReply),
+    XTest(XTestReply),
+    Record(RecordReply),
+    DPMS(DPMSReply),
+    ScreenSaver(ScreenSaverReply),
+    Present(PresentReply),
+    XRes(XResReply),
+    Xv(XvReply),
+    Dbe(DbeReply),
+    Security(SecurityReply),
     GetWindowAttributes {
         backing_store: WindowBackingStore,
         visual: u32,
@@ -641,6 +829,31 @@ pub enum ServerEvent {
         request: MappingType,
         first_keycode: u8,
         count: u8
+    },
+    Generic { // GenericEvent (XGE), used by extensions like XInput 2 and Present
+        extension: u8, // Major opcode of the extension that sent the event
+        evtype: u16,
+        data: Vec<u8> // Everything after `evtype`, including the extra length
+    },
+    Extension { // Decoded by an Extension registered from outside this crate
+        extension: &'static str,
+        data: Box<dyn Any + Send>
+    },
+    Xkb(XkbEvent),
+    XInput(XInputEvent),
+    RandR(RandREvent),
+    Sync(SyncEvent),
+    Shape(ShapeEvent),
+    XFixes(XFixesEvent),
+    Damage(DamageEvent),
+    Shm(ShmEvent),
+    ScreenSaver(ScreenSaverEvent),
+    Present(PresentEvent),
+    Xv(XvEvent),
+    Security(SecurityEvent),
+    Unknown { // Any event we don't know how to parse
+        code: u8,
+        raw: [u8; 32]
     }
 }
 
@@ -648,7 +861,13 @@ pub enum ServerEvent {
 pub enum ServerResponse {
     Error(ServerError, u16),
     Reply(ServerReply, u16),
-    Event(ServerEvent, u16, bool)
+    Event(ServerEvent, u16, EventSource)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum EventSource {
+    Server, // Generated by the X Server
+    SendEvent // Synthetic, sent by a client with SendEvent (ie ICCCM's synthetic ConfigureNotify)
 }
 
 //
@@ -795,6 +1014,28 @@ pub trait Drawable {
         client.put_image(self.get_drawable(), gcid, data, width, height, x, y, left_pad, depth, format)
     }
 
+    /** Copies a rectangle of this drawable into `dst` */
+    fn copy_area<D: Drawable>(&self, client: &mut XClient, dst: &D, gcid: u32, src_x: i16, src_y: i16, dst_x: i16, dst_y: i16, width: u16, height: u16) {
+        client.copy_area(self.get_drawable(), dst.get_drawable(), gcid, src_x, src_y, dst_x, dst_y, width, height)
+    }
+
+    /**
+     * Draws a ZPixmap image from shared memory, starting `offset` bytes into the segment. Much faster than put_image for big images.
+     * Don't change that part of the segment until the server is done (ie after a round trip).
+     */
+    fn shm_put_image(&self, client: &mut XClient, gcid: u32, segment: &ShmSegment, offset: u32, width: u16, height: u16, x: i16, y: i16, depth: u8) {
+        client.shm_put_image(self.get_drawable(), gcid, width, height, 0, 0, width, height, x, y, depth, ImageFormat::ZPixmap.val(), false, segment.shmseg, offset);
+    }
+
+    /** Copies a rectangle of this drawable into shared memory as a ZPixmap, starting `offset` bytes into the segment. Blocks. Returns false if the server couldn't. */
+    fn shm_get_image_sync(&self, client: &mut XClient, segment: &mut ShmSegment, offset: u32, x: i16, y: i16, width: u16, height: u16) -> bool {
+        let seq = match client.shm_get_image(self.get_drawable(), x, y, width, height, !0, ImageFormat::ZPixmap.val(), segment.shmseg, offset) {
+            Some(seq) => seq,
+            None => return false
+        };
+        matches!(client.wait_for_response(seq), ServerResponse::Reply(ServerReply::Shm(_), _))
+    }
+
     /**
      * Tells the X Server to [TODO]
      * `format` may only be ImageFormat::XYPixmap or ImageFormat::ZPixmap
@@ -1235,7 +1476,7 @@ impl GraphicsContext {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Point {
     pub x: i16,
     pub y: i16
@@ -1247,7 +1488,7 @@ impl Point {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Rectangle {
     pub x: i16,
     pub y: i16,
@@ -1263,7 +1504,7 @@ impl Rectangle {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Arc {
     pub x: i16,
     pub y: i16,
@@ -1627,7 +1868,8 @@ pub enum KeyButton {
     Button2,
     Button3,
     Button4,
-    Button5
+    Button5,
+    Group(u8) // The XKB group (0-3), only there if it isn't 0
 }
 impl KeyButton {
     pub fn get(mask: u16) -> Vec<KeyButton> {
@@ -1672,6 +1914,9 @@ impl KeyButton {
         if mask & 0x1000 == 0x1000 {
             v.push(KeyButton::Button5);
         }
+        if mask & 0x6000 != 0 {
+            v.push(KeyButton::Group(((mask >> 13) & 0x03) as u8));
+        }
         
         return v;
     }
@@ -1690,7 +1935,8 @@ impl KeyButton {
             &KeyButton::Button2 => 0x0200,
             &KeyButton::Button3 => 0x0400,
             &KeyButton::Button4 => 0x0800,
-            &KeyButton::Button5 => 0x1000
+            &KeyButton::Button5 => 0x1000,
+            &KeyButton::Group(group) => (group as u16 & 0x03) << 13
         }
     }
 }
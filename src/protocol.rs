@@ -170,6 +170,7 @@ pub const REPLY_SELECTION_NOTIFY: u8 = 31;
 pub const REPLY_COLORMAP_NOTIFY: u8 = 32;
 pub const REPLY_CLIENT_MESSAGE: u8 = 33;
 pub const REPLY_MAPPING_NOTIFY: u8 = 34;
+pub const REPLY_GENERIC_EVENT: u8 = 35;
 
 // Error codes
 pub const ERROR_REQUEST: u8 = 1;
@@ -189,3 +190,130 @@ pub const ERROR_ID_CHOICE: u8 = 14;
 pub const ERROR_NAME: u8 = 15;
 pub const ERROR_LENGTH: u8 = 16;
 pub const ERROR_IMPLEMENTATION: u8 = 17;
+
+/** The name of a core request (ie "ConfigureWindow"), from its major opcode */
+pub fn request_name(major_opcode: u8) -> Option<&'static str> {
+    match major_opcode {
+        OP_CREATE_WINDOW => Some("CreateWindow"),
+        OP_CHANGE_WINDOW_ATTRIBUTES => Some("ChangeWindowAttributes"),
+        OP_GET_WINDOW_ATTRIBUTES => Some("GetWindowAttributes"),
+        OP_DESTROY_WINDOW => Some("DestroyWindow"),
+        OP_DESTROY_SUBWINDOWS => Some("DestroySubwindows"),
+        OP_CHANGE_SAVE_SET => Some("ChangeSaveSet"),
+        OP_REPARENT_WINDOW => Some("ReparentWindow"),
+        OP_MAP_WINDOW => Some("MapWindow"),
+        OP_MAP_SUBWINDOWS => Some("MapSubwindows"),
+        OP_UNMAP_WINDOW => Some("UnmapWindow"),
+        OP_UNMAP_SUBWINDOWS => Some("UnmapSubwindows"),
+        OP_CONFIGURE_WINDOW => Some("ConfigureWindow"),
+        OP_CIRCULATE_WINDOW => Some("CirculateWindow"),
+        OP_GET_GEOMETRY => Some("GetGeometry"),
+        OP_QUERY_TREE => Some("QueryTree"),
+        OP_INTERN_ATOM => Some("InternAtom"),
+        OP_GET_ATOM_NAME => Some("GetAtomName"),
+        OP_CHANGE_PROPERTY => Some("ChangeProperty"),
+        OP_DELETE_PROPERTY => Some("DeleteProperty"),
+        OP_GET_PROPERTY => Some("GetProperty"),
+        OP_LIST_PROPERTIES => Some("ListProperties"),
+        OP_SET_SELECTION_OWNER => Some("SetSelectionOwner"),
+        OP_GET_SELECTION_OWNER => Some("GetSelectionOwner"),
+        OP_CONVERT_SELECTION => Some("ConvertSelection"),
+        OP_SEND_EVENT => Some("SendEvent"),
+        OP_GRAB_POINTER => Some("GrabPointer"),
+        OP_UNGRAB_POINTER => Some("UngrabPointer"),
+        OP_GRAB_BUTTON => Some("GrabButton"),
+        OP_UNGRAB_BUTTON => Some("UngrabButton"),
+        OP_CHANGE_ACTIVE_POINTER_GRAB => Some("ChangeActivePointerGrab"),
+        OP_GRAB_KEYBOARD => Some("GrabKeyboard"),
+        OP_UNGRAB_KEYBOARD => Some("UngrabKeyboard"),
+        OP_GRAB_KEY => Some("GrabKey"),
+        OP_UNGRAB_KEY => Some("UngrabKey"),
+        OP_ALLOW_EVENTS => Some("AllowEvents"),
+        OP_GRAB_SERVER => Some("GrabServer"),
+        OP_UNGRAB_SERVER => Some("UngrabServer"),
+        OP_QUERY_POINTER => Some("QueryPointer"),
+        OP_GET_MOTION_EVENTS => Some("GetMotionEvents"),
+        OP_TRANSLATE_COORDINATES => Some("TranslateCoordinates"),
+        OP_WARP_POINTER => Some("WarpPointer"),
+        OP_SET_INPUT_FOCUS => Some("SetInputFocus"),
+        OP_GET_INPUT_FOCUS => Some("GetInputFocus"),
+        OP_QUERY_KEYMAP => Some("QueryKeymap"),
+        OP_OPEN_FONT => Some("OpenFont"),
+        OP_CLOSE_FONT => Some("CloseFont"),
+        OP_QUERY_FONT => Some("QueryFont"),
+        OP_QUERY_TEXT_EXTENTS => Some("QueryTextExtents"),
+        OP_LIST_FONTS => Some("ListFonts"),
+        OP_LIST_FONTS_WITH_INFO => Some("ListFontsWithInfo"),
+        OP_SET_FONT_PATH => Some("SetFontPath"),
+        OP_GET_FONT_PATH => Some("GetFontPath"),
+        OP_CREATE_PIXMAP => Some("CreatePixmap"),
+        OP_FREE_PIXMAP => Some("FreePixmap"),
+        OP_CREATE_GC => Some("CreateGC"),
+        OP_CHANGE_GC => Some("ChangeGC"),
+        OP_COPY_GC => Some("CopyGC"),
+        OP_SET_DASHES => Some("SetDashes"),
+        OP_SET_CLIP_RECTANGLES => Some("SetClipRectangles"),
+        OP_FREE_GC => Some("FreeGC"),
+        OP_CLEAR_AREA => Some("ClearArea"),
+        OP_COPY_AREA => Some("CopyArea"),
+        OP_COPY_PLANE => Some("CopyPlane"),
+        OP_POLY_POINT => Some("PolyPoint"),
+        OP_POLY_LINE => Some("PolyLine"),
+        OP_POLY_SEGMENT => Some("PolySegment"),
+        OP_POLY_RECTANGLE => Some("PolyRectangle"),
+        OP_POLY_ARC => Some("PolyArc"),
+        OP_FILL_POLY => Some("FillPoly"),
+        OP_POLY_FILL_RECTANGLE => Some("PolyFillRectangle"),
+        OP_POLY_FILL_ARC => Some("PolyFillArc"),
+        OP_PUT_IMAGE => Some("PutImage"),
+        OP_GET_IMAGE => Some("GetImage"),
+        OP_POLY_TEXT8 => Some("PolyText8"),
+        OP_POLY_TEXT16 => Some("PolyText16"),
+        OP_IMAGE_TEXT8 => Some("ImageText8"),
+        OP_IMAGE_TEXT16 => Some("ImageText16"),
+        OP_CREATE_COLORMAP => Some("CreateColormap"),
+        OP_FREE_COLORMAP => Some("FreeColormap"),
+        OP_COPY_COLORMAP_AND_FREE => Some("CopyColormapAndFree"),
+        OP_INSTALL_COLORMAP => Some("InstallColormap"),
+        OP_UNINSTALL_COLORMAP => Some("UninstallColormap"),
+        OP_LIST_INSTALLED_COLORMAPS => Some("ListInstalledColormaps"),
+        OP_ALLOC_COLOR => Some("AllocColor"),
+        OP_ALLOC_NAMED_COLOR => Some("AllocNamedColor"),
+        OP_ALLOC_COLOR_CELLS => Some("AllocColorCells"),
+        OP_ALLOC_COLOR_PLANES => Some("AllocColorPlanes"),
+        OP_FREE_COLORS => Some("FreeColors"),
+        OP_STORE_COLORS => Some("StoreColors"),
+        OP_STORE_NAMED_COLOR => Some("StoreNamedColor"),
+        OP_QUERY_COLORS => Some("QueryColors"),
+        OP_LOOKUP_COLOR => Some("LookupColor"),
+        OP_CREATE_CURSOR => Some("CreateCursor"),
+        OP_CREATE_GLYPH_CURSOR => Some("CreateGlyphCursor"),
+        OP_FREE_CURSOR => Some("FreeCursor"),
+        OP_RECOLOR_CURSOR => Some("RecolorCursor"),
+        OP_QUERY_BEST_SIZE => Some("QueryBestSize"),
+        OP_QUERY_EXTENSION => Some("QueryExtension"),
+        OP_LIST_EXTENSIONS => Some("ListExtensions"),
+        OP_CHANGE_KEYBOARD_MAPPING => Some("ChangeKeyboardMapping"),
+        OP_GET_KEYBOARD_MAPPING => Some("GetKeyboardMapping"),
+        OP_CHANGE_KEYBOARD_CONTROL => Some("ChangeKeyboardControl"),
+        OP_GET_KEYBOARD_CONTROL => Some("GetKeyboardControl"),
+        OP_BELL => Some("Bell"),
+        OP_CHANGE_POINTER_CONTROL => Some("ChangePointerControl"),
+        OP_GET_POINTER_CONTROL => Some("GetPointerControl"),
+        OP_SET_SCREEN_SAVER => Some("SetScreenSaver"),
+        OP_GET_SCREEN_SAVER => Some("GetScreenSaver"),
+        OP_CHANGE_HOSTS => Some("ChangeHosts"),
+        OP_LIST_HOSTS => Some("ListHosts"),
+        OP_SET_ACCESS_CONTROL => Some("SetAccessControl"),
+        OP_SET_CLOSE_DOWN_MODE => Some("SetCloseDownMode"),
+        OP_KILL_CLIENT => Some("KillClient"),
+        OP_ROTATE_PROPERTIES => Some("RotateProperties"),
+        OP_FORCE_SCREEN_SAVER => Some("ForceScreenSaver"),
+        OP_SET_POINTER_MAPPING => Some("SetPointerMapping"),
+        OP_GET_POINTER_MAPPING => Some("GetPointerMapping"),
+        OP_SET_MODIFIER_MAPPING => Some("SetModifierMapping"),
+        OP_GET_MODIFIER_MAPPING => Some("GetModifierMapping"),
+        OP_NO_OPERATION => Some("NoOperation"),
+        _ => None
+    }
+}
@@ -21,6 +21,13 @@ pub trait XBufferedWriter {
     fn write_u16(&mut self, input: u16);
     fn write_i32(&mut self, input: i32);
     fn write_u32(&mut self, input: u32);
+    fn write_i64(&mut self, input: i64);
+    fn write_u64(&mut self, input: u64);
+    fn write_f32(&mut self, input: f32);
+    fn write_f64(&mut self, input: f64);
+    fn write_fp1616(&mut self, input: f64);
+    fn write_fp3232(&mut self, input: f64);
+    fn write_hi_lo_i64(&mut self, input: i64);
     fn write_str(&mut self, input: &str);
     fn write_mask_u16(&mut self, input: &Vec<u16>);
     fn write_mask_u32(&mut self, input: &Vec<u32>);
@@ -44,6 +51,13 @@ pub trait XBufferedReader {
     fn read_u16(&mut self) -> u16;
     fn read_i32(&mut self) -> i32;
     fn read_u32(&mut self) -> u32;
+    fn read_i64(&mut self) -> i64;
+    fn read_u64(&mut self) -> u64;
+    fn read_f32(&mut self) -> f32;
+    fn read_f64(&mut self) -> f64;
+    fn read_fp1616(&mut self) -> f64;
+    fn read_fp3232(&mut self) -> f64;
+    fn read_hi_lo_i64(&mut self) -> i64;
     fn read_char(&mut self) -> char;
     fn read_str(&mut self, len: usize) -> String;
     fn read_raw(&mut self, len: usize) -> Vec<u8>;
@@ -74,6 +88,37 @@ impl XReadHelper {
             pos: 0
         }
     }
+
+    /** Like prep_read, but returns false instead of panicking if the connection has been closed */
+    pub fn try_prep_read(&mut self, len: usize) -> bool {
+        self.buf.resize(len, 0);
+        self.pos = 0;
+        self.xin.read_exact(&mut self.buf).is_ok()
+    }
+
+    /** Moves to the given position in the current buffer (ie to re-read a header) */
+    pub fn seek(&mut self, pos: usize) {
+        if pos > self.buf.len() {
+            panic!("Attempt to seek out of buffer.");
+        }
+
+        self.pos = pos;
+    }
+
+    /** Where the next read will come from in the current buffer (for seek) */
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /** The number of bytes left to read in the current buffer */
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    /** The length of a reply or generic event past the first 32 bytes, in 4-byte units (ie the reply's length field) */
+    pub fn extra_length(&self) -> u32 {
+        (self.buf.len().saturating_sub(32) / 4) as u32
+    }
 }
 
 // Errors and replies
@@ -107,6 +152,17 @@ impl XReadHelper {
         }
     }
 
+    /** Reads an error that neither the core protocol nor an extension could decode, so it isn't lost */
+    pub fn read_unknown_error(&mut self, code: u8) -> ServerError {
+        self.seek(4);
+        let bad_value = self.read_u32();
+        let minor_opcode = self.read_u16();
+        let major_opcode = self.read_u8();
+        self.seek(0);
+        let raw = self.read_raw(32);
+        ServerError::Unknown { code, bad_value, minor_opcode, major_opcode, raw }
+    }
+
     /** Reads character info */
     fn read_char_info(&mut self) -> CharInfo {
         return CharInfo {
@@ -562,6 +618,13 @@ impl XReadHelper {
         Some(ServerReply::QueryExtension { present, major_opcode, first_event, first_error })
     }
 
+    /** Reads an extension reply that nothing could decode. Keeps the entire reply so nothing is lost. */
+    pub fn read_unknown_reply(&mut self, major_opcode: u8, minor_opcode: u8) -> Option<ServerReply> {
+        let raw = self.buf.clone();
+        self.pos = self.buf.len();
+        Some(ServerReply::Unknown { major_opcode, minor_opcode, raw })
+    }
+
     /** Reads TODO */
     pub fn read_list_extensions_reply(&mut self, len: u8) -> Option<ServerReply> {
         self.read_pad(24);
@@ -576,7 +639,10 @@ impl XReadHelper {
     /** Reads TODO */
     pub fn read_get_keyboard_mapping_reply(&mut self, keysyms_count: u8) -> Option<ServerReply> {
         self.read_pad(24);
-        let keycode_count = (self.buf.len() - self.pos) / keysyms_count as usize; // Because we don't know what value of `m` was passed
+        let keycode_count = match keysyms_count {
+            0 => 0,
+            _ => (self.buf.len() - self.pos) / 4 / keysyms_count as usize // Because we don't know what value of `m` was passed
+        };
         let mut mapping = Vec::with_capacity(keycode_count);
         
         for i in 0..keycode_count {
@@ -1139,6 +1205,71 @@ impl XReadHelper {
         self.read_pad(25);
         Some(ServerEvent::MappingNotify { request, first_keycode, count })
     }
+
+    /** Reads a core event (codes 2 to 34) after its 4 byte header, ie from the event stream or from RECORD data */
+    pub(crate) fn read_core_event(&mut self, code: u8, detail: u8, sequence_number: u16) -> Option<ServerEvent> {
+        match code {
+            protocol::REPLY_KEY_PRESS => self.read_key_press(detail),
+            protocol::REPLY_KEY_RELEASE => self.read_key_release(detail),
+            protocol::REPLY_BUTTON_PRESS => self.read_button_press(detail),
+            protocol::REPLY_BUTTON_RELEASE => self.read_button_release(detail),
+            protocol::REPLY_MOTION_NOTIFY => self.read_motion_notify(detail),
+            protocol::REPLY_ENTER_NOTIFY => self.read_enter_notify(detail),
+            protocol::REPLY_LEAVE_NOTIFY => self.read_leave_notify(detail),
+            protocol::REPLY_FOCUS_IN => self.read_focus_in(detail),
+            protocol::REPLY_FOCUS_OUT => self.read_focus_out(detail),
+            protocol::REPLY_KEYMAP_NOTIFY => self.read_keymap_notify(sequence_number, detail), // The one special case
+            protocol::REPLY_EXPOSE => self.read_expose(),
+            protocol::REPLY_GRAPHICS_EXPOSURE => self.read_graphics_exposure(),
+            protocol::REPLY_NO_EXPOSURE => self.read_no_exposure(),
+            protocol::REPLY_VISIBILITY_NOTIFY => self.read_visibility_notify(),
+            protocol::REPLY_CREATE_NOTIFY => self.read_create_notify(),
+            protocol::REPLY_DESTROY_NOTIFY => self.read_destroy_notify(),
+            protocol::REPLY_UNMAP_NOTIFY => self.read_unmap_notify(),
+            protocol::REPLY_MAP_NOTIFY => self.read_map_notify(),
+            protocol::REPLY_MAP_REQUEST => self.read_map_request(),
+            protocol::REPLY_REPARENT_NOTIFY => self.read_reparent_notify(),
+            protocol::REPLY_CONFIGURE_NOTIFY => self.read_configure_notify(),
+            protocol::REPLY_CONFIGURE_REQUEST => self.read_configure_request(detail),
+            protocol::REPLY_GRAVITY_NOTIFY => self.read_gravity_notify(),
+            protocol::REPLY_RESIZE_REQUEST => self.read_resize_request(),
+            protocol::REPLY_CIRCULATE_NOTIFY => self.read_circulate_notify(),
+            protocol::REPLY_CIRCULATE_REQUEST => self.read_circulate_request(),
+            protocol::REPLY_PROPERTY_NOTIFY => self.read_property_notify(),
+            protocol::REPLY_SELECTION_CLEAR => self.read_selection_clear(),
+            protocol::REPLY_SELECTION_REQUEST => self.read_selection_request(),
+            protocol::REPLY_SELECTION_NOTIFY => self.read_selection_notify(),
+            protocol::REPLY_COLORMAP_NOTIFY => self.read_colormap_notify(),
+            protocol::REPLY_CLIENT_MESSAGE => self.read_client_message(detail),
+            protocol::REPLY_MAPPING_NOTIFY => self.read_mapping_notify(),
+            _ => None
+        }
+    }
+
+    /**
+     * Reads the header of a GenericEvent from the server (assumes first byte read) and returns its `evtype`.
+     * These carry an extra length, so this also reads the rest of the event from the stream.
+     */
+    pub fn read_generic_event_header(&mut self) -> u16 {
+        let length = self.read_u32();
+        self.prep_read_extend((length * 4) as usize);
+        self.read_u16()
+    }
+
+    /** Reads a GenericEvent that no extension decoded (assumes read_generic_event_header was used) */
+    pub fn read_generic_event(&mut self, extension: u8, evtype: u16) -> Option<ServerEvent> {
+        self.seek(10);
+        let data = self.read_raw_remaining();
+        Some(ServerEvent::Generic { extension, evtype, data })
+    }
+
+    /** Reads an event we don't know about. Keeps the entire 32 bytes so nothing is lost. */
+    pub fn read_unknown_event(&mut self, code: u8) -> Option<ServerEvent> {
+        let mut raw = [0u8; 32];
+        raw.clone_from_slice(&self.buf[0..32]);
+        self.pos = 32;
+        Some(ServerEvent::Unknown { code, raw })
+    }
 }
 
 impl XBufferedReader for XReadHelper {
@@ -1225,6 +1356,66 @@ impl XBufferedReader for XReadHelper {
         x
     }
 
+    /**
+     * Reads an i64 from the buffer.
+     * Expects little endian.
+     */
+    fn read_i64(&mut self) -> i64 {
+        self.read_u64() as i64
+    }
+
+    /**
+     * Reads a u64 from the buffer.
+     * Expects little endian.
+     */
+    fn read_u64(&mut self) -> u64 {
+        let low = self.read_u32() as u64;
+        let high = self.read_u32() as u64;
+        low + (high << 32)
+    }
+
+    /**
+     * Reads an f32 from the buffer.
+     * Expects little endian.
+     */
+    fn read_f32(&mut self) -> f32 {
+        f32::from_bits(self.read_u32())
+    }
+
+    /**
+     * Reads an f64 from the buffer.
+     * Expects little endian.
+     */
+    fn read_f64(&mut self) -> f64 {
+        f64::from_bits(self.read_u64())
+    }
+
+    /**
+     * Reads a 16.16 fixed point number (a signed i32 counting 1/65536ths) from the buffer.
+     * Expects little endian.
+     */
+    fn read_fp1616(&mut self) -> f64 {
+        f64::from(self.read_i32()) / 65536.0
+    }
+
+    /**
+     * Reads a 32.32 fixed point number (a signed i32 integral part followed by a u32 fraction) from the buffer.
+     * Expects little endian.
+     */
+    fn read_fp3232(&mut self) -> f64 {
+        let integral = self.read_i32();
+        f64::from(integral) + f64::from(self.read_u32()) / 4294967296.0
+    }
+
+    /**
+     * Reads an i64 that is sent as its high i32 followed by its low u32 (ie SYNC's counter values).
+     * Expects little endian.
+     */
+    fn read_hi_lo_i64(&mut self) -> i64 {
+        let hi = self.read_i32();
+        (i64::from(hi) << 32) | i64::from(self.read_u32())
+    }
+
     /**
      * Reads a one-byte characters from the buffer.
      */
@@ -0,0 +1,177 @@
+// A tiny fake X server, so we can test parsing without a real X server running.
+// It accepts one connection (or two, for RECORD), sends a minimal connection setup, and then lets the
+// test write raw events/replies and read the raw requests the client sent.
+#![allow(dead_code)]
+
+use std::env;
+use std::fs;
+use std::io::prelude::*;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::sync::mpsc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use xrb::XClient;
+use xrb::models::ServerResponse;
+
+static NEXT_SOCKET: AtomicUsize = AtomicUsize::new(0);
+
+pub struct FakeServer {
+    pub stream: UnixStream,
+    pub auth_name: String, // The authorization the client connected with
+    pub auth_data: Vec<u8>,
+    pub sequence: u16 // The sequence number of the last request read
+}
+
+impl FakeServer {
+    /** Starts a fake server and connects a new client to it */
+    pub fn connect() -> (XClient, FakeServer) {
+        let (path, receiver) = FakeServer::listen(1);
+        let client = XClient::connect(path);
+        (client, receiver.recv().unwrap())
+    }
+
+    /**
+     * Like connect, but runs `answer` on another thread for the server side (ie answer_query_extension), so the client
+     * can send the requests it answers. Join the handle to get the server back.
+     */
+    pub fn connect_with<F>(answer: F) -> (XClient, thread::JoinHandle<FakeServer>) where F: FnOnce(&mut FakeServer) + Send + 'static {
+        let (client, mut server) = FakeServer::connect();
+        let handle = thread::spawn(move || {
+            answer(&mut server);
+            server
+        });
+        (client, handle)
+    }
+
+    /**
+     * Like connect, but the server has an extension, and the client has sent its version request (with `query_version`)
+     * and got the answer, which is returned too. See answer_extension.
+     */
+    pub fn connect_with_extension<F>(name: &str, major_opcode: u8, first_event: u8, first_error: u8, version_request: &[u8], version_reply: &[u8], query_version: F) -> (XClient, FakeServer, ServerResponse)
+        where F: FnOnce(&mut XClient) -> Option<u16>
+    {
+        let (name, version_request, version_reply) = (name.to_string(), version_request.to_vec(), version_reply.to_vec());
+        let (mut client, handle) = FakeServer::connect_with(move |server| {
+            server.answer_extension(&name, major_opcode, first_event, first_error, &version_request, &version_reply);
+        });
+        let seq = query_version(&mut client).unwrap();
+        let response = client.wait_for_response(seq);
+        (client, handle.join().unwrap(), response)
+    }
+
+    /** Like connect, but the client gives an authorization */
+    pub fn connect_with_authorization(auth_name: &str, auth_data: &[u8]) -> (XClient, FakeServer) {
+        let (path, receiver) = FakeServer::listen(1);
+        let client = XClient::connect_with_authorization(path, auth_name, auth_data);
+        (client, receiver.recv().unwrap())
+    }
+
+    /**
+     * Like connect_with_authorization, but also accepts the client's second connection (ie for RECORD data),
+     * which comes out of the receiver
+     */
+    pub fn connect_with_second(auth_name: &str, auth_data: &[u8]) -> (XClient, FakeServer, mpsc::Receiver<FakeServer>) {
+        let (path, receiver) = FakeServer::listen(2);
+        let client = XClient::connect_with_authorization(path, auth_name, auth_data);
+        let server = receiver.recv().unwrap();
+        (client, server, receiver)
+    }
+
+    /** Listens on a new socket, and sends a connection setup to the first `connections` clients */
+    fn listen(connections: usize) -> (String, mpsc::Receiver<FakeServer>) {
+        let path = env::temp_dir().join(format!("xrb-fake-{}-{}", std::process::id(), NEXT_SOCKET.fetch_add(1, Ordering::SeqCst)));
+        let _ = fs::remove_file(&path);
+        let listener = UnixListener::bind(&path).unwrap();
+        let (sender, receiver) = mpsc::channel();
+        let name = String::from(path.to_str().unwrap());
+
+        thread::spawn(move || {
+            for _ in 0..connections {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = [0u8; 12];
+                stream.read_exact(&mut request).unwrap();
+                let name_len = request[6] as usize + ((request[7] as usize) << 8);
+                let data_len = request[8] as usize + ((request[9] as usize) << 8);
+                let mut auth = vec![0u8; name_len.next_multiple_of(4) + data_len.next_multiple_of(4)];
+                stream.read_exact(&mut auth).unwrap();
+                let auth_name = String::from_utf8(auth[..name_len].to_vec()).unwrap();
+                let auth_data = auth[name_len.next_multiple_of(4)..][..data_len].to_vec();
+                stream.write_all(&FakeServer::setup()).unwrap();
+                if sender.send(FakeServer { stream, auth_name, auth_data, sequence: 0 }).is_err() {
+                    break;
+                }
+            }
+            let _ = fs::remove_file(&path);
+        });
+
+        (name, receiver)
+    }
+
+    /** A connection setup with no formats and no screens */
+    fn setup() -> Vec<u8> {
+        let mut buf = vec![1, 0, 11, 0, 0, 0, 9, 0];
+        buf.extend_from_slice(&[0, 0, 0, 0]); // release number
+        buf.extend_from_slice(&[0, 0, 0x40, 0]); // resource id base
+        buf.extend_from_slice(&[0xFF, 0xFF, 0x1F, 0]); // resource id mask
+        buf.extend_from_slice(&[0, 0, 0, 0]); // motion buffer size
+        buf.extend_from_slice(&[4, 0, 0xFF, 0xFF]); // vendor length, maximum request length
+        buf.extend_from_slice(&[0, 0, 0, 0, 32, 32, 8, 255]); // screens, formats, orders, scanline, keycodes
+        buf.extend_from_slice(&[0, 0, 0, 0]);
+        buf.extend_from_slice(b"fake");
+        buf
+    }
+
+    /** Sends raw bytes to the client */
+    pub fn send(&mut self, data: &[u8]) {
+        self.stream.write_all(data).unwrap();
+    }
+
+    /** Returns true once the client has closed the connection */
+    pub fn is_closed(&mut self) -> bool {
+        let mut buf = [0u8; 1];
+        matches!(self.stream.read(&mut buf), Ok(0))
+    }
+
+    /** Reads one request from the client, including its header */
+    pub fn read_request(&mut self) -> Vec<u8> {
+        let mut buf = vec![0u8; 4];
+        self.stream.read_exact(&mut buf).unwrap();
+        self.sequence = self.sequence.wrapping_add(1);
+        let len = (buf[2] as usize + ((buf[3] as usize) << 8)) * 4;
+        buf.resize(len, 0);
+        self.stream.read_exact(&mut buf[4..]).unwrap();
+        buf
+    }
+
+    /** Replies to the last request read. `body` is everything after the reply length, and is padded to 32 bytes. */
+    pub fn send_reply(&mut self, detail: u8, body: &[u8]) {
+        let extra = body.len().saturating_sub(24).next_multiple_of(4);
+        let mut reply = vec![1, detail];
+        reply.extend_from_slice(&self.sequence.to_le_bytes());
+        reply.extend_from_slice(&(extra as u32 / 4).to_le_bytes());
+        reply.extend_from_slice(body);
+        reply.resize(32 + extra, 0);
+        self.send(&reply);
+    }
+
+    /** Reads a QueryExtension for `name` and answers it. A major opcode of 0 means the server doesn't have the extension. */
+    pub fn answer_query_extension(&mut self, name: &str, major_opcode: u8, first_event: u8, first_error: u8) {
+        let request = self.read_request();
+        assert_eq!(request[0], 98);
+        assert_eq!(request[4..6], (name.len() as u16).to_le_bytes());
+        assert_eq!(&request[8..8 + name.len()], name.as_bytes());
+        self.send_reply(0, &[(major_opcode != 0) as u8, major_opcode, first_event, first_error]);
+    }
+
+    /**
+     * Answers the QueryExtension for `name`, then reads the version request after it and answers that with `version_reply`.
+     * The version request has to start with `version_request` (which can leave out pad bytes at the end).
+     */
+    pub fn answer_extension(&mut self, name: &str, major_opcode: u8, first_event: u8, first_error: u8, version_request: &[u8], version_reply: &[u8]) {
+        self.answer_query_extension(name, major_opcode, first_event, first_error);
+        let request = self.read_request();
+        assert_eq!(request[..version_request.len()], version_request[..]);
+        self.send_reply(0, version_reply);
+    }
+}
@@ -0,0 +1,50 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::extensions::composite::{self, CompositeReply, Redirect};
+use xrb::models::*;
+use common::FakeServer;
+
+#[test]
+fn named_pixmap_is_drawable() {
+    let (mut client, handle) = FakeServer::connect_with(|server| server.answer_query_extension("Composite", 130, 0, 0));
+    let window = Window {
+        depth: 24, wid: 0x200001, parent: 0x100, x: 0, y: 0, width: 100, height: 50, border_width: 2,
+        class: WindowInputType::InputOutput, visual_id: 0x21, values: vec![]
+    };
+    client.composite_redirect_window(window.wid, &Redirect::Manual);
+    let pixmap = window.name_pixmap(&mut client).unwrap();
+    pixmap.copy_area(&mut client, &window, 0x200010, 0, 0, 0, 0, pixmap.width, pixmap.height);
+    let mut server = handle.join().unwrap();
+
+    let request = server.read_request();
+    assert_eq!(&request[..9], &[130, composite::OP_REDIRECT_WINDOW, 3, 0, 0x01, 0, 0x20, 0, 1]);
+    let request = server.read_request();
+    assert_eq!(request[..8], [130, composite::OP_NAME_WINDOW_PIXMAP, 3, 0, 0x01, 0, 0x20, 0]);
+    assert_eq!(request[8..12], pixmap.pid.to_le_bytes());
+    assert_eq!((pixmap.depth, pixmap.width, pixmap.height), (24, 104, 54));
+    let request = server.read_request();
+    assert_eq!(request[0], 62); // CopyArea
+    assert_eq!(request[4..8], pixmap.pid.to_le_bytes());
+}
+
+#[test]
+fn overlay_window_reply() {
+    let (mut client, handle) = FakeServer::connect_with(|server| {
+        server.answer_query_extension("Composite", 130, 0, 0);
+
+        let request = server.read_request();
+        assert_eq!(request, vec![130, composite::OP_GET_OVERLAY_WINDOW, 2, 0, 0, 1, 0, 0]);
+        let mut reply = vec![1, 0, 2, 0, 0, 0, 0, 0, 0x05, 0, 0x40, 0];
+        reply.resize(32, 0);
+        server.send(&reply);
+    });
+
+    let seq = client.composite_get_overlay_window(0x100).unwrap();
+    match client.wait_for_response(seq) {
+        ServerResponse::Reply(ServerReply::Composite(CompositeReply::GetOverlayWindow { overlay_win }), _) => assert_eq!(overlay_win, 0x400005),
+        other => panic!("Expected a GetOverlayWindow reply, got {:?}", other)
+    };
+    handle.join().unwrap();
+}
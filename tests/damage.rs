@@ -0,0 +1,52 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::XClient;
+use xrb::extensions::damage::{self, DamageEvent, ReportLevel};
+use xrb::models::*;
+use common::FakeServer;
+
+fn connect() -> (XClient, FakeServer) {
+    let (client, server, _) = FakeServer::connect_with_extension("DAMAGE", 143, 91, 152, &[143, damage::OP_QUERY_VERSION, 3, 0, 1, 0, 0, 0, 1, 0, 0, 0], &[1, 0, 0, 0, 1, 0, 0, 0], |client| client.damage_query_version(1, 1));
+    (client, server)
+}
+
+#[test]
+fn create_and_subtract() {
+    let (mut client, mut server) = connect();
+    client.damage_create(0x200030, 0x400001, &ReportLevel::BoundingBox);
+    client.damage_subtract_all(0x200030);
+
+    let request = server.read_request();
+    assert_eq!(request[..12], [143, damage::OP_CREATE, 4, 0, 0x30, 0, 0x20, 0, 0x01, 0, 0x40, 0]);
+    assert_eq!(request[12], 2);
+    assert_eq!(server.read_request(), vec![143, damage::OP_SUBTRACT, 4, 0, 0x30, 0, 0x20, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn notify_is_decoded() {
+    let (mut client, mut server) = connect();
+    server.send(&[
+        91, 0x80, 3, 0, 0x01, 0, 0x40, 0, 0x30, 0, 0x20, 0, 0x10, 0, 0, 0,
+        5, 0, 6, 0, 7, 0, 8, 0, 0xF6, 0xFF, 20, 0, 0, 2, 0, 1
+    ]);
+    server.send(&[
+        91, 0x00, 3, 0, 0x01, 0, 0x40, 0, 0x30, 0, 0x20, 0, 0x11, 0, 0, 0,
+        1, 0, 1, 0, 1, 0, 1, 0, 0xF6, 0xFF, 20, 0, 0, 2, 0, 1
+    ]);
+
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::Damage(DamageEvent::Notify(event)), _, _) => {
+            assert_eq!((event.level, event.more), (ReportLevel::RawRectangles, true));
+            assert_eq!((event.drawable, event.damage, event.timestamp), (0x400001, 0x200030, 0x10));
+            assert_eq!((event.area.x, event.area.y, event.area.width, event.area.height), (5, 6, 7, 8));
+            assert_eq!((event.geometry.x, event.geometry.y, event.geometry.width, event.geometry.height), (-10, 20, 512, 256));
+        },
+        other => panic!("Expected a DamageNotify, got {:?}", other)
+    };
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::Damage(DamageEvent::Notify(event)), _, _) => assert!(!event.more),
+        other => panic!("Expected a DamageNotify, got {:?}", other)
+    };
+}
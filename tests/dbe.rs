@@ -0,0 +1,68 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::XClient;
+use xrb::extensions::dbe::{self, BackBuffer, DbeReply, SwapAction};
+use xrb::models::*;
+use common::FakeServer;
+
+fn connect() -> (XClient, FakeServer) {
+    let (client, server, response) = FakeServer::connect_with_extension("DOUBLE-BUFFER", 151, 0, 155, &[151, dbe::OP_QUERY_VERSION, 2, 0, 1, 0], &[1, 0], |client| client.dbe_query_version(1, 0));
+    match response {
+        ServerResponse::Reply(ServerReply::Dbe(DbeReply::QueryVersion { major_version: 1, minor_version: 0 }), 2) => (),
+        other => panic!("Wrong response: {:?}", other)
+    }
+    (client, server)
+}
+
+#[test]
+fn draw_into_back_buffer_and_swap() {
+    let (mut client, mut server) = connect();
+    let buffer = BackBuffer::allocate(&mut client, 0x200000, &SwapAction::Background).unwrap();
+    buffer.fill_rect(&mut client, 0x200001, Rectangle { x: 1, y: 2, width: 3, height: 4 });
+    client.dbe_begin_idiom();
+    buffer.swap(&mut client, &SwapAction::Copied);
+    client.dbe_end_idiom();
+    buffer.deallocate(&mut client);
+
+    let request = server.read_request();
+    assert_eq!(request[..13], [151, dbe::OP_ALLOCATE_BACK_BUFFER, 4, 0, 0, 0, 0x20, 0, 0, 0, 0x40, 0, 1]);
+    let request = server.read_request();
+    assert_eq!(request[0], 70);
+    assert_eq!(request[2..], [5, 0, 0, 0, 0x40, 0, 1, 0, 0x20, 0, 1, 0, 2, 0, 3, 0, 4, 0]);
+    assert_eq!(server.read_request(), vec![151, dbe::OP_BEGIN_IDIOM, 1, 0]);
+    let request = server.read_request();
+    assert_eq!(request[..13], [151, dbe::OP_SWAP_BUFFERS, 4, 0, 1, 0, 0, 0, 0, 0, 0x20, 0, 3]);
+    assert_eq!(server.read_request(), vec![151, dbe::OP_END_IDIOM, 1, 0]);
+    assert_eq!(server.read_request(), vec![151, dbe::OP_DEALLOCATE_BACK_BUFFER, 2, 0, 0, 0, 0x40, 0]);
+}
+
+#[test]
+fn visual_info() {
+    let (mut client, mut server) = connect();
+    let handle = std::thread::spawn(move || {
+        assert_eq!(server.read_request(), vec![151, dbe::OP_GET_VISUAL_INFO, 2, 0, 0, 0, 0, 0]);
+        let mut reply = vec![1, 0, 3, 0, 5, 0, 0, 0, 1, 0, 0, 0];
+        reply.resize(32, 0);
+        reply.extend_from_slice(&[2, 0, 0, 0, 0x21, 0, 0, 0, 24, 3, 0, 0, 0x22, 0, 0, 0, 32, 1, 0, 0]);
+        server.send(&reply);
+        server
+    });
+
+    let screens = client.dbe_get_visual_info_sync();
+    let mut server = handle.join().unwrap();
+    assert_eq!(screens.len(), 1);
+    let visuals: Vec<_> = screens[0].infos.iter().map(|v| (v.visual_id, v.depth, v.perf_level)).collect();
+    assert_eq!(visuals, vec![(0x21, 24, 3), (0x22, 32, 1)]);
+
+    let seq = client.dbe_get_back_buffer_attributes(0x400000).unwrap();
+    assert_eq!(server.read_request(), vec![151, dbe::OP_GET_BACK_BUFFER_ATTRIBUTES, 2, 0, 0, 0, 0x40, 0]);
+    let mut reply = vec![1, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0x20, 0];
+    reply.resize(32, 0);
+    server.send(&reply);
+    match client.wait_for_response(seq) {
+        ServerResponse::Reply(ServerReply::Dbe(DbeReply::GetBackBufferAttributes { attributes }), 4) => assert_eq!(attributes.window, 0x200000),
+        other => panic!("Wrong response: {:?}", other)
+    }
+}
@@ -0,0 +1,58 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::XClient;
+use xrb::extensions::dpms::{self, DPMSMode, DPMSReply};
+use xrb::models::*;
+use common::FakeServer;
+
+fn connect() -> (XClient, FakeServer) {
+    let (client, server, response) = FakeServer::connect_with_extension("DPMS", 147, 0, 0, &[147, dpms::OP_GET_VERSION, 2, 0, 1, 0, 1, 0], &[1, 0, 1, 0], |client| client.dpms_get_version(1, 1));
+    match response {
+        ServerResponse::Reply(ServerReply::DPMS(DPMSReply::GetVersion { server_major_version: 1, server_minor_version: 1 }), 2) => (),
+        other => panic!("Wrong response: {:?}", other)
+    }
+    (client, server)
+}
+
+#[test]
+fn timeouts_and_levels() {
+    let (mut client, mut server) = connect();
+    client.dpms_set_timeouts(600, 900, 1200);
+    client.dpms_enable();
+    client.dpms_force_level(&DPMSMode::Off);
+    client.dpms_disable();
+
+    let request = server.read_request();
+    assert_eq!(request[..10], [147, dpms::OP_SET_TIMEOUTS, 3, 0, 0x58, 0x02, 0x84, 0x03, 0xB0, 0x04]);
+    assert_eq!(server.read_request(), vec![147, dpms::OP_ENABLE, 1, 0]);
+    let request = server.read_request();
+    assert_eq!(request[..6], [147, dpms::OP_FORCE_LEVEL, 2, 0, 3, 0]);
+    assert_eq!(server.read_request(), vec![147, dpms::OP_DISABLE, 1, 0]);
+}
+
+#[test]
+fn info_and_timeouts_replies() {
+    let (mut client, mut server) = connect();
+    let info = client.dpms_info().unwrap();
+    let timeouts = client.dpms_get_timeouts().unwrap();
+
+    assert_eq!(server.read_request(), vec![147, dpms::OP_INFO, 1, 0]);
+    assert_eq!(server.read_request(), vec![147, dpms::OP_GET_TIMEOUTS, 1, 0]);
+    let mut reply = vec![1, 0, 3, 0, 0, 0, 0, 0, 2, 0, 1];
+    reply.resize(32, 0);
+    server.send(&reply);
+    let mut reply = vec![1, 0, 4, 0, 0, 0, 0, 0, 0x58, 0x02, 0x84, 0x03, 0xB0, 0x04];
+    reply.resize(32, 0);
+    server.send(&reply);
+
+    match client.wait_for_response(info) {
+        ServerResponse::Reply(ServerReply::DPMS(DPMSReply::Info { power_level: DPMSMode::Suspend, state: true }), 3) => (),
+        other => panic!("Wrong response: {:?}", other)
+    }
+    match client.wait_for_response(timeouts) {
+        ServerResponse::Reply(ServerReply::DPMS(DPMSReply::GetTimeouts { standby_timeout: 600, suspend_timeout: 900, off_timeout: 1200 }), 4) => (),
+        other => panic!("Wrong response: {:?}", other)
+    }
+}
@@ -0,0 +1,49 @@
+extern crate xrb;
+
+mod common;
+
+use std::error::Error;
+
+use xrb::models::*;
+use common::FakeServer;
+
+#[test]
+fn core_errors_read_like_xlib() {
+    let error = ServerError::Window { minor_opcode: 0, major_opcode: 12, bad_resource_id: 0x400001 };
+    assert_eq!(error.to_string(), "X Error of failed request: BadWindow (invalid Window parameter), Major opcode 12 (ConfigureWindow), Resource id 0x400001");
+
+    let error = ServerError::Value { minor_opcode: 0, major_opcode: 55, bad_value: 0xFF };
+    assert_eq!(error.to_string(), "X Error of failed request: BadValue (integer parameter out of range for operation), Major opcode 55 (CreateGC), Value 0xff");
+
+    let error = ServerError::Match { minor_opcode: 0, major_opcode: 73 };
+    assert_eq!(error.to_string(), "X Error of failed request: BadMatch (invalid parameter attributes), Major opcode 73 (GetImage)");
+
+    let error: Box<dyn Error> = Box::new(ServerError::Atom { minor_opcode: 0, major_opcode: 17, bad_atom_id: 900 });
+    assert_eq!(error.to_string(), "X Error of failed request: BadAtom (invalid Atom parameter), Major opcode 17 (GetAtomName), Atom id 0x384");
+}
+
+#[test]
+fn extension_errors_read_like_xlib() {
+    let error = ServerError::Extension { extension: "RANDR", code: 0, bad_value: 0x62, minor_opcode: 9, major_opcode: 140 };
+    assert_eq!(error.to_string(), "X Error of failed request: RANDR BadOutput, Major opcode 140 (RANDR), Minor opcode 9, Resource id 0x62");
+
+    let error = ServerError::Extension { extension: "FAKE", code: 3, bad_value: 0, minor_opcode: 2, major_opcode: 141 };
+    assert_eq!(error.to_string(), "X Error of failed request: FAKE error 3, Major opcode 141 (FAKE), Minor opcode 2, Resource id 0x0");
+
+    let error = ServerError::Unknown { code: 20, bad_value: 0, minor_opcode: 1, major_opcode: 200, raw: vec![0; 32] };
+    assert_eq!(error.to_string(), "X Error of failed request: error 20, Major opcode 200, Minor opcode 1, Resource id 0x0");
+}
+
+#[test]
+fn describe_error_names_the_extension() {
+    let (mut client, handle) = FakeServer::connect_with(|server| server.answer_query_extension("FAKE", 140, 90, 160));
+    client.query_extension_sync("FAKE").unwrap();
+    handle.join().unwrap();
+
+    // A core error from an extension request
+    let error = ServerError::Drawable { minor_opcode: 3, major_opcode: 140, bad_resource_id: 0x200000 };
+    assert_eq!(client.describe_error(&error, 7), "X Error of failed request: BadDrawable (invalid Pixmap or Window parameter), Major opcode 140 (FAKE), Minor opcode 3, Resource id 0x200000, Serial number 7");
+
+    let error = ServerError::Length { minor_opcode: 0, major_opcode: 64 };
+    assert_eq!(client.describe_error(&error, 8), "X Error of failed request: BadLength (poly request too large or internal Xlib length error), Major opcode 64 (PolyPoint), Serial number 8");
+}
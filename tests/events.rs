@@ -0,0 +1,94 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::models::*;
+use common::FakeServer;
+
+/** A MapNotify event for window 0x00400001, used to check the stream is still in sync */
+fn map_notify() -> Vec<u8> {
+    let mut buf = vec![19, 0, 1, 0];
+    buf.extend_from_slice(&[1, 0, 0x40, 0, 1, 0, 0x40, 0]);
+    buf.resize(32, 0);
+    buf
+}
+
+#[test]
+fn unknown_event_is_kept() {
+    let (mut client, mut server) = FakeServer::connect();
+    let mut event = vec![0u8; 32];
+    event[0] = 60;
+    event[4] = 0xAB;
+    server.send(&event);
+    server.send(&map_notify());
+
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::Unknown { code, raw }, _, _) => {
+            assert_eq!(code, 60);
+            assert_eq!(raw[4], 0xAB);
+        },
+        other => panic!("Expected an unknown event, got {:?}", other)
+    };
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::MapNotify { window, .. }, _, _) => assert_eq!(window, 0x00400001),
+        other => panic!("Expected MapNotify, got {:?}", other)
+    };
+}
+
+#[test]
+fn generic_event_reads_extra_length() {
+    let (mut client, mut server) = FakeServer::connect();
+    let mut event = vec![35, 131, 2, 0, 2, 0, 0, 0, 7, 0];
+    event.resize(40, 0);
+    event[39] = 0xCD;
+    server.send(&event);
+    server.send(&map_notify());
+
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::Generic { extension, evtype, data }, _, _) => {
+            assert_eq!(extension, 131);
+            assert_eq!(evtype, 7);
+            assert_eq!(data.len(), 30);
+            assert_eq!(data[29], 0xCD);
+        },
+        other => panic!("Expected a generic event, got {:?}", other)
+    };
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::MapNotify { window, .. }, _, _) => assert_eq!(window, 0x00400001),
+        other => panic!("Expected MapNotify, got {:?}", other)
+    };
+}
+
+#[test]
+fn send_event_flag_is_parsed() {
+    let (mut client, mut server) = FakeServer::connect();
+    let mut event = map_notify();
+    event[0] |= 0x80;
+    server.send(&event);
+    server.send(&map_notify());
+
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::MapNotify { .. }, _, source) => assert_eq!(source, EventSource::SendEvent),
+        other => panic!("Expected a synthetic MapNotify, got {:?}", other)
+    };
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::MapNotify { .. }, _, source) => assert_eq!(source, EventSource::Server),
+        other => panic!("Expected MapNotify, got {:?}", other)
+    };
+}
+
+#[test]
+fn extension_event_keeps_all_seven_bits() {
+    let (mut client, mut server) = FakeServer::connect();
+    let mut event = vec![0u8; 32];
+    event[0] = 0x80 | 100;
+    server.send(&event);
+
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::Unknown { code, .. }, _, source) => {
+            assert_eq!(code, 100);
+            assert_eq!(source, EventSource::SendEvent);
+        },
+        other => panic!("Expected an unknown event, got {:?}", other)
+    };
+}
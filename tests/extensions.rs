@@ -0,0 +1,182 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::extensions::Extension;
+use xrb::models::*;
+use xrb::xreaderwriter::{XBufferedReader, XBufferedWriter, XReadHelper};
+use common::FakeServer;
+
+/** A made up extension with two events, two errors, and one request with a reply */
+struct Fake;
+
+impl Extension for Fake {
+    fn name(&self) -> &'static str {
+        "FAKE"
+    }
+
+    fn event_count(&self) -> u8 {
+        2
+    }
+
+    fn error_names(&self) -> &'static [&'static str] {
+        &["BadFake", "BadFakeValue"]
+    }
+
+    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {
+        let value = reader.read_u32();
+        Some(ServerReply::Extension { extension: "FAKE", data: Box::new((minor_opcode, detail, value)) })
+    }
+
+    fn read_event(&self, reader: &mut XReadHelper, code: u8, detail: u8) -> Option<ServerEvent> {
+        let value = reader.read_u32();
+        Some(ServerEvent::Extension { extension: "FAKE", data: Box::new((code, detail, value)) })
+    }
+}
+
+#[test]
+fn query_extension_is_cached() {
+    let (mut client, handle) = FakeServer::connect_with(|server| server.answer_query_extension("FAKE", 140, 90, 160));
+
+    let info = client.query_extension_sync("FAKE").unwrap();
+    assert_eq!((info.major_opcode, info.first_event, info.first_error), (140, 90, 160));
+    let mut server = handle.join().unwrap();
+
+    // Second call doesn't send a request, so the next thing the server sees is the NoOperation
+    assert_eq!(client.query_extension_sync("FAKE"), Some(info));
+    client.no_operation(0);
+    assert_eq!(server.read_request()[0], 127);
+}
+
+#[test]
+fn extension_events_and_replies_are_routed() {
+    let (mut client, handle) = FakeServer::connect_with(|server| server.answer_query_extension("FAKE", 140, 90, 160));
+    client.register_extension(Box::new(Fake)).unwrap();
+    let mut server = handle.join().unwrap();
+
+    // Event 91 is FAKE's second event
+    let mut event = vec![91, 3, 1, 0, 0xEF, 0xBE, 0xAD, 0xDE];
+    event.resize(32, 0);
+    server.send(&event);
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::Extension { extension, data }, _, _) => {
+            assert_eq!(extension, "FAKE");
+            assert_eq!(*data.downcast::<(u8, u8, u32)>().unwrap(), (1, 3, 0xDEADBEEF));
+        },
+        other => panic!("Expected a FAKE event, got {:?}", other)
+    };
+
+    // A request with a reply, written from outside the crate
+    client.write_u8(140);
+    client.write_u8(5);
+    client.write_u16(1);
+    let seq = client.write_sequence(ServerReplyType::Extension(140, 5));
+    assert_eq!(server.read_request(), vec![140, 5, 1, 0]);
+
+    let mut reply = vec![1, 7, seq as u8, (seq >> 8) as u8, 0, 0, 0, 0, 0x78, 0x56, 0x34, 0x12];
+    reply.resize(32, 0);
+    server.send(&reply);
+    match client.wait_for_response(seq) {
+        ServerResponse::Reply(ServerReply::Extension { data, .. }, _) => {
+            assert_eq!(*data.downcast::<(u8, u8, u32)>().unwrap(), (5, 7, 0x12345678));
+        },
+        other => panic!("Expected a FAKE reply, got {:?}", other)
+    };
+}
+
+#[test]
+fn unregistered_extension_reply_is_kept() {
+    let (mut client, mut server) = FakeServer::connect();
+    client.write_u8(200);
+    client.write_u8(1);
+    client.write_u16(1);
+    let seq = client.write_sequence(ServerReplyType::Extension(200, 1));
+    server.read_request();
+
+    let mut reply = vec![1, 0, seq as u8, (seq >> 8) as u8, 1, 0, 0, 0];
+    reply.resize(36, 0);
+    reply[35] = 0xAB;
+    server.send(&reply);
+    match client.wait_for_response(seq) {
+        ServerResponse::Reply(ServerReply::Unknown { major_opcode, minor_opcode, raw }, _) => {
+            assert_eq!((major_opcode, minor_opcode), (200, 1));
+            assert_eq!(raw.len(), 36);
+            assert_eq!(raw[35], 0xAB);
+        },
+        other => panic!("Expected an unknown reply, got {:?}", other)
+    };
+}
+
+#[test]
+fn extension_errors_are_kept() {
+    let (mut client, handle) = FakeServer::connect_with(|server| server.answer_query_extension("FAKE", 140, 90, 160));
+    client.register_extension(Box::new(Fake)).unwrap();
+    let mut server = handle.join().unwrap();
+
+    // Error 161 is FAKE's second error, which it doesn't decode itself
+    let mut error = vec![0, 161, 2, 0, 0x01, 0x00, 0x40, 0x00, 3, 0, 140];
+    error.resize(32, 0);
+    server.send(&error);
+    match client.wait_for_message() {
+        ServerResponse::Error(ServerError::Extension { extension, code, bad_value, minor_opcode, major_opcode }, 2) => {
+            assert_eq!((extension, code, bad_value, minor_opcode, major_opcode), ("FAKE", 1, 0x400001, 3, 140));
+        },
+        other => panic!("Expected a FAKE error, got {:?}", other)
+    };
+
+    // Nobody has error 20
+    let mut error = vec![0, 20, 3, 0, 0x78, 0x56, 0x34, 0x12, 1, 0, 200];
+    error.resize(32, 0);
+    error[31] = 0xCD;
+    server.send(&error);
+    match client.wait_for_message() {
+        ServerResponse::Error(ServerError::Unknown { code, bad_value, minor_opcode, major_opcode, raw }, 3) => {
+            assert_eq!((code, bad_value, minor_opcode, major_opcode), (20, 0x12345678, 1, 200));
+            assert_eq!(raw.len(), 32);
+            assert_eq!(raw[31], 0xCD);
+        },
+        other => panic!("Expected an unknown error, got {:?}", other)
+    };
+}
+
+#[test]
+fn errors_of_queried_extensions_are_named_without_a_decoder() {
+    let (mut client, handle) = FakeServer::connect_with(|server| server.answer_query_extension("DAMAGE", 143, 91, 152));
+    client.query_extension_sync("DAMAGE").unwrap();
+    let mut server = handle.join().unwrap();
+
+    let mut error = vec![0, 152, 2, 0, 0x05, 0x00, 0x40, 0x00, 7, 0, 143];
+    error.resize(32, 0);
+    server.send(&error);
+    match client.wait_for_message() {
+        ServerResponse::Error(ServerError::Extension { extension, code, bad_value, minor_opcode, major_opcode }, 2) => {
+            assert_eq!((extension, code, bad_value, minor_opcode, major_opcode), ("DAMAGE", 0, 0x400005, 7, 143));
+        },
+        other => panic!("Expected a DAMAGE error, got {:?}", other)
+    };
+}
+
+#[test]
+fn codes_past_an_extension_are_not_its_own() {
+    let (mut client, handle) = FakeServer::connect_with(|server| server.answer_query_extension("FAKE", 140, 90, 160));
+    client.register_extension(Box::new(Fake)).unwrap();
+    let mut server = handle.join().unwrap();
+
+    // FAKE has two errors, so error 162 belongs to an extension we haven't queried
+    let mut error = vec![0, 162, 2, 0, 0x05, 0x00, 0x40, 0x00, 1, 0, 141];
+    error.resize(32, 0);
+    server.send(&error);
+    match client.wait_for_message() {
+        ServerResponse::Error(ServerError::Unknown { code, major_opcode, .. }, 2) => assert_eq!((code, major_opcode), (162, 141)),
+        other => panic!("Expected an unknown error, got {:?}", other)
+    };
+
+    // Same for event 92
+    let mut event = vec![92, 0, 3, 0];
+    event.resize(32, 0);
+    server.send(&event);
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::Unknown { code, .. }, _, _) => assert_eq!(code, 92),
+        other => panic!("Expected an unknown event, got {:?}", other)
+    };
+}
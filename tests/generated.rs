@@ -0,0 +1,51 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::extensions::render;
+use xrb::extensions::xc_misc::{self, XCMiscReply};
+use xrb::models::*;
+use common::FakeServer;
+
+#[test]
+fn generated_request_and_reply() {
+    let (mut client, handle) = FakeServer::connect_with(|server| {
+        server.answer_query_extension("XC-MISC", 130, 0, 0);
+
+        let request = server.read_request();
+        assert_eq!(request, vec![130, xc_misc::OP_GET_XID_LIST, 2, 0, 3, 0, 0, 0]);
+
+        let mut reply = vec![1, 0, 2, 0, 2, 0, 0, 0, 2, 0, 0, 0];
+        reply.resize(32, 0);
+        reply.extend_from_slice(&[0x10, 0, 0x40, 0, 0x20, 0, 0x40, 0]);
+        server.send(&reply);
+    });
+
+    let seq = client.xc_misc_get_xid_list(3).unwrap();
+    match client.wait_for_response(seq) {
+        ServerResponse::Reply(ServerReply::XCMisc(XCMiscReply::GetXIDList { ids }), _) => assert_eq!(ids, vec![0x400010, 0x400020]),
+        other => panic!("Expected a GetXIDList reply, got {:?}", other)
+    };
+    handle.join().unwrap();
+}
+
+#[test]
+fn requests_without_the_extension_send_nothing() {
+    let (mut client, handle) = FakeServer::connect_with(|server| server.answer_query_extension("XC-MISC", 0, 0, 0));
+    assert_eq!(client.xc_misc_get_xid_list(3), None);
+    let mut server = handle.join().unwrap();
+
+    // Asking again doesn't send anything either, so the next thing the server sees is the NoOperation
+    assert_eq!(client.xc_misc_get_xid_range(), None);
+    client.no_operation(0);
+    assert_eq!(server.read_request()[0], 127);
+}
+
+#[test]
+#[should_panic(expected = "stops and colors need the same length")]
+fn lists_sharing_a_length_must_match() {
+    let (mut client, _server) = FakeServer::connect();
+    let point = render::PointFix { x: 0.0, y: 0.0 };
+    let white = render::Color { red: 0xFFFF, green: 0xFFFF, blue: 0xFFFF, alpha: 0xFFFF };
+    client.render_create_linear_gradient(0x400001, &point, &point, &vec![0.0, 1.0], &vec![white]);
+}
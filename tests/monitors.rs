@@ -0,0 +1,133 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::XClient;
+use xrb::extensions::{randr, xinerama, MonitorRect};
+use xrb::models::*;
+use common::FakeServer;
+
+fn connect() -> (XClient, FakeServer) {
+    let (mut client, server) = FakeServer::connect();
+    let mut screen = Screen::empty();
+    screen.root = 0x100;
+    screen.width_in_pixels = 1024;
+    screen.height_in_pixels = 768;
+    client.info.screens.push(screen);
+    (client, server)
+}
+
+#[test]
+fn monitors_from_randr() {
+    let (mut client, mut server) = connect();
+    let handle = std::thread::spawn(move || {
+        server.answer_query_extension("RANDR", 140, 0, 0);
+        assert_eq!(server.read_request()[..2], [140, randr::OP_QUERY_VERSION]);
+        let mut reply = vec![1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0];
+        reply.resize(32, 0);
+        server.send(&reply);
+
+        assert_eq!(server.read_request()[..2], [140, randr::OP_GET_MONITORS]);
+        let mut reply = vec![1, 0, 3, 0, 14, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0];
+        reply.resize(32, 0);
+        reply.extend_from_slice(&[1, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0x80, 0x07, 0x38, 0x04, 0, 0, 0, 0, 0, 0, 0, 0, 0x42, 0, 0, 0]);
+        reply.extend_from_slice(&[2, 0, 0, 0, 1, 1, 1, 0, 0x80, 0x07, 0, 0, 0x00, 0x05, 0x00, 0x04, 0, 0, 0, 0, 0, 0, 0, 0, 0x43, 0, 0, 0]);
+        server.send(&reply);
+    });
+
+    let monitors = client.monitors();
+    handle.join().unwrap();
+    assert_eq!(monitors, vec![
+        MonitorRect { x: 0, y: 0, width: 1920, height: 1080, primary: false },
+        MonitorRect { x: 1920, y: 0, width: 1280, height: 1024, primary: true }
+    ]);
+}
+
+#[test]
+fn monitors_from_xinerama() {
+    let (mut client, mut server) = connect();
+    let handle = std::thread::spawn(move || {
+        server.answer_query_extension("RANDR", 0, 0, 0);
+        server.answer_query_extension("XINERAMA", 141, 0, 0);
+        assert_eq!(server.read_request(), vec![141, xinerama::OP_IS_ACTIVE, 1, 0]);
+        let mut reply = vec![1, 0, 3, 0, 0, 0, 0, 0, 1, 0, 0, 0];
+        reply.resize(32, 0);
+        server.send(&reply);
+
+        assert_eq!(server.read_request(), vec![141, xinerama::OP_QUERY_SCREENS, 1, 0]);
+        let mut reply = vec![1, 0, 4, 0, 4, 0, 0, 0, 2, 0, 0, 0];
+        reply.resize(32, 0);
+        reply.extend_from_slice(&[0, 0, 0, 0, 0x00, 0x02, 0x00, 0x03, 0x00, 0x02, 0, 0, 0x00, 0x02, 0x00, 0x03]);
+        server.send(&reply);
+    });
+
+    let monitors = client.monitors();
+    handle.join().unwrap();
+    assert_eq!(monitors, vec![
+        MonitorRect { x: 0, y: 0, width: 512, height: 768, primary: true },
+        MonitorRect { x: 512, y: 0, width: 512, height: 768, primary: false }
+    ]);
+}
+
+#[test]
+fn monitors_from_screens() {
+    let (mut client, mut server) = connect();
+    let handle = std::thread::spawn(move || {
+        server.answer_query_extension("RANDR", 0, 0, 0);
+        server.answer_query_extension("XINERAMA", 0, 0, 0);
+    });
+
+    let monitors = client.monitors();
+    handle.join().unwrap();
+    assert_eq!(monitors, vec![MonitorRect { x: 0, y: 0, width: 1024, height: 768, primary: true }]);
+}
+
+#[test]
+fn monitors_from_screens_are_the_first_screen() {
+    let (mut client, mut server) = connect();
+    let mut screen = Screen::empty();
+    screen.root = 0x200;
+    screen.width_in_pixels = 800;
+    screen.height_in_pixels = 600;
+    client.info.screens.push(screen);
+    let handle = std::thread::spawn(move || {
+        server.answer_query_extension("RANDR", 0, 0, 0);
+        server.answer_query_extension("XINERAMA", 0, 0, 0);
+    });
+
+    let monitors = client.monitors();
+    handle.join().unwrap();
+    assert_eq!(monitors, vec![MonitorRect { x: 0, y: 0, width: 1024, height: 768, primary: true }]);
+}
+
+#[test]
+fn monitors_without_screens() {
+    let (mut client, _server) = FakeServer::connect();
+    assert_eq!(client.monitors(), vec![]);
+}
+
+#[test]
+fn monitors_ask_for_the_randr_version_once() {
+    let (mut client, mut server) = connect();
+    let handle = std::thread::spawn(move || {
+        server.answer_query_extension("RANDR", 140, 0, 0);
+        assert_eq!(server.read_request()[..2], [140, randr::OP_QUERY_VERSION]);
+        let mut reply = vec![1, 0, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0];
+        reply.resize(32, 0);
+        server.send(&reply);
+
+        for seq in 3..5 {
+            assert_eq!(server.read_request()[..2], [140, randr::OP_GET_MONITORS]);
+            let mut reply = vec![1, 0, seq, 0, 7, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0];
+            reply.resize(32, 0);
+            reply.extend_from_slice(&[1, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0x00, 0x04, 0x00, 0x03, 0, 0, 0, 0, 0, 0, 0, 0, 0x42, 0, 0, 0]);
+            server.send(&reply);
+        }
+    });
+
+    let first = client.monitors();
+    let second = client.monitors();
+    handle.join().unwrap();
+    assert_eq!(first, vec![MonitorRect { x: 0, y: 0, width: 1024, height: 768, primary: true }]);
+    assert_eq!(second, first);
+}
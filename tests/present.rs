@@ -0,0 +1,80 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::XClient;
+use xrb::extensions::present::{self, CompleteKind, CompleteMode, PresentEvent, PresentReply, Swapchain};
+use xrb::models::*;
+use common::FakeServer;
+
+fn connect() -> (XClient, FakeServer) {
+    let (client, server, _) = FakeServer::connect_with_extension("Present", 149, 93, 0, &[149, present::OP_QUERY_VERSION, 3, 0, 1, 0, 0, 0, 2, 0, 0, 0], &[1, 0, 0, 0, 2, 0, 0, 0], |client| client.present_query_version(1, 2));
+    (client, server)
+}
+
+#[test]
+fn query_capabilities() {
+    let (mut client, mut server) = connect();
+    let seq = client.present_query_capabilities(0x200000).unwrap();
+    assert_eq!(server.read_request(), vec![149, present::OP_QUERY_CAPABILITIES, 2, 0, 0, 0, 0x20, 0]);
+    let mut reply = vec![1, 0, 3, 0, 0, 0, 0, 0, 5, 0, 0, 0];
+    reply.resize(32, 0);
+    server.send(&reply);
+
+    match client.wait_for_response(seq) {
+        ServerResponse::Reply(ServerReply::Present(PresentReply::QueryCapabilities { capabilities: 5 }), 3) => (),
+        other => panic!("Wrong response: {:?}", other)
+    }
+}
+
+#[test]
+fn swapchain_waits_for_idle() {
+    let (mut client, mut server) = connect();
+    let mut swapchain = Swapchain::create(&mut client, 0x200000, 24, 640, 480, 2).unwrap();
+
+    assert_eq!(server.read_request(), vec![53, 24, 4, 0, 0, 0, 0x40, 0, 0, 0, 0x20, 0, 0x80, 2, 0xE0, 1]);
+    assert_eq!(server.read_request(), vec![53, 24, 4, 0, 1, 0, 0x40, 0, 0, 0, 0x20, 0, 0x80, 2, 0xE0, 1]);
+    assert_eq!(server.read_request(), vec![149, present::OP_SELECT_INPUT, 4, 0, 2, 0, 0x40, 0, 0, 0, 0x20, 0, 7, 0, 0, 0]);
+
+    assert_eq!(swapchain.acquire(), Some(0));
+    assert_eq!(swapchain.present(&mut client, 0), 1);
+    assert_eq!(swapchain.acquire(), Some(1));
+    assert_eq!(swapchain.present(&mut client, 1), 2);
+    assert_eq!(swapchain.acquire(), None);
+
+    let request = server.read_request();
+    assert_eq!(request.len(), 72);
+    assert_eq!(request[..16], [149, present::OP_PIXMAP, 18, 0, 0, 0, 0x20, 0, 0, 0, 0x40, 0, 1, 0, 0, 0]);
+    assert_eq!(request[40..44], [0, 0, 0, 0]); // No options, so the server can flip
+    assert_eq!(request[48..72], [0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]); // Next MSC
+    assert_eq!(server.read_request()[8..12], [1, 0, 0x40, 0]);
+
+    // CompleteNotify for serial 1 at MSC 100, then IdleNotify for the first pixmap
+    server.send(&[
+        35, 149, 3, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0x40, 0, 0, 0, 0x20, 0, 1, 0, 0, 0,
+        0x10, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0
+    ]);
+    server.send(&[
+        35, 149, 3, 0, 0, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0x40, 0, 0, 0, 0x20, 0, 1, 0, 0, 0,
+        0, 0, 0x40, 0, 0, 0, 0, 0
+    ]);
+
+    match client.wait_for_message() {
+        ServerResponse::Event(event, _, _) => {
+            match event {
+                ServerEvent::Present(PresentEvent::CompleteNotify { kind: CompleteKind::Pixmap, mode: CompleteMode::Copy, serial: 1, ust: 0x10, msc: 100, .. }) => (),
+                ref other => panic!("Wrong event: {:?}", other)
+            }
+            assert!(swapchain.handle_event(&event));
+        },
+        other => panic!("Wrong response: {:?}", other)
+    }
+    assert_eq!(swapchain.last_msc(), 100);
+    assert_eq!(swapchain.acquire(), None);
+
+    match client.wait_for_message() {
+        ServerResponse::Event(event, _, _) => assert!(swapchain.handle_event(&event)),
+        other => panic!("Wrong response: {:?}", other)
+    }
+    assert_eq!(swapchain.acquire(), Some(0));
+}
@@ -0,0 +1,80 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::extensions::randr::{self, MonitorInfo, NotifyEvent, RandREvent, RandRReply};
+use xrb::XClient;
+use xrb::models::*;
+use common::FakeServer;
+
+fn connect() -> (XClient, FakeServer) {
+    let (client, server, _) = FakeServer::connect_with_extension("RANDR", 140, 89, 147, &[140, randr::OP_QUERY_VERSION, 3, 0, 1, 0, 0, 0, 5, 0, 0, 0], &[1, 0, 0, 0, 5, 0, 0, 0], |client| client.randr_query_version(1, 5));
+    (client, server)
+}
+
+#[test]
+fn get_monitors_reply() {
+    let (mut client, mut server) = connect();
+    let seq = client.randr_get_monitors(0x100, true).unwrap();
+    assert_eq!(server.read_request(), vec![140, randr::OP_GET_MONITORS, 3, 0, 0, 1, 0, 0, 1, 0, 0, 0]);
+
+    let mut reply = vec![1, 0, 3, 0, 7, 0, 0, 0, 0x10, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0];
+    reply.resize(32, 0);
+    reply.extend_from_slice(&[0xA1, 0x01, 0, 0, 1, 1, 1, 0, 0x80, 0x07, 0, 0, 0x00, 0x05, 0x00, 0x04]);
+    reply.extend_from_slice(&[0x54, 0x01, 0, 0, 0x0E, 0x01, 0, 0, 0x42, 0, 0, 0]);
+    server.send(&reply);
+
+    match client.wait_for_response(seq) {
+        ServerResponse::Reply(ServerReply::RandR(RandRReply::GetMonitors { timestamp, monitors, .. }), _) => {
+            assert_eq!(timestamp, 0x10);
+            assert_eq!(monitors.len(), 1);
+            let monitor = &monitors[0];
+            assert_eq!((monitor.name, monitor.primary, monitor.automatic), (0x1A1, true, true));
+            assert_eq!((monitor.x, monitor.y, monitor.width, monitor.height), (1920, 0, 1280, 1024));
+            assert_eq!((monitor.width_in_millimeters, monitor.height_in_millimeters), (340, 270));
+            assert_eq!(monitor.outputs, vec![0x42]);
+        },
+        other => panic!("Expected a GetMonitors reply, got {:?}", other)
+    };
+}
+
+#[test]
+fn set_monitor_writes_outputs() {
+    let (mut client, mut server) = connect();
+    client.randr_set_monitor(0x100, &MonitorInfo {
+        name: 0x1A1, primary: false, automatic: false, x: 0, y: 0, width: 960, height: 1080,
+        width_in_millimeters: 0, height_in_millimeters: 0, outputs: vec![0x42, 0x43]
+    });
+
+    let request = server.read_request();
+    assert_eq!(request.len(), 40);
+    assert_eq!(&request[..12], &[140, randr::OP_SET_MONITOR, 10, 0, 0, 1, 0, 0, 0xA1, 0x01, 0, 0]);
+    assert_eq!(&request[14..24], &[2, 0, 0, 0, 0, 0, 0xC0, 0x03, 0x38, 0x04]);
+    assert_eq!(&request[32..], &[0x42, 0, 0, 0, 0x43, 0, 0, 0]);
+}
+
+#[test]
+fn notify_subevents_are_decoded() {
+    let (mut client, mut server) = connect();
+
+    let mut event = vec![90, 0, 3, 0, 0x20, 0, 0, 0, 0, 1, 0, 0, 0x3F, 0, 0, 0, 0x4E, 0, 0, 0, 1, 0, 0, 0];
+    event.extend_from_slice(&[0x80, 0x07, 0, 0, 0x00, 0x05, 0x00, 0x04]);
+    server.send(&event);
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::RandR(RandREvent::Notify(NotifyEvent::CrtcChange(change))), _, _) => {
+            assert_eq!((change.timestamp, change.window, change.crtc, change.mode, change.rotation), (0x20, 0x100, 0x3F, 0x4E, 1));
+            assert_eq!((change.x, change.y, change.width, change.height), (1920, 0, 1280, 1024));
+        },
+        other => panic!("Expected a CrtcChange, got {:?}", other)
+    };
+
+    let mut event = vec![90, 2, 3, 0, 0, 1, 0, 0, 0x42, 0, 0, 0, 0x50, 0, 0, 0, 0x21, 0, 0, 0, 0];
+    event.resize(32, 0);
+    server.send(&event);
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::RandR(RandREvent::Notify(NotifyEvent::OutputProperty(property))), _, _) => {
+            assert_eq!((property.window, property.output, property.atom, property.timestamp, property.status), (0x100, 0x42, 0x50, 0x21, 0));
+        },
+        other => panic!("Expected an OutputProperty, got {:?}", other)
+    };
+}
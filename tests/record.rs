@@ -0,0 +1,120 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::XClient;
+use xrb::extensions::record::{self, Category, Cs, HType, Range, RecordContext};
+use xrb::models::*;
+use common::FakeServer;
+
+/** An EnableContext reply with some data after the header */
+fn enable_context_reply(category: u8, rec_sequence_num: u8, data: &[u8]) -> Vec<u8> {
+    let mut reply = vec![1, category, 2, 0, (data.len() / 4) as u8, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0x10, 0, 0, 0, rec_sequence_num, 0, 0, 0];
+    reply.resize(32, 0);
+    reply.extend_from_slice(data);
+    reply
+}
+
+fn connect() -> (XClient, FakeServer, std::sync::mpsc::Receiver<FakeServer>) {
+    let (mut client, mut server, second) = FakeServer::connect_with_second("MIT-MAGIC-COOKIE-1", &[7; 16]);
+    let handle = std::thread::spawn(move || {
+        server.answer_extension("RECORD", 146, 0, 154, &[146, record::OP_QUERY_VERSION, 2, 0, 1, 0, 13, 0], &[1, 0, 13, 0]);
+        server
+    });
+    let seq = client.record_query_version(1, 13).unwrap();
+    match client.wait_for_response(seq) {
+        ServerResponse::Reply(ServerReply::Record(record::RecordReply::QueryVersion { major_version: 1, minor_version: 13 }), 2) => (),
+        other => panic!("Wrong response: {:?}", other)
+    }
+    (client, handle.join().unwrap(), second)
+}
+
+#[test]
+fn create_context() {
+    let (mut client, mut server, _) = connect();
+    let context = RecordContext::create(&mut client, HType::FromServerTime.val() as u8, &[Cs::AllClients.val()], &[Range::device_events(2, 6)]).unwrap();
+    context.free(&mut client);
+
+    let request = server.read_request();
+    assert_eq!(request.len(), 48);
+    assert_eq!(request[..4], [146, record::OP_CREATE_CONTEXT, 12, 0]);
+    assert_eq!(request[4..9], [0, 0, 0x40, 0, 1]);
+    assert_eq!(request[12..24], [1, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0]);
+    let mut range = [0u8; 24];
+    range[18] = 2;
+    range[19] = 6;
+    assert_eq!(request[24..], range);
+    assert_eq!(server.read_request(), vec![146, record::OP_FREE_CONTEXT, 2, 0, 0, 0, 0x40, 0]);
+}
+
+#[test]
+fn enable_context_streams_until_end_of_data() {
+    let (mut client, _server, second) = connect();
+    let context = RecordContext::create(&mut client, HType::FromServerTime.val() as u8, &[Cs::AllClients.val()], &[Range::device_events(2, 6)]).unwrap();
+
+    let handle = std::thread::spawn(move || {
+        let mut data = second.recv().unwrap();
+        data.answer_query_extension("RECORD", 146, 0, 154);
+        assert_eq!(data.read_request(), vec![146, record::OP_ENABLE_CONTEXT, 2, 0, 0, 0, 0x40, 0]);
+
+        // A KeyPress (keycode 38) and a KeyRelease, each after a server time
+        let mut recorded = vec![0x20, 0, 0, 0];
+        recorded.extend_from_slice(&[
+            2, 38, 0, 0, 0x20, 0, 0, 0, 0x01, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 10, 0, 20, 0, 0, 0, 0, 0, 1, 0, 1, 0
+        ]);
+        recorded.extend_from_slice(&[0x21, 0, 0, 0]);
+        recorded.extend_from_slice(&[
+            3, 38, 0, 0, 0x21, 0, 0, 0, 0x01, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 10, 0, 20, 0, 0, 0, 0, 0, 0, 0, 1, 0
+        ]);
+        data.send(&enable_context_reply(4, 0, &[]));
+        data.send(&enable_context_reply(0, 1, &recorded));
+        data.send(&enable_context_reply(5, 2, &[]));
+        data
+    });
+
+    let replies: Vec<_> = context.enable(&client).collect();
+    let _data = handle.join().unwrap();
+
+    assert_eq!(replies.len(), 3);
+    assert_eq!(replies[0].category, Category::StartOfData);
+    assert_eq!(replies[2].category, Category::EndOfData);
+
+    let reply = &replies[1];
+    assert_eq!(reply.category, Category::FromServer);
+    assert_eq!(reply.rec_sequence_num, 1);
+    assert_eq!(reply.data.len(), 72);
+    assert_eq!(reply.events.len(), 2);
+    match reply.events[0] {
+        ServerEvent::KeyPress { key_code: 38, time: 0x20, root: 1, root_x: 10, root_y: 20, same_screen: true, .. } => (),
+        ref other => panic!("Wrong event: {:?}", other)
+    }
+    match reply.events[1] {
+        ServerEvent::KeyRelease { key_code: 38, time: 0x21, .. } => (),
+        ref other => panic!("Wrong event: {:?}", other)
+    }
+}
+
+#[test]
+fn dropped_stream_closes_its_connection() {
+    let (mut client, _server, second) = connect();
+    let context = RecordContext::create(&mut client, 0, &[Cs::AllClients.val()], &[Range::device_events(2, 6)]).unwrap();
+
+    let handle = std::thread::spawn(move || {
+        let mut data = second.recv().unwrap();
+        assert_eq!((data.auth_name.as_str(), data.auth_data.as_slice()), ("MIT-MAGIC-COOKIE-1", &[7u8; 16][..]));
+        data.answer_query_extension("RECORD", 146, 0, 154);
+        data.read_request();
+        data.send(&enable_context_reply(4, 0, &[]));
+        data
+    });
+
+    let mut stream = context.enable(&client);
+    assert_eq!(stream.next().unwrap().category, Category::StartOfData);
+    let mut data = handle.join().unwrap();
+
+    // Stopping before EndOfData still closes the data connection
+    drop(stream);
+    assert!(data.is_closed());
+}
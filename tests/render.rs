@@ -0,0 +1,117 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::XClient;
+use xrb::extensions::render::{self, DrawablePicture, GlyphElt, PictOp, Transform, StandardFormat, Color};
+use xrb::models::*;
+use common::FakeServer;
+
+fn connect() -> (XClient, FakeServer) {
+    let (client, server, _) = FakeServer::connect_with_extension("RENDER", 139, 0, 142, &[139, render::OP_QUERY_VERSION, 3, 0, 0, 0, 0, 0, 11, 0, 0, 0], &[0, 0, 0, 0, 11, 0, 0, 0], |client| client.render_query_version(0, 11));
+    (client, server)
+}
+
+/** A PICTFORMINFO for a direct format */
+fn direct_format(id: u8, depth: u8, shifts_masks: [u16; 8]) -> Vec<u8> {
+    let mut bytes = vec![id, 0, 0, 0, 1, depth, 0, 0];
+    for value in shifts_masks.iter() {
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+    bytes.extend_from_slice(&[0, 0, 0, 0]);
+    bytes
+}
+
+#[test]
+fn pict_formats_are_found() {
+    let (mut client, mut server) = connect();
+    let handle = std::thread::spawn(move || {
+        assert_eq!(server.read_request(), vec![139, render::OP_QUERY_PICT_FORMATS, 1, 0]);
+        let mut body = vec![];
+        body.extend(direct_format(0x20, 8, [0, 0, 0, 0, 0, 0, 0, 0xFF]));
+        body.extend(direct_format(0x21, 24, [16, 0xFF, 8, 0xFF, 0, 0xFF, 0, 0]));
+        body.extend(direct_format(0x22, 32, [16, 0xFF, 8, 0xFF, 0, 0xFF, 24, 0xFF]));
+        body.extend_from_slice(&[1, 0, 0, 0, 0x21, 0, 0, 0]); // Screen with 1 depth, fallback RGB24
+        body.extend_from_slice(&[24, 0, 1, 0, 0, 0, 0, 0, 0x45, 0, 0, 0, 0x21, 0, 0, 0]); // Depth 24 with visual 0x45
+        body.extend_from_slice(&[0, 0, 0, 0]); // SubPixel Unknown
+
+        let mut reply = vec![1, 0, 3, 0, (body.len() / 4) as u8, 0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0];
+        reply.resize(32, 0);
+        reply.extend(body);
+        server.send(&reply);
+    });
+
+    let formats = client.render_query_pict_formats_sync().unwrap();
+    handle.join().unwrap();
+    assert_eq!((formats.argb32(), formats.rgb24(), formats.a8()), (Some(0x22), Some(0x21), Some(0x20)));
+    assert_eq!(formats.find_standard(StandardFormat::Argb32).unwrap().depth, 32);
+    assert_eq!(formats.find_visual_format(0x45), Some(0x21));
+    assert_eq!(formats.screens[0].fallback, 0x21);
+    assert_eq!(formats.subpixels, vec![0]);
+}
+
+#[test]
+fn picture_wraps_a_drawable() {
+    let (mut client, mut server) = connect();
+    let pixmap = Pixmap { depth: 32, pid: 0x200010, drawable: 0x100, width: 16, height: 16 };
+    let picture = DrawablePicture::create(&mut client, &pixmap, 0x22, &vec![render::CpValue::Repeat(1)]).unwrap();
+    assert_eq!(picture.drawable, 0x200010);
+    picture.set_transform(&mut client, &Transform::scale(0.5, 2.0));
+    picture.fill_rectangles(&mut client, &PictOp::Over, &Color::from_rgba8(255, 0, 0, 128), &vec![Rectangle { x: 1, y: 2, width: 3, height: 4 }]);
+
+    let pid = picture.pid.to_le_bytes();
+    let request = server.read_request();
+    assert_eq!(request[..4], [139, render::OP_CREATE_PICTURE, 6, 0]);
+    assert_eq!(request[4..8], pid);
+    assert_eq!(request[8..], [0x10, 0, 0x20, 0, 0x22, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0]);
+
+    let request = server.read_request();
+    assert_eq!(request[..4], [139, render::OP_SET_PICTURE_TRANSFORM, 11, 0]);
+    assert_eq!(request[8..20], [0, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(request[24..28], [0, 0, 2, 0]);
+    assert_eq!(request[40..44], [0, 0, 1, 0]);
+
+    let request = server.read_request();
+    assert_eq!(request[..5], [139, render::OP_FILL_RECTANGLES, 7, 0, PictOp::Over.val() as u8]);
+    assert_eq!(request[12..], [0x80, 0x80, 0, 0, 0, 0, 0x80, 0x80, 1, 0, 2, 0, 3, 0, 4, 0]);
+}
+
+#[test]
+fn composite_takes_each_origin() {
+    let (mut client, mut server) = connect();
+    let src = DrawablePicture { pid: 0x200001, drawable: 0x200010, format: 0x22 };
+    let mask = DrawablePicture { pid: 0x200002, drawable: 0x200020, format: 0x23 };
+    let dst = DrawablePicture { pid: 0x200003, drawable: 0x100, format: 0x22 };
+    dst.composite(&mut client, &PictOp::Over, &src, Point { x: 1, y: 2 }, Some((&mask, Point { x: 7, y: -8 })), Point { x: 30, y: 40 }, 5, 6);
+    dst.composite(&mut client, &PictOp::Src, &src, Point { x: 1, y: 2 }, None, Point { x: 0, y: 0 }, 5, 6);
+
+    let request = server.read_request();
+    assert_eq!(request[..5], [139, render::OP_COMPOSITE, 9, 0, PictOp::Over.val() as u8]);
+    assert_eq!(request[8..20], [0x01, 0, 0x20, 0, 0x02, 0, 0x20, 0, 0x03, 0, 0x20, 0]);
+    assert_eq!(request[20..], [1, 0, 2, 0, 7, 0, 0xF8, 0xFF, 30, 0, 40, 0, 5, 0, 6, 0]);
+
+    let request = server.read_request();
+    assert_eq!(request[12..16], [0, 0, 0, 0]);
+    assert_eq!(request[20..28], [1, 0, 2, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn glyph_runs_are_encoded() {
+    let elts = vec![GlyphElt { dx: 5, dy: -1, glyphs: vec![0x41, 0x42, 0x43] }, GlyphElt { dx: 1, dy: 0, glyphs: vec![0x44] }];
+    assert_eq!(render::glyph_cmds(&elts, 1), vec![
+        3, 0, 0, 0, 5, 0, 0xFF, 0xFF, 0x41, 0x42, 0x43, 0,
+        1, 0, 0, 0, 1, 0, 0, 0, 0x44, 0, 0, 0
+    ]);
+
+    let long = vec![GlyphElt { dx: 2, dy: 3, glyphs: vec![7; 300] }];
+    let cmds = render::glyph_cmds(&long, 2);
+    assert_eq!(cmds[..8], [254, 0, 0, 0, 2, 0, 3, 0]);
+    assert_eq!(cmds[8 + 254 * 2..8 + 254 * 2 + 8], [46, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(cmds.len(), 8 + 254 * 2 + 8 + 46 * 2);
+
+    let (mut client, mut server) = connect();
+    client.render_composite_glyphs(&PictOp::Over, 0x300001, 0x300002, 0, 0x300003, 0, 0, &[GlyphElt { dx: 0, dy: 0, glyphs: vec![0x41, 0x1234] }]);
+    let request = server.read_request();
+    assert_eq!(request[..4], [139, render::OP_COMPOSITE_GLYPHS16, 10, 0]);
+    assert_eq!(request[28..], [2, 0, 0, 0, 0, 0, 0, 0, 0x41, 0, 0x34, 0x12]);
+}
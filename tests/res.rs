@@ -0,0 +1,88 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::XClient;
+use xrb::extensions::res::{self, XResReply};
+use xrb::models::*;
+use common::FakeServer;
+
+fn connect() -> (XClient, FakeServer) {
+    let (client, server, _) = FakeServer::connect_with_extension("X-Resource", 150, 0, 0, &[150, res::OP_QUERY_VERSION, 2, 0, 1, 2], &[1, 0, 2, 0], |client| client.res_query_version(1, 2));
+    (client, server)
+}
+
+/** A GetAtomName reply */
+fn atom_name_reply(seq: u8, name: &str) -> Vec<u8> {
+    let padded = name.len().div_ceil(4) * 4;
+    let mut reply = vec![1, 0, seq, 0, (padded / 4) as u8, 0, 0, 0, name.len() as u8, 0];
+    reply.resize(32, 0);
+    reply.extend_from_slice(name.as_bytes());
+    reply.resize(32 + padded, 0);
+    reply
+}
+
+#[test]
+fn query_clients() {
+    let (mut client, mut server) = connect();
+    let seq = client.res_query_clients().unwrap();
+    assert_eq!(server.read_request(), vec![150, res::OP_QUERY_CLIENTS, 1, 0]);
+    let mut reply = vec![1, 0, 3, 0, 4, 0, 0, 0, 2, 0, 0, 0];
+    reply.resize(32, 0);
+    reply.extend_from_slice(&[0, 0, 0x20, 0, 0xFF, 0xFF, 0x1F, 0, 0, 0, 0x40, 0, 0xFF, 0xFF, 0x1F, 0]);
+    server.send(&reply);
+
+    match client.wait_for_response(seq) {
+        ServerResponse::Reply(ServerReply::XRes(XResReply::QueryClients { clients }), 3) => {
+            assert_eq!(clients.len(), 2);
+            assert_eq!((clients[1].resource_base, clients[1].resource_mask), (0x400000, 0x1FFFFF));
+        },
+        other => panic!("Wrong response: {:?}", other)
+    }
+}
+
+#[test]
+fn client_resources_have_names() {
+    let (mut client, mut server) = connect();
+    let handle = std::thread::spawn(move || {
+        assert_eq!(server.read_request(), vec![150, res::OP_QUERY_CLIENT_RESOURCES, 2, 0, 0, 0, 0x20, 0]);
+        let mut reply = vec![1, 0, 3, 0, 4, 0, 0, 0, 2, 0, 0, 0];
+        reply.resize(32, 0);
+        reply.extend_from_slice(&[0x50, 0, 0, 0, 12, 0, 0, 0, 0x51, 0, 0, 0, 3, 0, 0, 0]);
+        server.send(&reply);
+
+        for atom in &[0x50, 0x51] {
+            let request = server.read_request();
+            assert_eq!((request[0], &request[2..]), (17, &[2, 0, *atom, 0, 0, 0][..]));
+        }
+        server.send(&atom_name_reply(4, "PIXMAP"));
+        server.send(&atom_name_reply(5, "WINDOW"));
+        server
+    });
+
+    let resources = client.res_client_resources_sync(0x200000);
+    handle.join().unwrap();
+    assert_eq!(resources, vec![(String::from("PIXMAP"), 12), (String::from("WINDOW"), 3)]);
+}
+
+#[test]
+fn pixmap_bytes_and_pid() {
+    let (mut client, mut server) = connect();
+    let handle = std::thread::spawn(move || {
+        assert_eq!(server.read_request(), vec![150, res::OP_QUERY_CLIENT_PIXMAP_BYTES, 2, 0, 0, 0, 0x20, 0]);
+        let mut reply = vec![1, 0, 3, 0, 0, 0, 0, 0, 0, 0x10, 0, 0, 1, 0, 0, 0];
+        reply.resize(32, 0);
+        server.send(&reply);
+
+        assert_eq!(server.read_request(), vec![150, res::OP_QUERY_CLIENT_IDS, 4, 0, 1, 0, 0, 0, 0, 0, 0x20, 0, 2, 0, 0, 0]);
+        let mut reply = vec![1, 0, 4, 0, 4, 0, 0, 0, 1, 0, 0, 0];
+        reply.resize(32, 0);
+        reply.extend_from_slice(&[0, 0, 0x20, 0, 2, 0, 0, 0, 4, 0, 0, 0, 0xD2, 0x04, 0, 0]);
+        server.send(&reply);
+        server
+    });
+
+    assert_eq!(client.res_client_pixmap_bytes_sync(0x200000), Some(0x1_0000_1000));
+    assert_eq!(client.res_client_pid_sync(0x200000), Some(1234));
+    handle.join().unwrap();
+}
@@ -0,0 +1,54 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::XClient;
+use xrb::extensions::screensaver::{self, Event, Kind, ScreenSaverEvent, State};
+use xrb::models::*;
+use common::FakeServer;
+
+fn connect() -> (XClient, FakeServer) {
+    let (client, server, _) = FakeServer::connect_with_extension("MIT-SCREEN-SAVER", 148, 92, 0, &[148, screensaver::OP_QUERY_VERSION, 2, 0, 1, 1], &[1, 0, 1, 0], |client| client.screensaver_query_version(1, 1));
+    (client, server)
+}
+
+#[test]
+fn set_attributes_and_select_input() {
+    let (mut client, mut server) = connect();
+    client.screensaver_set_attributes(0x100, 0, 0, 640, 480, 0, &WindowInputType::InputOutput, 24, 0x21, &vec![WindowValue::BackgroundPixel(0x123456)]);
+    client.screensaver_select_events(0x100, &[Event::NotifyMask, Event::CycleMask]);
+
+    assert_eq!(server.read_request(), vec![
+        148, screensaver::OP_SET_ATTRIBUTES, 8, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0x80, 2, 0xE0, 1,
+        0, 0, 1, 24, 0x21, 0, 0, 0, 2, 0, 0, 0, 0x56, 0x34, 0x12, 0
+    ]);
+    assert_eq!(server.read_request(), vec![148, screensaver::OP_SELECT_INPUT, 3, 0, 0, 1, 0, 0, 3, 0, 0, 0]);
+}
+
+#[test]
+fn idle_time() {
+    let (mut client, mut server) = connect();
+    let handle = std::thread::spawn(move || {
+        assert_eq!(server.read_request(), vec![148, screensaver::OP_QUERY_INFO, 2, 0, 0, 1, 0, 0]);
+        let mut reply = vec![1, 0, 3, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0x10, 0, 0, 0, 0x39, 0x30, 0, 0, 1, 0, 0, 0, 1];
+        reply.resize(32, 0);
+        server.send(&reply);
+        server
+    });
+    assert_eq!(client.screensaver_idle_time_sync(0x100), Some(12345));
+    handle.join().unwrap();
+}
+
+#[test]
+fn notify_is_decoded() {
+    let (mut client, mut server) = connect();
+    server.send(&[
+        92, 1, 3, 0, 0x10, 0, 0, 0, 0x00, 1, 0, 0, 0x00, 2, 0, 0,
+        2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0
+    ]);
+
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::ScreenSaver(ScreenSaverEvent::Notify { state: State::On, time: 0x10, root: 0x100, window: 0x200, kind: Kind::External, forced: true }), 3, _) => (),
+        other => panic!("Wrong event: {:?}", other)
+    }
+}
@@ -0,0 +1,75 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::XClient;
+use xrb::extensions::security::{self, Authorization, AuthorizationValue, SecurityEvent, SecurityReply, TrustLevel};
+use xrb::models::*;
+use common::FakeServer;
+
+fn connect() -> (XClient, FakeServer) {
+    let (client, server, response) = FakeServer::connect_with_extension("SECURITY", 153, 95, 157, &[153, security::OP_QUERY_VERSION, 2, 0, 1, 0, 0, 0], &[1, 0, 0, 0], |client| client.security_query_version(1, 0));
+    match response {
+        ServerResponse::Reply(ServerReply::Security(SecurityReply::QueryVersion { server_major_version: 1, server_minor_version: 0 }), 2) => (),
+        other => panic!("Wrong response: {:?}", other)
+    }
+    (client, server)
+}
+
+#[test]
+fn generate_untrusted_and_revoke() {
+    let (mut client, mut server) = connect();
+    let handle = std::thread::spawn(move || {
+        let request = server.read_request();
+        assert_eq!(request[..4], [153, security::OP_GENERATE_AUTHORIZATION, 11, 0]);
+        assert_eq!(request[4..12], [18, 0, 0, 0, 0x0B, 0, 0, 0]);
+        assert_eq!(&request[12..30], b"MIT-MAGIC-COOKIE-1");
+        assert_eq!(request[32..], [60, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0]);
+
+        let mut reply = vec![1, 0, 3, 0, 4, 0, 0, 0, 0x2A, 0, 0, 0, 16, 0];
+        reply.resize(32, 0);
+        reply.extend((0..16).map(|i| i * 3));
+        server.send(&reply);
+
+        assert_eq!(server.read_request(), vec![153, security::OP_REVOKE_AUTHORIZATION, 2, 0, 0x2A, 0, 0, 0]);
+        let mut event = vec![95, 0, 4, 0, 0x2A, 0, 0, 0];
+        event.resize(32, 0);
+        server.send(&event);
+        server
+    });
+
+    let auth = client.security_generate_untrusted_sync(60).unwrap();
+    assert_eq!(auth, Authorization { id: 0x2A, data: (0..16).map(|i| i * 3).collect() });
+    client.security_revoke_authorization(auth.id);
+    handle.join().unwrap();
+
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::Security(SecurityEvent::AuthorizationRevoked { authorization_id: 0x2A }), 4, _) => (),
+        other => panic!("Wrong response: {:?}", other)
+    }
+}
+
+#[test]
+fn generate_with_group_and_given_data() {
+    let (mut client, mut server) = connect();
+    let values = vec![AuthorizationValue::Group(0x600000), AuthorizationValue::TrustLevel(TrustLevel::Trusted)];
+    client.security_generate_authorization("XDM-AUTHORIZATION-1", &[1, 2, 3, 4, 5], &values);
+
+    let request = server.read_request();
+    assert_eq!(request[..12], [153, security::OP_GENERATE_AUTHORIZATION, 12, 0, 19, 0, 5, 0, 0x06, 0, 0, 0]);
+    assert_eq!(&request[12..31], b"XDM-AUTHORIZATION-1");
+    assert_eq!(request[32..37], [1, 2, 3, 4, 5]);
+    assert_eq!(request[40..], [0, 0, 0, 0, 0, 0, 0x60, 0]);
+}
+
+#[test]
+fn connect_with_cookie() {
+    let cookie: Vec<u8> = (0..16).collect();
+    let (_client, server) = FakeServer::connect_with_authorization(security::MIT_MAGIC_COOKIE, &cookie);
+    assert_eq!(server.auth_name, "MIT-MAGIC-COOKIE-1");
+    assert_eq!(server.auth_data, cookie);
+
+    let (_client, server) = FakeServer::connect();
+    assert_eq!(server.auth_name, "");
+    assert!(server.auth_data.is_empty());
+}
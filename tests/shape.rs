@@ -0,0 +1,75 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::XClient;
+use xrb::extensions::shape::{self, ShapeEvent, ShapeReply, Sk};
+use xrb::models::*;
+use common::FakeServer;
+
+fn connect() -> (XClient, FakeServer) {
+    let (client, server, _) = FakeServer::connect_with_extension("SHAPE", 129, 64, 0, &[129, shape::OP_QUERY_VERSION, 1, 0], &[1, 0, 1, 0], |client| client.shape_query_version());
+    (client, server)
+}
+
+#[test]
+fn set_input_shape_writes_rectangles() {
+    let (mut client, mut server) = connect();
+    let window = Window {
+        depth: 24, wid: 0x400001, parent: 0x100, x: 0, y: 0, width: 100, height: 40, border_width: 0,
+        class: WindowInputType::InputOutput, visual_id: 0x21, values: vec![]
+    };
+    window.set_input_shape(&mut client, &[
+        Rectangle { x: 0, y: 0, width: 100, height: 20 },
+        Rectangle { x: -5, y: 30, width: 10, height: 10 }
+    ]);
+
+    let request = server.read_request();
+    assert_eq!(&request[..7], &[129, shape::OP_RECTANGLES, 8, 0, 0, 2, 0]);
+    assert_eq!(&request[8..], &[
+        0x01, 0, 0x40, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 100, 0, 20, 0,
+        0xFB, 0xFF, 30, 0, 10, 0, 10, 0
+    ]);
+}
+
+#[test]
+fn get_rectangles_reply_is_decoded() {
+    let (mut client, mut server) = connect();
+    let handle = std::thread::spawn(move || {
+        let request = server.read_request();
+        assert_eq!(&request[..], &[129, shape::OP_GET_RECTANGLES, 3, 0, 0x01, 0, 0x40, 0, 0, 0, 0, 0]);
+        let mut reply = vec![1, 3, 3, 0, 2, 0, 0, 0, 1, 0, 0, 0];
+        reply.resize(32, 0);
+        reply.extend_from_slice(&[10, 0, 20, 0, 30, 0, 40, 0]);
+        server.send(&reply);
+    });
+
+    let seq = client.shape_get_rectangles(0x400001, &Sk::Bounding).unwrap();
+    match client.wait_for_response(seq) {
+        ServerResponse::Reply(ServerReply::Shape(ShapeReply::GetRectangles { ordering, rectangles }), _) => {
+            assert_eq!(ordering, 3);
+            assert_eq!(rectangles.len(), 1);
+            assert_eq!((rectangles[0].x, rectangles[0].y, rectangles[0].width, rectangles[0].height), (10, 20, 30, 40));
+        },
+        other => panic!("Expected a GetRectangles reply, got {:?}", other)
+    };
+    handle.join().unwrap();
+}
+
+#[test]
+fn shape_notify_is_decoded() {
+    let (mut client, mut server) = connect();
+    let mut event = vec![64, 2, 3, 0, 0x01, 0, 0x40, 0, 0xFE, 0xFF, 4, 0, 0x20, 0, 0x10, 0, 0x78, 0x56, 0x34, 0x12, 1];
+    event.resize(32, 0);
+    server.send(&event);
+
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::Shape(ShapeEvent::Notify { shape_kind, affected_window, extents_x, extents_y, extents_width, extents_height, server_time, shaped }), _, _) => {
+            assert_eq!(shape_kind, Sk::Input);
+            assert_eq!((affected_window, extents_x, extents_y, extents_width, extents_height), (0x400001, -2, 4, 0x20, 0x10));
+            assert_eq!((server_time, shaped), (0x12345678, true));
+        },
+        other => panic!("Expected a ShapeNotify, got {:?}", other)
+    };
+}
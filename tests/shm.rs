@@ -0,0 +1,147 @@
+extern crate libc;
+extern crate xrb;
+
+mod common;
+
+use std::mem;
+use std::os::unix::io::AsRawFd;
+use std::ptr;
+
+use xrb::extensions::shm::{self, ShmSegment};
+use xrb::models::*;
+use common::FakeServer;
+
+/** The shm_perm.mode bit for a segment that is removed once nothing has it attached (Linux) */
+const SHM_DEST: u16 = 0o1000;
+
+/** Reads a 12 byte request and the fd that came with it */
+fn read_request_with_fd(server: &mut FakeServer) -> (Vec<u8>, i32) {
+    let mut request = vec![0u8; 12];
+    let mut control = vec![0u64; 4];
+    let mut iov = libc::iovec { iov_base: request.as_mut_ptr() as *mut libc::c_void, iov_len: 12 };
+    let mut msg: libc::msghdr = unsafe { mem::zeroed() };
+    msg.msg_iov = &mut iov;
+    msg.msg_iovlen = 1;
+    msg.msg_control = control.as_mut_ptr() as *mut libc::c_void;
+    msg.msg_controllen = 32;
+    let read = unsafe { libc::recvmsg(server.stream.as_raw_fd(), &mut msg, libc::MSG_WAITALL) };
+    assert_eq!(read, 12);
+
+    let cmsg = unsafe { &*libc::CMSG_FIRSTHDR(&msg) };
+    assert_eq!((cmsg.cmsg_level, cmsg.cmsg_type), (libc::SOL_SOCKET, libc::SCM_RIGHTS));
+    let fd = unsafe { *(libc::CMSG_DATA(cmsg) as *const i32) };
+    (request, fd)
+}
+
+#[test]
+fn memfd_segment_is_shared() {
+    let (mut client, mut server) = FakeServer::connect();
+    let handle = std::thread::spawn(move || {
+        server.answer_query_extension("MIT-SHM", 130, 65, 128);
+        let (request, fd) = read_request_with_fd(&mut server);
+
+        let map = unsafe { libc::mmap(ptr::null_mut(), 4096, libc::PROT_READ | libc::PROT_WRITE, libc::MAP_SHARED, fd, 0) } as *mut u8;
+        assert!(map as *mut libc::c_void != libc::MAP_FAILED);
+        unsafe {
+            *map.offset(10) = 0xAB;
+            libc::munmap(map as *mut libc::c_void, 4096);
+            libc::close(fd);
+        }
+        (request, server)
+    });
+
+    let mut segment = ShmSegment::create_memfd(&mut client, 4096, false).unwrap();
+    let (request, mut server) = handle.join().unwrap();
+    let shmseg = segment.shmseg.to_le_bytes();
+    assert_eq!(request[..8], [130, shm::OP_ATTACH_FD, 3, 0, shmseg[0], shmseg[1], shmseg[2], shmseg[3]]);
+    assert_eq!(request[8], 0);
+    assert_eq!(segment.len(), 4096);
+    assert_eq!(segment.as_slice()[10], 0xAB);
+    segment.as_mut_slice()[0] = 1;
+
+    segment.detach(&mut client);
+    assert_eq!(server.read_request(), vec![130, shm::OP_DETACH, 2, 0, shmseg[0], shmseg[1], shmseg[2], shmseg[3]]);
+}
+
+#[test]
+fn sysv_segment_is_shared() {
+    let (mut client, mut server) = FakeServer::connect();
+    let handle = std::thread::spawn(move || {
+        server.answer_query_extension("MIT-SHM", 130, 65, 128);
+        let request = server.read_request();
+        let shmid = i32::from_le_bytes([request[8], request[9], request[10], request[11]]);
+
+        let map = unsafe { libc::shmat(shmid, ptr::null(), 0) } as *mut u8;
+        assert!(map as isize != -1);
+        unsafe {
+            *map.offset(3) = 0xCD;
+            libc::shmdt(map as *const libc::c_void);
+        }
+
+        // The client waits for the server to attach before removing the segment
+        assert_eq!(server.read_request()[0], 43); // GetInputFocus
+        server.send_reply(0, &[0x01, 0, 0, 0]);
+        (request, shmid, server)
+    });
+
+    let segment = ShmSegment::create_sysv(&mut client, 1024, true).unwrap();
+    let (request, shmid, _server) = handle.join().unwrap();
+    assert_eq!(request[..4], [130, shm::OP_ATTACH, 4, 0]);
+    assert_eq!(request[4..8], segment.shmseg.to_le_bytes());
+    assert_eq!(request[12], 1);
+    assert_eq!(segment.as_slice()[3], 0xCD);
+
+    let mut stat: libc::shmid_ds = unsafe { mem::zeroed() };
+    assert_eq!(unsafe { libc::shmctl(shmid, libc::IPC_STAT, &mut stat) }, 0);
+    assert!(stat.shm_perm.mode & SHM_DEST != 0);
+    drop(segment);
+    assert_eq!(unsafe { libc::shmctl(shmid, libc::IPC_STAT, &mut stat) }, -1);
+}
+
+#[test]
+fn drawable_gets_image_into_segment() {
+    let (mut client, handle) = FakeServer::connect_with(|server| {
+        server.answer_query_extension("MIT-SHM", 130, 65, 128);
+        let request = server.read_request();
+        assert_eq!(request[..4], [130, shm::OP_ATTACH, 4, 0]);
+        assert_eq!(server.read_request()[0], 43); // GetInputFocus
+        server.send_reply(0, &[0x01, 0, 0, 0]);
+
+        let request = server.read_request();
+        assert_eq!(request[..20], [130, shm::OP_GET_IMAGE, 8, 0, 0x01, 0, 0x40, 0, 0, 0, 0, 0, 16, 0, 8, 0, 0xFF, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(request[20], ImageFormat::ZPixmap.val());
+        assert_eq!(request[28..], [0, 0, 0, 0]);
+        server.send_reply(24, &[0x21, 0, 0, 0, 0, 2, 0, 0]);
+    });
+
+    let window = Window {
+        depth: 24, wid: 0x400001, parent: 0x100, x: 0, y: 0, width: 100, height: 40, border_width: 0,
+        class: WindowInputType::InputOutput, visual_id: 0x21, values: vec![]
+    };
+    let mut segment = ShmSegment::create_sysv(&mut client, 16 * 8 * 4, false).unwrap();
+    assert!(window.shm_get_image_sync(&mut client, &mut segment, 0, 0, 0, 16, 8));
+    handle.join().unwrap();
+}
+
+#[test]
+fn sysv_segment_fails_when_server_cannot_attach() {
+    let (mut client, mut server) = FakeServer::connect();
+    let handle = std::thread::spawn(move || {
+        server.answer_query_extension("MIT-SHM", 130, 65, 128);
+        let request = server.read_request();
+        let shmid = i32::from_le_bytes([request[8], request[9], request[10], request[11]]);
+        let seq = server.sequence.to_le_bytes();
+        let mut error = vec![0, 10, seq[0], seq[1], 0, 0, 0, 0, shm::OP_ATTACH, 0, 130]; // BadAccess
+        error.resize(32, 0);
+        server.send(&error);
+
+        assert_eq!(server.read_request()[0], 43); // GetInputFocus
+        server.send_reply(0, &[0x01, 0, 0, 0]);
+        (shmid, server)
+    });
+
+    assert!(ShmSegment::create_sysv(&mut client, 1024, false).is_err());
+    let (shmid, _server) = handle.join().unwrap();
+    let mut stat: libc::shmid_ds = unsafe { mem::zeroed() };
+    assert_eq!(unsafe { libc::shmctl(shmid, libc::IPC_STAT, &mut stat) }, -1);
+}
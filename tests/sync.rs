@@ -0,0 +1,74 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::XClient;
+use xrb::extensions::sync::{self, AlarmValue, AlarmState, SyncEvent, TestType};
+use xrb::models::*;
+use common::FakeServer;
+
+fn connect() -> (XClient, FakeServer) {
+    let (client, server, _) = FakeServer::connect_with_extension("SYNC", 134, 83, 134, &[134, sync::OP_INITIALIZE, 2, 0, 3, 1], &[3, 1], |client| client.sync_initialize(3, 1));
+    (client, server)
+}
+
+#[test]
+fn idle_counter_and_value() {
+    let (mut client, mut server) = connect();
+    let handle = std::thread::spawn(move || {
+        assert_eq!(server.read_request(), vec![134, sync::OP_LIST_SYSTEM_COUNTERS, 1, 0]);
+        let mut reply = vec![1, 0, 3, 0, 12, 0, 0, 0, 2, 0, 0, 0];
+        reply.resize(32, 0);
+        reply.extend_from_slice(&[0x10, 0, 0x20, 0, 0, 0, 0, 0, 1, 0, 0, 0, 10, 0]);
+        reply.extend_from_slice(b"SERVERTIME\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0");
+        reply.truncate(32 + 24);
+        reply.extend_from_slice(&[0x11, 0, 0x20, 0, 0, 0, 0, 0, 1, 0, 0, 0, 8, 0]);
+        reply.extend_from_slice(b"IDLETIME\0\0");
+        assert_eq!(reply.len(), 32 + 12 * 4);
+        server.send(&reply);
+
+        assert_eq!(server.read_request(), vec![134, sync::OP_QUERY_COUNTER, 2, 0, 0x11, 0, 0x20, 0]);
+        let mut reply = vec![1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0x10, 0x27, 0, 0];
+        reply.resize(32, 0);
+        server.send(&reply);
+    });
+
+    let counter = client.sync_system_counter_sync(sync::IDLETIME).unwrap();
+    assert_eq!(counter, 0x200011);
+    assert_eq!(client.sync_query_counter_sync(counter), Some(0x1_0000_2710));
+    handle.join().unwrap();
+}
+
+#[test]
+fn create_alarm_writes_values() {
+    let (mut client, mut server) = connect();
+    client.sync_create_alarm(0x200020, &vec![
+        AlarmValue::Events(true),
+        AlarmValue::Value(-2),
+        AlarmValue::TestType(TestType::PositiveComparison),
+        AlarmValue::Counter(0x200011)
+    ]);
+
+    assert_eq!(server.read_request(), vec![
+        134, sync::OP_CREATE_ALARM, 8, 0, 0x20, 0, 0x20, 0, 0x2D, 0, 0, 0,
+        0x11, 0, 0x20, 0, // Counter
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0xFF, 0xFF, 0xFF, // Value
+        2, 0, 0, 0, // TestType
+        1, 0, 0, 0 // Events
+    ]);
+}
+
+#[test]
+fn alarm_notify_is_decoded() {
+    let (mut client, mut server) = connect();
+    let mut event = vec![84, 0, 3, 0, 0x20, 0, 0x20, 0, 0, 0, 0, 0, 0xB8, 0x0B, 0, 0, 0, 0, 0, 0, 0xB8, 0x0B, 0, 0, 0x30, 0, 0, 0, 0];
+    event.resize(32, 0);
+    server.send(&event);
+
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::Sync(SyncEvent::AlarmNotify { alarm, counter_value, alarm_value, timestamp, state, .. }), _, _) => {
+            assert_eq!((alarm, counter_value, alarm_value, timestamp, state), (0x200020, 3000, 3000, 0x30, AlarmState::Active));
+        },
+        other => panic!("Expected an AlarmNotify, got {:?}", other)
+    };
+}
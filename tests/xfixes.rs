@@ -0,0 +1,79 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::XClient;
+use xrb::extensions::shape::{self, Sk};
+use xrb::extensions::xfixes::{self, SelectionEvent, SelectionEventMask, ServerRegion, XFixesEvent};
+use xrb::models::*;
+use common::FakeServer;
+
+fn connect() -> (XClient, FakeServer) {
+    let (client, server, _) = FakeServer::connect_with_extension("XFIXES", 138, 87, 140, &[138, xfixes::OP_QUERY_VERSION, 3, 0, 5, 0, 0, 0, 0, 0, 0, 0], &[5, 0, 0, 0, 0, 0, 0, 0], |client| client.xfixes_query_version(5, 0));
+    (client, server)
+}
+
+#[test]
+fn selection_notify_is_decoded() {
+    let (mut client, mut server) = connect();
+    let mask = SelectionEventMask::SetSelectionOwner.val() | SelectionEventMask::SelectionClientClose.val();
+    client.xfixes_select_selection_input(0x100, 0x45, mask);
+    assert_eq!(server.read_request(), vec![138, xfixes::OP_SELECT_SELECTION_INPUT, 4, 0, 0, 1, 0, 0, 0x45, 0, 0, 0, 5, 0, 0, 0]);
+
+    let mut event = vec![87, 2, 3, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0x45, 0, 0, 0, 0x10, 0, 0, 0, 0x08, 0, 0, 0];
+    event.resize(32, 0);
+    server.send(&event);
+
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::XFixes(XFixesEvent::SelectionNotify { subtype, window, owner, selection, timestamp, selection_timestamp }), _, _) => {
+            assert_eq!(subtype, SelectionEvent::SelectionClientClose);
+            assert_eq!((window, owner, selection, timestamp, selection_timestamp), (0x100, 0, 0x45, 0x10, 0x08));
+        },
+        other => panic!("Expected a SelectionNotify, got {:?}", other)
+    };
+}
+
+#[test]
+fn cursor_image_is_read() {
+    let (mut client, mut server) = connect();
+    let handle = std::thread::spawn(move || {
+        assert_eq!(server.read_request(), vec![138, xfixes::OP_GET_CURSOR_IMAGE, 1, 0]);
+        let mut reply = vec![1, 0, 3, 0, 2, 0, 0, 0, 10, 0, 20, 0, 2, 0, 1, 0, 1, 0, 0, 0, 7, 0, 0, 0];
+        reply.resize(32, 0);
+        reply.extend_from_slice(&[0xFF, 0, 0, 0xFF, 0, 0, 0, 0]);
+        server.send(&reply);
+    });
+
+    let image = client.xfixes_get_cursor_image_sync().unwrap();
+    handle.join().unwrap();
+    assert_eq!((image.x, image.y, image.width, image.height, image.xhot, image.yhot), (10, 20, 2, 1, 1, 0));
+    assert_eq!(image.cursor_serial, 7);
+    assert_eq!(image.pixels, vec![0xFF0000FF, 0]);
+}
+
+#[test]
+fn regions_use_resource_ids() {
+    let (mut client, mut server) = connect();
+    let first = client.new_resource_id();
+    let region = ServerRegion::create(&mut client, &[Rectangle { x: 0, y: 0, width: 10, height: 10 }]).unwrap();
+    let next = client.new_resource_id();
+    assert!(region.rid != first && region.rid != next);
+
+    let window = Window {
+        depth: 24, wid: 0x400001, parent: 0x100, x: 0, y: 0, width: 100, height: 40, border_width: 0,
+        class: WindowInputType::InputOutput, visual_id: 0x21, values: vec![]
+    };
+    window.set_shape_region(&mut client, &Sk::Input, Some(&region));
+    window.set_shape_region(&mut client, &Sk::Input, None);
+
+    let rid = region.rid.to_le_bytes();
+    let request = server.read_request();
+    assert_eq!(request[..4], [138, xfixes::OP_CREATE_REGION, 4, 0]);
+    assert_eq!(request[4..8], rid);
+    assert_eq!(request[8..], [0, 0, 0, 0, 10, 0, 10, 0]);
+
+    let request = server.read_request();
+    assert_eq!(request[..9], [138, xfixes::OP_SET_WINDOW_SHAPE_REGION, 5, 0, 0x01, 0, 0x40, 0, shape::Sk::Input.val() as u8]);
+    assert_eq!(request[12..], [0, 0, 0, 0, rid[0], rid[1], rid[2], rid[3]]);
+    assert_eq!(server.read_request()[16..], [0, 0, 0, 0]);
+}
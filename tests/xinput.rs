@@ -0,0 +1,103 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::extensions::xinput::{self, Device, DeviceClass, DeviceType, EventMask, XIEventMask, XInputEvent, XInputReply};
+use xrb::models::*;
+use common::FakeServer;
+
+#[test]
+fn select_events_writes_masks() {
+    let (mut client, handle) = FakeServer::connect_with(|server| server.answer_query_extension("XInputExtension", 130, 66, 129));
+    client.xinput_xi_select_events(0x100, &vec![EventMask::new(Device::AllMaster.val() as u16, &[XIEventMask::Motion, XIEventMask::RawMotion])]);
+    let mut server = handle.join().unwrap();
+
+    let request = server.read_request();
+    assert_eq!(&request[..10], &[130, xinput::OP_XI_SELECT_EVENTS, 5, 0, 0x00, 0x01, 0, 0, 1, 0]);
+    assert_eq!(&request[12..], &[1, 0, 1, 0, 0x40, 0, 0x02, 0]);
+}
+
+#[test]
+fn query_device_reads_classes() {
+    let (mut client, handle) = FakeServer::connect_with(|server| {
+        server.answer_query_extension("XInputExtension", 130, 66, 129);
+
+        let request = server.read_request();
+        assert_eq!(&request[..6], &[130, xinput::OP_XI_QUERY_DEVICE, 2, 0, 1, 0]);
+
+        let mut reply = vec![1, 0, 2, 0, 18, 0, 0, 0, 1, 0];
+        reply.resize(32, 0);
+        reply.extend_from_slice(&[2, 0, 1, 0, 3, 0, 2, 0, 5, 0, 1, 0]);
+        reply.extend_from_slice(b"Mouse\0\0\0");
+        // Valuator 0, from 0 to 1919, currently at 960.5
+        reply.extend_from_slice(&[2, 0, 11, 0, 2, 0, 0, 0, 0x55, 0, 0, 0]);
+        reply.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 0]);
+        reply.extend_from_slice(&[0x7F, 0x07, 0, 0, 0, 0, 0, 0]);
+        reply.extend_from_slice(&[0xC0, 0x03, 0, 0, 0, 0, 0, 0x80]);
+        reply.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0]);
+        // A class from a newer version of XI, which is skipped
+        reply.extend_from_slice(&[9, 0, 2, 0, 2, 0, 0, 0]);
+        assert_eq!(reply.len(), 32 + 18 * 4);
+        server.send(&reply);
+    });
+
+    let infos = client.xinput_xi_query_device_sync(Device::AllMaster.val() as u16);
+    handle.join().unwrap();
+
+    assert_eq!(infos.len(), 1);
+    let info = &infos[0];
+    assert_eq!((info.deviceid, info.kind, info.attachment, info.enabled), (2, DeviceType::MasterPointer, 3, true));
+    assert_eq!(info.name, "Mouse");
+    match info.classes[0] {
+        DeviceClass::Valuator(ref valuator) => {
+            assert_eq!((valuator.number, valuator.label), (0, 0x55));
+            assert_eq!((valuator.min, valuator.max, valuator.value), (0.0, 1919.0, 960.5));
+        },
+        ref other => panic!("Expected a valuator class, got {:?}", other)
+    };
+    match info.classes[1] {
+        DeviceClass::Unknown { kind, sourceid } => assert_eq!((kind, sourceid), (9, 2)),
+        ref other => panic!("Expected an unknown class, got {:?}", other)
+    };
+}
+
+#[test]
+fn motion_converts_fixed_point() {
+    let (mut client, handle) = FakeServer::connect_with(|server| {
+        server.answer_query_extension("XInputExtension", 130, 66, 129);
+
+        let request = server.read_request();
+        assert_eq!(request, vec![130, xinput::OP_XI_QUERY_VERSION, 2, 0, 2, 0, 3, 0]);
+        let mut reply = vec![1, 0, 2, 0, 0, 0, 0, 0, 2, 0, 3, 0];
+        reply.resize(32, 0);
+        server.send(&reply);
+    });
+    let seq = client.xinput_xi_query_version(2, 3).unwrap();
+    match client.wait_for_response(seq) {
+        ServerResponse::Reply(ServerReply::XInput(XInputReply::XIQueryVersion { major_version, minor_version }), _) => {
+            assert_eq!((major_version, minor_version), (2, 3));
+        },
+        other => panic!("Expected an XIQueryVersion reply, got {:?}", other)
+    };
+    let mut server = handle.join().unwrap();
+
+    let mut event = vec![35, 130, 3, 0, 18, 0, 0, 0, 6, 0, 2, 0, 0x10, 0, 0, 0];
+    event.extend_from_slice(&[0, 0, 0, 0, 0x00, 0x01, 0, 0, 0x00, 0x01, 0, 0, 0, 0, 0, 0]);
+    event.extend_from_slice(&[0x00, 0x80, 0x64, 0x00, 0, 0, 0x0A, 0, 0x00, 0xC0, 0xFD, 0xFF, 0, 0, 0, 0]); // 100.5, 10, -2.25, 0
+    event.extend_from_slice(&[1, 0, 1, 0, 4, 0, 0, 0, 0, 0, 0, 0]);
+    event.resize(80, 0); // Modifiers and group
+    event.extend_from_slice(&[0, 0, 0, 0, 0x05, 0, 0, 0]);
+    event.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0x80]); // 1.5
+    event.extend_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0xC0]); // -0.25
+    assert_eq!(event.len(), 32 + 18 * 4);
+    server.send(&event);
+
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::XInput(XInputEvent::Motion { deviceid, sourceid, root_x, root_y, event_x, event_y, valuator_mask, axisvalues, .. }), _, _) => {
+            assert_eq!((deviceid, sourceid), (2, 4));
+            assert_eq!((root_x, root_y, event_x, event_y), (100.5, 10.0, -2.25, 0.0));
+            assert_eq!(xinput::axis_values(&valuator_mask, &axisvalues), vec![(0, 1.5), (2, -0.25)]);
+        },
+        other => panic!("Expected a Motion event, got {:?}", other)
+    };
+}
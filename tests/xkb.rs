@@ -0,0 +1,118 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::extensions::xkb::{self, Group, Keymap, SelectEventsDetail, XkbEvent};
+use xrb::keysym;
+use xrb::models::*;
+use common::FakeServer;
+
+/** Answers QueryExtension for XKEYBOARD and then UseExtension */
+fn answer_use_extension(server: &mut FakeServer) {
+    server.answer_query_extension("XKEYBOARD", 130, 90, 150);
+    assert_eq!(server.read_request(), vec![130, xkb::OP_USE_EXTENSION, 2, 0, 1, 0, 0, 0]);
+    server.send_reply(1, &[1, 0, 0, 0]);
+}
+
+/**
+ * A GetMap reply with two key types and two keys:
+ *   - ONE_LEVEL, and ALPHABETIC (Shift or Lock go to level 2)
+ *   - Key 38 is ALPHABETIC with two groups: a A, Cyrillic_ef Cyrillic_EF
+ *   - Key 39 is ONE_LEVEL with one group: b
+ */
+fn get_map_reply() -> Vec<u8> {
+    let mut reply = vec![1, 3, 3, 0, 19, 0, 0, 0];
+    reply.extend_from_slice(&[0, 0, 8, 255, 0x03, 0x00, 0, 2, 2, 38, 5, 0, 2, 0, 0, 0]);
+    reply.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    // ONE_LEVEL
+    reply.extend_from_slice(&[0, 0, 0, 0, 1, 0, 0, 0]);
+    // ALPHABETIC
+    reply.extend_from_slice(&[0x03, 0x03, 0, 0, 2, 2, 0, 0]);
+    reply.extend_from_slice(&[1, 0x01, 1, 0x01, 0, 0, 0, 0]);
+    reply.extend_from_slice(&[1, 0x02, 1, 0x02, 0, 0, 0, 0]);
+    // Key 38
+    reply.extend_from_slice(&[1, 1, 0, 0, 2, 2, 4, 0]);
+    reply.extend_from_slice(&[0x61, 0, 0, 0, 0x41, 0, 0, 0, 0xC6, 0x06, 0, 0, 0xE6, 0x06, 0, 0]);
+    // Key 39
+    reply.extend_from_slice(&[0, 0, 0, 0, 1, 1, 1, 0]);
+    reply.extend_from_slice(&[0x62, 0, 0, 0]);
+    assert_eq!(reply.len(), 32 + 19 * 4);
+    reply
+}
+
+#[test]
+fn keymap_follows_groups_and_levels() {
+    let (mut client, handle) = FakeServer::connect_with(|server| {
+        answer_use_extension(server);
+
+        let request = server.read_request();
+        assert_eq!(&request[..8], &[130, xkb::OP_GET_MAP, 7, 0, 0x00, 0x01, 0x03, 0x00]);
+        server.send(&get_map_reply());
+    });
+
+    let seq = client.xkb_use_extension(1, 0).unwrap();
+    client.wait_for_response(seq);
+    let keymap: Keymap = client.xkb_get_keymap_sync(0x100).unwrap();
+    handle.join().unwrap();
+
+    assert_eq!(keymap.keysym(38, &[]), 0x61);
+    assert_eq!(keymap.keysym(38, &[KeyButton::Shift]), 0x41);
+    assert_eq!(keymap.keysym(38, &[KeyButton::Lock]), 0x41);
+    assert_eq!(keymap.keysym(38, &[KeyButton::Shift, KeyButton::Lock]), 0x61);
+    assert_eq!(keymap.utf8(38, &[KeyButton::Group(1)]), "ф");
+    assert_eq!(keymap.utf8(38, &[KeyButton::Group(1), KeyButton::Shift]), "Ф");
+    assert_eq!(keymap.utf8(38, &[KeyButton::Group(2)]), "a"); // Wraps back to the first group
+    assert_eq!(keymap.utf8(38, &[KeyButton::Control]), "\u{1}");
+
+    // ONE_LEVEL doesn't use Lock, so Lock makes it upper case
+    assert_eq!(keymap.utf8(39, &[KeyButton::Lock]), "B");
+    assert_eq!(keymap.utf8(39, &[KeyButton::Shift, KeyButton::Group(1)]), "b");
+
+    assert_eq!(keymap.keysym(100, &[]), keysym::NO_SYMBOL);
+    assert_eq!(keymap.utf8(100, &[]), "");
+}
+
+#[test]
+fn state_notify_is_decoded() {
+    let (mut client, handle) = FakeServer::connect_with(answer_use_extension);
+    let seq = client.xkb_use_extension(1, 0).unwrap();
+    client.wait_for_response(seq);
+    let mut server = handle.join().unwrap();
+
+    let mut event = vec![90, xkb::EVENT_STATE_NOTIFY, 2, 0, 0x10, 0, 0, 0, 3, 0x01, 0x01, 0, 0, 1, 0, 0, 0, 0, 1];
+    event.resize(32, 0);
+    server.send(&event);
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::Xkb(XkbEvent::StateNotify { time, device_id, mods, group, locked_group, .. }), _, _) => {
+            assert_eq!((time, device_id, mods), (0x10, 3, 0x01));
+            assert_eq!((group, locked_group), (Group::N2, Group::N2));
+        },
+        other => panic!("Expected a StateNotify, got {:?}", other)
+    };
+}
+
+#[test]
+fn select_events_writes_details() {
+    let (mut client, handle) = FakeServer::connect_with(answer_use_extension);
+    let seq = client.xkb_use_extension(1, 0).unwrap();
+    client.wait_for_response(seq);
+    let mut server = handle.join().unwrap();
+
+    client.xkb_select_events(0x100, 0x0005, 0, 0x0001, 0x00FF, 0x00FF, &vec![SelectEventsDetail::StateNotify { affect: 0xFFFF, details: 0x0001 }]);
+    assert_eq!(server.read_request(), vec![
+        130, xkb::OP_SELECT_EVENTS, 5, 0, 0x00, 0x01, 0x05, 0x00, 0, 0, 0x01, 0, 0xFF, 0, 0xFF, 0, 0xFF, 0xFF, 0x01, 0
+    ]);
+}
+
+#[test]
+fn keysyms_and_characters() {
+    assert_eq!(keysym::to_char(0x41), Some('A'));
+    assert_eq!(keysym::to_char(0x6C1), Some('а'));
+    assert_eq!(keysym::to_char(0x10020AC), Some('€'));
+    assert_eq!(keysym::to_char(keysym::RETURN), Some('\r'));
+    assert_eq!(keysym::to_char(keysym::SHIFT_L), None);
+    assert_eq!(keysym::from_char('а'), 0x6C1);
+    assert_eq!(keysym::from_char('😀'), 0x0101F600);
+    assert_eq!(keysym::to_upper(0x6C1), 0x6E1);
+    assert_eq!(keysym::to_lower(0x41), 0x61);
+}
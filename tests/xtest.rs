@@ -0,0 +1,120 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::extensions::xtest;
+use common::FakeServer;
+
+const SHIFT: u8 = 50;
+
+/** Answers GetKeyboardMapping for keycodes 8 to 255, with two keysyms each and only 254 and 255 unused */
+fn reply_keyboard_mapping(server: &mut FakeServer) {
+    let request = server.read_request();
+    assert_eq!((request[0], request[4], request[5]), (101, 8, 248));
+    let mut mapping = vec![];
+    for keycode in 8u32..256 {
+        let keysyms = match keycode {
+            10 => [0x68, 0x48], // h H
+            11 => [0x31, 0x21], // 1 !
+            12 => [0x69, 0], // i, which also types I with Shift
+            23 => [0xFF09, 0], // Tab
+            36 => [0xFF0D, 0], // Return
+            50 => [0xFFE1, 0], // Shift_L
+            254 | 255 => [0, 0],
+            _ => [0x10000000 + keycode, 0]
+        };
+        mapping.extend_from_slice(&keysyms);
+    }
+
+    let len = mapping.len() as u32;
+    let mut reply = vec![1, 2, 1, 0];
+    reply.extend_from_slice(&len.to_le_bytes());
+    reply.resize(32, 0);
+    for keysym in mapping {
+        reply.extend_from_slice(&keysym.to_le_bytes());
+    }
+    server.send(&reply);
+}
+
+/** A request, as (XTEST event type, keycode, 0) for FakeInput or (0, keycode, keysym) for ChangeKeyboardMapping */
+fn read_input(server: &mut FakeServer) -> (u8, u8, u32) {
+    let request = server.read_request();
+    match request[0] {
+        132 => {
+            assert_eq!(request[..4], [132, xtest::OP_FAKE_INPUT, 9, 0]);
+            (request[4], request[5], 0)
+        },
+        100 => {
+            assert_eq!(request[1..6], [1, 4, 0, request[4], 2]);
+            assert_eq!(request[8..12], request[12..16]);
+            (0, request[4], u32::from_le_bytes([request[8], request[9], request[10], request[11]]))
+        },
+        other => panic!("Unexpected request {}", other)
+    }
+}
+
+fn tap(keycode: u8) -> Vec<(u8, u8, u32)> {
+    vec![(2, keycode, 0), (3, keycode, 0)]
+}
+
+fn shifted(keycode: u8) -> Vec<(u8, u8, u32)> {
+    vec![(2, SHIFT, 0), (2, keycode, 0), (3, keycode, 0), (3, SHIFT, 0)]
+}
+
+#[test]
+fn type_text_uses_shift_and_spare_keycodes() {
+    let (mut client, handle) = FakeServer::connect_with(|server| {
+        reply_keyboard_mapping(server);
+        server.answer_query_extension("XTEST", 132, 0, 0);
+
+        let mut expected = vec![];
+        expected.extend(shifted(10)); // H
+        expected.extend(tap(12)); // i
+        expected.extend(shifted(11)); // !
+        expected.extend(tap(23)); // \t
+        expected.push((0, 255, 0xE9)); // é isn't on the keyboard
+        expected.extend(tap(255));
+        expected.extend(shifted(12)); // I
+        expected.push((0, 254, 0x20AC)); // Neither is €
+        expected.extend(tap(254));
+        expected.extend(tap(255)); // é is still mapped
+        expected.extend(tap(36)); // \n
+        for input in expected {
+            assert_eq!(read_input(server), input);
+        }
+
+        // The key presses are handled before their keycodes are put back
+        let request = server.read_request();
+        assert_eq!((request[0], request.len()), (43, 4)); // GetInputFocus
+        server.send_reply(0, &[0x01, 0, 0, 0]);
+        assert_eq!(read_input(server), (0, 255, 0));
+        assert_eq!(read_input(server), (0, 254, 0));
+    });
+
+    assert!(client.type_text("Hi!\téI€é\n"));
+    handle.join().unwrap();
+}
+
+#[test]
+fn fake_pointer_input() {
+    let (mut client, handle) = FakeServer::connect_with(|server| {
+        server.answer_query_extension("XTEST", 132, 0, 0);
+        let request = server.read_request();
+        assert_eq!(request[..6], [132, xtest::OP_FAKE_INPUT, 9, 0, 6, 0]);
+        assert_eq!(request[12..16], [0, 0, 0, 0]);
+        assert_eq!(request[24..28], [100, 0, 200, 0]);
+
+        let request = server.read_request();
+        assert_eq!(request[4..6], [6, 1]);
+        assert_eq!(request[24..28], [0xFB, 0xFF, 10, 0]);
+
+        assert_eq!(server.read_request()[4..6], [4, 3]);
+        assert_eq!(server.read_request()[4..6], [5, 3]);
+    });
+
+    client.xtest_fake_motion(100, 200);
+    client.xtest_fake_relative_motion(-5, 10);
+    client.xtest_fake_button(3, true);
+    client.xtest_fake_button(3, false);
+    handle.join().unwrap();
+}
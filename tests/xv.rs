@@ -0,0 +1,110 @@
+extern crate xrb;
+
+mod common;
+
+use xrb::XClient;
+use xrb::extensions::xv::{self, XvEvent, XvReply};
+use xrb::models::*;
+use common::FakeServer;
+
+fn connect() -> (XClient, FakeServer) {
+    let (client, server, response) = FakeServer::connect_with_extension("XVideo", 152, 94, 156, &[152, xv::OP_QUERY_EXTENSION, 1, 0], &[2, 0, 2, 0], |client| client.xv_query_extension());
+    match response {
+        ServerResponse::Reply(ServerReply::Xv(XvReply::QueryExtension { major: 2, minor: 2 }), 2) => (),
+        other => panic!("Wrong response: {:?}", other)
+    }
+    (client, server)
+}
+
+#[test]
+fn fourcc() {
+    assert_eq!(xv::fourcc(b"YV12"), xv::FOURCC_YV12);
+    assert_eq!(xv::fourcc(b"I420"), xv::FOURCC_I420);
+    assert_eq!(xv::fourcc(b"YUY2"), xv::FOURCC_YUY2);
+    assert_eq!(xv::fourcc(b"UYVY"), xv::FOURCC_UYVY);
+}
+
+#[test]
+fn image_requests() {
+    let (mut client, mut server) = connect();
+    client.xv_put_image(0x51, 0x200000, 0x200001, xv::FOURCC_YV12, 0, 0, 2, 2, 10, 20, 4, 4, 2, 2, &[1, 2, 3, 4, 5, 6]);
+    client.xv_shm_put_image(0x51, 0x200000, 0x200001, 0x400000, xv::FOURCC_YV12, 64, 0, 0, 2, 2, 10, 20, 4, 4, 2, 2, 1);
+    client.xv_set_port_attribute(0x51, 0x90, -5);
+    client.xv_ungrab_port(0x51, 0);
+
+    let request = server.read_request();
+    assert_eq!(request.len(), 48);
+    assert_eq!(request[..46], [
+        152, xv::OP_PUT_IMAGE, 12, 0, 0x51, 0, 0, 0, 0, 0, 0x20, 0, 1, 0, 0x20, 0, 0x59, 0x56, 0x31, 0x32,
+        0, 0, 0, 0, 2, 0, 2, 0, 10, 0, 20, 0, 4, 0, 4, 0, 2, 0, 2, 0, 1, 2, 3, 4, 5, 6
+    ]);
+    let request = server.read_request();
+    assert_eq!(request[..49], [
+        152, xv::OP_SHM_PUT_IMAGE, 13, 0, 0x51, 0, 0, 0, 0, 0, 0x20, 0, 1, 0, 0x20, 0, 0, 0, 0x40, 0,
+        0x59, 0x56, 0x31, 0x32, 64, 0, 0, 0, 0, 0, 0, 0, 2, 0, 2, 0, 10, 0, 20, 0, 4, 0, 4, 0, 2, 0, 2, 0, 1
+    ]);
+    assert_eq!(server.read_request(), vec![152, xv::OP_SET_PORT_ATTRIBUTE, 4, 0, 0x51, 0, 0, 0, 0x90, 0, 0, 0, 0xFB, 0xFF, 0xFF, 0xFF]);
+    assert_eq!(server.read_request(), vec![152, xv::OP_UNGRAB_PORT, 3, 0, 0x51, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn grab_image_port() {
+    let (mut client, mut server) = connect();
+    let handle = std::thread::spawn(move || {
+        assert_eq!(server.read_request(), vec![152, xv::OP_QUERY_ADAPTORS, 2, 0, 0, 0, 0x20, 0]);
+        // One adaptor "Tex" with ports 0x50 and 0x51, that takes images
+        let mut reply = vec![1, 0, 3, 0, 6, 0, 0, 0, 1, 0];
+        reply.resize(32, 0);
+        reply.extend_from_slice(&[0x50, 0, 0, 0, 3, 0, 2, 0, 1, 0, 0x11, 0, b'T', b'e', b'x', 0, 0x21, 0, 0, 0, 24, 0, 0, 0]);
+        server.send(&reply);
+
+        assert_eq!(server.read_request(), vec![152, xv::OP_LIST_IMAGE_FORMATS, 2, 0, 0x50, 0, 0, 0]);
+        let mut reply = vec![1, 0, 4, 0, 32, 0, 0, 0, 1, 0, 0, 0];
+        reply.resize(32, 0);
+        let mut format = vec![0x59, 0x56, 0x31, 0x32, 1, 0];
+        format.resize(128, 0);
+        reply.extend_from_slice(&format);
+        server.send(&reply);
+
+        // The first port is taken
+        assert_eq!(server.read_request(), vec![152, xv::OP_GRAB_PORT, 3, 0, 0x50, 0, 0, 0, 0, 0, 0, 0]);
+        let mut reply = vec![1, 2, 5, 0];
+        reply.resize(32, 0);
+        server.send(&reply);
+        assert_eq!(server.read_request(), vec![152, xv::OP_GRAB_PORT, 3, 0, 0x51, 0, 0, 0, 0, 0, 0, 0]);
+        let mut reply = vec![1, 0, 6, 0];
+        reply.resize(32, 0);
+        server.send(&reply);
+        server
+    });
+
+    assert_eq!(client.xv_grab_image_port_sync(0x200000, xv::FOURCC_YV12), Some(0x51));
+    handle.join().unwrap();
+}
+
+#[test]
+fn image_attributes_and_port_notify() {
+    let (mut client, mut server) = connect();
+    let seq = client.xv_query_image_attributes(0x51, xv::FOURCC_YV12, 4, 4).unwrap();
+    assert_eq!(server.read_request(), vec![152, xv::OP_QUERY_IMAGE_ATTRIBUTES, 4, 0, 0x51, 0, 0, 0, 0x59, 0x56, 0x31, 0x32, 4, 0, 4, 0]);
+    let mut reply = vec![1, 0, 3, 0, 6, 0, 0, 0, 3, 0, 0, 0, 24, 0, 0, 0, 4, 0, 4, 0];
+    reply.resize(32, 0);
+    reply.extend_from_slice(&[4, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 20, 0, 0, 0]);
+    server.send(&reply);
+    server.send(&[
+        95, 0, 3, 0, 0x10, 0, 0, 0, 0x51, 0, 0, 0, 0x90, 0, 0, 0,
+        0xFB, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0
+    ]);
+
+    match client.wait_for_response(seq) {
+        ServerResponse::Reply(ServerReply::Xv(XvReply::QueryImageAttributes { data_size: 24, width: 4, height: 4, pitches, offsets }), 3) => {
+            assert_eq!(pitches, vec![4, 2, 2]);
+            assert_eq!(offsets, vec![0, 16, 20]);
+        },
+        other => panic!("Wrong response: {:?}", other)
+    }
+    match client.wait_for_message() {
+        ServerResponse::Event(ServerEvent::Xv(XvEvent::PortNotify { time: 0x10, port: 0x51, attribute: 0x90, value: -5 }), 3, _) => (),
+        other => panic!("Wrong event: {:?}", other)
+    }
+}
@@ -0,0 +1,1385 @@
+// Turns xcb-proto modules into Rust, in the same style as the hand written core protocol in src/lib.rs and src/models.rs.
+//
+// Each extension becomes one module in src/extensions with:
+//   - EXTENSION_NAME, OP_*, EVENT_*, GENERIC_EVENT_*, and ERROR_* constants
+//   - Enums with get/val, and structs with read/write
+//   - `impl XClient` with one method per request (ie `client.damage_create(...)`)
+//   - <Ext>Reply and <Ext>Event enums, wrapped in ServerReply::<Ext> and ServerEvent::<Ext>
+//   - A unit struct implementing Extension, registered the first time a request is sent
+//
+// Anything the generator can't handle (ie <switch> in replies, unions, file descriptors) is skipped and listed
+// at the top of the generated file, so it can be written by hand in src/extensions/<header>_extra.rs.
+//
+// Only extensions are generated. The core protocol stays written by hand in src/lib.rs and src/models.rs, since its API
+// is built around models (Window, GraphicsContext, ...) the XML doesn't describe. xproto.xml is only read for the types
+// extensions import from it. Extension errors all have the core error layout, so they are read into
+// ServerError::Extension by ExtensionRegistry::read_error, and only their ERROR_* codes and names are generated.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use crate::proto::{self, BitCase, EnumValue, Expr, Item, Module};
+use crate::xml;
+
+/** Extensions that send all of their events with one code, and put the event type in the second byte */
+const SUBTYPED_EVENTS: &[&str] = &["xkb"];
+
+/** Names that read better than the extension-name in the XML */
+const EXTENSION_NAMES: &[(&str, &str)] = &[("Input", "XInput"), ("Test", "XTest"), ("Res", "XRes"), ("xkb", "Xkb")];
+
+/** xproto structs that already exist in models.rs */
+const MODELS_STRUCTS: &[(&str, &str)] = &[("RECTANGLE", "Rectangle"), ("POINT", "Point"), ("ARC", "Arc")];
+
+/**
+ * Types that are one number in Rust, but not a plain integer on the wire: (module, type, Rust type, method, size).
+ * They are read and written with XBufferedReader::read_<method> and XBufferedWriter::write_<method>.
+ */
+const WIRE_TYPES: &[(&str, &str, &str, &str, usize)] = &[
+    ("xinput", "FP1616", "f64", "fp1616", 4),
+    ("xinput", "FP3232", "f64", "fp3232", 8),
+    ("sync", "INT64", "i64", "hi_lo_i64", 8),
+    ("render", "FIXED", "f64", "fp1616", 4)
+];
+
+const KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop",
+    "match", "mod", "move", "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true", "unsafe", "use",
+    "where", "while", "dyn", "abstract", "become", "box", "do", "final", "macro", "override", "priv", "typeof", "unsized", "virtual",
+    "yield", "async", "await", "try"
+];
+
+const PRELUDE_TYPES: &[&str] = &["Option", "Result", "Box", "Vec", "String", "Some", "None", "Ok", "Err"];
+
+/** Converts XML names like "GetXIDRange" or "xkbType" to snake case */
+pub fn snake(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_lower) {
+                out.push('_');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+
+    out.replace("__", "_")
+}
+
+/** Converts XML names like "ModeInfo", "SYSTEMCOUNTER", or "Rotate_0" to camel case */
+pub fn camel(name: &str) -> String {
+    let mut out = String::new();
+
+    for part in name.split(['_', '-']).filter(|p| !p.is_empty()) {
+        let shouting = !part.chars().any(|c| c.is_lowercase());
+        for (i, c) in part.chars().enumerate() {
+            if i == 0 {
+                out.extend(c.to_uppercase());
+            } else if shouting {
+                out.extend(c.to_lowercase());
+            } else {
+                out.push(c);
+            }
+        }
+    }
+
+    if out.starts_with(|c: char| c.is_ascii_digit()) {
+        out.insert(0, 'N');
+    }
+    out
+}
+
+/**
+ * Type names the XML runs together in capitals, so camel can't find the words, or that carry a protocol version:
+ * (XML name, Rust name)
+ */
+const TYPE_NAMES: &[(&str, &str)] = &[
+    ("ALARMSTATE", "AlarmState"), ("ANIMCURSORELT", "AnimCursorElt"), ("DIRECTFORMAT", "DirectFormat"), ("GLYPHINFO", "GlyphInfo"),
+    ("INDEXVALUE", "IndexValue"), ("LINEFIX", "LineFix"), ("PICTDEPTH", "PictDepth"), ("PICTFORMINFO", "PictFormInfo"),
+    ("PICTSCREEN", "PictScreen"), ("PICTVISUAL", "PictVisual"), ("POINTFIX", "PointFix"), ("SPANFIX", "SpanFix"),
+    ("SYSTEMCOUNTER", "SystemCounter"), ("TESTTYPE", "TestType"), ("VALUETYPE", "ValueType"), ("WAITCONDITION", "WaitCondition"),
+    ("GrabMode22", "GrabMode")
+];
+
+/** Converts an XML type name to a Rust one. Names that would hide a std prelude type get an s (ie Present's Option mask). */
+pub fn type_name(name: &str) -> String {
+    if let Some(&(_, rust)) = TYPE_NAMES.iter().find(|&&(xml, _)| xml == name) {
+        return String::from(rust);
+    }
+    let name = camel(name);
+    if PRELUDE_TYPES.contains(&name.as_str()) {
+        format!("{}s", name)
+    } else {
+        name
+    }
+}
+
+fn field_name(name: &str) -> String {
+    let name = snake(name);
+    if name == "type" {
+        String::from("kind")
+    } else if KEYWORDS.contains(&name.as_str()) {
+        format!("{}_", name)
+    } else {
+        name
+    }
+}
+
+fn const_name(name: &str) -> String {
+    snake(name).to_uppercase()
+}
+
+#[derive(Debug, Clone)]
+enum Type {
+    Prim(&'static str, usize),
+    Bool,
+    Char,
+    Wire(&'static str, &'static str, usize), // Rust type, reader/writer name, and size
+    Struct(String, String) // Module and name
+}
+
+fn prim(name: &str) -> Option<Type> {
+    Some(match name {
+        "CARD8" | "BYTE" | "void" => Type::Prim("u8", 1),
+        "INT8" => Type::Prim("i8", 1),
+        "CARD16" => Type::Prim("u16", 2),
+        "INT16" => Type::Prim("i16", 2),
+        "CARD32" => Type::Prim("u32", 4),
+        "INT32" => Type::Prim("i32", 4),
+        "CARD64" => Type::Prim("u64", 8),
+        "INT64" => Type::Prim("i64", 8),
+        "float" => Type::Prim("f32", 4),
+        "double" => Type::Prim("f64", 8),
+        "BOOL" => Type::Bool,
+        "char" => Type::Char,
+        _ => return None
+    })
+}
+
+/** A running byte offset: a fixed part, plus Rust expressions for anything variable */
+#[derive(Clone)]
+struct Offset {
+    fixed: usize,
+    dynamic: Vec<String>,
+    unknown: bool
+}
+
+impl Offset {
+    fn new(fixed: usize) -> Offset {
+        Offset { fixed, dynamic: Vec::new(), unknown: false }
+    }
+
+    fn expr(&self) -> String {
+        let mut parts = vec![];
+        if self.fixed != 0 || self.dynamic.is_empty() {
+            parts.push(self.fixed.to_string());
+        }
+        parts.extend(self.dynamic.iter().cloned());
+        parts.join(" + ")
+    }
+
+    /** Returns the Rust expression for the padding needed to align to `align`, or a number if it's known now */
+    fn align(&self, align: usize) -> Result<Result<usize, String>, String> {
+        if self.unknown {
+            Err(String::from("alignment after a list of variable sized items"))
+        } else if self.dynamic.is_empty() {
+            Ok(Ok((align - self.fixed % align) % align))
+        } else {
+            Ok(Err(format!("({} - ({}) % {}) % {}", align, self.expr(), align, align)))
+        }
+    }
+}
+
+/** Returns the names every expression in `items` refers to */
+fn referenced(items: &[Item]) -> HashSet<String> {
+    fn walk(expr: &Expr, out: &mut HashSet<String>) {
+        match expr {
+            Expr::Field(name) => {
+                out.insert(name.clone());
+            },
+            Expr::Op(_, a, b) => {
+                walk(a, out);
+                walk(b, out);
+            },
+            Expr::Unop(_, a) | Expr::Popcount(a) => walk(a, out),
+            Expr::Sumof(list, _) => {
+                out.insert(list.clone());
+            },
+            Expr::Value(_) | Expr::ListElement | Expr::Unsupported(_) => ()
+        }
+    }
+
+    let mut out = HashSet::new();
+    for item in items {
+        match item {
+            Item::List { len: Some(len), .. } => walk(len, &mut out),
+            Item::Switch { expr, .. } => walk(expr, &mut out),
+            _ => ()
+        }
+    }
+    out
+}
+
+/** Returns field name -> list name, for length fields that can be computed from the list */
+fn length_fields(items: &[Item]) -> HashMap<String, String> {
+    let mut out = HashMap::new();
+    for item in items {
+        if let Item::List { name, len: Some(Expr::Field(len)), .. } = item {
+            let is_field = items.iter().any(|i| match i {
+                Item::Field { name, .. } => name == len,
+                _ => false
+            });
+            if is_field && !out.contains_key(len) {
+                out.insert(len.clone(), name.clone());
+            }
+        }
+    }
+    out
+}
+
+/** Pairs of lists that share a length field, ie (stops, colors) in RENDER CreateLinearGradient. The first one's length is the one written. */
+fn shared_lengths(items: &[Item]) -> Vec<(String, String)> {
+    let lengths = length_fields(items);
+    let mut out = Vec::new();
+    for item in items {
+        if let Item::List { name, len: Some(Expr::Field(len)), .. } = item {
+            match lengths.get(len) {
+                Some(first) if first != name => out.push((first.clone(), name.clone())),
+                _ => ()
+            };
+        }
+    }
+    out
+}
+
+/** Returns true if `a / b` is rounding up, ie (num_buttons + 31) / 32 */
+fn is_round_up(a: &Expr, b: &Expr) -> bool {
+    match (a, b) {
+        (Expr::Op(op, _, n), Expr::Value(d)) => op == "+" && *d > 1 && matches!(n.as_ref(), Expr::Value(v) if *v == d - 1),
+        _ => false
+    }
+}
+
+/** Turns a length expression into Rust. Every field it refers to must be in `locals`. */
+fn expr(e: &Expr, locals: &HashSet<String>, prefix: &str) -> Result<String, String> {
+    fn wrap(s: String, e: &Expr) -> String {
+        match e {
+            Expr::Value(_) => s,
+            _ => format!("({})", s)
+        }
+    }
+
+    Ok(match e {
+        Expr::Field(name) => {
+            if !locals.contains(name) {
+                return Err(format!("a length refers to `{}`, which isn't available", name));
+            }
+            format!("{}{} as usize", prefix, field_name(name))
+        },
+        Expr::Value(v) => v.to_string(),
+        Expr::Op(op, a, b) if op == "/" && is_round_up(a, b) => {
+            // (x + n - 1) / n
+            let x = match a.as_ref() {
+                Expr::Op(_, x, _) => x,
+                _ => unreachable!()
+            };
+            format!("{}.div_ceil({})", wrap(expr(x, locals, prefix)?, x), expr(b, locals, prefix)?)
+        },
+        Expr::Op(op, a, b) => {
+            match op.as_str() {
+                "+" | "-" | "*" | "/" | "&" | "<<" => (),
+                _ => return Err(format!("operator {}", op))
+            };
+            format!("{} {} {}", wrap(expr(a, locals, prefix)?, a), op, wrap(expr(b, locals, prefix)?, b))
+        },
+        Expr::Unop(op, a) if op == "~" => format!("!{}", wrap(expr(a, locals, prefix)?, a)),
+        Expr::Unop(op, _) => return Err(format!("operator {}", op)),
+        Expr::Popcount(a) => format!("{}.count_ones() as usize", wrap(expr(a, locals, prefix)?, a)),
+        Expr::Sumof(list, element) => {
+            if !locals.contains(list) {
+                return Err(format!("a length sums `{}`, which isn't available", list));
+            }
+            let element = match element {
+                Some(e) => expr(e, locals, prefix)?,
+                None => String::from("*x as usize")
+            };
+            format!("{}{}.iter().map(|x| {}).sum::<usize>()", prefix, field_name(list), element)
+        },
+        Expr::ListElement => String::from("*x as usize"),
+        Expr::Unsupported(what) => return Err(what.clone())
+    })
+}
+
+/** Lines of Rust, and the (name, type) of every field they read */
+type ReadBody = (Vec<String>, Vec<(String, String)>);
+
+struct ReadContext {
+    reader: &'static str, // `self` or `reader`
+    optional: bool, // Can return None, so enums can be converted
+    detail: bool, // The first item is in `detail`, and the rest starts at `start`
+    start: usize,
+    remaining: bool // Lists without a length use the rest of the message
+}
+
+struct WriteContext {
+    writer: &'static str, // `self` or `client`
+    value: &'static str, // Prefix for values (`` for parameters, `self.` for struct fields)
+    start: usize
+}
+
+/** What a request body turns into */
+struct WrittenBody {
+    params: Vec<String>,
+    lines: Vec<String>,
+    fixed: usize,
+    variable: Vec<String>
+}
+
+/** A line of modules.txt */
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    pub header: String,
+    pub extra: bool, // Has a hand written src/extensions/<header>_extra.rs, which is re-exported
+    pub requests: Vec<String>, // Requests written by hand
+    pub replies: Vec<String>, // Replies read by hand, by request name
+    pub events: Vec<String> // Events read by hand
+}
+
+pub struct Protocol {
+    modules: HashMap<String, Module>,
+    generated: Vec<Options>
+}
+
+impl Protocol {
+    /** Loads the given modules and everything they import */
+    pub fn load(dir: &Path, generated: &[Options]) -> Result<Protocol, String> {
+        let mut protocol = Protocol { modules: HashMap::new(), generated: generated.to_vec() };
+        for options in generated {
+            protocol.load_module(dir, &options.header)?;
+        }
+        Ok(protocol)
+    }
+
+    fn load_module(&mut self, dir: &Path, header: &str) -> Result<(), String> {
+        if self.modules.contains_key(header) {
+            return Ok(());
+        }
+
+        let path = dir.join(format!("{}.xml", header));
+        let text = fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let root = xml::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let module = proto::module(&root).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let imports = module.imports.clone();
+        self.modules.insert(header.to_string(), module);
+
+        for import in imports {
+            self.load_module(dir, &import)?;
+        }
+        Ok(())
+    }
+
+    fn is_generated(&self, header: &str) -> bool {
+        self.generated.iter().any(|options| options.header == header)
+    }
+
+    fn ext_name(module: &Module) -> String {
+        let name = module.ext_name.clone().unwrap_or_else(|| camel(&module.header));
+        match EXTENSION_NAMES.iter().find(|(from, _)| *from == name) {
+            Some((_, to)) => to.to_string(),
+            None => name
+        }
+    }
+
+    fn lookup(&self, header: &str, name: &str) -> Option<Result<Type, String>> {
+        if let Some((_, _, rust, method, size)) = WIRE_TYPES.iter().find(|(h, n, _, _, _)| *h == header && *n == name) {
+            return Some(Ok(Type::Wire(rust, method, *size)));
+        }
+        let module = self.modules.get(header)?;
+        if let Some(old) = module.typedefs.get(name) {
+            return Some(self.resolve(header, old));
+        }
+        if module.xids.iter().any(|x| x == name) {
+            return Some(Ok(Type::Prim("u32", 4)));
+        }
+        if module.find_struct(name).is_some() {
+            return Some(Ok(Type::Struct(header.to_string(), name.to_string())));
+        }
+        None
+    }
+
+    /** Finds a type by name, looking in the module and then everything it imports */
+    fn resolve(&self, header: &str, ty: &str) -> Result<Type, String> {
+        if let Some(i) = ty.find(':') {
+            return match self.lookup(&ty[..i], &ty[i + 1..]) {
+                Some(t) => t,
+                None => Err(format!("unknown type {}", ty))
+            };
+        }
+        if let Some(t) = prim(ty) {
+            return Ok(t);
+        }
+        if ty == "fd" {
+            return Err(String::from("file descriptors"));
+        }
+
+        let mut queue = vec![header.to_string()];
+        let mut seen = HashSet::new();
+        while !queue.is_empty() {
+            let current = queue.remove(0);
+            if !seen.insert(current.clone()) {
+                continue;
+            }
+            if let Some(t) = self.lookup(&current, ty) {
+                return t;
+            }
+            if let Some(module) = self.modules.get(&current) {
+                queue.extend(module.imports.iter().cloned());
+            }
+        }
+        Err(format!("unknown type {}", ty))
+    }
+
+    fn size(&self, t: &Type) -> Option<usize> {
+        match t {
+            Type::Prim(_, size) | Type::Wire(_, _, size) => Some(*size),
+            Type::Bool | Type::Char => Some(1),
+            Type::Struct(header, name) => {
+                let s = self.modules[header].find_struct(name)?;
+                let mut size = 0;
+                for item in &s.items {
+                    size += match item {
+                        Item::Field { ty, .. } => self.size(&self.resolve(header, ty).ok()?)?,
+                        Item::Pad(n) => *n,
+                        Item::List { ty, len: Some(Expr::Value(n)), .. } => self.size(&self.resolve(header, ty).ok()?)? * *n as usize,
+                        _ => return None
+                    };
+                }
+                Some(size)
+            }
+        }
+    }
+
+    /** Structs are Copy if they are fixed size and have no lists */
+    fn is_copy(&self, t: &Type) -> bool {
+        match t {
+            Type::Struct(header, name) => {
+                let s = match self.modules[header].find_struct(name) {
+                    Some(s) => s,
+                    None => return false
+                };
+                s.items.iter().all(|item| match item {
+                    Item::Field { ty, .. } => self.resolve(header, ty).map(|t| self.is_copy(&t)).unwrap_or(false),
+                    Item::Pad(_) | Item::Align(_) => true,
+                    _ => false
+                })
+            },
+            _ => true
+        }
+    }
+
+    /** The Rust name of a type, as seen from the module `current` */
+    fn rust_type(&self, current: &str, t: &Type) -> Result<String, String> {
+        Ok(match t {
+            Type::Prim(name, _) => name.to_string(),
+            Type::Bool => String::from("bool"),
+            Type::Char => String::from("u8"),
+            Type::Wire(rust, _, _) => rust.to_string(),
+            Type::Struct(header, name) => {
+                if header == "xproto" {
+                    match MODELS_STRUCTS.iter().find(|(x, _)| x == name) {
+                        Some((_, rust)) => rust.to_string(),
+                        None => return Err(format!("xproto struct {}", name))
+                    }
+                } else {
+                    self.check_struct(header, name)?;
+                    if header == current {
+                        type_name(name)
+                    } else if self.is_generated(header) {
+                        format!("super::{}::{}", header, type_name(name))
+                    } else {
+                        return Err(format!("struct {} from {}, which isn't generated", name, header));
+                    }
+                }
+            }
+        })
+    }
+
+    /** Checks that a struct can be generated */
+    fn check_struct(&self, header: &str, name: &str) -> Result<(), String> {
+        self.generate_struct(header, name).map(|_| ())
+    }
+
+    /** Rust to read one value of a type */
+    fn read_value(&self, current: &str, t: &Type, reader: &str) -> Result<String, String> {
+        Ok(match t {
+            Type::Prim("u8", _) => format!("{}.read_u8()", reader),
+            Type::Prim("i8", _) => format!("{}.read_u8() as i8", reader),
+            Type::Prim(name, _) => format!("{}.read_{}()", reader, name),
+            Type::Bool => format!("{}.read_bool()", reader),
+            Type::Char => format!("{}.read_u8()", reader),
+            Type::Wire(_, method, _) => format!("{}.read_{}()", reader, method),
+            Type::Struct(header, name) if header == "xproto" => {
+                // Read the fields straight into the models.rs struct (they are read in the order they are written)
+                let rust = self.rust_type(current, t)?;
+                let s = self.modules[header].find_struct(name).unwrap();
+                let mut fields = Vec::new();
+                for item in &s.items {
+                    match item {
+                        Item::Field { name, ty, .. } => {
+                            let t = self.resolve(header, ty)?;
+                            fields.push(format!("{}: {}", field_name(name), self.read_value(current, &t, reader)?));
+                        },
+                        _ => return Err(format!("xproto struct {}", name))
+                    }
+                }
+                format!("{} {{ {} }}", rust, fields.join(", "))
+            },
+            Type::Struct(_, _) => format!("{}::read({})", self.rust_type(current, t)?, reader)
+        })
+    }
+
+    /** Rust to write one value of a type */
+    fn write_value(&self, t: &Type, value: &str, writer: &str) -> String {
+        match t {
+            Type::Prim(name, _) | Type::Wire(_, name, _) => format!("{}.write_{}({});", writer, name, value),
+            Type::Bool => format!("{}.write_bool({});", writer, value),
+            Type::Char => format!("{}.write_u8({});", writer, value),
+            Type::Struct(_, _) => format!("{}.write({});", value, writer)
+        }
+    }
+
+    /** Returns the enum for a field, if it is a value (not a mask) enum defined in the current module */
+    fn value_enum(&self, current: &str, enum_name: &Option<String>) -> Option<String> {
+        let name = enum_name.as_ref()?;
+        let e = self.modules[current].find_enum(name)?;
+        if e.is_mask() || e.items.is_empty() {
+            None
+        } else {
+            Some(type_name(name))
+        }
+    }
+
+    /** Reads the items of a reply, event, or struct into locals. Returns the lines and the fields to output. */
+    fn read_body(&self, current: &str, items: &[Item], ctx: &ReadContext) -> Result<ReadBody, String> {
+        let referenced = referenced(items);
+        let lengths = length_fields(items);
+        let r = ctx.reader;
+        let mut lines = Vec::new();
+        let mut fields = Vec::new();
+        let mut locals = HashSet::new();
+        let mut offset = Offset::new(ctx.start);
+
+        // Replies and generic events can use their own length, which isn't one of the items
+        let has_length = items.iter().any(|item| match item {
+            Item::Field { name, .. } => name == "length",
+            _ => false
+        });
+        if ctx.remaining && referenced.contains("length") && !has_length {
+            lines.push(format!("let length = {}.extra_length();", r));
+            locals.insert(String::from("length"));
+        }
+
+        for (i, item) in items.iter().enumerate() {
+            if ctx.detail && i == 0 {
+                match item {
+                    Item::Pad(1) => (),
+                    Item::Field { name, ty, enum_name } => {
+                        let t = self.resolve(current, ty)?;
+                        let value = match t {
+                            Type::Prim("u8", _) | Type::Char => String::from("detail"),
+                            Type::Prim("i8", _) => String::from("detail as i8"),
+                            Type::Bool => String::from("detail != 0"),
+                            _ => return Err(String::from("the first field isn't one byte"))
+                        };
+                        let local = field_name(name);
+                        if local != value {
+                            lines.push(format!("let {} = {};", local, value));
+                        }
+                        self.convert_enum(current, &local, enum_name, &referenced, name, ctx, &mut lines, &mut fields, &t)?;
+                        locals.insert(name.clone());
+                    },
+                    _ => return Err(String::from("the first field isn't one byte"))
+                }
+                continue;
+            }
+
+            match item {
+                Item::Field { name, ty, enum_name } => {
+                    let t = self.resolve(current, ty)?;
+                    let local = field_name(name);
+                    lines.push(format!("let {} = {};", local, self.read_value(current, &t, r)?));
+                    if lengths.contains_key(name) {
+                        // Only used for the length of a list
+                    } else {
+                        self.convert_enum(current, &local, enum_name, &referenced, name, ctx, &mut lines, &mut fields, &t)?;
+                    }
+                    locals.insert(name.clone());
+                    match self.size(&t) {
+                        Some(size) => offset.fixed += size,
+                        None => offset.unknown = true
+                    };
+                },
+                Item::Pad(n) => {
+                    lines.push(format!("{}.read_pad({});", r, n));
+                    offset.fixed += n;
+                },
+                Item::Align(align) => {
+                    match offset.align(*align)? {
+                        Ok(0) => (),
+                        Ok(n) => {
+                            lines.push(format!("{}.read_pad({});", r, n));
+                            offset.fixed += n;
+                        },
+                        Err(pad) => {
+                            lines.push(format!("{}.read_pad({});", r, pad));
+                            offset = Offset::new(0); // Aligned again
+                        }
+                    };
+                },
+                Item::List { name, ty, len } => {
+                    let t = self.resolve(current, ty)?;
+                    let local = field_name(name);
+                    let size = self.size(&t);
+                    let count = match (len, size) {
+                        (Some(len), _) => expr(len, &locals, "")?,
+                        (None, Some(1)) if ctx.remaining => format!("{}.remaining()", r),
+                        (None, Some(size)) if ctx.remaining => format!("{}.remaining() / {}", r, size),
+                        _ => return Err(format!("list {} has no length", name))
+                    };
+
+                    let rust = match t {
+                        Type::Char => {
+                            lines.push(format!("let {} = {}.read_str({});", local, r, count));
+                            String::from("String")
+                        },
+                        Type::Prim("u8", _) => {
+                            lines.push(format!("let {} = {}.read_raw({});", local, r, count));
+                            String::from("Vec<u8>")
+                        },
+                        _ => {
+                            lines.push(format!("let mut {} = Vec::with_capacity({});", local, count));
+                            lines.push(format!("for _ in 0..{} {{", count));
+                            lines.push(format!("    {}.push({});", local, self.read_value(current, &t, r)?));
+                            lines.push(String::from("}"));
+                            format!("Vec<{}>", self.rust_type(current, &t)?)
+                        }
+                    };
+                    match size {
+                        Some(1) => offset.dynamic.push(format!("{}.len()", local)),
+                        Some(size) => offset.dynamic.push(format!("{}.len() * {}", local, size)),
+                        None => offset.unknown = true
+                    };
+                    fields.push((local, rust));
+                    locals.insert(name.clone());
+                },
+                Item::Switch { .. } => return Err(String::from("<switch>")),
+                Item::Unsupported(what) => return Err(what.clone())
+            }
+        }
+
+        Ok((lines, fields))
+    }
+
+    /** Converts a local to an enum if possible, and adds it to the output fields */
+    #[allow(clippy::too_many_arguments)]
+    fn convert_enum(&self, current: &str, local: &str, enum_name: &Option<String>, referenced: &HashSet<String>, name: &str, ctx: &ReadContext,
+                    lines: &mut Vec<String>, fields: &mut Vec<(String, String)>, t: &Type) -> Result<(), String> {
+        let is_int = match t {
+            Type::Prim(name, _) => !name.starts_with('f'),
+            _ => false
+        };
+
+        match self.value_enum(current, enum_name) {
+            Some(e) if ctx.optional && is_int && !referenced.contains(name) => {
+                let cast = if let Type::Prim("u32", _) = t { "" } else { " as u32" };
+                lines.push(format!("let {} = match {}::get({}{}) {{", local, e, local, cast));
+                lines.push(String::from("    Some(x) => x,"));
+                lines.push(String::from("    None => return None"));
+                lines.push(String::from("};"));
+                fields.push((local.to_string(), e));
+            },
+            _ => fields.push((local.to_string(), self.rust_type(current, t)?))
+        };
+        Ok(())
+    }
+
+    /** Writes the items of a request or struct. Returns the parameters, lines, fixed length, and variable length. */
+    fn write_body(&self, current: &str, items: &[Item], ctx: &WriteContext, value_enums: &mut BTreeMap<String, String>) -> Result<WrittenBody, String> {
+        let lengths = length_fields(items);
+        let w = ctx.writer;
+        let v = ctx.value;
+        let mut body = WrittenBody { params: Vec::new(), lines: Vec::new(), fixed: 0, variable: Vec::new() };
+        let mut offset = Offset::new(ctx.start);
+        let mut skip = HashSet::new();
+        let mut switch_types = HashMap::new();
+
+        // A CARD32 mask followed by a <switch> on it is written with write_values, like the core protocol's value lists
+        for (i, item) in items.iter().enumerate() {
+            if let Item::Switch { expr: switch, .. } = item {
+                if !matches!(switch, Expr::Field(_)) {
+                    return Err(String::from("<switch> on an expression"));
+                }
+            }
+            if let Item::Switch { name, expr: Expr::Field(mask), cases } = item {
+                let mask_index = match i.checked_sub(1).map(|j| &items[j]) {
+                    Some(Item::Field { name, ty, .. }) if name == mask && self.resolve(current, ty).map(|t| self.size(&t)) == Ok(Some(4)) => i - 1,
+                    _ => return Err(String::from("<switch> not right after its CARD32 mask"))
+                };
+                skip.insert(mask_index);
+                let (type_name, code) = self.generate_value_enum(current, cases)?;
+                value_enums.insert(type_name.clone(), code);
+                switch_types.insert(name.clone(), type_name);
+            }
+        }
+
+        for (i, item) in items.iter().enumerate() {
+            if skip.contains(&i) {
+                offset.fixed += 4;
+                continue;
+            }
+
+            match item {
+                Item::Field { name, ty, enum_name } => {
+                    let t = self.resolve(current, ty)?;
+                    let local = field_name(name);
+                    let rust = self.rust_type(current, &t)?;
+
+                    if let Some(list) = lengths.get(name) {
+                        body.lines.push(self.write_value(&t, &format!("{}{}.len() as {}", v, field_name(list), rust), w));
+                    } else if let (Some(e), Type::Prim(prim, _)) = (self.value_enum(current, enum_name), &t) {
+                        if ctx.value.is_empty() {
+                            body.params.push(format!("{}: &{}", local, e));
+                            body.lines.push(self.write_value(&t, &format!("{}.val() as {}", local, prim), w));
+                        } else {
+                            body.lines.push(self.write_value(&t, &format!("{}{}", v, local), w));
+                        }
+                    } else {
+                        match t {
+                            Type::Struct(_, _) if ctx.value.is_empty() => {
+                                body.params.push(format!("{}: &{}", local, rust));
+                                body.lines.push(self.write_value(&t, &local, w));
+                            },
+                            Type::Struct(_, _) => body.lines.push(self.write_value(&t, &format!("{}{}", v, local), w)),
+                            _ => {
+                                if ctx.value.is_empty() {
+                                    body.params.push(format!("{}: {}", local, rust));
+                                }
+                                body.lines.push(self.write_value(&t, &format!("{}{}", v, local), w));
+                            }
+                        };
+                    }
+
+                    match self.size(&t) {
+                        Some(size) => offset.fixed += size,
+                        None => return Err(format!("{} isn't a fixed size", ty))
+                    };
+                },
+                Item::Pad(n) => {
+                    body.lines.push(format!("{}.write_pad({});", w, n));
+                    offset.fixed += n;
+                },
+                Item::Align(align) => {
+                    match offset.align(*align)? {
+                        Ok(0) => (),
+                        Ok(n) => {
+                            body.lines.push(format!("{}.write_pad({});", w, n));
+                            offset.fixed += n;
+                        },
+                        Err(pad) => {
+                            body.lines.push(format!("{}.write_pad_op({});", w, pad));
+                            offset.dynamic.push(pad);
+                        }
+                    };
+                },
+                Item::List { name, ty, .. } => {
+                    let t = self.resolve(current, ty)?;
+                    let local = field_name(name);
+                    let size = match self.size(&t) {
+                        Some(size) => size,
+                        None => return Err(format!("list {} of variable sized items", name))
+                    };
+                    let value = format!("{}{}", v, local);
+
+                    match t {
+                        Type::Char => {
+                            if ctx.value.is_empty() {
+                                body.params.push(format!("{}: &str", local));
+                                body.lines.push(format!("{}.write_str({});", w, value));
+                            } else {
+                                body.lines.push(format!("{}.write_str(&{});", w, value));
+                            }
+                        },
+                        Type::Prim("u8", _) => {
+                            if ctx.value.is_empty() {
+                                body.params.push(format!("{}: &[u8]", local));
+                                body.lines.push(format!("{}.write_raw({});", w, value));
+                            } else {
+                                body.lines.push(format!("{}.write_raw(&{});", w, value));
+                            }
+                        },
+                        _ => {
+                            if ctx.value.is_empty() {
+                                body.params.push(format!("{}: &Vec<{}>", local, self.rust_type(current, &t)?));
+                                body.lines.push(format!("for value in {} {{", value));
+                            } else {
+                                body.lines.push(format!("for value in &{} {{", value));
+                            }
+                            let element = match t {
+                                Type::Struct(_, _) => String::from("value"),
+                                _ => String::from("*value")
+                            };
+                            body.lines.push(format!("    {}", self.write_value(&t, &element, w)));
+                            body.lines.push(String::from("}"));
+                        }
+                    };
+
+                    let len = if size == 1 { format!("{}.len()", value) } else { format!("{}.len() * {}", value, size) };
+                    offset.dynamic.push(len);
+                },
+                Item::Switch { name, .. } => {
+                    // A parameter in XML order, so the value list comes after the fields before it
+                    if ctx.value.is_empty() {
+                        body.params.push(format!("{}: &Vec<{}>", field_name(name), switch_types[name]));
+                    }
+                    body.lines.push(format!("{}.write_values({}{}, 32);", w, v, field_name(name)));
+                    offset.dynamic.push(format!("{}{}.len() * 4", v, field_name(name)));
+                },
+                Item::Unsupported(what) => return Err(what.clone())
+            }
+        }
+
+        body.fixed = offset.fixed - ctx.start;
+        body.variable = offset.dynamic;
+        Ok(body)
+    }
+
+    /** Generates a Value enum for a <switch> value list, like WindowValue in models.rs */
+    fn generate_value_enum(&self, current: &str, cases: &[BitCase]) -> Result<(String, String), String> {
+        let enum_name = match cases.first() {
+            Some(case) => case.enum_name.clone(),
+            None => return Err(String::from("empty <switch>"))
+        };
+        let e = match self.modules[current].find_enum(&enum_name) {
+            Some(e) => e,
+            None => return Err(format!("unknown enum {}", enum_name))
+        };
+        let type_name = format!("{}Value", type_name(&enum_name));
+
+        let mut variants = Vec::new();
+        for case in cases {
+            let field = match (case.enum_name == enum_name, case.fields.as_slice()) {
+                (true, [Item::Field { ty, .. }]) => self.resolve(current, ty)?,
+                _ => return Err(String::from("<bitcase> without exactly one field"))
+            };
+            let (rust, write) = match field {
+                Type::Prim(name, size) if size <= 4 && name != "i8" && name != "f32" => (name, format!("write_val_{}(val)", name)),
+                Type::Bool => ("bool", String::from("write_val_bool(val)")),
+                _ => return Err(String::from("<bitcase> field isn't a 4 byte value"))
+            };
+            let mask = match e.items.iter().find(|(name, _)| *name == case.item) {
+                Some((_, EnumValue::Bit(bit))) => 1u32 << bit,
+                Some((_, EnumValue::Value(value))) => *value,
+                None => return Err(format!("unknown enum item {}", case.item))
+            };
+            variants.push((camel(&case.item), rust, write, mask));
+        }
+
+        let mut code = String::new();
+        code.push_str("#[derive(Debug, Copy, Clone, PartialEq)]\n");
+        code.push_str(&format!("pub enum {} {{\n", type_name));
+        code.push_str(&variants.iter().map(|(name, rust, _, _)| format!("    {}({})", name, rust)).collect::<Vec<_>>().join(",\n"));
+        code.push_str("\n}\n");
+        code.push_str(&format!("impl Value for {} {{\n", type_name));
+        code.push_str("    fn get_mask(&self) -> u32 {\n        match self {\n");
+        code.push_str(&variants.iter().map(|(name, _, _, mask)| format!("            &{}::{}(_) => 0x{:08X}", type_name, name, mask)).collect::<Vec<_>>().join(",\n"));
+        code.push_str("\n        }\n    }\n\n");
+        code.push_str("    fn write(&self, client: &mut XClient) {\n        match self {\n");
+        code.push_str(&variants.iter().map(|(name, _, write, _)| format!("            &{}::{}(val) => client.{}", type_name, name, write)).collect::<Vec<_>>().join(",\n"));
+        code.push_str("\n        }\n    }\n}\n");
+
+        Ok((type_name, code))
+    }
+
+    fn generate_enum(&self, e: &proto::Enum) -> String {
+        let name = type_name(&e.name);
+        let items: Vec<(String, u32)> = e.items.iter().filter_map(|(item, value)| match value {
+            EnumValue::Value(v) => Some((camel(item), *v)),
+            EnumValue::Bit(bit) if *bit < 32 => Some((camel(item), 1 << bit)),
+            EnumValue::Bit(_) => None
+        }).collect();
+
+        let mut code = String::new();
+        code.push_str("#[derive(Debug, Copy, Clone, PartialEq)]\n");
+        code.push_str(&format!("pub enum {} {{\n", name));
+        code.push_str(&items.iter().map(|(item, _)| format!("    {}", item)).collect::<Vec<_>>().join(",\n"));
+        code.push_str("\n}\n");
+        code.push_str(&format!("impl {} {{\n", name));
+
+        if !e.is_mask() {
+            let mut seen = HashSet::new();
+            code.push_str(&format!("    pub fn get(id: u32) -> Option<{}> {{\n        match id {{\n", name));
+            for (item, value) in &items {
+                if seen.insert(*value) {
+                    code.push_str(&format!("            {} => Some({}::{}),\n", value, name, item));
+                }
+            }
+            code.push_str("            _ => None\n        }\n    }\n\n");
+        }
+
+        code.push_str("    pub fn val(&self) -> u32 {\n        match self {\n");
+        code.push_str(&items.iter().map(|(item, value)| {
+            if e.is_mask() {
+                format!("            &{}::{} => 0x{:08X}", name, item, value)
+            } else {
+                format!("            &{}::{} => {}", name, item, value)
+            }
+        }).collect::<Vec<_>>().join(",\n"));
+        code.push_str("\n        }\n    }\n}\n");
+        code
+    }
+
+    fn generate_struct(&self, header: &str, name: &str) -> Result<String, String> {
+        let s = match self.modules[header].find_struct(name) {
+            Some(s) => s,
+            None => return Err(format!("unknown struct {}", name))
+        };
+        let rust = type_name(name);
+        let copy = self.is_copy(&Type::Struct(header.to_string(), name.to_string()));
+
+        let read = ReadContext { reader: "reader", optional: false, detail: false, start: 0, remaining: false };
+        let (read_lines, fields) = self.read_body(header, &s.items, &read)?;
+        let write = WriteContext { writer: "client", value: "self.", start: 0 };
+        let written = self.write_body(header, &s.items, &write, &mut BTreeMap::new())?;
+
+        let mut code = String::new();
+        code.push_str(if copy { "#[derive(Debug, Copy, Clone)]\n" } else { "#[derive(Debug, Clone)]\n" });
+        code.push_str(&format!("pub struct {} {{\n", rust));
+        code.push_str(&fields.iter().map(|(field, t)| format!("    pub {}: {}", field, t)).collect::<Vec<_>>().join(",\n"));
+        code.push_str("\n}\n");
+        code.push_str(&format!("impl {} {{\n", rust));
+        code.push_str(&format!("    pub fn read(reader: &mut XReadHelper) -> {} {{\n", rust));
+        for line in &read_lines {
+            code.push_str(&format!("        {}\n", line));
+        }
+        code.push_str(&format!("        {} {{ {} }}\n    }}\n\n", rust, fields.iter().map(|(f, _)| f.clone()).collect::<Vec<_>>().join(", ")));
+        code.push_str("    pub fn write(&self, client: &mut XClient) {\n");
+        for line in &written.lines {
+            code.push_str(&format!("        {}\n", line));
+        }
+        code.push_str("    }\n}\n");
+        Ok(code)
+    }
+
+    /** Generates the module for an extension */
+    pub fn generate(&self, header: &str) -> Result<String, String> {
+        let module = &self.modules[header];
+        let xname = match &module.xname {
+            Some(xname) => xname.clone(),
+            None => return Err(format!("{} isn't an extension", header))
+        };
+        let ext = Self::ext_name(module);
+        let options = self.generated.iter().find(|options| options.header == header).cloned().unwrap_or_default();
+        let extra = options.extra;
+        let subtyped = SUBTYPED_EVENTS.contains(&header);
+        let mut skipped: Vec<String> = module.unsupported.iter().map(|what| format!("{} (not understood)", what)).collect();
+
+        // Types
+        let mut types = String::new();
+        for e in &module.enums {
+            types.push('\n');
+            types.push_str(&self.generate_enum(e));
+        }
+        for s in &module.structs {
+            if WIRE_TYPES.iter().any(|(h, n, _, _, _)| *h == header && *n == s.name) {
+                continue;
+            }
+            match self.generate_struct(header, &s.name) {
+                Ok(code) => {
+                    types.push('\n');
+                    types.push_str(&code);
+                },
+                Err(why) => skipped.push(format!("struct {}: {}", s.name, why))
+            }
+        }
+
+        // Requests and replies
+        let mut value_enums = BTreeMap::new();
+        let mut requests = Vec::new();
+        let mut replies = Vec::new(); // (variant, fields, reader fn)
+        let mut reply_dispatch = Vec::new();
+        let mut hand_written_replies = Vec::new();
+
+        for request in &module.requests {
+            if options.requests.contains(&request.name) {
+                continue;
+            }
+            let method = format!("{}_{}", header, snake(&request.name));
+            let write = WriteContext { writer: "self", value: "", start: 4 };
+            let body = match self.write_body(header, &request.items, &write, &mut value_enums) {
+                Ok(body) => body,
+                Err(why) => {
+                    skipped.push(format!("request {}: {}", request.name, why));
+                    continue;
+                }
+            };
+
+            let mut code = String::new();
+            let mut doc = match &request.doc {
+                Some(doc) => doc.clone(),
+                None => format!("Sends the {} {} request", xname, request.name)
+            };
+            if request.opcode == 0 || request.name.ends_with("Version") {
+                // The request sent first, so it says what happens without the extension
+                doc = doc.trim_end_matches('.').to_string();
+                doc.push_str(&format!(". Returns None without sending anything if the server doesn't have {}, like every request here.", xname));
+            }
+            let shared = shared_lengths(&request.items);
+            if let Some((first, _)) = shared.first() {
+                let mut names: Vec<String> = shared.iter().map(|(_, other)| format!("`{}`", field_name(other))).collect();
+                names.insert(0, format!("`{}`", field_name(first)));
+                let last = names.pop().unwrap();
+                let names = if names.len() == 1 { format!("{} and {}", names[0], last) } else { format!("{}, and {}", names.join(", "), last) };
+                doc = doc.trim_end_matches('.').to_string();
+                doc.push_str(&format!(". Panics if {} aren't the same length.", names));
+            }
+            code.push_str(&format!("    /** {} */\n", doc));
+            let params = if body.params.is_empty() { String::new() } else { format!(", {}", body.params.join(", ")) };
+            let returns = if request.reply.is_some() { "Option<u16>" } else { "Option<()>" };
+            code.push_str(&format!("    pub fn {}(&mut self{}) -> {} {{\n", method, params, returns));
+            for (first, other) in &shared {
+                let (first, other) = (field_name(first), field_name(other));
+                code.push_str(&format!("        assert_eq!({}.len(), {}.len(), \"{} and {} need the same length\");\n", first, other, first, other));
+            }
+            code.push_str(&format!("        let extension_opcode = self.extension_opcode::<{}>()?;\n", ext));
+            code.push_str("        self.write_u8(extension_opcode);\n");
+            code.push_str(&format!("        self.write_u8(OP_{});\n", const_name(&request.name)));
+
+            let fixed = 4 + body.fixed;
+            let padded = if body.variable.is_empty() {
+                code.push_str(&format!("        self.write_u16({});\n", fixed.div_ceil(4)));
+                false
+            } else {
+                let mut variable = body.variable.clone();
+                if fixed % 4 != 0 {
+                    variable.insert(0, (fixed % 4).to_string());
+                }
+                code.push_str(&format!("        let pad = self.write_dynamic_len({}, {});\n", fixed / 4, variable.join(" + ")));
+                true
+            };
+            for line in &body.lines {
+                code.push_str(&format!("        {}\n", line));
+            }
+            if padded {
+                code.push_str("        self.write_pad_op(pad);\n");
+            } else if fixed % 4 != 0 {
+                code.push_str(&format!("        self.write_pad({});\n", 4 - fixed % 4));
+            }
+            code.push('\n');
+            if request.reply.is_some() {
+                code.push_str(&format!("        Some(self.write_sequence(ServerReplyType::Extension(extension_opcode, OP_{})))\n", const_name(&request.name)));
+            } else {
+                code.push_str("        self.write_request();\n        Some(())\n");
+            }
+            code.push_str("    }\n");
+            requests.push(code);
+
+            if request.reply.is_some() && options.replies.contains(&request.name) {
+                hand_written_replies.push(request.name.clone());
+            } else if let Some(reply) = &request.reply {
+                let ctx = ReadContext { reader: "self", optional: true, detail: true, start: 8, remaining: true };
+                match self.read_body(header, reply, &ctx) {
+                    Ok((lines, fields)) => {
+                        let variant = camel(&request.name);
+                        let function = format!("read_{}_{}_reply", header, snake(&request.name));
+                        let uses_detail = lines.iter().any(|l| l.contains("detail")) || fields.iter().any(|(name, _)| name == "detail");
+                        let mut code = format!("    /** Reads a reply to {} {} */\n", xname, request.name);
+                        code.push_str(&format!("    fn {}(&mut self, {}detail: u8) -> Option<ServerReply> {{\n", function, if uses_detail { "" } else { "_" }));
+                        for line in &lines {
+                            code.push_str(&format!("        {}\n", line));
+                        }
+                        code.push_str(&format!("        Some(ServerReply::{}({}Reply::{}))\n    }}\n", ext, ext, variant_value(&variant, &fields)));
+                        reply_dispatch.push(format!("OP_{} => reader.{}(detail)", const_name(&request.name), function));
+                        replies.push((variant, fields, code));
+                    },
+                    Err(why) => skipped.push(format!("reply to {} (comes back as ServerReply::Unknown): {}", request.name, why))
+                };
+            }
+        }
+
+        // Events
+        let mut events = Vec::new();
+        let mut event_dispatch = Vec::new();
+        let mut generic_dispatch = Vec::new();
+
+        let mut hand_written_events = Vec::new();
+        for event in &module.events {
+            if options.events.contains(&event.name) {
+                hand_written_events.push(event);
+                continue;
+            }
+            let ctx = if event.xge {
+                ReadContext { reader: "self", optional: true, detail: false, start: 10, remaining: true }
+            } else {
+                ReadContext { reader: "self", optional: true, detail: true, start: 4, remaining: false }
+            };
+            let (lines, fields) = match self.read_body(header, &event.items, &ctx) {
+                Ok(x) => x,
+                Err(why) => {
+                    skipped.push(format!("event {} (comes back as ServerEvent::Unknown or ServerEvent::Generic): {}", event.name, why));
+                    continue;
+                }
+            };
+            let variant = camel(&event.name);
+            let function = format!("read_{}_{}_event", header, snake(&event.name));
+            let mut code = match &event.doc {
+                Some(doc) => format!("    /** Reads a {} {} event: {} */\n", xname, event.name, doc),
+                None => format!("    /** Reads a {} {} event */\n", xname, event.name)
+            };
+
+            if event.xge {
+                code.push_str(&format!("    fn {}(&mut self) -> Option<ServerEvent> {{\n", function));
+                generic_dispatch.push(format!("GENERIC_EVENT_{} => reader.{}()", const_name(&event.name), function));
+            } else {
+                let uses_detail = lines.iter().any(|l| l.contains("detail")) || fields.iter().any(|(name, _)| name == "detail");
+                code.push_str(&format!("    fn {}(&mut self, {}detail: u8) -> Option<ServerEvent> {{\n", function, if uses_detail { "" } else { "_" }));
+                event_dispatch.push(format!("EVENT_{} => reader.{}(detail)", const_name(&event.name), function));
+            }
+            for line in &lines {
+                code.push_str(&format!("        {}\n", line));
+            }
+            code.push_str(&format!("        Some(ServerEvent::{}({}Event::{}))\n    }}\n", ext, ext, variant_value(&variant, &fields)));
+            events.push((variant, fields, code));
+        }
+
+        // Hand written readers, in src/extensions/<header>_extra.rs
+        for name in &hand_written_replies {
+            let function = format!("read_{}_{}_reply", header, snake(name));
+            reply_dispatch.push(format!("OP_{} => reader.{}(detail)", const_name(name), function));
+        }
+        for event in &hand_written_events {
+            let function = format!("read_{}_{}_event", header, snake(&event.name));
+            if event.xge {
+                generic_dispatch.push(format!("GENERIC_EVENT_{} => reader.{}()", const_name(&event.name), function));
+            } else {
+                event_dispatch.push(format!("EVENT_{} => reader.{}(detail)", const_name(&event.name), function));
+            }
+        }
+
+        // Put it all together
+        let mut out = String::new();
+        out.push_str(&format!("// {} extension, version {}.{}\n", xname, module_version(module).0, module_version(module).1));
+        out.push_str(&format!("// Generated by tools/xcbgen from tools/xcb-proto/{}.xml. Don't edit this file, change the generator or\n", header));
+        if extra {
+            out.push_str(&format!("// {}_extra.rs (which has everything written by hand) instead.\n", header));
+        } else {
+            out.push_str(&format!("// add a {}_extra.rs for anything that has to be written by hand.\n", header));
+        }
+        let mut hand_written: Vec<String> = options.requests.iter().map(|name| format!("request {}", name)).collect();
+        hand_written.extend(options.replies.iter().map(|name| format!("reply to {}", name)));
+        hand_written.extend(options.events.iter().map(|name| format!("event {}", name)));
+        if !hand_written.is_empty() {
+            out.push_str(&format!("//\n// Written by hand in {}_extra.rs:\n", header));
+            for what in &hand_written {
+                out.push_str(&format!("//   - {}\n", what));
+            }
+        }
+        if !skipped.is_empty() {
+            out.push_str("//\n// Not generated:\n");
+            for what in &skipped {
+                out.push_str(&format!("//   - {}\n", what));
+            }
+        }
+        // The generated code is written like the rest of xrb (ie `&Enum::Variant` patterns), not like clippy wants
+        out.push_str("\n#![allow(clippy::match_ref_pats, clippy::question_mark, clippy::too_many_arguments, clippy::ptr_arg)]\n");
+        out.push_str("\n{{IMPORTS}}\n");
+        if extra {
+            out.push_str("#[allow(unused_imports)] // Some extra modules only add methods\n");
+            out.push_str(&format!("pub use super::{}_extra::*;\n\n", header));
+        }
+
+        out.push_str(&format!("pub const EXTENSION_NAME: &str = \"{}\";\n", xname));
+        out.push_str(&format!("pub const MAJOR_VERSION: u32 = {};\n", module_version(module).0));
+        out.push_str(&format!("pub const MINOR_VERSION: u32 = {};\n", module_version(module).1));
+        if !module.requests.is_empty() {
+            out.push('\n');
+            for request in &module.requests {
+                out.push_str(&format!("pub const OP_{}: u8 = {};\n", const_name(&request.name), request.opcode));
+            }
+        }
+        if !module.events.is_empty() {
+            out.push('\n');
+            for event in &module.events {
+                if event.xge {
+                    out.push_str(&format!("pub const GENERIC_EVENT_{}: u16 = {};\n", const_name(&event.name), event.number));
+                } else {
+                    out.push_str(&format!("pub const EVENT_{}: u8 = {};\n", const_name(&event.name), event.number));
+                }
+            }
+        }
+        if !module.errors.is_empty() {
+            out.push('\n');
+            for error in &module.errors {
+                out.push_str(&format!("pub const ERROR_{}: u8 = {};\n", const_name(&error.name), error.number));
+            }
+        }
+
+        out.push_str(&types);
+        for code in value_enums.values() {
+            out.push('\n');
+            out.push_str(code);
+        }
+
+        let mut reply_variants: Vec<String> = replies.iter().map(|(variant, fields, _)| variant_decl(variant, fields)).collect();
+        reply_variants.extend(hand_written_replies.iter().map(|name| format!("    {}({}Reply)", camel(name), camel(name))));
+        if !reply_variants.is_empty() {
+            out.push_str(&format!("\n#[derive(Debug)]\npub enum {}Reply {{\n", ext));
+            out.push_str(&reply_variants.join(",\n"));
+            out.push_str("\n}\n");
+        }
+        let mut event_variants: Vec<String> = events.iter().map(|(variant, fields, _)| variant_decl(variant, fields)).collect();
+        event_variants.extend(hand_written_events.iter().map(|event| format!("    {}({}Event)", camel(&event.name), camel(&event.name))));
+        if !event_variants.is_empty() {
+            out.push_str(&format!("\n#[derive(Debug)]\npub enum {}Event {{\n", ext));
+            out.push_str(&event_variants.join(",\n"));
+            out.push_str("\n}\n");
+        }
+
+        out.push_str(&format!("\n/** Decodes {} replies and events */\n", xname));
+        out.push_str(&format!("#[derive(Debug, Default)]\npub struct {};\n\n", ext));
+        out.push_str(&format!("impl Extension for {} {{\n", ext));
+        out.push_str("    fn name(&self) -> &'static str {\n        EXTENSION_NAME\n    }\n");
+        let event_count = match module.events.iter().filter(|event| !event.xge).map(|event| event.number).max() {
+            Some(_) if subtyped => 1,
+            Some(last) => last as u32 + 1,
+            None => 0
+        };
+        if event_count > 0 {
+            out.push_str(&format!("\n    fn event_count(&self) -> u8 {{\n        {}\n    }}\n", event_count));
+        }
+        if let Some(last) = module.errors.iter().map(|error| error.number).max() {
+            let names: Vec<String> = (0..=last).map(|number| {
+                match module.errors.iter().find(|error| error.number == number) {
+                    Some(error) if error.name.starts_with("Bad") => format!("\"{}\"", error.name),
+                    Some(error) => format!("\"Bad{}\"", error.name),
+                    None => String::from("\"\"")
+                }
+            }).collect();
+            out.push_str(&format!("\n    fn error_names(&self) -> &'static [&'static str] {{\n        &[{}]\n    }}\n", names.join(", ")));
+        }
+        if !reply_dispatch.is_empty() {
+            out.push_str("\n    fn read_reply(&self, reader: &mut XReadHelper, minor_opcode: u8, detail: u8) -> Option<ServerReply> {\n");
+            out.push_str(&dispatch("minor_opcode", &reply_dispatch));
+            out.push_str("    }\n");
+        }
+        if !event_dispatch.is_empty() {
+            out.push_str("\n    fn read_event(&self, reader: &mut XReadHelper, code: u8, detail: u8) -> Option<ServerEvent> {\n");
+            if subtyped {
+                out.push_str(&format!("        // {} sends every event with the same code, with the kind of event in `detail`\n", xname));
+                out.push_str("        if code != 0 {\n            return None;\n        }\n");
+                out.push_str(&dispatch("detail", &event_dispatch));
+            } else {
+                out.push_str(&dispatch("code", &event_dispatch));
+            }
+            out.push_str("    }\n");
+        }
+        if !generic_dispatch.is_empty() {
+            out.push_str("\n    fn read_generic_event(&self, reader: &mut XReadHelper, evtype: u16) -> Option<ServerEvent> {\n");
+            out.push_str(&dispatch("evtype", &generic_dispatch));
+            out.push_str("    }\n");
+        }
+        out.push_str("}\n");
+
+        if !requests.is_empty() {
+            out.push_str("\nimpl XClient {\n");
+            out.push_str(&requests.join("\n"));
+            out.push_str("}\n");
+        }
+
+        let readers: Vec<&String> = replies.iter().map(|(_, _, code)| code).chain(events.iter().map(|(_, _, code)| code)).collect();
+        if !readers.is_empty() {
+            out.push_str("\nimpl XReadHelper {\n");
+            out.push_str(&readers.iter().map(|code| code.as_str()).collect::<Vec<_>>().join("\n"));
+            out.push_str("}\n");
+        }
+
+        Ok(out.replace("{{IMPORTS}}\n", &imports(&out)))
+    }
+}
+
+fn module_version(module: &Module) -> (u32, u32) {
+    (module.major_version, module.minor_version)
+}
+
+fn variant_decl(variant: &str, fields: &[(String, String)]) -> String {
+    if fields.is_empty() {
+        format!("    {}", variant)
+    } else {
+        format!("    {} {{\n{}\n    }}", variant, fields.iter().map(|(name, t)| format!("        {}: {}", name, t)).collect::<Vec<_>>().join(",\n"))
+    }
+}
+
+fn variant_value(variant: &str, fields: &[(String, String)]) -> String {
+    if fields.is_empty() {
+        variant.to_string()
+    } else {
+        format!("{} {{ {} }}", variant, fields.iter().map(|(name, _)| name.clone()).collect::<Vec<_>>().join(", "))
+    }
+}
+
+fn dispatch(on: &str, arms: &[String]) -> String {
+    let mut code = format!("        match {} {{\n", on);
+    for arm in arms {
+        code.push_str(&format!("            {},\n", arm));
+    }
+    code.push_str("            _ => None\n        }\n");
+    code
+}
+
+/** Returns true if `code` uses `name` as a whole identifier */
+fn uses(code: &str, name: &str) -> bool {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    code.match_indices(name).any(|(i, _)| {
+        let before = code[..i].chars().next_back();
+        let after = code[i + name.len()..].chars().next();
+        !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+    })
+}
+
+/** Works out the `use` lines from the generated code */
+fn imports(code: &str) -> String {
+    let mut models: Vec<&str> = ["Arc", "Point", "Rectangle", "ServerEvent", "ServerReply", "ServerReplyType"].iter()
+        .cloned().filter(|name| uses(code, name)).collect();
+    if code.contains("impl Value for") {
+        models.push("Value"); // Not just `uses`, since enums can have a variant called Value
+    }
+    let reads = ["read_u8", "read_u16", "read_u32", "read_u64", "read_i16", "read_i32", "read_i64", "read_f32", "read_f64", "read_bool",
+                 "read_str", "read_raw", "read_pad", "read_fp1616", "read_fp3232", "read_hi_lo_i64"].iter().any(|name| uses(code, name));
+    let mut readerwriter = Vec::new();
+    if reads {
+        readerwriter.push("XBufferedReader");
+    }
+    if code.contains(".write_") {
+        readerwriter.push("XBufferedWriter");
+    }
+    if uses(code, "XReadHelper") {
+        readerwriter.push("XReadHelper");
+    }
+
+    let mut out = String::new();
+    if !models.is_empty() {
+        out.push_str(&format!("use models::{{{}}};\n", models.join(", ")));
+    }
+    out.push_str("use extensions::Extension;\n");
+    if !readerwriter.is_empty() {
+        out.push_str(&format!("use xreaderwriter::{{{}}};\n", readerwriter.join(", ")));
+    }
+    if uses(code, "XClient") {
+        out.push_str("use XClient;\n");
+    }
+    out.push('\n');
+    out
+}
@@ -0,0 +1,59 @@
+// Generates the extension modules in src/extensions from the xcb-proto XML files in tools/xcb-proto.
+// The list of extensions to generate is in tools/xcbgen/modules.txt.
+
+pub mod gen;
+pub mod proto;
+pub mod xml;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::gen::Options;
+
+/** Reads modules.txt */
+pub fn modules(root: &Path) -> Result<Vec<Options>, String> {
+    let path = root.join("tools/xcbgen/modules.txt");
+    let text = fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let mut modules = Vec::new();
+
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut words = line.split_whitespace();
+        let mut options = Options { header: words.next().unwrap().to_string(), ..Options::default() };
+        for option in words {
+            let (kind, name) = match option.find(':') {
+                Some(i) => (&option[..i], option[i + 1..].to_string()),
+                None => (option, String::new())
+            };
+            match kind {
+                "extra" => (),
+                "request" => options.requests.push(name),
+                "reply" => options.replies.push(name),
+                "event" => options.events.push(name),
+                other => return Err(format!("Unknown option {} for {}", other, options.header))
+            };
+            options.extra = true;
+        }
+        modules.push(options);
+    }
+
+    Ok(modules)
+}
+
+/** Generates every module in modules.txt. Returns the path and contents of each file. */
+pub fn generate(root: &Path) -> Result<Vec<(PathBuf, String)>, String> {
+    let modules = modules(root)?;
+    let protocol = gen::Protocol::load(&root.join("tools/xcb-proto"), &modules)?;
+    let mut files = Vec::new();
+
+    for options in &modules {
+        let code = protocol.generate(&options.header).map_err(|e| format!("{}: {}", options.header, e))?;
+        files.push((root.join("src/extensions").join(format!("{}.rs", options.header)), code));
+    }
+
+    Ok(files)
+}
@@ -0,0 +1,47 @@
+// Usage (from anywhere in the repository):
+//   cargo run -p xcbgen            Regenerates src/extensions/*.rs for everything in tools/xcbgen/modules.txt
+//   cargo run -p xcbgen -- --check Fails if any generated file is out of date
+//   --root <dir> uses another checkout instead of the one xcbgen was built from
+// New modules still need a `pub mod` in src/extensions/mod.rs, and variants in ServerReply and ServerEvent.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let check = args.iter().any(|arg| arg == "--check");
+    let root = match args.iter().position(|arg| arg == "--root") {
+        Some(i) if i + 1 < args.len() => PathBuf::from(&args[i + 1]),
+        _ => Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+    };
+
+    let files = match xcbgen::generate(&root) {
+        Ok(files) => files,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let mut outdated = false;
+    for (path, code) in files {
+        let current = fs::read_to_string(&path).unwrap_or_default();
+        if current == code {
+            continue;
+        }
+
+        if check {
+            eprintln!("{} is out of date", path.display());
+            outdated = true;
+        } else {
+            fs::write(&path, code).unwrap();
+            println!("Wrote {}", path.display());
+        }
+    }
+
+    if outdated {
+        process::exit(1);
+    }
+}
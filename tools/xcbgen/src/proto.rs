@@ -0,0 +1,338 @@
+// The parts of an xcb-proto description the generator understands.
+// Anything else is kept as `Unsupported` so the generator can say why it skipped an item.
+
+use std::collections::HashMap;
+
+use crate::xml::Element;
+
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Field(String),
+    Value(u64),
+    Op(String, Box<Expr>, Box<Expr>),
+    Unop(String, Box<Expr>),
+    Popcount(Box<Expr>),
+    Sumof(String, Option<Box<Expr>>), // Sum of a list, or of an expression for each element
+    ListElement, // The current element, inside <sumof>
+    Unsupported(String)
+}
+
+#[derive(Debug, Clone)]
+pub struct BitCase {
+    pub enum_name: String,
+    pub item: String,
+    pub fields: Vec<Item>
+}
+
+#[derive(Debug, Clone)]
+pub enum Item {
+    Field { name: String, ty: String, enum_name: Option<String> },
+    Pad(usize),
+    Align(usize),
+    List { name: String, ty: String, len: Option<Expr> },
+    Switch { name: String, expr: Expr, cases: Vec<BitCase> },
+    Unsupported(String)
+}
+
+#[derive(Debug)]
+pub struct Request {
+    pub name: String,
+    pub opcode: u8,
+    pub doc: Option<String>,
+    pub items: Vec<Item>,
+    pub reply: Option<Vec<Item>>
+}
+
+#[derive(Debug)]
+pub struct Event {
+    pub name: String,
+    pub number: u8,
+    pub xge: bool,
+    pub doc: Option<String>,
+    pub items: Vec<Item>
+}
+
+#[derive(Debug)]
+pub struct Error {
+    pub name: String,
+    pub number: u8
+}
+
+#[derive(Debug)]
+pub struct Struct {
+    pub name: String,
+    pub items: Vec<Item>
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum EnumValue {
+    Value(u32),
+    Bit(u32)
+}
+
+#[derive(Debug)]
+pub struct Enum {
+    pub name: String,
+    pub items: Vec<(String, EnumValue)>
+}
+
+impl Enum {
+    /** Enums with bits are masks. They only get val(). */
+    pub fn is_mask(&self) -> bool {
+        self.items.iter().any(|(_, value)| match value {
+            EnumValue::Bit(_) => true,
+            EnumValue::Value(_) => false
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Module {
+    pub header: String,
+    pub xname: Option<String>, // The name given to QueryExtension
+    pub ext_name: Option<String>,
+    pub major_version: u32,
+    pub minor_version: u32,
+    pub imports: Vec<String>,
+    pub typedefs: HashMap<String, String>,
+    pub xids: Vec<String>,
+    pub structs: Vec<Struct>,
+    pub enums: Vec<Enum>,
+    pub requests: Vec<Request>,
+    pub events: Vec<Event>,
+    pub errors: Vec<Error>,
+    pub unsupported: Vec<String> // Top level items that were skipped
+}
+
+impl Module {
+    pub fn find_struct(&self, name: &str) -> Option<&Struct> {
+        self.structs.iter().find(|s| s.name == name)
+    }
+
+    pub fn find_enum(&self, name: &str) -> Option<&Enum> {
+        self.enums.iter().find(|e| e.name == name)
+    }
+}
+
+fn parse_u8(element: &Element, attr: &str) -> Result<u8, String> {
+    match element.attr(attr).map(|v| v.parse::<u8>()) {
+        Some(Ok(v)) => Ok(v),
+        _ => Err(format!("<{}> is missing a valid `{}`", element.name, attr))
+    }
+}
+
+fn name(element: &Element) -> Result<String, String> {
+    match element.attr("name") {
+        Some(name) => Ok(name.to_string()),
+        None => Err(format!("<{}> is missing a name", element.name))
+    }
+}
+
+fn doc(element: &Element) -> Option<String> {
+    let brief = element.child("doc")?.child("brief")?.text();
+    if brief.is_empty() {
+        None
+    } else {
+        Some(brief.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+}
+
+fn expr(element: &Element) -> Expr {
+    match element.name.as_str() {
+        "fieldref" => Expr::Field(element.text()),
+        "value" => {
+            let text = element.text();
+            let value = if let Some(hex) = text.strip_prefix("0x") {
+                u64::from_str_radix(hex, 16)
+            } else {
+                text.parse::<u64>()
+            };
+            match value {
+                Ok(v) => Expr::Value(v),
+                Err(_) => Expr::Unsupported(format!("bad value {:?}", text))
+            }
+        },
+        "op" => {
+            let mut operands = element.elements().map(expr);
+            match (element.attr("op"), operands.next(), operands.next()) {
+                (Some(op), Some(a), Some(b)) => Expr::Op(op.to_string(), Box::new(a), Box::new(b)),
+                _ => Expr::Unsupported(String::from("malformed <op>"))
+            }
+        },
+        "unop" => match (element.attr("op"), element.elements().next()) {
+            (Some(op), Some(a)) => Expr::Unop(op.to_string(), Box::new(expr(a))),
+            _ => Expr::Unsupported(String::from("malformed <unop>"))
+        },
+        "popcount" => match element.elements().next() {
+            Some(a) => Expr::Popcount(Box::new(expr(a))),
+            None => Expr::Unsupported(String::from("malformed <popcount>"))
+        },
+        "sumof" => match element.attr("ref") {
+            Some(list) => Expr::Sumof(list.to_string(), element.elements().next().map(|e| Box::new(expr(e)))),
+            None => Expr::Unsupported(String::from("malformed <sumof>"))
+        },
+        "listelement-ref" => Expr::ListElement,
+        other => Expr::Unsupported(format!("<{}>", other))
+    }
+}
+
+fn items(parent: &Element) -> Result<Vec<Item>, String> {
+    let mut out = Vec::new();
+
+    for element in parent.elements() {
+        out.push(match element.name.as_str() {
+            "field" => Item::Field {
+                name: name(element)?,
+                ty: element.attr("type").unwrap_or("").to_string(),
+                enum_name: element.attr("enum").map(String::from)
+            },
+            "pad" => match (element.attr("bytes"), element.attr("align")) {
+                (Some(bytes), _) => Item::Pad(bytes.parse().map_err(|_| String::from("bad pad"))?),
+                (None, Some(align)) => Item::Align(align.parse().map_err(|_| String::from("bad pad"))?),
+                _ => return Err(String::from("<pad> without bytes or align"))
+            },
+            "list" => Item::List {
+                name: name(element)?,
+                ty: element.attr("type").unwrap_or("").to_string(),
+                len: element.elements().next().map(expr)
+            },
+            "switch" => {
+                let mut cases = Vec::new();
+                let mut switch_expr = None;
+                let mut supported = true;
+
+                for child in element.elements() {
+                    match child.name.as_str() {
+                        "bitcase" => {
+                            let mut enumrefs = child.elements().filter(|e| e.name == "enumref");
+                            match (enumrefs.next(), enumrefs.next()) {
+                                (Some(enumref), None) => {
+                                    cases.push(BitCase {
+                                        enum_name: enumref.attr("ref").unwrap_or("").to_string(),
+                                        item: enumref.text(),
+                                        fields: items(child)?
+                                    });
+                                },
+                                _ => supported = false
+                            }
+                        },
+                        "case" => supported = false,
+                        _ if switch_expr.is_none() => switch_expr = Some(expr(child)),
+                        _ => ()
+                    }
+                }
+
+                match switch_expr {
+                    Some(expr) if supported => Item::Switch { name: name(element)?, expr, cases },
+                    _ => Item::Unsupported(String::from("<switch> with <case>"))
+                }
+            },
+            "doc" | "required_start_align" | "enumref" => continue,
+            other => Item::Unsupported(format!("<{}>", other))
+        });
+    }
+
+    Ok(out)
+}
+
+fn enum_items(element: &Element) -> Result<Vec<(String, EnumValue)>, String> {
+    let mut values = Vec::new();
+    let mut next = 0;
+
+    for item in element.elements().filter(|e| e.name == "item") {
+        let value = if let Some(value) = item.child("value") {
+            EnumValue::Value(value.text().parse().map_err(|_| format!("bad enum value in {}", name(element).unwrap_or_default()))?)
+        } else if let Some(bit) = item.child("bit") {
+            EnumValue::Bit(bit.text().parse().map_err(|_| format!("bad enum bit in {}", name(element).unwrap_or_default()))?)
+        } else {
+            EnumValue::Value(next)
+        };
+        if let EnumValue::Value(v) = value {
+            next = v + 1;
+        }
+        values.push((name(item)?, value));
+    }
+
+    Ok(values)
+}
+
+/** Reads a module from the root <xcb> element */
+pub fn module(root: &Element) -> Result<Module, String> {
+    let mut module = Module {
+        header: root.attr("header").unwrap_or("").to_string(),
+        xname: root.attr("extension-xname").map(String::from),
+        ext_name: root.attr("extension-name").map(String::from),
+        major_version: root.attr("major-version").and_then(|v| v.parse().ok()).unwrap_or(0),
+        minor_version: root.attr("minor-version").and_then(|v| v.parse().ok()).unwrap_or(0),
+        imports: Vec::new(),
+        typedefs: HashMap::new(),
+        xids: Vec::new(),
+        structs: Vec::new(),
+        enums: Vec::new(),
+        requests: Vec::new(),
+        events: Vec::new(),
+        errors: Vec::new(),
+        unsupported: Vec::new()
+    };
+    let mut event_copies = Vec::new();
+    let mut error_copies = Vec::new();
+
+    for element in root.elements() {
+        match element.name.as_str() {
+            "import" => module.imports.push(element.text()),
+            "typedef" => {
+                if let (Some(old), Some(new)) = (element.attr("oldname"), element.attr("newname")) {
+                    module.typedefs.insert(new.to_string(), old.to_string());
+                }
+            },
+            "xidtype" | "xidunion" => module.xids.push(name(element)?),
+            "struct" => module.structs.push(Struct { name: name(element)?, items: items(element)? }),
+            "enum" => module.enums.push(Enum { name: name(element)?, items: enum_items(element)? }),
+            "request" => {
+                let reply = match element.child("reply") {
+                    Some(reply) => Some(items(reply)?),
+                    None => None
+                };
+                let request_items = items(element)?.into_iter().filter(|item| match item {
+                    Item::Unsupported(what) => what != "<reply>",
+                    _ => true
+                }).collect();
+                module.requests.push(Request {
+                    name: name(element)?,
+                    opcode: parse_u8(element, "opcode")?,
+                    doc: doc(element),
+                    items: request_items,
+                    reply
+                });
+            },
+            "event" => module.events.push(Event {
+                name: name(element)?,
+                number: parse_u8(element, "number")?,
+                xge: element.attr("xge") == Some("true"),
+                doc: doc(element),
+                items: items(element)?
+            }),
+            "eventcopy" => event_copies.push((name(element)?, parse_u8(element, "number")?, element.attr("ref").unwrap_or("").to_string())),
+            "error" => module.errors.push(Error { name: name(element)?, number: parse_u8(element, "number")? }),
+            "errorcopy" => error_copies.push((name(element)?, parse_u8(element, "number")?)),
+            other => module.unsupported.push(format!("<{}> {}", other, element.attr("name").unwrap_or("")))
+        }
+    }
+
+    // Copies have the same layout as the event they reference
+    for (name, number, reference) in event_copies {
+        let (xge, items) = match module.events.iter().find(|e| e.name == reference) {
+            Some(event) => (event.xge, event.items.clone()),
+            None => {
+                module.unsupported.push(format!("<eventcopy> {} (unknown event {})", name, reference));
+                continue;
+            }
+        };
+        module.events.push(Event { name, number, xge, doc: None, items });
+    }
+    for (name, number) in error_copies {
+        module.errors.push(Error { name, number });
+    }
+
+    Ok(module)
+}
@@ -0,0 +1,190 @@
+// A very small XML parser. It only handles what the xcb-proto files use:
+// elements, attributes, text, CDATA, comments, and the XML declaration.
+
+#[derive(Debug)]
+pub enum Node {
+    Element(Element),
+    Text(String)
+}
+
+#[derive(Debug)]
+pub struct Element {
+    pub name: String,
+    pub attrs: Vec<(String, String)>,
+    pub children: Vec<Node>
+}
+
+impl Element {
+    /** Returns the value of an attribute */
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str())
+    }
+
+    /** Returns the child elements, skipping text */
+    pub fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|node| match node {
+            Node::Element(element) => Some(element),
+            Node::Text(_) => None
+        })
+    }
+
+    /** Returns the first child element with the given name */
+    pub fn child(&self, name: &str) -> Option<&Element> {
+        self.elements().find(|element| element.name == name)
+    }
+
+    /** Returns all of the text inside this element, trimmed */
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        for node in &self.children {
+            match node {
+                Node::Text(t) => text.push_str(t),
+                Node::Element(element) => text.push_str(&element.text())
+            }
+        }
+        text.trim().to_string()
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize
+}
+
+/** Parses a document and returns the root element */
+pub fn parse(input: &str) -> Result<Element, String> {
+    let mut parser = Parser { input, pos: 0 };
+    parser.skip_misc()?;
+    let root = parser.element()?;
+    parser.skip_misc()?;
+    if parser.pos != parser.input.len() {
+        return Err(format!("Unexpected content after the root element at {}", parser.pos));
+    }
+    Ok(root)
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn skip_until(&mut self, end: &str) -> Result<(), String> {
+        match self.rest().find(end) {
+            Some(i) => {
+                self.pos += i + end.len();
+                Ok(())
+            },
+            None => Err(format!("Expected {:?} after {}", end, self.pos))
+        }
+    }
+
+    /** Skips whitespace, comments, and declarations */
+    fn skip_misc(&mut self) -> Result<(), String> {
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("<?") {
+                self.skip_until("?>")?;
+            } else if self.rest().starts_with("<!--") {
+                self.skip_until("-->")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<String, String> {
+        let rest = self.rest();
+        let len = rest.find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-' || c == ':' || c == '.')).unwrap_or(rest.len());
+        if len == 0 {
+            return Err(format!("Expected a name at {}", self.pos));
+        }
+        self.pos += len;
+        Ok(rest[..len].to_string())
+    }
+
+    fn expect(&mut self, s: &str) -> Result<(), String> {
+        if self.rest().starts_with(s) {
+            self.pos += s.len();
+            Ok(())
+        } else {
+            Err(format!("Expected {:?} at {}", s, self.pos))
+        }
+    }
+
+    fn element(&mut self) -> Result<Element, String> {
+        self.expect("<")?;
+        let name = self.name()?;
+        let mut attrs = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.pos += 2;
+                return Ok(Element { name, attrs, children: Vec::new() });
+            }
+            if self.rest().starts_with('>') {
+                self.pos += 1;
+                break;
+            }
+
+            let key = self.name()?;
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let quote = match self.rest().chars().next() {
+                Some(q) if q == '"' || q == '\'' => q,
+                _ => return Err(format!("Expected a quoted attribute value at {}", self.pos))
+            };
+            self.pos += 1;
+            let len = match self.rest().find(quote) {
+                Some(len) => len,
+                None => return Err(format!("Unterminated attribute value at {}", self.pos))
+            };
+            let value = unescape(&self.rest()[..len]);
+            self.pos += len + 1;
+            attrs.push((key, value));
+        }
+
+        let mut children = Vec::new();
+        loop {
+            if self.rest().starts_with("</") {
+                self.pos += 2;
+                let end = self.name()?;
+                if end != name {
+                    return Err(format!("Expected </{}> but found </{}> at {}", name, end, self.pos));
+                }
+                self.skip_whitespace();
+                self.expect(">")?;
+                return Ok(Element { name, attrs, children });
+            } else if self.rest().starts_with("<!--") {
+                self.skip_until("-->")?;
+            } else if self.rest().starts_with("<![CDATA[") {
+                self.pos += "<![CDATA[".len();
+                let len = match self.rest().find("]]>") {
+                    Some(len) => len,
+                    None => return Err(format!("Unterminated CDATA in <{}>", name))
+                };
+                children.push(Node::Text(self.rest()[..len].to_string()));
+                self.pos += len + 3;
+            } else if self.rest().starts_with('<') {
+                children.push(Node::Element(self.element()?));
+            } else {
+                let len = match self.rest().find('<') {
+                    Some(len) => len,
+                    None => return Err(format!("Unterminated element <{}>", name))
+                };
+                children.push(Node::Text(unescape(&self.rest()[..len])));
+                self.pos += len;
+            }
+        }
+    }
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<").replace("&gt;", ">").replace("&quot;", "\"").replace("&apos;", "'").replace("&amp;", "&")
+}
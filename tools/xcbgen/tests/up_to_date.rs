@@ -0,0 +1,23 @@
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn generated_files_are_up_to_date() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    for (path, code) in xcbgen::generate(&root).unwrap() {
+        let current = fs::read_to_string(&path).unwrap_or_default();
+        assert!(current == code, "{} is out of date, run `cargo run -p xcbgen`", path.display());
+    }
+}
+
+#[test]
+fn names() {
+    assert_eq!(xcbgen::gen::snake("GetXIDRange"), "get_xid_range");
+    assert_eq!(xcbgen::gen::snake("XIQueryVersion"), "xi_query_version");
+    assert_eq!(xcbgen::gen::camel("SYSTEMCOUNTER"), "Systemcounter");
+    assert_eq!(xcbgen::gen::camel("Rotate_0"), "Rotate0");
+    assert_eq!(xcbgen::gen::camel("1_0"), "N10");
+    assert_eq!(xcbgen::gen::type_name("SYSTEMCOUNTER"), "SystemCounter");
+    assert_eq!(xcbgen::gen::type_name("GrabMode22"), "GrabMode");
+    assert_eq!(xcbgen::gen::type_name("Option"), "Options");
+}